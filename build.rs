@@ -15,9 +15,10 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use std::{
+    fmt::Write,
     fs::File,
     io::{BufRead, BufReader},
-    path::Path,
+    path::{Path, PathBuf},
 };
 
 use walkdir::WalkDir;
@@ -75,8 +76,79 @@ fn check_file_licenses<P: AsRef<Path>>(path: P) {
     println!("cargo:rerun-if-changed=.");
 }
 
-// The build script; it currently only checks the licenses.
+// The files and directories of an example that are not bundled with it, as in `leo/cli/helpers/templates.rs`.
+const EXAMPLE_FILES_TO_SKIP: [&str; 4] = [".git", ".env", "build", "outputs"];
+
+// Returns the files of the example package in `directory`, by their path relative to it.
+// The packages nested in the example, e.g. its imports, are not part of it.
+fn example_files(directory: &Path) -> Vec<(String, PathBuf)> {
+    let mut files = Vec::new();
+    let mut iter = WalkDir::new(directory).sort_by_file_name().into_iter();
+    while let Some(entry) = iter.next() {
+        let entry = entry.unwrap();
+        let name = entry.file_name().to_str().unwrap_or("");
+        let is_nested_package = entry.depth() > 0 && entry.path().join("program.json").exists();
+        if EXAMPLE_FILES_TO_SKIP.contains(&name) || is_nested_package {
+            if entry.file_type().is_dir() {
+                iter.skip_current_dir();
+            }
+            continue;
+        }
+        if entry.file_type().is_file() {
+            let relative = entry.path().strip_prefix(directory).unwrap();
+            let relative =
+                relative.components().map(|component| component.as_os_str().to_string_lossy()).collect::<Vec<_>>();
+            files.push((relative.join("/"), entry.path().to_path_buf()));
+        }
+    }
+    files
+}
+
+// Writes the `EXAMPLES` that `leo example` creates packages from, one for each package in `examples/`.
+// An example is named after its directory, and described by the description in its manifest.
+fn generate_examples() {
+    let examples_directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+    let mut examples = Vec::new();
+    let mut iter = WalkDir::new(&examples_directory).sort_by_file_name().into_iter();
+    while let Some(entry) = iter.next() {
+        let entry = entry.unwrap();
+        let manifest = entry.path().join("program.json");
+        if !entry.file_type().is_dir() || !manifest.exists() {
+            continue;
+        }
+        iter.skip_current_dir();
+
+        let manifest: serde_json::Value = serde_json::from_reader(File::open(&manifest).unwrap())
+            .unwrap_or_else(|_| panic!("Can't parse the manifest \"{}\"!", manifest.display()));
+        let name = entry.file_name().to_str().unwrap().to_string();
+        let about = manifest["description"].as_str().unwrap_or("").to_string();
+        examples.push((name, about, example_files(entry.path())));
+    }
+    examples.sort_by(|(a, ..), (b, ..)| a.cmp(b));
+
+    let mut output = String::from("pub const EXAMPLES: &[BundledExample] = &[\n");
+    for (name, about, files) in examples {
+        writeln!(
+            output,
+            "    BundledExample {{\n        name: {name:?},\n        about: {about:?},\n        files: &["
+        )
+        .unwrap();
+        for (relative, path) in files {
+            writeln!(output, "            ({relative:?}, include_bytes!({:?})),", path.display().to_string()).unwrap();
+        }
+        output.push_str("        ],\n    },\n");
+    }
+    output.push_str("];\n");
+
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR").unwrap());
+    std::fs::write(out_dir.join("examples.rs"), output).unwrap();
+}
+
+// The build script; it checks the licenses and bundles the examples.
 fn main() {
     // Check licenses in the current folder.
     check_file_licenses(".");
+
+    // Bundle the examples with `leo example`.
+    generate_examples();
 }
@@ -26,5 +26,8 @@ pub use associated_function_access::*;
 mod member_access;
 pub use member_access::*;
 
+mod method_call_access;
+pub use method_call_access::*;
+
 mod tuple_access;
 pub use tuple_access::*;
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod variant;
+pub use variant::*;
+
+use crate::{Identifier, IntegerType, Member, Mode, Node, NodeID, Struct, Type};
+use leo_span::{sym, Span, Symbol};
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// An enum type definition, e.g., `enum Shape { Empty, Circle(u32), Rectangle(u32, u32) }`.
+///
+/// Enums are lowered into structs before code generation.
+/// The lowered struct has the same name as the enum, a `tag: u8` member holding the index of the variant,
+/// and one member per payload element of each variant, e.g. `Circle_0: u32`.
+/// The payload members of the variants that are not held by the value are zero.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Enum {
+    /// The name of the type in the type system in this module.
+    pub identifier: Identifier,
+    /// The variants of the enum, in declaration order.
+    pub variants: Vec<EnumVariant>,
+    /// The entire span of the enum definition.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl PartialEq for Enum {
+    fn eq(&self, other: &Self) -> bool {
+        self.identifier == other.identifier
+    }
+}
+
+impl Eq for Enum {}
+
+impl Enum {
+    /// Returns the enum name as a Symbol.
+    pub fn name(&self) -> Symbol {
+        self.identifier.name
+    }
+
+    /// Returns the variant with the given name, if it exists.
+    pub fn variant(&self, name: Symbol) -> Option<&EnumVariant> {
+        self.variants.iter().find(|variant| variant.name() == name)
+    }
+
+    /// Returns the tag of the variant with the given name, if it exists.
+    pub fn tag(&self, name: Symbol) -> Option<usize> {
+        self.variants.iter().position(|variant| variant.name() == name)
+    }
+
+    /// Returns the name of the lowered struct member holding the `index`-th payload element of a variant.
+    pub fn payload_member(variant: Symbol, index: usize) -> Symbol {
+        Symbol::intern(&format!("{variant}_{index}"))
+    }
+
+    /// Returns the struct this enum is lowered into.
+    pub fn to_struct(&self) -> Struct {
+        let tag = Member {
+            mode: Mode::None,
+            identifier: Identifier::new(sym::tag, Default::default()),
+            type_: Type::Integer(IntegerType::U8),
+            span: Default::default(),
+            id: Default::default(),
+        };
+        let payload = self.variants.iter().flat_map(|variant| {
+            variant.payload.iter().enumerate().map(|(index, type_)| Member {
+                mode: Mode::None,
+                identifier: Identifier::new(Self::payload_member(variant.name(), index), Default::default()),
+                type_: type_.clone(),
+                span: Default::default(),
+                id: Default::default(),
+            })
+        });
+
+        Struct {
+            identifier: self.identifier,
+            generic_parameters: Vec::new(),
+            members: std::iter::once(tag).chain(payload).collect(),
+            is_record: false,
+            span: self.span,
+            id: self.id,
+        }
+    }
+}
+
+impl fmt::Debug for Enum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        <Self as fmt::Display>::fmt(self, f)
+    }
+}
+
+impl fmt::Display for Enum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "enum {} {{ ", self.identifier)?;
+        for variant in self.variants.iter() {
+            writeln!(f, "    {variant}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+crate::simple_node_impl!(Enum);
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{GroupLiteral, IntegerType};
+use crate::{GroupLiteral, IntegerType, Type};
 
 use super::*;
 
@@ -39,6 +39,9 @@ pub enum Literal {
     Scalar(String, #[serde(with = "leo_span::span_json")] Span, NodeID),
     /// A string literal, e.g., `"foobar"`.
     String(String, #[serde(with = "leo_span::span_json")] Span, NodeID),
+    /// A number without a type suffix, e.g., `42`.
+    /// Its type is inferred from the context it is used in.
+    Unsuffixed(String, #[serde(with = "leo_span::span_json")] Span, NodeID),
 }
 
 impl fmt::Display for Literal {
@@ -51,6 +54,7 @@ impl fmt::Display for Literal {
             Self::Integer(type_, value, _, _) => write!(f, "{value}{type_}"),
             Self::Scalar(scalar, _, _) => write!(f, "{scalar}scalar"),
             Self::String(string, _, _) => write!(f, "\"{string}\""),
+            Self::Unsuffixed(value, _, _) => write!(f, "{value}"),
         }
     }
 }
@@ -63,7 +67,8 @@ impl Node for Literal {
             | Self::Field(_, span, _)
             | Self::Integer(_, _, span, _)
             | Self::Scalar(_, span, _)
-            | Self::String(_, span, _) => *span,
+            | Self::String(_, span, _)
+            | Self::Unsuffixed(_, span, _) => *span,
             Self::Group(group) => *group.span(),
         }
     }
@@ -75,7 +80,8 @@ impl Node for Literal {
             | Self::Field(_, span, _)
             | Self::Integer(_, _, span, _)
             | Self::Scalar(_, span, _)
-            | Self::String(_, span, _) => *span = new_span,
+            | Self::String(_, span, _)
+            | Self::Unsuffixed(_, span, _) => *span = new_span,
             Self::Group(group) => group.set_span(new_span),
         }
     }
@@ -87,7 +93,8 @@ impl Node for Literal {
             | Self::Field(_, _, id)
             | Self::Integer(_, _, _, id)
             | Self::Scalar(_, _, id)
-            | Self::String(_, _, id) => *id,
+            | Self::String(_, _, id)
+            | Self::Unsuffixed(_, _, id) => *id,
             Self::Group(group) => *group.id(),
         }
     }
@@ -99,8 +106,27 @@ impl Node for Literal {
             | Self::Field(_, _, old_id)
             | Self::Integer(_, _, _, old_id)
             | Self::Scalar(_, _, old_id)
-            | Self::String(_, _, old_id) => *old_id = id,
+            | Self::String(_, _, old_id)
+            | Self::Unsuffixed(_, _, old_id) => *old_id = id,
             Self::Group(group) => group.set_id(id),
         }
     }
 }
+
+impl Literal {
+    /// Returns the literal with the given type, if it is an unsuffixed literal of a numeric type.
+    /// Otherwise, the literal is returned unchanged.
+    pub fn with_type(self, type_: &Type) -> Self {
+        match (self, type_) {
+            (Self::Unsuffixed(value, span, id), Type::Integer(integer_type)) => {
+                Self::Integer(*integer_type, value, span, id)
+            }
+            (Self::Unsuffixed(value, span, id), Type::Field) => Self::Field(value, span, id),
+            (Self::Unsuffixed(value, span, id), Type::Group) => {
+                Self::Group(Box::new(GroupLiteral::Single(value, span, id)))
+            }
+            (Self::Unsuffixed(value, span, id), Type::Scalar) => Self::Scalar(value, span, id),
+            (literal, _) => literal,
+        }
+    }
+}
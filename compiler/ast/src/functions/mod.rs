@@ -38,7 +38,7 @@ pub use output::*;
 pub mod mode;
 pub use mode::*;
 
-use crate::{Block, Identifier, Node, NodeID, TupleType, Type};
+use crate::{Block, GenericParameter, Identifier, Node, NodeID, TupleType, Type};
 use leo_span::{sym, Span, Symbol};
 
 use serde::{Deserialize, Serialize};
@@ -53,6 +53,9 @@ pub struct Function {
     pub variant: Variant,
     /// The function identifier, e.g., `foo` in `function foo(...) { ... }`.
     pub identifier: Identifier,
+    /// The function's type parameters, e.g., `T` in `inline max<T>(a: T, b: T) -> T { ... }`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub generic_parameters: Vec<GenericParameter>,
     /// The function's input parameters.
     pub input: Vec<Input>,
     /// The function's output declarations.
@@ -84,6 +87,7 @@ impl Function {
         annotations: Vec<Annotation>,
         variant: Variant,
         identifier: Identifier,
+        generic_parameters: Vec<GenericParameter>,
         input: Vec<Input>,
         output: Vec<Output>,
         block: Block,
@@ -103,7 +107,19 @@ impl Function {
             _ => Type::Tuple(TupleType::new(output.iter().map(get_output_type).collect())),
         };
 
-        Function { annotations, variant, identifier, input, output, output_type, block, finalize, span, id }
+        Function {
+            annotations,
+            variant,
+            identifier,
+            generic_parameters,
+            input,
+            output,
+            output_type,
+            block,
+            finalize,
+            span,
+            id,
+        }
     }
 
     /// Returns function name.
@@ -111,11 +127,26 @@ impl Function {
         self.identifier.name
     }
 
+    /// Returns `true` if the function has type parameters.
+    pub fn is_generic(&self) -> bool {
+        !self.generic_parameters.is_empty()
+    }
+
     /// Returns `true` if the function name is `main`.
     pub fn is_main(&self) -> bool {
         self.name() == sym::main
     }
 
+    /// Returns `true` if the function is a method, i.e. its first input is `self`.
+    pub fn is_method(&self) -> bool {
+        self.input.first().map_or(false, |input| input.identifier().name == sym::SelfLower)
+    }
+
+    /// Returns `true` if the function is annotated with `@test`.
+    pub fn is_test(&self) -> bool {
+        self.annotations.iter().any(|annotation| annotation.identifier.name == sym::test)
+    }
+
     ///
     /// Private formatting method used for optimizing [fmt::Debug] and [fmt::Display] implementations.
     ///
@@ -126,6 +157,9 @@ impl Function {
             Variant::Transition => write!(f, "transition ")?,
         }
         write!(f, "{}", self.identifier)?;
+        if self.is_generic() {
+            write!(f, "<{}>", self.generic_parameters.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", "))?;
+        }
 
         let parameters = self.input.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",");
         let returns = match self.output.len() {
@@ -0,0 +1,49 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{ArrayLength, Type};
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The argument bound to a parameter of a generic function or struct when it is instantiated.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GenericArgument {
+    /// The type bound to a type parameter, e.g. `u32` for `T`.
+    Type(Type),
+    /// The array length bound to a const parameter, e.g. `8` for `N`.
+    Const(ArrayLength),
+}
+
+impl GenericArgument {
+    /// Returns `true` if the two arguments are the same type, or the same array length.
+    pub fn eq_flat(&self, other: &Self) -> bool {
+        match (self, other) {
+            (GenericArgument::Type(left), GenericArgument::Type(right)) => left.eq_flat(right),
+            (GenericArgument::Const(left), GenericArgument::Const(right)) => left.eq_flat(right),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for GenericArgument {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GenericArgument::Type(type_) => write!(f, "{type_}"),
+            GenericArgument::Const(length) => write!(f, "{length}"),
+        }
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{IntegerType, Type};
+use leo_span::Symbol;
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A bound on a generic type parameter, e.g. `Int` in `T: Int`.
+///
+/// The bounds mirror the categories of types that the operators of the language accept,
+/// so that the body of a generic function can be checked once for every type it may be instantiated with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GenericBound {
+    /// Any integer type.
+    Int,
+    /// Any signed integer type.
+    SignedInt,
+    /// Any unsigned integer type.
+    UnsignedInt,
+    /// The magnitude types `u8`, `u16`, and `u32`.
+    Magnitude,
+    /// The `bool` type or any integer type.
+    BoolInt,
+    /// The `field` type or any integer type.
+    FieldInt,
+    /// The `field` or `group` types.
+    FieldGroup,
+    /// The `field` or `group` types, or any integer type.
+    FieldGroupInt,
+    /// The `field` or `group` types, or any signed integer type.
+    FieldGroupSignedInt,
+    /// The `field` or `scalar` types, or any integer type.
+    FieldScalarInt,
+    /// The `field`, `group`, or `scalar` types, or any integer type.
+    FieldGroupScalarInt,
+}
+
+const SIGNED_INT_TYPES: [IntegerType; 5] =
+    [IntegerType::I8, IntegerType::I16, IntegerType::I32, IntegerType::I64, IntegerType::I128];
+
+const UNSIGNED_INT_TYPES: [IntegerType; 5] =
+    [IntegerType::U8, IntegerType::U16, IntegerType::U32, IntegerType::U64, IntegerType::U128];
+
+const MAGNITUDE_TYPES: [IntegerType; 3] = [IntegerType::U8, IntegerType::U16, IntegerType::U32];
+
+impl GenericBound {
+    /// Returns the bound with the given name, if it exists.
+    pub fn from_symbol(symbol: Symbol) -> Option<Self> {
+        Some(match symbol.to_string().as_str() {
+            "Int" => Self::Int,
+            "SignedInt" => Self::SignedInt,
+            "UnsignedInt" => Self::UnsignedInt,
+            "Magnitude" => Self::Magnitude,
+            "BoolInt" => Self::BoolInt,
+            "FieldInt" => Self::FieldInt,
+            "FieldGroup" => Self::FieldGroup,
+            "FieldGroupInt" => Self::FieldGroupInt,
+            "FieldGroupSignedInt" => Self::FieldGroupSignedInt,
+            "FieldScalarInt" => Self::FieldScalarInt,
+            "FieldGroupScalarInt" => Self::FieldGroupScalarInt,
+            _ => return None,
+        })
+    }
+
+    /// Returns the types satisfying the bound.
+    pub fn types(&self) -> Vec<Type> {
+        let signed = SIGNED_INT_TYPES.into_iter().map(Type::Integer);
+        let unsigned = UNSIGNED_INT_TYPES.into_iter().map(Type::Integer);
+        let integers = signed.clone().chain(unsigned.clone());
+        match self {
+            Self::Int => integers.collect(),
+            Self::SignedInt => signed.collect(),
+            Self::UnsignedInt => unsigned.collect(),
+            Self::Magnitude => MAGNITUDE_TYPES.into_iter().map(Type::Integer).collect(),
+            Self::BoolInt => std::iter::once(Type::Boolean).chain(integers).collect(),
+            Self::FieldInt => std::iter::once(Type::Field).chain(integers).collect(),
+            Self::FieldGroup => vec![Type::Field, Type::Group],
+            Self::FieldGroupInt => [Type::Field, Type::Group].into_iter().chain(integers).collect(),
+            Self::FieldGroupSignedInt => [Type::Field, Type::Group].into_iter().chain(signed).collect(),
+            Self::FieldScalarInt => [Type::Field, Type::Scalar].into_iter().chain(integers).collect(),
+            Self::FieldGroupScalarInt => [Type::Field, Type::Group, Type::Scalar].into_iter().chain(integers).collect(),
+        }
+    }
+
+    /// Returns `true` if the given type satisfies the bound.
+    pub fn is_satisfied_by(&self, type_: &Type) -> bool {
+        self.types().iter().any(|bound_type| bound_type.eq_flat(type_))
+    }
+}
+
+impl fmt::Display for GenericBound {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Int => write!(f, "Int"),
+            Self::SignedInt => write!(f, "SignedInt"),
+            Self::UnsignedInt => write!(f, "UnsignedInt"),
+            Self::Magnitude => write!(f, "Magnitude"),
+            Self::BoolInt => write!(f, "BoolInt"),
+            Self::FieldInt => write!(f, "FieldInt"),
+            Self::FieldGroup => write!(f, "FieldGroup"),
+            Self::FieldGroupInt => write!(f, "FieldGroupInt"),
+            Self::FieldGroupSignedInt => write!(f, "FieldGroupSignedInt"),
+            Self::FieldScalarInt => write!(f, "FieldScalarInt"),
+            Self::FieldGroupScalarInt => write!(f, "FieldGroupScalarInt"),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod argument;
+pub use argument::*;
+
+pub mod bound;
+pub use bound::*;
+
+use crate::{Identifier, Node, NodeID, Type};
+use leo_span::{Span, Symbol};
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A parameter of a generic function or struct.
+/// A type parameter is written `T` or `T: Int`, as in `inline max<T: Int>(a: T, b: T) -> T`,
+/// and a const parameter is written `const N: u32`, as in `inline sum<const N: u32>(a: [u32; N]) -> u32`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GenericParameter {
+    /// The name of the type parameter.
+    pub identifier: Identifier,
+    /// The bound restricting the types the parameter can be instantiated with, if any.
+    pub bound: Option<GenericBound>,
+    /// The type of a const parameter. Type parameters do not have one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub const_type: Option<Type>,
+    /// The span of the parameter, including its bound.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl GenericParameter {
+    /// Returns the name of the type parameter.
+    pub fn name(&self) -> Symbol {
+        self.identifier.name
+    }
+
+    /// Returns `true` if this is a const parameter.
+    pub fn is_const(&self) -> bool {
+        self.const_type.is_some()
+    }
+}
+
+impl fmt::Display for GenericParameter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (&self.const_type, &self.bound) {
+            (Some(type_), _) => write!(f, "const {}: {type_}", self.identifier),
+            (None, Some(bound)) => write!(f, "{}: {bound}", self.identifier),
+            (None, None) => write!(f, "{}", self.identifier),
+        }
+    }
+}
+
+crate::simple_node_impl!(GenericParameter);
@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{ConstDeclaration, Function, Identifier, Node, NodeID};
+use leo_span::{Span, Symbol};
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// An `impl` block declaring the methods and associated constants of a struct or record, e.g.
+/// `impl Token { const MAX: u64 = 100u64; inline total(self) -> u64 { ... } }`.
+///
+/// The items of an `impl` block are declared as consts and functions of the program scope before the symbol table
+/// is created, named by [`Impl::item_name`]. A method takes the struct as its first input, `self`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Impl {
+    /// The name of the struct or record the items belong to.
+    pub target: Identifier,
+    /// The associated constants of the struct.
+    pub consts: Vec<(Symbol, ConstDeclaration)>,
+    /// The methods and associated functions of the struct.
+    pub functions: Vec<(Symbol, Function)>,
+    /// The entire span of the `impl` block.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl Impl {
+    /// Returns the name of the struct or record as a Symbol.
+    pub fn name(&self) -> Symbol {
+        self.target.name
+    }
+
+    /// Returns the name the item `name` of the `impl` block of `target` is declared as, e.g. `Token__total`.
+    pub fn item_name(target: Symbol, name: Symbol) -> Symbol {
+        Symbol::intern(&format!("{target}__{name}"))
+    }
+
+    /// Renames the consts and functions of the block to the names they are declared as in the program scope.
+    pub fn rename_items(&mut self) {
+        let target = self.name();
+        for (name, const_) in self.consts.iter_mut() {
+            *name = Self::item_name(target, *name);
+            const_.place.name = *name;
+        }
+        for (name, function) in self.functions.iter_mut() {
+            *name = Self::item_name(target, *name);
+            function.identifier.name = *name;
+        }
+    }
+}
+
+impl fmt::Display for Impl {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "impl {} {{", self.target)?;
+        for (_, const_) in self.consts.iter() {
+            writeln!(f, "    const {const_}")?;
+        }
+        for (_, function) in self.functions.iter() {
+            writeln!(f, "    {function}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+crate::simple_node_impl!(Impl);
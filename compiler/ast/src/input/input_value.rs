@@ -46,6 +46,14 @@ impl TryFrom<(Type, Expression)> for InputValue {
                         return Err(InputError::unexpected_type(expected.to_string(), actual, span).into());
                     }
                 }
+                // An unsuffixed value takes the type of the input it is assigned to, e.g. `a: u8 = 1;`.
+                (
+                    type_ @ (Type::Integer(_) | Type::Field | Type::Group | Type::Scalar),
+                    literal @ Literal::Unsuffixed(..),
+                ) => {
+                    let literal = literal.with_type(&type_);
+                    InputValue::try_from((type_, Expression::Literal(literal)))?
+                }
                 (x, y) => {
                     return Err(InputError::unexpected_type(x, &y, y.span()).into());
                 }
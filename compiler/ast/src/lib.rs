@@ -25,6 +25,12 @@
 pub mod access;
 pub use self::access::*;
 
+pub mod r#enum;
+pub use self::r#enum::*;
+
+pub mod r#impl;
+pub use self::r#impl::*;
+
 pub mod r#struct;
 pub use self::r#struct::*;
 
@@ -37,6 +43,9 @@ pub use self::expressions::*;
 pub mod functions;
 pub use self::functions::*;
 
+pub mod generics;
+pub use self::generics::*;
+
 pub mod groups;
 pub use self::groups::*;
 
@@ -79,13 +79,17 @@ pub trait StatementConsumer {
             Statement::Assert(assert) => self.consume_assert(assert),
             Statement::Assign(stmt) => self.consume_assign(*stmt),
             Statement::Block(stmt) => self.consume_block(stmt),
+            Statement::Break(stmt) => self.consume_break(stmt),
             Statement::Conditional(stmt) => self.consume_conditional(stmt),
             Statement::Console(stmt) => self.consume_console(stmt),
             Statement::Const(stmt) => self.consume_const(stmt),
+            Statement::Continue(stmt) => self.consume_continue(stmt),
             Statement::Definition(stmt) => self.consume_definition(stmt),
             Statement::Expression(stmt) => self.consume_expression_statement(stmt),
             Statement::Iteration(stmt) => self.consume_iteration(*stmt),
+            Statement::Match(stmt) => self.consume_match(stmt),
             Statement::Return(stmt) => self.consume_return(stmt),
+            Statement::While(stmt) => self.consume_while(*stmt),
         }
     }
 
@@ -95,19 +99,27 @@ pub trait StatementConsumer {
 
     fn consume_block(&mut self, input: Block) -> Self::Output;
 
+    fn consume_break(&mut self, input: BreakStatement) -> Self::Output;
+
     fn consume_conditional(&mut self, input: ConditionalStatement) -> Self::Output;
 
     fn consume_console(&mut self, input: ConsoleStatement) -> Self::Output;
 
     fn consume_const(&mut self, input: ConstDeclaration) -> Self::Output;
 
+    fn consume_continue(&mut self, input: ContinueStatement) -> Self::Output;
+
     fn consume_definition(&mut self, input: DefinitionStatement) -> Self::Output;
 
     fn consume_expression_statement(&mut self, input: ExpressionStatement) -> Self::Output;
 
     fn consume_iteration(&mut self, input: IterationStatement) -> Self::Output;
 
+    fn consume_match(&mut self, input: MatchStatement) -> Self::Output;
+
     fn consume_return(&mut self, input: ReturnStatement) -> Self::Output;
+
+    fn consume_while(&mut self, input: WhileStatement) -> Self::Output;
 }
 
 /// A Consumer trait for functions in the AST.
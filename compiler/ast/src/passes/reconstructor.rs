@@ -48,6 +48,7 @@ pub trait ExpressionReconstructor {
             AccessExpression::AssociatedConstant(constant) => self.reconstruct_associated_constant(constant),
             AccessExpression::AssociatedFunction(function) => self.reconstruct_associated_function(function),
             AccessExpression::Member(member) => self.reconstruct_member_access(member),
+            AccessExpression::MethodCall(call) => self.reconstruct_method_call(call),
             AccessExpression::Tuple(tuple) => self.reconstruct_tuple_access(tuple),
         }
     }
@@ -101,6 +102,19 @@ pub trait ExpressionReconstructor {
         )
     }
 
+    fn reconstruct_method_call(&mut self, input: MethodCall) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Access(AccessExpression::MethodCall(MethodCall {
+                receiver: Box::new(self.reconstruct_expression(*input.receiver).0),
+                name: input.name,
+                arguments: input.arguments.into_iter().map(|arg| self.reconstruct_expression(arg).0).collect(),
+                span: input.span,
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_tuple_access(&mut self, input: TupleAccess) -> (Expression, Self::AdditionalOutput) {
         (
             Expression::Access(AccessExpression::Tuple(TupleAccess {
@@ -249,13 +263,17 @@ pub trait StatementReconstructor: ExpressionReconstructor {
                 let (stmt, output) = self.reconstruct_block(stmt);
                 (Statement::Block(stmt), output)
             }
+            Statement::Break(stmt) => self.reconstruct_break(stmt),
             Statement::Conditional(stmt) => self.reconstruct_conditional(stmt),
             Statement::Console(stmt) => self.reconstruct_console(stmt),
             Statement::Const(stmt) => self.reconstruct_const(stmt),
+            Statement::Continue(stmt) => self.reconstruct_continue(stmt),
             Statement::Definition(stmt) => self.reconstruct_definition(stmt),
             Statement::Expression(stmt) => self.reconstruct_expression_statement(stmt),
             Statement::Iteration(stmt) => self.reconstruct_iteration(*stmt),
+            Statement::Match(stmt) => self.reconstruct_match(stmt),
             Statement::Return(stmt) => self.reconstruct_return(stmt),
+            Statement::While(stmt) => self.reconstruct_while(*stmt),
         }
     }
 
@@ -303,6 +321,10 @@ pub trait StatementReconstructor: ExpressionReconstructor {
         )
     }
 
+    fn reconstruct_break(&mut self, input: BreakStatement) -> (Statement, Self::AdditionalOutput) {
+        (Statement::Break(input), Default::default())
+    }
+
     fn reconstruct_conditional(&mut self, input: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
         (
             Statement::Conditional(ConditionalStatement {
@@ -350,6 +372,10 @@ pub trait StatementReconstructor: ExpressionReconstructor {
         )
     }
 
+    fn reconstruct_continue(&mut self, input: ContinueStatement) -> (Statement, Self::AdditionalOutput) {
+        (Statement::Continue(input), Default::default())
+    }
+
     fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
         (
             Statement::Definition(DefinitionStatement {
@@ -393,6 +419,27 @@ pub trait StatementReconstructor: ExpressionReconstructor {
         )
     }
 
+    fn reconstruct_match(&mut self, input: MatchStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Match(MatchStatement {
+                expression: self.reconstruct_expression(input.expression).0,
+                arms: input
+                    .arms
+                    .into_iter()
+                    .map(|arm| MatchArm {
+                        pattern: arm.pattern,
+                        block: self.reconstruct_block(arm.block).0,
+                        span: arm.span,
+                        id: arm.id,
+                    })
+                    .collect(),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_return(&mut self, input: ReturnStatement) -> (Statement, Self::AdditionalOutput) {
         (
             Statement::Return(ReturnStatement {
@@ -406,6 +453,19 @@ pub trait StatementReconstructor: ExpressionReconstructor {
             Default::default(),
         )
     }
+
+    fn reconstruct_while(&mut self, input: WhileStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::While(Box::new(WhileStatement {
+                condition: self.reconstruct_expression(input.condition).0,
+                bound: self.reconstruct_expression(input.bound).0,
+                block: self.reconstruct_block(input.block).0,
+                span: input.span,
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
 }
 
 /// A Reconstructor trait for the program represented by the AST.
@@ -417,6 +477,7 @@ pub trait ProgramReconstructor: StatementReconstructor {
                 .into_iter()
                 .map(|(id, import)| (id, (self.reconstruct_import(import.0), import.1)))
                 .collect(),
+            uses: input.uses,
             program_scopes: input
                 .program_scopes
                 .into_iter()
@@ -429,6 +490,8 @@ pub trait ProgramReconstructor: StatementReconstructor {
         ProgramScope {
             program_id: input.program_id,
             structs: input.structs.into_iter().map(|(i, c)| (i, self.reconstruct_struct(c))).collect(),
+            enums: input.enums.into_iter().map(|(i, e)| (i, self.reconstruct_enum(e))).collect(),
+            impls: input.impls,
             mappings: input.mappings.into_iter().map(|(id, mapping)| (id, self.reconstruct_mapping(mapping))).collect(),
             functions: input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect(),
             consts: input
@@ -448,6 +511,7 @@ pub trait ProgramReconstructor: StatementReconstructor {
             annotations: input.annotations,
             variant: input.variant,
             identifier: input.identifier,
+            generic_parameters: input.generic_parameters,
             input: input.input,
             output: input.output,
             output_type: input.output_type,
@@ -470,6 +534,10 @@ pub trait ProgramReconstructor: StatementReconstructor {
         input
     }
 
+    fn reconstruct_enum(&mut self, input: Enum) -> Enum {
+        input
+    }
+
     fn reconstruct_import(&mut self, input: Program) -> Program {
         self.reconstruct_program(input)
     }
@@ -57,6 +57,12 @@ pub trait ExpressionVisitor<'a> {
             AccessExpression::Member(member) => {
                 self.visit_expression(&member.inner, additional);
             }
+            AccessExpression::MethodCall(call) => {
+                self.visit_expression(&call.receiver, &Default::default());
+                call.arguments.iter().for_each(|arg| {
+                    self.visit_expression(arg, &Default::default());
+                });
+            }
             AccessExpression::Tuple(tuple) => {
                 self.visit_expression(&tuple.tuple, additional);
             }
@@ -138,13 +144,17 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
             Statement::Assert(stmt) => self.visit_assert(stmt),
             Statement::Assign(stmt) => self.visit_assign(stmt),
             Statement::Block(stmt) => self.visit_block(stmt),
+            Statement::Break(stmt) => self.visit_break(stmt),
             Statement::Conditional(stmt) => self.visit_conditional(stmt),
             Statement::Console(stmt) => self.visit_console(stmt),
             Statement::Const(stmt) => self.visit_const(stmt),
+            Statement::Continue(stmt) => self.visit_continue(stmt),
             Statement::Definition(stmt) => self.visit_definition(stmt),
             Statement::Expression(stmt) => self.visit_expression_statement(stmt),
             Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Match(stmt) => self.visit_match(stmt),
             Statement::Return(stmt) => self.visit_return(stmt),
+            Statement::While(stmt) => self.visit_while(stmt),
         }
     }
 
@@ -166,6 +176,8 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
         input.statements.iter().for_each(|stmt| self.visit_statement(stmt));
     }
 
+    fn visit_break(&mut self, _input: &'a BreakStatement) {}
+
     fn visit_conditional(&mut self, input: &'a ConditionalStatement) {
         self.visit_expression(&input.condition, &Default::default());
         self.visit_block(&input.then);
@@ -194,6 +206,8 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
         self.visit_expression(&input.value, &Default::default());
     }
 
+    fn visit_continue(&mut self, _input: &'a ContinueStatement) {}
+
     fn visit_definition(&mut self, input: &'a DefinitionStatement) {
         self.visit_expression(&input.value, &Default::default());
     }
@@ -208,6 +222,11 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
         self.visit_block(&input.block);
     }
 
+    fn visit_match(&mut self, input: &'a MatchStatement) {
+        self.visit_expression(&input.expression, &Default::default());
+        input.arms.iter().for_each(|arm| self.visit_block(&arm.block));
+    }
+
     fn visit_return(&mut self, input: &'a ReturnStatement) {
         self.visit_expression(&input.expression, &Default::default());
         if let Some(arguments) = &input.finalize_arguments {
@@ -216,6 +235,12 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
             })
         }
     }
+
+    fn visit_while(&mut self, input: &'a WhileStatement) {
+        self.visit_expression(&input.condition, &Default::default());
+        self.visit_expression(&input.bound, &Default::default());
+        self.visit_block(&input.block);
+    }
 }
 
 /// A Visitor trait for the program represented by the AST.
@@ -229,6 +254,8 @@ pub trait ProgramVisitor<'a>: StatementVisitor<'a> {
     fn visit_program_scope(&mut self, input: &'a ProgramScope) {
         input.structs.iter().for_each(|(_, c)| (self.visit_struct(c)));
 
+        input.enums.iter().for_each(|(_, c)| self.visit_enum(c));
+
         input.mappings.iter().for_each(|(_, c)| (self.visit_mapping(c)));
 
         input.functions.iter().for_each(|(_, c)| (self.visit_function(c)));
@@ -242,6 +269,8 @@ pub trait ProgramVisitor<'a>: StatementVisitor<'a> {
 
     fn visit_struct(&mut self, _input: &'a Struct) {}
 
+    fn visit_enum(&mut self, _input: &'a Enum) {}
+
     fn visit_mapping(&mut self, _input: &'a Mapping) {}
 
     fn visit_function(&mut self, input: &'a Function) {
@@ -14,7 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-//! A Leo program consists of import statements and program scopes.
+//! A Leo program consists of import statements, module declarations, and program scopes.
+
+pub mod module;
+pub use module::*;
 
 pub mod program_id;
 pub use program_id::*;
@@ -33,6 +36,9 @@ use std::fmt;
 pub struct Program {
     /// A map from import names to import definitions.
     pub imports: IndexMap<Symbol, (Program, Span)>,
+    /// The modules used by the program.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub uses: Vec<UseDeclaration>,
     /// A map from program names to program scopes.
     pub program_scopes: IndexMap<Symbol, ProgramScope>,
 }
@@ -42,6 +48,9 @@ impl fmt::Display for Program {
         for (id, _import) in self.imports.iter() {
             writeln!(f, "import {id}.leo;")?;
         }
+        for use_ in self.uses.iter() {
+            writeln!(f, "{use_}")?;
+        }
         for (_, program_scope) in self.program_scopes.iter() {
             program_scope.fmt(f)?;
             writeln!(f,)?;
@@ -53,6 +62,6 @@ impl fmt::Display for Program {
 impl Default for Program {
     /// Constructs an empty program node.
     fn default() -> Self {
-        Self { imports: IndexMap::new(), program_scopes: IndexMap::new() }
+        Self { imports: IndexMap::new(), uses: Vec::new(), program_scopes: IndexMap::new() }
     }
 }
@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{ConstDeclaration, Enum, Function, Identifier, Impl, Node, NodeID, Struct};
+
+use itertools::Itertools;
+use leo_span::{Span, Symbol};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A `use` declaration of a module of the program, e.g. `use utils::math;`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct UseDeclaration {
+    /// The path of the module, relative to the source directory, e.g. `utils::math` for `src/utils/math.leo`.
+    pub path: Vec<Identifier>,
+    /// The span of the declaration.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl UseDeclaration {
+    /// Returns the path of the module as symbols.
+    pub fn symbols(&self) -> Vec<Symbol> {
+        self.path.iter().map(|segment| segment.name).collect()
+    }
+}
+
+impl fmt::Display for UseDeclaration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "use {};", self.path.iter().format("::"))
+    }
+}
+
+crate::simple_node_impl!(UseDeclaration);
+
+/// A module of a program, i.e. a file in the source directory other than `main.leo`.
+/// A module declares consts, structs, enums, impl blocks, and helper functions outside of a program scope.
+/// The items of the modules used by a program are merged into its program scope before the symbol table is created.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Module {
+    /// The modules used by this module.
+    pub uses: Vec<UseDeclaration>,
+    /// A vector of const definitions.
+    pub consts: Vec<(Symbol, ConstDeclaration)>,
+    /// A vector of struct definitions.
+    pub structs: Vec<(Symbol, Struct)>,
+    /// A vector of enum definitions.
+    pub enums: Vec<(Symbol, Enum)>,
+    /// A vector of `impl` blocks.
+    pub impls: Vec<(Symbol, Impl)>,
+    /// A vector of function definitions.
+    pub functions: Vec<(Symbol, Function)>,
+}
+
+impl fmt::Display for Module {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for use_ in self.uses.iter() {
+            writeln!(f, "{use_}")?;
+        }
+        for (_, const_) in self.consts.iter() {
+            writeln!(f, "const {const_}")?;
+        }
+        for (_, struct_) in self.structs.iter() {
+            writeln!(f, "{struct_}")?;
+        }
+        for (_, enum_) in self.enums.iter() {
+            writeln!(f, "{enum_}")?;
+        }
+        for (_, impl_) in self.impls.iter() {
+            writeln!(f, "{impl_}")?;
+        }
+        for (_, function) in self.functions.iter() {
+            writeln!(f, "{function}")?;
+        }
+        Ok(())
+    }
+}
@@ -14,9 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-//! A Leo program scope consists of struct, function, and mapping definitions.
+//! A Leo program scope consists of struct, enum, impl, function, and mapping definitions.
 
-use crate::{ConstDeclaration, Function, Mapping, ProgramId, Struct};
+use crate::{ConstDeclaration, Enum, Function, Impl, Mapping, ProgramId, Struct};
 
 use leo_span::{Span, Symbol};
 use serde::{Deserialize, Serialize};
@@ -31,6 +31,13 @@ pub struct ProgramScope {
     pub consts: Vec<(Symbol, ConstDeclaration)>,
     /// A vector of struct definitions.
     pub structs: Vec<(Symbol, Struct)>,
+    /// A vector of enum definitions.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub enums: Vec<(Symbol, Enum)>,
+    /// A vector of `impl` blocks, keyed by the name of the struct they belong to.
+    /// The blocks are declared as consts and functions of the program scope once the modules have been resolved.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub impls: Vec<(Symbol, Impl)>,
     /// A vector of mapping definitions.
     pub mappings: Vec<(Symbol, Mapping)>,
     /// A vector of function definitions.
@@ -45,6 +52,12 @@ impl fmt::Display for ProgramScope {
         for (_, struct_) in self.structs.iter() {
             writeln!(f, "    {struct_}")?;
         }
+        for (_, enum_) in self.enums.iter() {
+            writeln!(f, "    {enum_}")?;
+        }
+        for (_, impl_) in self.impls.iter() {
+            writeln!(f, "    {impl_}")?;
+        }
         for (_, mapping) in self.mappings.iter() {
             writeln!(f, "    {mapping}")?;
         }
@@ -31,7 +31,7 @@ pub struct DefinitionStatement {
     /// The bindings / variable names to declare.
     pub place: Expression,
     /// The types of the bindings, if specified, or inferred otherwise.
-    pub type_: Type,
+    pub type_: Option<Type>,
     /// An initializer value for the bindings.
     pub value: Expression,
     /// The span excluding the semicolon.
@@ -44,7 +44,9 @@ impl fmt::Display for DefinitionStatement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{} ", self.declaration_type)?;
         write!(f, "{}", self.place)?;
-        write!(f, ": {}", self.type_)?;
+        if let Some(type_) = &self.type_ {
+            write!(f, ": {type_}")?;
+        }
         write!(f, " = {};", self.value)
     }
 }
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Block, Expression, Identifier, Node, NodeID};
+
+use itertools::Itertools;
+use leo_span::Span;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A `match expression { arms }` statement over a value of an enum type.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct MatchStatement {
+    /// The value being matched.
+    pub expression: Expression,
+    /// The arms of the match, in the order they are written.
+    pub arms: Vec<MatchArm>,
+    /// The span from `match` to the closing brace.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl fmt::Display for MatchStatement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "match {} {{", self.expression)?;
+        for arm in self.arms.iter() {
+            writeln!(f, "{arm}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+crate::simple_node_impl!(MatchStatement);
+
+/// An arm `pattern => block` of a `match` statement.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct MatchArm {
+    /// The pattern the value must match for the block to run.
+    pub pattern: MatchPattern,
+    /// The block to run if the pattern matches.
+    pub block: Block,
+    /// The span from the pattern to the end of the block.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl fmt::Display for MatchArm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} => {}", self.pattern, self.block)
+    }
+}
+
+crate::simple_node_impl!(MatchArm);
+
+/// The pattern of a `match` arm.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub enum MatchPattern {
+    /// A variant of an enum, binding its payload, e.g. `Shape::Rectangle(width, _)`.
+    /// A binding written as `_` is `None`.
+    Variant { ty: Identifier, variant: Identifier, bindings: Vec<Option<Identifier>>, span: Span },
+    /// The pattern `_`, matching the variants not matched by the previous arms.
+    Wildcard(Span),
+}
+
+impl MatchPattern {
+    /// Returns the span of the pattern.
+    pub fn span(&self) -> Span {
+        match self {
+            MatchPattern::Variant { span, .. } | MatchPattern::Wildcard(span) => *span,
+        }
+    }
+}
+
+impl fmt::Display for MatchPattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MatchPattern::Variant { ty, variant, bindings, .. } => {
+                write!(f, "{ty}::{variant}")?;
+                if !bindings.is_empty() {
+                    let bindings = bindings.iter().map(|binding| match binding {
+                        Some(binding) => binding.to_string(),
+                        None => "_".to_string(),
+                    });
+                    write!(f, "({})", bindings.format(", "))?;
+                }
+                Ok(())
+            }
+            MatchPattern::Wildcard(_) => write!(f, "_"),
+        }
+    }
+}
@@ -17,7 +17,7 @@
 pub mod member;
 pub use member::*;
 
-use crate::{Identifier, Node, NodeID};
+use crate::{GenericParameter, Identifier, Node, NodeID};
 use leo_span::{Span, Symbol};
 
 use serde::{Deserialize, Serialize};
@@ -33,6 +33,9 @@ use std::fmt;
 pub struct Struct {
     /// The name of the type in the type system in this module.
     pub identifier: Identifier,
+    /// The type parameters of the struct, e.g., `T` in `struct Pair<T> { first: T, second: T }`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub generic_parameters: Vec<GenericParameter>,
     /// The fields, constant variables, and functions of this structure.
     pub members: Vec<Member>,
     /// Was this a `record Foo { ... }`?
@@ -57,6 +60,11 @@ impl Struct {
     pub fn name(&self) -> Symbol {
         self.identifier.name
     }
+
+    /// Returns `true` if the struct has type parameters.
+    pub fn is_generic(&self) -> bool {
+        !self.generic_parameters.is_empty()
+    }
 }
 
 impl fmt::Debug for Struct {
@@ -68,7 +76,11 @@ impl fmt::Debug for Struct {
 impl fmt::Display for Struct {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str(if self.is_record { "record" } else { "struct" })?;
-        writeln!(f, " {} {{ ", self.identifier)?;
+        write!(f, " {}", self.identifier)?;
+        if self.is_generic() {
+            write!(f, "<{}>", self.generic_parameters.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", "))?;
+        }
+        writeln!(f, " {{ ")?;
         for field in self.members.iter() {
             writeln!(f, "    {field}")?;
         }
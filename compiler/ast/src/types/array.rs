@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{NonNegativeNumber, Type};
+use crate::{Identifier, NonNegativeNumber, Type};
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -23,13 +23,13 @@ use std::fmt;
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ArrayType {
     element_type: Box<Type>,
-    length: NonNegativeNumber,
+    length: ArrayLength,
 }
 
 impl ArrayType {
     /// Creates a new array type.
-    pub fn new(element: Type, length: NonNegativeNumber) -> Self {
-        Self { element_type: Box::new(element), length }
+    pub fn new(element: Type, length: impl Into<ArrayLength>) -> Self {
+        Self { element_type: Box::new(element), length: length.into() }
     }
 
     /// Returns the element type of the array.
@@ -38,8 +38,24 @@ impl ArrayType {
     }
 
     /// Returns the length of the array.
+    /// Note that monomorphization replaces every const parameter with its value before loop unrolling.
     pub fn length(&self) -> usize {
-        self.length.value()
+        match &self.length {
+            ArrayLength::Constant(length) => length.value(),
+            ArrayLength::Parameter(parameter) => {
+                unreachable!("The length `{parameter}` is resolved by monomorphization")
+            }
+        }
+    }
+
+    /// Returns the length of the array, which may be a const parameter.
+    pub fn array_length(&self) -> &ArrayLength {
+        &self.length
+    }
+
+    /// Returns an array type of the same length, with `f` applied to the element type.
+    pub fn map_element_type(&self, f: impl FnOnce(&Type) -> Type) -> Self {
+        Self { element_type: Box::new(f(&self.element_type)), length: self.length.clone() }
     }
 
     /// Returns the base element type of the array.
@@ -56,3 +72,45 @@ impl fmt::Display for ArrayType {
         write!(f, "[{}; {}]", self.element_type, self.length)
     }
 }
+
+/// The length of an array type.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ArrayLength {
+    /// A literal length, e.g. `8` in `[u32; 8]`.
+    Constant(NonNegativeNumber),
+    /// A const parameter of a generic function, e.g. `N` in `[u32; N]`.
+    Parameter(Identifier),
+}
+
+impl ArrayLength {
+    /// Returns `true` if the two lengths are the same number, or the same const parameter.
+    pub fn eq_flat(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ArrayLength::Constant(left), ArrayLength::Constant(right)) => left.value() == right.value(),
+            (ArrayLength::Parameter(left), ArrayLength::Parameter(right)) => left.matches(right),
+            _ => false,
+        }
+    }
+}
+
+impl From<NonNegativeNumber> for ArrayLength {
+    fn from(length: NonNegativeNumber) -> Self {
+        ArrayLength::Constant(length)
+    }
+}
+
+impl From<Identifier> for ArrayLength {
+    fn from(parameter: Identifier) -> Self {
+        ArrayLength::Parameter(parameter)
+    }
+}
+
+impl fmt::Display for ArrayLength {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArrayLength::Constant(length) => write!(f, "{length}"),
+            ArrayLength::Parameter(parameter) => write!(f, "{parameter}"),
+        }
+    }
+}
@@ -20,6 +20,9 @@ pub use array::*;
 pub mod core_constant;
 pub use core_constant::*;
 
+pub mod generic;
+pub use generic::*;
+
 pub mod integer_type;
 pub use integer_type::*;
 
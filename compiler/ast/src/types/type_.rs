@@ -14,8 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{ArrayType, Identifier, IntegerType, MappingType, TupleType};
+use crate::{ArrayLength, ArrayType, GenericArgument, GenericType, Identifier, IntegerType, MappingType, TupleType};
+use leo_span::Symbol;
 
+use indexmap::IndexMap;
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -31,6 +33,8 @@ pub enum Type {
     Boolean,
     /// The `field` type.
     Field,
+    /// An instantiation of a generic struct, e.g. `Pair<u32>`.
+    Generic(GenericType),
     /// The `group` type.
     Group,
     /// A reference to a built in type.
@@ -71,7 +75,15 @@ impl Type {
             | (Type::String, Type::String)
             | (Type::Unit, Type::Unit) => true,
             (Type::Array(left), Type::Array(right)) => {
-                left.element_type().eq_flat(right.element_type()) && left.length() == right.length()
+                left.element_type().eq_flat(right.element_type()) && left.array_length().eq_flat(right.array_length())
+            }
+            (Type::Generic(left), Type::Generic(right)) if left.arguments.len() == right.arguments.len() => {
+                left.identifier.matches(&right.identifier)
+                    && left
+                        .arguments
+                        .iter()
+                        .zip_eq(right.arguments.iter())
+                        .all(|(left_type, right_type)| left_type.eq_flat(right_type))
             }
             (Type::Identifier(left), Type::Identifier(right)) => left.matches(right),
             (Type::Integer(left), Type::Integer(right)) => left.eq(right),
@@ -86,6 +98,88 @@ impl Type {
             _ => false,
         }
     }
+
+    ///
+    /// Returns the type with every type parameter and const parameter in `arguments` replaced by its argument.
+    ///
+    pub fn substitute(&self, arguments: &IndexMap<Symbol, GenericArgument>) -> Type {
+        match self {
+            Type::Identifier(identifier) => match arguments.get(&identifier.name) {
+                Some(GenericArgument::Type(argument)) => argument.clone(),
+                _ => self.clone(),
+            },
+            Type::Array(array_type) => {
+                let length = match array_type.array_length() {
+                    ArrayLength::Parameter(parameter) => match arguments.get(&parameter.name) {
+                        Some(GenericArgument::Const(length)) => length.clone(),
+                        _ => array_type.array_length().clone(),
+                    },
+                    length => length.clone(),
+                };
+                Type::Array(ArrayType::new(array_type.element_type().substitute(arguments), length))
+            }
+            Type::Generic(generic_type) => Type::Generic(GenericType {
+                identifier: generic_type.identifier,
+                arguments: generic_type.arguments.iter().map(|type_| type_.substitute(arguments)).collect(),
+            }),
+            Type::Mapping(mapping_type) => Type::Mapping(MappingType {
+                key: Box::new(mapping_type.key.substitute(arguments)),
+                value: Box::new(mapping_type.value.substitute(arguments)),
+            }),
+            Type::Tuple(tuple_type) => Type::Tuple(TupleType::new(
+                tuple_type.elements().iter().map(|type_| type_.substitute(arguments)).collect(),
+            )),
+            _ => self.clone(),
+        }
+    }
+
+    ///
+    /// Matches the type against the `actual` type, binding the parameters named in `arguments` that are still `None`.
+    ///
+    /// Returns `false` if the types do not match, or if a parameter would be bound to two different arguments.
+    ///
+    pub fn infer(&self, actual: &Type, arguments: &mut IndexMap<Symbol, Option<GenericArgument>>) -> bool {
+        fn bind(
+            name: Symbol,
+            actual: GenericArgument,
+            arguments: &mut IndexMap<Symbol, Option<GenericArgument>>,
+        ) -> bool {
+            match &arguments[&name] {
+                Some(bound) => bound.eq_flat(&actual),
+                None => {
+                    arguments.insert(name, Some(actual));
+                    true
+                }
+            }
+        }
+
+        match (self, actual) {
+            (Type::Identifier(identifier), _) if arguments.contains_key(&identifier.name) => {
+                bind(identifier.name, GenericArgument::Type(actual.clone()), arguments)
+            }
+            (Type::Array(left), Type::Array(right)) => {
+                let length_matches = match left.array_length() {
+                    ArrayLength::Parameter(parameter) if arguments.contains_key(&parameter.name) => {
+                        bind(parameter.name, GenericArgument::Const(right.array_length().clone()), arguments)
+                    }
+                    length => length.eq_flat(right.array_length()),
+                };
+                length_matches && left.element_type().infer(right.element_type(), arguments)
+            }
+            (Type::Generic(left), Type::Generic(right))
+                if left.identifier.matches(&right.identifier) && left.arguments.len() == right.arguments.len() =>
+            {
+                left.arguments.iter().zip_eq(right.arguments.iter()).all(|(left, right)| left.infer(right, arguments))
+            }
+            (Type::Mapping(left), Type::Mapping(right)) => {
+                left.key.infer(&right.key, arguments) && left.value.infer(&right.value, arguments)
+            }
+            (Type::Tuple(left), Type::Tuple(right)) if left.length() == right.length() => {
+                left.elements().iter().zip_eq(right.elements().iter()).all(|(left, right)| left.infer(right, arguments))
+            }
+            _ => self.eq_flat(actual),
+        }
+    }
 }
 
 impl fmt::Display for Type {
@@ -95,6 +189,7 @@ impl fmt::Display for Type {
             Type::Array(ref array_type) => write!(f, "{array_type}"),
             Type::Boolean => write!(f, "boolean"),
             Type::Field => write!(f, "field"),
+            Type::Generic(ref generic_type) => write!(f, "{generic_type}"),
             Type::Group => write!(f, "group"),
             Type::Identifier(ref variable) => write!(f, "{variable}"),
             Type::Integer(ref integer_type) => write!(f, "{integer_type}"),
@@ -28,9 +28,6 @@ use std::{
 
 // TODO: Consider refactoring this module to use the console implementations from snarkVM.
 
-// This is temporary since the currently unused code is used in constant folding.
-#[allow(dead_code)]
-
 // Macro for making implementing unary operations over appropriate types easier.
 macro_rules! implement_const_unary {
     (
@@ -71,9 +68,7 @@ macro_rules! implement_const_unary {
             l: $logic:expr
         ]),+]
     ) => {
-        // TODO: This is temporary since the currently unused code is used in constant folding.
-        #[allow(dead_code)]
-        pub(crate) fn $name(self, span: Span) -> Result<Self> {
+        pub fn $name(self, span: Span) -> Result<Self> {
             use Value::*;
 
             match self {
@@ -157,9 +152,7 @@ macro_rules! implement_const_binary {
             logic: $logic:expr
         ]),+]
     ) => {
-        // This is temporary since the currently unused code is used in constant folding.
-        #[allow(dead_code)]
-        pub(crate) fn $name(self, other: Self, span: Span) -> Result<Self> {
+        pub fn $name(self, other: Self, span: Span) -> Result<Self> {
             use Value::*;
 
             match (self, other) {
@@ -371,6 +364,43 @@ impl Value {
         ]
     );
 
+    implement_const_binary!(
+        @overflowing
+        name: rem,
+        method: checked_rem,
+        string: "%",
+        patterns: [
+            [I8, [I8], I8, i8, i8],
+            [I16, [I16], I16, i16, i16],
+            [I32, [I32], I32, i32, i32],
+            [I64, [I64], I64, i64, i64],
+            [I128, [I128], I128, i128, i128],
+            [U8, [U8], U8, u8, u8],
+            [U16, [U16], U16, u16, u16],
+            [U32, [U32], U32, u32, u32],
+            [U64, [U64], U64, u64, u64],
+            [U128, [U128], U128, u128, u128]
+        ]
+    );
+
+    implement_const_binary!(
+        @non-overflowing
+        name: rem_wrapped,
+        method: wrapping_rem,
+        patterns: [
+            [I8, [I8], I8, i8, i8],
+            [I16, [I16], I16, i16, i16],
+            [I32, [I32], I32, i32, i32],
+            [I64, [I64], I64, i64, i64],
+            [I128, [I128], I128, i128, i128],
+            [U8, [U8], U8, u8, u8],
+            [U16, [U16], U16, u16, u16],
+            [U32, [U32], U32, u32, u32],
+            [U64, [U64], U64, u64, u64],
+            [U128, [U128], U128, u128, u128]
+        ]
+    );
+
     implement_const_binary!(
         @cmp
         name: eq,
@@ -702,9 +732,8 @@ impl Value {
         ]
     );
 
-    // TODO: This is temporary since the currently unused code is used in constant folding.
-    #[allow(dead_code)]
-    pub(crate) fn is_supported_const_fold_type(&self) -> bool {
+    /// Returns `true` if the value can be operated on at compile time.
+    pub fn is_supported_const_fold_type(&self) -> bool {
         use Value::*;
         matches!(
             self,
@@ -721,6 +750,24 @@ impl Value {
                 | U128(_, _)
         )
     }
+
+    /// Returns `true` if the value is an integer equal to zero.
+    pub fn is_zero(&self) -> bool {
+        use Value::*;
+        match self {
+            I8(v, _) => *v == 0,
+            I16(v, _) => *v == 0,
+            I32(v, _) => *v == 0,
+            I64(v, _) => *v == 0,
+            I128(v, _) => *v == 0,
+            U8(v, _) => *v == 0,
+            U16(v, _) => *v == 0,
+            U32(v, _) => *v == 0,
+            U64(v, _) => *v == 0,
+            U128(v, _) => *v == 0,
+            _ => false,
+        }
+    }
 }
 
 impl Display for Value {
@@ -877,6 +924,8 @@ impl TryFrom<&Literal> for Value {
             Literal::Group(group_literal) => Self::Group(group_literal.clone()),
             Literal::Scalar(string, span, _) => Self::Scalar(string.clone(), *span),
             Literal::String(string, span, _) => Self::String(string.clone(), *span),
+            // An unsuffixed literal has no value until its type is inferred.
+            Literal::Unsuffixed(..) => return Err("".parse::<u8>().unwrap_err()),
             Literal::Integer(integer_type, raw_string, span, _) => {
                 let string = raw_string.replace('_', "");
                 match integer_type {
@@ -897,8 +946,8 @@ impl TryFrom<&Literal> for Value {
 }
 
 impl Literal {
-    #[allow(unused)]
-    fn from_value(v: Value, id: NodeID) -> Self {
+    /// Converts a value back into a literal with the given node ID.
+    pub fn from_value(v: Value, id: NodeID) -> Self {
         use Value::*;
         match v {
             Input(_, _) => todo!("We need to test if this is hittable"),
@@ -0,0 +1,192 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Input, Member, Mode, Output, ProgramScope, Struct, Variant};
+use leo_passes::SymbolTable;
+
+use serde::{Deserialize, Serialize};
+
+/// The visibility of an input, output or record field of a program.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AbiMode {
+    Constant,
+    Private,
+    Public,
+}
+
+impl From<Mode> for AbiMode {
+    fn from(mode: Mode) -> Self {
+        match mode {
+            Mode::Constant => AbiMode::Constant,
+            Mode::Public => AbiMode::Public,
+            // Values without a mode are private.
+            Mode::None | Mode::Private => AbiMode::Private,
+        }
+    }
+}
+
+/// A field of a struct or record.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct AbiField {
+    /// The name of the field.
+    pub name: String,
+    /// The visibility of the field. Only the fields of records have one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<AbiMode>,
+    /// The type of the field, e.g. `u64` or `[u8; 4]`.
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+/// The layout of a struct or record.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct AbiStruct {
+    /// The name of the struct or record.
+    pub name: String,
+    /// The fields, in the order in which they are declared.
+    pub fields: Vec<AbiField>,
+}
+
+/// A mapping of the program.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct AbiMapping {
+    /// The name of the mapping.
+    pub name: String,
+    /// The type of the keys.
+    pub key: String,
+    /// The type of the values.
+    pub value: String,
+}
+
+/// An input or output of a transition. Outputs do not have a name.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct AbiParameter {
+    /// The name of the input.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The visibility of the input or output.
+    pub mode: AbiMode,
+    /// The type of the input or output, records of other programs are written as `program.aleo/Record`.
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+/// A transition of the program.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct AbiTransition {
+    /// The name of the transition.
+    pub name: String,
+    /// The inputs, in the order in which they are passed.
+    pub inputs: Vec<AbiParameter>,
+    /// The outputs, in the order in which they are returned.
+    pub outputs: Vec<AbiParameter>,
+    /// Does the transition have a finalize block?
+    pub has_finalize: bool,
+}
+
+/// The interface of a compiled program: everything that a client needs to call its transitions,
+/// read its records and query its mappings.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct Abi {
+    /// The program ID, e.g. `token.aleo`.
+    pub program: String,
+    /// The structs of the program.
+    pub structs: Vec<AbiStruct>,
+    /// The records of the program.
+    pub records: Vec<AbiStruct>,
+    /// The mappings of the program.
+    pub mappings: Vec<AbiMapping>,
+    /// The transitions of the program.
+    pub transitions: Vec<AbiTransition>,
+}
+
+impl Abi {
+    /// Returns the interface of the type-checked program scope, with the types of its symbol table.
+    pub fn new(program_scope: &ProgramScope, symbol_table: &SymbolTable) -> Self {
+        // The structs and records declared in the program, the layout of a generic struct depends on its type arguments.
+        let (records, structs): (Vec<_>, Vec<_>) = program_scope
+            .structs
+            .iter()
+            .filter_map(|(name, _)| symbol_table.lookup_struct(*name))
+            .filter(|struct_| !struct_.is_generic())
+            .partition(|struct_| struct_.is_record);
+        let layout = |struct_: &Struct| AbiStruct {
+            name: struct_.name().to_string(),
+            fields: struct_.members.iter().map(|member| Self::field(member, struct_.is_record)).collect(),
+        };
+
+        let mappings = program_scope
+            .mappings
+            .iter()
+            .map(|(name, mapping)| AbiMapping {
+                name: name.to_string(),
+                key: mapping.key_type.to_string(),
+                value: mapping.value_type.to_string(),
+            })
+            .collect();
+
+        let transitions = program_scope
+            .functions
+            .iter()
+            .filter(|(_, function)| function.variant == Variant::Transition)
+            .filter_map(|(name, function)| Some((function, symbol_table.lookup_fn_symbol(*name)?)))
+            .map(|(function, symbol)| AbiTransition {
+                name: function.name().to_string(),
+                inputs: symbol.input().iter().map(Self::input).collect(),
+                outputs: function.output.iter().map(Self::output).collect(),
+                has_finalize: symbol.has_finalize(),
+            })
+            .collect();
+
+        Self {
+            program: program_scope.program_id.to_string(),
+            structs: structs.into_iter().map(layout).collect(),
+            records: records.into_iter().map(layout).collect(),
+            mappings,
+            transitions,
+        }
+    }
+
+    /// Serializes the interface into a pretty-printed JSON string.
+    pub fn to_json_string(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    fn field(member: &Member, is_record: bool) -> AbiField {
+        AbiField {
+            name: member.name().to_string(),
+            mode: is_record.then(|| member.mode.into()),
+            type_: member.type_.to_string(),
+        }
+    }
+
+    fn input(input: &Input) -> AbiParameter {
+        let type_ = match input {
+            Input::Internal(input) => input.type_.to_string(),
+            Input::External(input) => format!("{}.aleo/{}", input.program_name, input.record),
+        };
+        AbiParameter { name: Some(input.identifier().to_string()), mode: input.mode().into(), type_ }
+    }
+
+    fn output(output: &Output) -> AbiParameter {
+        let type_ = match output {
+            Output::Internal(output) => output.type_.to_string(),
+            Output::External(output) => format!("{}.aleo/{}", output.program_name, output.record),
+        };
+        AbiParameter { name: None, mode: output.mode().into(), type_ }
+    }
+}
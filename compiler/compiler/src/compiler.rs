@@ -18,16 +18,16 @@
 //!
 //! The [`Compiler`] type compiles Leo programs into R1CS circuits.
 pub use leo_ast::{Ast, InputAst};
-use leo_ast::{NodeBuilder, Program};
+use leo_ast::{NodeBuilder, Program, Variant};
 use leo_errors::{emitter::Handler, CompilerError, Result};
-pub use leo_passes::SymbolTable;
 use leo_passes::*;
-use leo_span::{source_map::FileName, symbol::with_session_globals};
+pub use leo_passes::{DebugInfo, SymbolTable, TypeTable};
+use leo_span::{source_map::FileName, symbol::with_session_globals, Symbol};
 
 use sha2::{Digest, Sha256};
 use std::{fs, path::PathBuf};
 
-use crate::CompilerOptions;
+use crate::{Abi, CompilerOptions};
 
 /// The primary entry point of the Leo compiler.
 #[derive(Clone)]
@@ -54,6 +54,12 @@ pub struct Compiler<'a> {
     assigner: Assigner,
     /// The type table.
     type_table: TypeTable,
+    /// The sources of the instructions and registers of the generated program.
+    pub debug_info: DebugInfo,
+    /// The paths of the files of the modules used by the program.
+    pub modules: Vec<PathBuf>,
+    /// The interface of the program, as it was type checked.
+    abi: Option<Abi>,
 }
 
 impl<'a> Compiler<'a> {
@@ -81,6 +87,9 @@ impl<'a> Compiler<'a> {
             node_builder,
             assigner,
             type_table,
+            debug_info: DebugInfo::default(),
+            modules: Vec::new(),
+            abi: None,
         }
     }
 
@@ -104,7 +113,9 @@ impl<'a> Compiler<'a> {
         let prg_sf = with_session_globals(|s| s.source_map.new_source(program_string, name));
 
         // Use the parser to construct the abstract syntax tree (ast).
+        // The parser recovers from syntax errors to report all of them, but the program is not compiled any further.
         self.ast = leo_parser::parse_ast(self.handler, &self.node_builder, &prg_sf.src, prg_sf.start_pos)?;
+        self.handler.last_err().map_err(|e| *e)?;
 
         // If the program is imported, then check that the name of its program scope matches the file name.
         // Note that parsing enforces that there is exactly one program scope in a file.
@@ -120,6 +131,14 @@ impl<'a> Compiler<'a> {
             .into());
         }
 
+        // Merge the modules used by the program into its program scope, resolving them relative to the main file.
+        let source_directory = self.main_file_path.parent().map(PathBuf::from).unwrap_or_default();
+        self.modules =
+            leo_parser::resolve_modules(self.handler, &self.node_builder, &mut self.ast.ast, &source_directory)?;
+        self.handler.last_err().map_err(|e| *e)?;
+        // Declare the methods and associated constants of the structs as functions and consts of the program scope.
+        leo_parser::declare_impls(&mut self.ast.ast)?;
+
         if self.compiler_options.output.initial_ast {
             self.write_ast_to_json("initial_ast.json")?;
         }
@@ -186,6 +205,25 @@ impl<'a> Compiler<'a> {
         Ok((symbol_table, struct_graph, call_graph))
     }
 
+    /// Runs the method desugaring pass.
+    pub fn method_desugaring_pass(&mut self, symbol_table: &SymbolTable) -> Result<()> {
+        self.ast = MethodDesugarer::do_pass((std::mem::take(&mut self.ast), symbol_table, &self.type_table))?;
+        Ok(())
+    }
+
+    /// Runs the monomorphization pass.
+    /// Returns `true` if the program had generic functions or structs, in which case the result must be type checked again.
+    pub fn monomorphization_pass(&mut self) -> Result<bool> {
+        let is_generic = self.ast.ast.program_scopes.values().any(|scope| {
+            scope.functions.iter().any(|(_, function)| function.is_generic())
+                || scope.structs.iter().any(|(_, struct_)| struct_.is_generic())
+        });
+        if is_generic {
+            self.ast = Monomorphizer::do_pass((std::mem::take(&mut self.ast), &self.type_table, &self.node_builder))?;
+        }
+        Ok(is_generic)
+    }
+
     /// Runs the loop unrolling pass.
     pub fn loop_unrolling_pass(&mut self, symbol_table: SymbolTable) -> Result<SymbolTable> {
         let (ast, symbol_table) = Unroller::do_pass((
@@ -208,6 +246,17 @@ impl<'a> Compiler<'a> {
         Ok(symbol_table)
     }
 
+    /// Runs the dynamic indexing pass.
+    pub fn dynamic_indexing_pass(&mut self) -> Result<()> {
+        self.ast = DynamicIndexLowerer::do_pass((
+            std::mem::take(&mut self.ast),
+            &self.node_builder,
+            &self.assigner,
+            &self.type_table,
+        ))?;
+        Ok(())
+    }
+
     /// Runs the static single assignment pass.
     pub fn static_single_assignment_pass(&mut self, symbol_table: &SymbolTable) -> Result<()> {
         self.ast = StaticSingleAssigner::do_pass((
@@ -242,6 +291,17 @@ impl<'a> Compiler<'a> {
         Ok(())
     }
 
+    /// Runs the constant folding pass.
+    pub fn constant_folding_pass(&mut self) -> Result<()> {
+        self.ast = ConstantFolder::do_pass((std::mem::take(&mut self.ast), self.handler))?;
+
+        if self.compiler_options.output.const_folded_ast {
+            self.write_ast_to_json("const_folded_ast.json")?;
+        }
+
+        Ok(())
+    }
+
     /// Runs the destructuring pass.
     pub fn destructuring_pass(&mut self) -> Result<()> {
         self.ast = Destructurer::do_pass((
@@ -296,21 +356,106 @@ impl<'a> Compiler<'a> {
         struct_graph: &StructGraph,
         call_graph: &CallGraph,
     ) -> Result<String> {
-        CodeGenerator::do_pass((&self.ast, symbol_table, &self.type_table, struct_graph, call_graph, &self.ast.ast))
+        let (bytecode, debug_info) = CodeGenerator::do_pass((
+            &self.ast,
+            symbol_table,
+            &self.type_table,
+            struct_graph,
+            call_graph,
+            &self.ast.ast,
+        ))?;
+        self.debug_info = debug_info;
+        self.debug_info.record_files();
+        Ok(bytecode)
     }
 
-    /// Runs the compiler stages.
-    pub fn compiler_stages(&mut self) -> Result<(SymbolTable, StructGraph, CallGraph)> {
+    /// Returns the names of the `@test` functions of the program.
+    pub fn tests(&self) -> Vec<Symbol> {
+        self.ast
+            .ast
+            .program_scopes
+            .values()
+            .flat_map(|scope| scope.functions.iter())
+            .filter(|(_, function)| function.is_test())
+            .map(|(name, _)| *name)
+            .collect()
+    }
+
+    /// Compiles the `@test` functions of the program as transitions, so that they can be executed.
+    /// This must run before the symbol table is created, and does nothing unless tests are enabled.
+    pub fn expose_tests_pass(&mut self) {
+        if !self.compiler_options.build.tests_enabled {
+            return;
+        }
+        self.ast.ast.program_scopes.values_mut().flat_map(|scope| scope.functions.iter_mut()).for_each(
+            |(_, function)| {
+                if function.is_test() {
+                    function.variant = Variant::Transition;
+                }
+            },
+        );
+    }
+
+    /// Removes the `@test` functions from the program, once they have been type checked.
+    /// This does nothing if tests are enabled.
+    pub fn strip_tests_pass(&mut self) {
+        if self.compiler_options.build.tests_enabled {
+            return;
+        }
+        self.ast
+            .ast
+            .program_scopes
+            .values_mut()
+            .for_each(|scope| scope.functions.retain(|(_, function)| !function.is_test()));
+    }
+
+    /// Returns the types of the expressions of the program, as inferred by the type checker.
+    pub fn type_table(&self) -> &TypeTable {
+        &self.type_table
+    }
+
+    /// Runs the stages that check the program and resolve its methods and generics, leaving its control flow intact.
+    pub fn front_end_stages(&mut self) -> Result<(SymbolTable, StructGraph, CallGraph)> {
+        self.expose_tests_pass();
+
         let st = self.symbol_table_pass()?;
         let (st, struct_graph, call_graph) = self.type_checker_pass(st)?;
 
+        // The tests are type checked with the rest of the program, but are only compiled when they are enabled.
+        self.strip_tests_pass();
+
+        // The interface is that of the program as written, before its generics and enums are lowered.
+        self.abi = self.ast.ast.program_scopes.values().next().map(|program_scope| Abi::new(program_scope, &st));
+
+        self.method_desugaring_pass(&st)?;
+
+        // Check the concrete copies of the generic functions and structs like the rest of the program.
+        let (st, struct_graph, call_graph) = match self.monomorphization_pass()? {
+            true => {
+                let st = self.symbol_table_pass()?;
+                self.type_checker_pass(st)?
+            }
+            false => (st, struct_graph, call_graph),
+        };
+
+        Ok((st, struct_graph, call_graph))
+    }
+
+    /// Runs the compiler stages.
+    pub fn compiler_stages(&mut self) -> Result<(SymbolTable, StructGraph, CallGraph)> {
+        let (st, struct_graph, call_graph) = self.front_end_stages()?;
+
         // TODO: Make this pass optional.
         let st = self.loop_unrolling_pass(st)?;
 
+        self.dynamic_indexing_pass()?;
+
         self.static_single_assignment_pass(&st)?;
 
         self.flattening_pass(&st)?;
 
+        self.constant_folding_pass()?;
+
         self.destructuring_pass()?;
 
         self.function_inlining_pass(&call_graph)?;
@@ -331,6 +476,11 @@ impl<'a> Compiler<'a> {
         Ok((symbol_table, bytecode))
     }
 
+    /// Returns the interface of the program, which is known once the program has been type checked.
+    pub fn abi(&self) -> Result<&Abi> {
+        Ok(self.abi.as_ref().ok_or_else(|| CompilerError::program_interface_not_found(&self.program_name))?)
+    }
+
     /// Writes the AST to a JSON file.
     fn write_ast_to_json(&self, file_suffix: &str) -> Result<()> {
         // Remove `Span`s if they are not enabled.
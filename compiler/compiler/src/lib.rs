@@ -19,6 +19,9 @@
 #![allow(clippy::upper_case_acronyms)]
 #![doc = include_str!("../README.md")]
 
+mod abi;
+pub use abi::*;
+
 mod compiler;
 pub use compiler::*;
 
@@ -28,6 +28,8 @@ pub struct CompilerOptions {
 pub struct BuildOptions {
     /// Whether to enable dead code elimination.
     pub dce_enabled: bool,
+    /// Whether to compile the `@test` functions as transitions, so that they can be executed.
+    pub tests_enabled: bool,
 }
 
 #[derive(Clone, Default)]
@@ -52,6 +54,8 @@ pub struct OutputOptions {
     pub ssa_ast: bool,
     /// If enabled writes the AST after flattening.
     pub flattened_ast: bool,
+    /// If enabled writes the AST after constant folding.
+    pub const_folded_ast: bool,
     /// If enabled writes the AST after destructuring.
     pub destructured_ast: bool,
     /// If enabled writes the AST after inlining.
@@ -66,6 +66,7 @@ struct CompileOutput {
     pub unrolled_ast: String,
     pub ssa_ast: String,
     pub flattened_ast: String,
+    pub const_folded_ast: String,
     pub destructured_ast: String,
     pub inlined_ast: String,
     pub dce_ast: String,
@@ -96,6 +97,7 @@ fn run_test(test: Test, handler: &Handler, buf: &BufferEmitter) -> Result<Value,
                 unrolled_ast: true,
                 ssa_ast: true,
                 flattened_ast: true,
+                const_folded_ast: true,
                 destructured_ast: true,
                 inlined_ast: true,
                 dce_ast: true,
@@ -118,7 +120,16 @@ fn run_test(test: Test, handler: &Handler, buf: &BufferEmitter) -> Result<Value,
         handler.extend_if_error(package.get_process().map_err(LeoError::Anyhow))?;
 
         // Hash the ast files.
-        let (initial_ast, unrolled_ast, ssa_ast, flattened_ast, destructured_ast, inlined_ast, dce_ast) = hash_asts();
+        let (
+            initial_ast,
+            unrolled_ast,
+            ssa_ast,
+            flattened_ast,
+            const_folded_ast,
+            destructured_ast,
+            inlined_ast,
+            dce_ast,
+        ) = hash_asts();
 
         // Hash the symbol tables.
         let (initial_symbol_table, type_checked_symbol_table, unrolled_symbol_table) = hash_symbol_tables();
@@ -136,6 +147,7 @@ fn run_test(test: Test, handler: &Handler, buf: &BufferEmitter) -> Result<Value,
             unrolled_ast,
             ssa_ast,
             flattened_ast,
+            const_folded_ast,
             destructured_ast,
             inlined_ast,
             dce_ast,
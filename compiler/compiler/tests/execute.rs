@@ -74,6 +74,7 @@ struct ExecuteOutput {
     pub unrolled_ast: String,
     pub ssa_ast: String,
     pub flattened_ast: String,
+    pub const_folded_ast: String,
     pub destructured_ast: String,
     pub inlined_ast: String,
     pub dce_ast: String,
@@ -110,6 +111,7 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
                 unrolled_ast: true,
                 ssa_ast: true,
                 flattened_ast: true,
+                const_folded_ast: true,
                 destructured_ast: true,
                 inlined_ast: true,
                 dce_ast: true,
@@ -202,7 +204,16 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
         }
 
         // Hash the ast files.
-        let (initial_ast, unrolled_ast, ssa_ast, flattened_ast, destructured_ast, inlined_ast, dce_ast) = hash_asts();
+        let (
+            initial_ast,
+            unrolled_ast,
+            ssa_ast,
+            flattened_ast,
+            const_folded_ast,
+            destructured_ast,
+            inlined_ast,
+            dce_ast,
+        ) = hash_asts();
 
         // Hash the symbol tables.
         let (initial_symbol_table, type_checked_symbol_table, unrolled_symbol_table) = hash_symbol_tables();
@@ -220,6 +231,7 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
             unrolled_ast,
             ssa_ast,
             flattened_ast,
+            const_folded_ast,
             destructured_ast,
             inlined_ast,
             dce_ast,
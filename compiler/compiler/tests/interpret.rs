@@ -0,0 +1,162 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+mod utilities;
+use utilities::{buffer_if_err, get_cwd_option, parse_program, BufferEmitter};
+
+use leo_errors::emitter::Handler;
+use leo_interpreter::{Debugger, Interpreter};
+use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+use leo_test_framework::{
+    runner::{Namespace, ParseType, Runner},
+    test::TestExpectationMode,
+    Test,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_yaml::Value;
+use std::{cell::RefCell, collections::BTreeMap, io::Write, rc::Rc};
+
+struct InterpretNamespace;
+
+impl Namespace for InterpretNamespace {
+    fn parse_type(&self) -> ParseType {
+        ParseType::Whole
+    }
+
+    fn run_test(&self, test: Test) -> Result<Value, String> {
+        let buf = BufferEmitter(Rc::default(), Rc::default());
+        let handler = Handler::new(Box::new(buf.clone()));
+        create_session_if_not_set_then(|_| {
+            run_test(test, &handler, &buf).map_err(|()| buf.0.take().to_string() + &buf.1.take().to_string())
+        })
+    }
+}
+
+#[derive(Deserialize, PartialEq, Eq, Serialize)]
+struct InterpretOutput {
+    pub warnings: String,
+    pub results: BTreeMap<String, Vec<BTreeMap<String, String>>>,
+}
+
+fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Value, ()> {
+    // Check that config expectation is always pass.
+    if test.config.expectation != TestExpectationMode::Pass {
+        buffer_if_err(err_buf, Err("Test expectation must be `Pass` for `Interpret` tests.".to_string()))?;
+    }
+
+    // Check for CWD option:
+    let cwd = get_cwd_option(&test);
+
+    // Parse and check the program.
+    let mut parsed = handler.extend_if_error(parse_program(handler, &test.content, cwd, None))?;
+    handler.extend_if_error(parsed.front_end_stages())?;
+
+    // Extract the cases from the test config.
+    let all_cases =
+        test.config.extra.get("cases").expect("An `Interpret` config must have a `cases` field.").as_mapping().unwrap();
+
+    // The mappings persist from one case to the next, as they would on chain.
+    let mut interpreter = Interpreter::new(&parsed.ast.ast, parsed.type_table());
+
+    // Run each test case for each function.
+    let mut results = BTreeMap::new();
+    for (function_name, function_cases) in all_cases {
+        let function_name = function_name.as_str().unwrap();
+        let function = Symbol::intern(function_name);
+        let cases = function_cases.as_sequence().unwrap();
+        let mut function_results = Vec::with_capacity(cases.len());
+
+        for case in cases {
+            let case = case.as_mapping().unwrap();
+            let inputs: Vec<String> = case
+                .get(&Value::from("input"))
+                .unwrap()
+                .as_sequence()
+                .unwrap()
+                .iter()
+                .map(|input| input.as_str().unwrap().to_string())
+                .collect();
+
+            // A case with debugger commands runs them, and records what the debugger shows.
+            let transcript = SharedBuffer::default();
+            let commands = case.get(&Value::from("commands")).map(|commands| {
+                commands
+                    .as_sequence()
+                    .unwrap()
+                    .iter()
+                    .map(|command| format!("{}\n", command.as_str().unwrap()))
+                    .collect()
+            });
+            interpreter.set_hook(commands.map(|commands: String| {
+                Box::new(Debugger::new(std::io::Cursor::new(commands), transcript.clone(), None)) as Box<_>
+            }));
+
+            let output = interpreter
+                .parse_inputs(function, &inputs)
+                .and_then(|inputs| interpreter.run(function, inputs))
+                .map_or_else(
+                    |err| err.to_string(),
+                    |outputs| {
+                        format!("[{}]", outputs.iter().map(|output| output.to_string()).collect::<Vec<_>>().join(", "))
+                    },
+                );
+
+            // Store the inputs and outputs in a map.
+            let mut result = BTreeMap::new();
+            result.insert("input".to_string(), format!("[{}]", inputs.join(", ")));
+            result.insert("output".to_string(), output);
+            if case.contains_key(&Value::from("commands")) {
+                result.insert("transcript".to_string(), String::from_utf8(transcript.0.take()).unwrap());
+            }
+            function_results.push(result);
+        }
+        results.insert(function_name.to_string(), function_results);
+    }
+
+    let final_output = InterpretOutput { warnings: err_buf.1.take().to_string(), results };
+    Ok(serde_yaml::to_value(final_output).expect("serialization failed"))
+}
+
+/// A buffer that the debugger of a case writes to, and that is read once the case has run.
+#[derive(Clone, Default)]
+struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+struct TestRunner;
+
+impl Runner for TestRunner {
+    fn resolve_namespace(&self, name: &str) -> Option<Box<dyn Namespace>> {
+        Some(match name {
+            "Interpret" => Box::new(InterpretNamespace),
+            _ => return None,
+        })
+    }
+}
+
+#[test]
+pub fn interpretation_tests() {
+    leo_test_framework::run_tests(&TestRunner, "interpretation");
+}
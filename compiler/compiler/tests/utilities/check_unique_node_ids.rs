@@ -85,6 +85,14 @@ impl<'a> ExpressionVisitor<'a> for CheckUniqueNodeIds<'a> {
                 self.visit_identifier(name, &Default::default());
                 self.check(*id);
             }
+            AccessExpression::MethodCall(MethodCall { receiver, name, arguments, id, .. }) => {
+                self.visit_expression(receiver, &Default::default());
+                self.visit_identifier(name, &Default::default());
+                for argument in arguments {
+                    self.visit_expression(argument, &Default::default());
+                }
+                self.check(*id);
+            }
             AccessExpression::Tuple(TupleAccess { tuple, id, .. }) => {
                 self.visit_expression(tuple, &Default::default());
                 self.check(*id);
@@ -193,6 +201,10 @@ impl<'a> StatementVisitor<'a> for CheckUniqueNodeIds<'a> {
         self.check(input.id)
     }
 
+    fn visit_break(&mut self, input: &'a BreakStatement) {
+        self.check(input.id)
+    }
+
     fn visit_conditional(&mut self, input: &'a ConditionalStatement) {
         self.visit_expression(&input.condition, &Default::default());
         self.visit_block(&input.then);
@@ -219,9 +231,15 @@ impl<'a> StatementVisitor<'a> for CheckUniqueNodeIds<'a> {
         self.check(input.id)
     }
 
+    fn visit_continue(&mut self, input: &'a ContinueStatement) {
+        self.check(input.id)
+    }
+
     fn visit_definition(&mut self, input: &'a DefinitionStatement) {
         self.visit_expression(&input.place, &Default::default());
-        self.check_ty(&input.type_);
+        if let Some(type_) = &input.type_ {
+            self.check_ty(type_);
+        }
         self.visit_expression(&input.value, &Default::default());
         self.check(input.id)
     }
@@ -249,6 +267,13 @@ impl<'a> StatementVisitor<'a> for CheckUniqueNodeIds<'a> {
         }
         self.check(input.id)
     }
+
+    fn visit_while(&mut self, input: &'a WhileStatement) {
+        self.visit_expression(&input.condition, &Default::default());
+        self.visit_expression(&input.bound, &Default::default());
+        self.visit_block(&input.block);
+        self.check(input.id)
+    }
 }
 
 impl<'a> ProgramVisitor<'a> for CheckUniqueNodeIds<'a> {
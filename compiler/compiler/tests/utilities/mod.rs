@@ -44,18 +44,21 @@ pub type Network = Testnet3;
 #[allow(unused)]
 pub type Aleo = snarkvm::circuit::AleoV0;
 
-pub fn hash_asts() -> (String, String, String, String, String, String, String) {
+#[allow(unused)]
+pub fn hash_asts() -> (String, String, String, String, String, String, String, String) {
     let initial_ast = hash_file("/tmp/output/test.initial_ast.json");
     let unrolled_ast = hash_file("/tmp/output/test.unrolled_ast.json");
     let ssa_ast = hash_file("/tmp/output/test.ssa_ast.json");
     let flattened_ast = hash_file("/tmp/output/test.flattened_ast.json");
+    let const_folded_ast = hash_file("/tmp/output/test.const_folded_ast.json");
     let destructured_ast = hash_file("/tmp/output/test.destructured_ast.json");
     let inlined_ast = hash_file("/tmp/output/test.inlined_ast.json");
     let dce_ast = hash_file("/tmp/output/test.dce_ast.json");
 
-    (initial_ast, unrolled_ast, ssa_ast, flattened_ast, destructured_ast, inlined_ast, dce_ast)
+    (initial_ast, unrolled_ast, ssa_ast, flattened_ast, const_folded_ast, destructured_ast, inlined_ast, dce_ast)
 }
 
+#[allow(unused)]
 pub fn hash_symbol_tables() -> (String, String, String) {
     let initial_symbol_table = hash_file("/tmp/output/test.initial_symbol_table.json");
     let type_checked_symbol_table = hash_file("/tmp/output/test.type_checked_symbol_table.json");
@@ -75,6 +78,7 @@ pub fn get_cwd_option(test: &Test) -> Option<PathBuf> {
     })
 }
 
+#[allow(unused)]
 pub fn get_build_options(test_config: &TestConfig) -> Vec<BuildOptions> {
     match test_config.extra.get("configs") {
         Some(configs) => {
@@ -85,22 +89,23 @@ pub fn get_build_options(test_config: &TestConfig) -> Vec<BuildOptions> {
                 .iter()
                 .map(|config| {
                     let config = config.as_mapping().expect("Expected the compiler configuration to be a mapping.");
-                    assert_eq!(
-                        config.len(),
-                        1,
-                        "A compiler configuration must have exactly one key-value pair. e.g. `dce_enabled`: true"
+                    let get_flag = |key: &str| {
+                        config
+                            .get(&serde_yaml::Value::String(key.to_string()))
+                            .map(|value| value.as_bool().expect("Expected value to be a boolean."))
+                    };
+                    assert!(
+                        config.iter().all(|(key, _)| matches!(key.as_str(), Some("dce_enabled" | "tests_enabled"))),
+                        "A compiler configuration may only contain `dce_enabled` and `tests_enabled`. e.g. `dce_enabled`: true"
                     );
                     BuildOptions {
-                        dce_enabled: config
-                            .get(&serde_yaml::Value::String("dce_enabled".to_string()))
-                            .expect("Expected key `dce_enabled`")
-                            .as_bool()
-                            .expect("Expected value to be a boolean."),
+                        dce_enabled: get_flag("dce_enabled").expect("Expected key `dce_enabled`"),
+                        tests_enabled: get_flag("tests_enabled").unwrap_or(false),
                     }
                 })
                 .collect()
         }
-        None => vec![BuildOptions { dce_enabled: true }],
+        None => vec![BuildOptions { dce_enabled: true, tests_enabled: false }],
     }
 }
 
@@ -160,6 +165,7 @@ pub fn parse_program<'a>(
     Ok(compiler)
 }
 
+#[allow(unused)]
 pub fn hash_content(content: &str) -> String {
     use sha2::{Digest, Sha256};
     let mut hasher = Sha256::new();
@@ -169,6 +175,7 @@ pub fn hash_content(content: &str) -> String {
     format!("{hash:x}")
 }
 
+#[allow(unused)]
 pub fn hash_file(path: &str) -> String {
     let file = fs::read_to_string(Path::new(path)).unwrap();
     hash_content(&file)
@@ -221,7 +228,10 @@ pub fn temp_dir() -> PathBuf {
     tempfile::tempdir().expect("Failed to open temporary directory").into_path()
 }
 
+#[allow(unused)]
 pub fn compile_and_process<'a>(parsed: &'a mut Compiler<'a>) -> Result<String, LeoError> {
+    parsed.expose_tests_pass();
+
     let st = parsed.symbol_table_pass()?;
 
     CheckUniqueNodeIds::new().visit_program(&parsed.ast.ast);
@@ -230,12 +240,28 @@ pub fn compile_and_process<'a>(parsed: &'a mut Compiler<'a>) -> Result<String, L
 
     CheckUniqueNodeIds::new().visit_program(&parsed.ast.ast);
 
+    parsed.strip_tests_pass();
+
+    parsed.method_desugaring_pass(&st)?;
+
+    let (st, struct_graph, call_graph) = match parsed.monomorphization_pass()? {
+        true => {
+            let st = parsed.symbol_table_pass()?;
+            parsed.type_checker_pass(st)?
+        }
+        false => (st, struct_graph, call_graph),
+    };
+
     let st = parsed.loop_unrolling_pass(st)?;
 
+    parsed.dynamic_indexing_pass()?;
+
     parsed.static_single_assignment_pass(&st)?;
 
     parsed.flattening_pass(&st)?;
 
+    parsed.constant_folding_pass()?;
+
     parsed.destructuring_pass()?;
 
     parsed.function_inlining_pass(&call_graph)?;
@@ -0,0 +1,176 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{CurrentNetwork, Interpreter, Value};
+
+use leo_ast::{AssociatedFunction, Expression};
+use leo_errors::{InterpreterError, Result};
+use leo_span::{sym, Span, Symbol};
+
+use snarkvm_console::{
+    network::Network,
+    prelude::{bail, Result as ConsoleResult, ToBits, ToFields},
+    program::{Literal, LiteralType, Plaintext},
+    types::{Boolean, Field},
+};
+
+use std::str::FromStr;
+
+type N = CurrentNetwork;
+
+impl Interpreter<'_> {
+    /// Evaluates a call to a core function, e.g. `BHP256::hash_to_field(x)`, with the semantics of snarkVM.
+    pub(crate) fn evaluate_core_function(
+        &mut self,
+        module: Symbol,
+        function: Symbol,
+        arguments: Vec<Value>,
+        span: Span,
+    ) -> Result<Value> {
+        let operation = format!("{module}::{function}");
+        let module_name = module.to_string();
+        let function_name = function.to_string();
+
+        let result = if let Some(destination) = function_name.strip_prefix("hash_to_") {
+            plaintext(&arguments[0]).and_then(|input| hash(&module_name, &input, destination_type(destination)?))
+        } else if let Some(destination) = function_name.strip_prefix("commit_to_") {
+            let randomizer = match arguments[1].to_console() {
+                Some(Literal::Scalar(randomizer)) => randomizer,
+                _ => unreachable!("Type checking guarantees that commitments are randomized by scalars."),
+            };
+            plaintext(&arguments[0]).and_then(|input| {
+                let bits = input.to_bits_le();
+                let commitment = match module_name.as_str() {
+                    "BHP256" => N::commit_to_group_bhp256(&bits, &randomizer)?,
+                    "BHP512" => N::commit_to_group_bhp512(&bits, &randomizer)?,
+                    "BHP768" => N::commit_to_group_bhp768(&bits, &randomizer)?,
+                    "BHP1024" => N::commit_to_group_bhp1024(&bits, &randomizer)?,
+                    "Pedersen64" => N::commit_to_group_ped64(&bits, &randomizer)?,
+                    "Pedersen128" => N::commit_to_group_ped128(&bits, &randomizer)?,
+                    _ => bail!("unknown commitment scheme"),
+                };
+                Literal::Group(commitment).cast_lossy(destination_type(destination)?)
+            })
+        } else if let Some(destination) = function_name.strip_prefix("rand_") {
+            // The random values are derived from a counter, so that runs are reproducible.
+            self.random_counter += 1;
+            let seed = Field::from_u64(self.random_counter);
+            N::hash_to_group_psd2(&[seed])
+                .and_then(|group| Literal::Group(group).cast_lossy(destination_type(destination)?))
+        } else {
+            match (module, function, arguments.as_slice()) {
+                (sym::group, sym::to_x_coordinate, [value]) | (sym::group, sym::to_y_coordinate, [value]) => {
+                    match value.to_console() {
+                        Some(Literal::Group(group)) if function == sym::to_x_coordinate => {
+                            Ok(Literal::Field(group.to_x_coordinate()))
+                        }
+                        Some(Literal::Group(group)) => Ok(Literal::Field(group.to_y_coordinate())),
+                        _ => unreachable!("Type checking guarantees that `{operation}` takes a group."),
+                    }
+                }
+                (sym::signature, sym::verify, [signature, address, message]) => {
+                    match (signature.to_console(), address.to_console()) {
+                        (Some(Literal::Signature(signature)), Some(Literal::Address(address))) => plaintext(message)
+                            .and_then(|message| message.to_fields())
+                            .map(|message| Literal::Boolean(Boolean::new(signature.verify(&address, &message)))),
+                        _ => unreachable!(
+                            "Type checking guarantees that `{operation}` takes a signature and an address."
+                        ),
+                    }
+                }
+                _ => unreachable!("Type checking guarantees that `{operation}` is a core function."),
+            }
+        };
+
+        match result {
+            Ok(literal) => Ok(Value::from_console(literal)),
+            Err(err) => Err(InterpreterError::operation_failed(operation, err, span).into()),
+        }
+    }
+
+    /// Evaluates a call to a function of a mapping of the program that is running, e.g. `Mapping::get(balances, owner)`.
+    pub(crate) fn evaluate_mapping_function(&mut self, access: &AssociatedFunction) -> Result<Value> {
+        let mapping = match access.arguments.first() {
+            Some(Expression::Identifier(mapping)) => mapping.name,
+            _ => unreachable!("Type checking guarantees that the first argument of a mapping function is a mapping."),
+        };
+        let program = self.frame().program;
+        let mut arguments = self.evaluate_expressions(&access.arguments[1..])?.into_iter();
+        let key = arguments.next().expect("Type checking guarantees that mapping functions take a key.");
+
+        match access.name.name {
+            sym::get => match self.store.get(program, mapping, &key) {
+                Some(value) => Ok(value.clone()),
+                None => Err(InterpreterError::mapping_key_not_found(mapping, key, access.span).into()),
+            },
+            sym::get_or_use => {
+                let default = arguments.next().expect("Type checking guarantees that `get_or_use` takes a default.");
+                Ok(self.store.get(program, mapping, &key).cloned().unwrap_or(default))
+            }
+            sym::set => {
+                let value = arguments.next().expect("Type checking guarantees that `set` takes a value.");
+                self.store.set(program, mapping, key, value);
+                Ok(Value::Unit)
+            }
+            sym::remove => {
+                self.store.remove(program, mapping, &key);
+                Ok(Value::Unit)
+            }
+            sym::contains => Ok(Value::boolean(self.store.contains(program, mapping, &key))),
+            _ => unreachable!("Type checking guarantees that mapping functions exist."),
+        }
+    }
+}
+
+/// Returns the plaintext of a value that is passed to a core function.
+fn plaintext(value: &Value) -> ConsoleResult<Plaintext<N>> {
+    match value.to_plaintext() {
+        Some(plaintext) => Ok(plaintext),
+        None => bail!("`{value}` has no Aleo representation"),
+    }
+}
+
+/// Returns the type of the output of a core function, from the suffix of its name, e.g. `field` in `hash_to_field`.
+fn destination_type(destination: &str) -> ConsoleResult<LiteralType> {
+    LiteralType::from_str(if destination == "bool" { "boolean" } else { destination })
+}
+
+/// Hashes a plaintext with the hash function of a core module, as the `hash` instructions of snarkVM do.
+fn hash(module: &str, input: &Plaintext<N>, destination: LiteralType) -> ConsoleResult<Literal<N>> {
+    let to_group = matches!(destination, LiteralType::Address | LiteralType::Group);
+    let output = match module {
+        "BHP256" => Literal::Group(N::hash_to_group_bhp256(&input.to_bits_le())?),
+        "BHP512" => Literal::Group(N::hash_to_group_bhp512(&input.to_bits_le())?),
+        "BHP768" => Literal::Group(N::hash_to_group_bhp768(&input.to_bits_le())?),
+        "BHP1024" => Literal::Group(N::hash_to_group_bhp1024(&input.to_bits_le())?),
+        "Keccak256" => Literal::Group(N::hash_to_group_bhp256(&N::hash_keccak256(&input.to_bits_le())?)?),
+        "Keccak384" => Literal::Group(N::hash_to_group_bhp512(&N::hash_keccak384(&input.to_bits_le())?)?),
+        "Keccak512" => Literal::Group(N::hash_to_group_bhp512(&N::hash_keccak512(&input.to_bits_le())?)?),
+        "Pedersen64" => Literal::Group(N::hash_to_group_ped64(&input.to_bits_le())?),
+        "Pedersen128" => Literal::Group(N::hash_to_group_ped128(&input.to_bits_le())?),
+        "Poseidon2" if to_group => Literal::Group(N::hash_to_group_psd2(&input.to_fields()?)?),
+        "Poseidon2" => Literal::Field(N::hash_psd2(&input.to_fields()?)?),
+        "Poseidon4" if to_group => Literal::Group(N::hash_to_group_psd4(&input.to_fields()?)?),
+        "Poseidon4" => Literal::Field(N::hash_psd4(&input.to_fields()?)?),
+        "Poseidon8" if to_group => Literal::Group(N::hash_to_group_psd8(&input.to_fields()?)?),
+        "Poseidon8" => Literal::Field(N::hash_psd8(&input.to_fields()?)?),
+        "SHA3_256" => Literal::Group(N::hash_to_group_bhp256(&N::hash_sha3_256(&input.to_bits_le())?)?),
+        "SHA3_384" => Literal::Group(N::hash_to_group_bhp512(&N::hash_sha3_384(&input.to_bits_le())?)?),
+        "SHA3_512" => Literal::Group(N::hash_to_group_bhp512(&N::hash_sha3_512(&input.to_bits_le())?)?),
+        _ => bail!("unknown hash function"),
+    };
+    output.cast_lossy(destination)
+}
@@ -0,0 +1,271 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Interpreter, StatementHook, Value};
+
+use leo_ast::{Node, Statement};
+use leo_errors::{InterpreterError, LeoError, Result};
+use leo_span::{symbol::with_session_globals, Span, Symbol};
+
+use indexmap::IndexMap;
+use std::{
+    collections::BTreeSet,
+    fmt::Display,
+    io::{BufRead, Write},
+};
+
+const HELP: &str = "Commands:
+  step, s               Run to the next statement, entering calls.
+  next, n               Run to the next statement of this call, stepping over calls.
+  continue, c           Run to the next breakpoint.
+  break, b <line>       Stop before the statements on a line. Without a line, lists the breakpoints.
+  delete, d <line>      Remove the breakpoint on a line.
+  print, p <path>       Print a variable, or a member or element of one, e.g. `p point.x` or `p values[2]`.
+  locals, l             Print the variables in scope.
+  mapping, m <name>     Print the entries of a mapping of the running program.
+  where, w              Print the calls that are running.
+  quit, q               Stop the run.";
+
+/// How far the program runs before the debugger stops it again.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Mode {
+    /// Stop before the next statement.
+    Step,
+    /// Stop before the next statement that runs in at most this many frames.
+    Next(usize),
+    /// Stop at the next breakpoint.
+    Continue,
+    /// Never stop again, since there are no more commands to read.
+    Detached,
+}
+
+/// An interactive debugger, which stops a run before its statements so that its state can be inspected.
+/// Commands are read from `input`, and the state of the run is written to `output`.
+pub struct Debugger<R, W> {
+    input: R,
+    output: W,
+    /// The source lines that the run stops on.
+    breakpoints: BTreeSet<usize>,
+    mode: Mode,
+    /// The line of the last statement that ran, so that a breakpoint stops each visit to its line only once.
+    last_line: Option<usize>,
+}
+
+impl<R: BufRead, W: Write> Debugger<R, W> {
+    /// Returns a new debugger with the given breakpoints.
+    /// Without breakpoints, the debugger stops before the first statement.
+    pub fn new(input: R, output: W, breakpoints: impl IntoIterator<Item = usize>) -> Self {
+        let breakpoints: BTreeSet<usize> = breakpoints.into_iter().collect();
+        let mode = if breakpoints.is_empty() { Mode::Step } else { Mode::Continue };
+        Self { input, output, breakpoints, mode, last_line: None }
+    }
+
+    /// Writes a line of output. The debugger carries on even if the output is closed.
+    fn say(&mut self, message: impl Display) {
+        let _ = writeln!(self.output, "{message}");
+    }
+
+    /// Reads commands until one of them resumes the run. Returns whether the run should be stopped.
+    fn prompt(&mut self, interpreter: &Interpreter) -> bool {
+        loop {
+            let _ = write!(self.output, "(debug) ");
+            let _ = self.output.flush();
+
+            let mut line = String::new();
+            match self.input.read_line(&mut line) {
+                Ok(0) | Err(_) => {
+                    // Without more commands, the rest of the run goes on uninterrupted.
+                    self.say("");
+                    self.mode = Mode::Detached;
+                    return false;
+                }
+                Ok(_) => {}
+            }
+            let line = line.trim();
+            let (command, argument) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            let argument = argument.trim();
+
+            match command {
+                "" => {}
+                "s" | "step" => {
+                    self.mode = Mode::Step;
+                    return false;
+                }
+                "n" | "next" => {
+                    self.mode = Mode::Next(interpreter.frames().len());
+                    return false;
+                }
+                "c" | "continue" => {
+                    self.mode = Mode::Continue;
+                    return false;
+                }
+                "q" | "quit" => return true,
+                "b" | "break" if argument.is_empty() => match self.breakpoints.is_empty() {
+                    true => self.say("No breakpoints."),
+                    false => {
+                        let lines = self.breakpoints.iter().map(|line| line.to_string()).collect::<Vec<_>>();
+                        self.say(format!("Breakpoints on lines {}.", lines.join(", ")));
+                    }
+                },
+                "b" | "break" => match argument.parse::<usize>() {
+                    Ok(line) => {
+                        self.breakpoints.insert(line);
+                        self.say(format!("Breakpoint on line {line}."));
+                    }
+                    Err(_) => self.say(format!("`{argument}` is not a line number.")),
+                },
+                "d" | "delete" => match argument.parse::<usize>() {
+                    Ok(line) if self.breakpoints.remove(&line) => {
+                        self.say(format!("Removed the breakpoint on line {line}."))
+                    }
+                    _ => self.say(format!("There is no breakpoint on line `{argument}`.")),
+                },
+                "p" | "print" => match inspect(interpreter, argument) {
+                    Ok(value) => self.say(format!("{argument} = {value}")),
+                    Err(message) => self.say(message),
+                },
+                "l" | "locals" => {
+                    // Inner scopes shadow the variables of outer scopes.
+                    let mut locals = IndexMap::new();
+                    for scope in &interpreter.frames().last().expect("A function is running.").scopes {
+                        locals.extend(scope.iter());
+                    }
+                    match locals.is_empty() {
+                        true => self.say("No variables are in scope."),
+                        false => locals.into_iter().for_each(|(name, value)| self.say(format!("{name} = {value}"))),
+                    }
+                }
+                "m" | "mapping" => {
+                    let program = interpreter.frames().last().expect("A function is running.").program;
+                    let entries = interpreter
+                        .store
+                        .entries(program, Symbol::intern(argument))
+                        .map(|(key, value)| format!("{key} => {value}"))
+                        .collect::<Vec<_>>();
+                    match entries.is_empty() {
+                        true => self.say(format!("The mapping `{argument}` is empty.")),
+                        false => entries.into_iter().for_each(|entry| self.say(entry)),
+                    }
+                }
+                "w" | "where" => {
+                    for (depth, frame) in interpreter.frames().iter().rev().enumerate() {
+                        let kind = if frame.is_finalize { "finalize" } else { "function" };
+                        self.say(format!("#{depth} {kind} {}.aleo/{}", frame.program, frame.function));
+                    }
+                }
+                "h" | "help" => self.say(HELP),
+                _ => self.say(format!("Unknown command `{command}`. Type `help` for a list of commands.")),
+            }
+        }
+    }
+
+    /// Writes where the run is, and the source line it is at.
+    fn show_position(&mut self, interpreter: &Interpreter, span: Span) {
+        let frame = interpreter.frames().last().expect("A function is running.");
+        let kind = if frame.is_finalize { "finalize" } else { "function" };
+        let call = format!("{kind} {}.aleo/{}", frame.program, frame.function);
+        match location(span) {
+            Some((file, line, source)) => {
+                self.say(format!("In {call} at {file}:{line}"));
+                self.say(format!("{line:>4} | {source}"));
+            }
+            None => self.say(format!("In {call}")),
+        }
+    }
+}
+
+impl<R: BufRead, W: Write> StatementHook for Debugger<R, W> {
+    fn before_statement(&mut self, interpreter: &Interpreter, statement: &Statement) -> Result<()> {
+        // Blocks only group the statements that the run stops at.
+        if matches!(statement, Statement::Block(_)) {
+            return Ok(());
+        }
+
+        let line = location(statement.span()).map(|(_, line, _)| line);
+        let on_new_line = line != self.last_line;
+        self.last_line = line;
+
+        let stop = match self.mode {
+            Mode::Step => true,
+            Mode::Next(depth) => interpreter.frames().len() <= depth,
+            Mode::Continue => on_new_line && line.map_or(false, |line| self.breakpoints.contains(&line)),
+            Mode::Detached => false,
+        };
+        if !stop {
+            return Ok(());
+        }
+
+        self.show_position(interpreter, statement.span());
+        match self.prompt(interpreter) {
+            true => Err(InterpreterError::interrupted().into()),
+            false => Ok(()),
+        }
+    }
+
+    fn on_failure(&mut self, interpreter: &Interpreter, statement: &Statement, error: &LeoError) {
+        if self.mode == Mode::Detached {
+            return;
+        }
+        self.say(format!("Failed: {}", error.message()));
+        self.show_position(interpreter, statement.span());
+        // The run cannot go on, but its state can still be inspected.
+        self.prompt(interpreter);
+    }
+}
+
+/// Returns the file, line and source of the first line of a span, if it is in the source map.
+fn location(span: Span) -> Option<(String, usize, String)> {
+    with_session_globals(|session| {
+        let location = session.source_map.span_to_location(span)?;
+        let source = session.source_map.line_contents_of_span(Span::new(span.lo, span.lo))?;
+        Some((location.source_file.name.to_string(), location.line_start, source.trim_end().to_string()))
+    })
+}
+
+/// Returns the value at a path of the running call, e.g. `point.x`, `values[2]` or `pair.0`.
+fn inspect(interpreter: &Interpreter, path: &str) -> std::result::Result<Value, String> {
+    let end = path.find(['.', '[']).unwrap_or(path.len());
+    let (name, mut rest) = path.split_at(end);
+    if name.is_empty() {
+        return Err("Print a variable, e.g. `p point.x`.".to_string());
+    }
+    let mut value = match interpreter.variable(Symbol::intern(name)) {
+        Some(value) => value.clone(),
+        None => return Err(format!("There is no variable named `{name}` in scope.")),
+    };
+
+    while !rest.is_empty() {
+        let (step, remaining) = if let Some(member) = rest.strip_prefix('.') {
+            member.split_at(member.find(['.', '[']).unwrap_or(member.len()))
+        } else if let Some((index, remaining)) = rest.strip_prefix('[').and_then(|rest| rest.split_once(']')) {
+            (index, remaining)
+        } else {
+            return Err(format!("`{path}` is not a path to a value."));
+        };
+        value = match (value, step.parse::<usize>()) {
+            (Value::Struct(_, mut members), _) => match members.shift_remove(&Symbol::intern(step)) {
+                Some(member) => member,
+                None => return Err(format!("There is no member named `{step}` in `{path}`.")),
+            },
+            (Value::Array(mut elements) | Value::Tuple(mut elements), Ok(index)) if index < elements.len() => {
+                elements.swap_remove(index)
+            }
+            _ => return Err(format!("`{step}` cannot be accessed in `{path}`.")),
+        };
+        rest = remaining;
+    }
+    Ok(value)
+}
@@ -0,0 +1,361 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{literal_type, Interpreter, Value};
+
+use leo_ast::*;
+use leo_errors::{InterpreterError, Result};
+use leo_span::{sym, Span};
+
+use snarkvm_console::{
+    prelude::{Compare as _, Double as _, Inverse as _, Pow as _, Square as _, SquareRoot as _, Zero as _},
+    program::Literal,
+    types::Group,
+};
+
+impl Interpreter<'_> {
+    /// Evaluates an expression.
+    pub(crate) fn evaluate_expression(&mut self, expression: &Expression) -> Result<Value> {
+        match expression {
+            Expression::Access(access) => self.evaluate_access(access),
+            Expression::Array(array) => Ok(Value::Array(self.evaluate_expressions(&array.elements)?)),
+            Expression::Binary(binary) => {
+                let left = self.evaluate_expression(&binary.left)?;
+                let right = self.evaluate_expression(&binary.right)?;
+                evaluate_binary(binary.op, left, right, binary.span)
+            }
+            Expression::Call(call) => self.evaluate_call(call),
+            Expression::Cast(cast) => {
+                let value = self.evaluate_expression(&cast.expression)?;
+                evaluate_cast(value, &cast.type_, cast.span)
+            }
+            Expression::Struct(struct_) => self.evaluate_struct(struct_),
+            Expression::Err(_) => unreachable!("Type checking guarantees that there are no error expressions."),
+            Expression::Identifier(identifier) => self.evaluate_identifier(identifier),
+            Expression::Literal(literal) => match self.type_of(&literal.id()) {
+                Some(type_) => Value::from_literal(&literal.clone().with_type(&type_)),
+                None => Value::from_literal(literal),
+            },
+            Expression::Ternary(ternary) => match self.evaluate_condition(&ternary.condition)? {
+                true => self.evaluate_expression(&ternary.if_true),
+                false => self.evaluate_expression(&ternary.if_false),
+            },
+            Expression::Tuple(tuple) => Ok(Value::Tuple(self.evaluate_expressions(&tuple.elements)?)),
+            Expression::Unary(unary) => {
+                let receiver = self.evaluate_expression(&unary.receiver)?;
+                evaluate_unary(unary.op, receiver, unary.span)
+            }
+            Expression::Unit(_) => Ok(Value::Unit),
+        }
+    }
+
+    /// Evaluates expressions in order.
+    pub(crate) fn evaluate_expressions(&mut self, expressions: &[Expression]) -> Result<Vec<Value>> {
+        expressions.iter().map(|expression| self.evaluate_expression(expression)).collect()
+    }
+
+    /// Evaluates a boolean expression.
+    pub(crate) fn evaluate_condition(&mut self, expression: &Expression) -> Result<bool> {
+        match self.evaluate_expression(expression)?.as_bool() {
+            Some(condition) => Ok(condition),
+            None => unreachable!("Type checking guarantees that conditions are booleans."),
+        }
+    }
+
+    /// Evaluates a variable, or else a constant of the program that is running.
+    fn evaluate_identifier(&mut self, identifier: &Identifier) -> Result<Value> {
+        if let Some(value) = self.variable(identifier.name) {
+            return Ok(value.clone());
+        }
+        let constant = self
+            .program_scope(self.frame().program)
+            .and_then(|scope| scope.consts.iter().find(|(name, _)| *name == identifier.name));
+        match constant {
+            Some((_, constant)) => self.evaluate_expression(&constant.value),
+            None => unreachable!("Type checking guarantees that identifiers are declared."),
+        }
+    }
+
+    /// Evaluates an access expression.
+    fn evaluate_access(&mut self, access: &AccessExpression) -> Result<Value> {
+        match access {
+            AccessExpression::Array(access) => {
+                let array = self.evaluate_expression(&access.array)?;
+                let index = self.evaluate_expression(&access.index)?;
+                match (array, index.as_index()) {
+                    (Value::Array(mut elements), Some(position)) if position < elements.len() => {
+                        Ok(elements.swap_remove(position))
+                    }
+                    (Value::Array(elements), _) => {
+                        Err(InterpreterError::array_index_out_of_bounds(index, elements.len(), access.span).into())
+                    }
+                    _ => unreachable!("Type checking guarantees that only arrays are indexed."),
+                }
+            }
+            AccessExpression::AssociatedConstant(access) => {
+                let name = match &access.ty {
+                    Type::Identifier(identifier) => identifier.name,
+                    _ => unreachable!("Type checking guarantees that associated constants belong to named types."),
+                };
+                if let Some(enum_) = self.find_enum(name) {
+                    return Ok(Value::Variant(enum_.name(), access.name.name, Vec::new()));
+                }
+                match CoreConstant::from_symbols(name, access.name.name) {
+                    Some(CoreConstant::GroupGenerator) => Ok(Value::Literal(Literal::Group(Group::generator()))),
+                    None => unreachable!("Type checking guarantees that associated constants exist."),
+                }
+            }
+            AccessExpression::AssociatedFunction(access) => {
+                let name = match &access.ty {
+                    Type::Identifier(identifier) => identifier.name,
+                    _ => unreachable!("Type checking guarantees that associated functions belong to named types."),
+                };
+                if let Some(enum_) = self.find_enum(name) {
+                    let payload = self.evaluate_expressions(&access.arguments)?;
+                    return Ok(Value::Variant(enum_.name(), access.name.name, payload));
+                }
+                if name == sym::Mapping {
+                    return self.evaluate_mapping_function(access);
+                }
+                let arguments = self.evaluate_expressions(&access.arguments)?;
+                self.evaluate_core_function(name, access.name.name, arguments, access.span)
+            }
+            AccessExpression::Member(access) => match &*access.inner {
+                Expression::Identifier(identifier)
+                    if identifier.name == sym::SelfLower && self.variable(sym::SelfLower).is_none() =>
+                {
+                    match access.name.name {
+                        sym::caller => Ok(self.frame().caller.clone()),
+                        sym::signer => Ok(Value::Literal(Literal::Address(self.signer))),
+                        _ => unreachable!("Type checking guarantees that only `self.caller` and `self.signer` exist."),
+                    }
+                }
+                Expression::Identifier(identifier) if identifier.name == sym::block => {
+                    Ok(Value::Const(leo_ast::Value::U32(self.block_height, access.span)))
+                }
+                inner => match self.evaluate_expression(inner)? {
+                    Value::Struct(_, mut members) => match members.shift_remove(&access.name.name) {
+                        Some(value) => Ok(value),
+                        None => unreachable!("Type checking guarantees that members exist."),
+                    },
+                    _ => unreachable!("Type checking guarantees that only structs have members."),
+                },
+            },
+            AccessExpression::MethodCall(_) => {
+                unreachable!("Method desugaring replaces method calls with calls to functions.")
+            }
+            AccessExpression::Tuple(access) => match self.evaluate_expression(&access.tuple)? {
+                Value::Tuple(mut elements) => Ok(elements.swap_remove(access.index.value())),
+                _ => unreachable!("Type checking guarantees that only tuples are accessed by position."),
+            },
+        }
+    }
+
+    /// Evaluates a call to a function, which may be declared by an imported program.
+    fn evaluate_call(&mut self, call: &CallExpression) -> Result<Value> {
+        let function = match &*call.function {
+            Expression::Identifier(identifier) => identifier.name,
+            _ => unreachable!("Parsing guarantees that a function name is always an identifier."),
+        };
+        let arguments = self.evaluate_expressions(&call.arguments)?;
+        let caller = self.frame().caller.clone();
+        match call.external.as_deref() {
+            Some(Expression::Identifier(program)) => {
+                if self.program_scope(program.name).is_none() {
+                    return Err(InterpreterError::external_program_not_found(program.name, call.span).into());
+                }
+                // The calling program is the caller of the transitions of other programs.
+                let caller = Self::program_address(self.frame().program);
+                self.call_function(program.name, function, arguments, caller)
+            }
+            Some(_) => unreachable!("Parsing guarantees that a program name is always an identifier."),
+            None => self.call_function(self.frame().program, function, arguments, caller),
+        }
+    }
+
+    /// Evaluates a struct expression, with its members in declaration order.
+    fn evaluate_struct(&mut self, struct_: &StructExpression) -> Result<Value> {
+        let mut members = struct_
+            .members
+            .iter()
+            .map(|member| {
+                let value = match &member.expression {
+                    Some(expression) => self.evaluate_expression(expression)?,
+                    None => self.evaluate_identifier(&member.identifier)?,
+                };
+                Ok((member.identifier.name, value))
+            })
+            .collect::<Result<indexmap::IndexMap<_, _>>>()?;
+        if let Some(declaration) = self.find_struct(struct_.name.name) {
+            members = declaration
+                .members
+                .iter()
+                .filter_map(|member| members.shift_remove(&member.name()).map(|value| (member.name(), value)))
+                .collect();
+        }
+        Ok(Value::Struct(struct_.name.name, members))
+    }
+}
+
+/// Evaluates a binary operation.
+/// Booleans and integers use the operations of constant folding, and other literals use those of snarkVM.
+pub(crate) fn evaluate_binary(op: BinaryOperation, left: Value, right: Value, span: Span) -> Result<Value> {
+    use BinaryOperation::*;
+
+    // Any two values of the same type can be compared.
+    match op {
+        Eq => return Ok(Value::boolean(left == right)),
+        Neq => return Ok(Value::boolean(left != right)),
+        _ => {}
+    }
+
+    let operation = format!("{left} {op} {right}");
+    let overflow = || InterpreterError::overflow(&operation, span);
+
+    match (left, right) {
+        (Value::Const(left), Value::Const(right)) => {
+            if matches!(op, Div | DivWrapped | Mod | Rem | RemWrapped) && right.is_zero() {
+                return Err(
+                    InterpreterError::division_by_zero(Value::Const(left), op, Value::Const(right), span).into()
+                );
+            }
+            let result = match op {
+                Add => left.add(right, span),
+                AddWrapped => left.add_wrapped(right, span),
+                And | BitwiseAnd => left.bitand(right, span),
+                Div => left.div(right, span),
+                DivWrapped => left.div_wrapped(right, span),
+                Gte => left.ge(right, span),
+                Gt => left.gt(right, span),
+                Lte => left.le(right, span),
+                Lt => left.lt(right, span),
+                Mod | Rem => left.rem(right, span),
+                RemWrapped => left.rem_wrapped(right, span),
+                Mul => left.mul(right, span),
+                MulWrapped => left.mul_wrapped(right, span),
+                Nand => left.bitand(right, span).and_then(|value| value.not(span)),
+                Nor => left.bitor(right, span).and_then(|value| value.not(span)),
+                Or | BitwiseOr => left.bitor(right, span),
+                Pow => left.pow(right, span),
+                PowWrapped => left.pow_wrapped(right, span),
+                Shl => left.shl(right, span),
+                ShlWrapped => left.shl_wrapped(right, span),
+                Shr => left.shr(right, span),
+                ShrWrapped => left.shr_wrapped(right, span),
+                Sub => left.sub(right, span),
+                SubWrapped => left.sub_wrapped(right, span),
+                Xor => left.xor(right, span),
+                Eq | Neq => unreachable!("Equality is checked above."),
+            };
+            result.map(Value::Const).map_err(|_| overflow().into())
+        }
+        (Value::Literal(left), Value::Literal(right)) => {
+            let literal = match (op, left, right) {
+                (Add, Literal::Field(left), Literal::Field(right)) => Literal::Field(left + right),
+                (Sub, Literal::Field(left), Literal::Field(right)) => Literal::Field(left - right),
+                (Mul, Literal::Field(left), Literal::Field(right)) => Literal::Field(left * right),
+                (Div, Literal::Field(left), Literal::Field(right)) => match right.is_zero() {
+                    true => {
+                        return Err(InterpreterError::division_by_zero(left, op, right, span).into());
+                    }
+                    false => Literal::Field(left / right),
+                },
+                (Pow, Literal::Field(left), Literal::Field(right)) => Literal::Field(left.pow(right)),
+                (Lt, Literal::Field(left), Literal::Field(right)) => Literal::Boolean(left.is_less_than(&right)),
+                (Lte, Literal::Field(left), Literal::Field(right)) => {
+                    Literal::Boolean(left.is_less_than_or_equal(&right))
+                }
+                (Gt, Literal::Field(left), Literal::Field(right)) => Literal::Boolean(left.is_greater_than(&right)),
+                (Gte, Literal::Field(left), Literal::Field(right)) => {
+                    Literal::Boolean(left.is_greater_than_or_equal(&right))
+                }
+                (Add, Literal::Group(left), Literal::Group(right)) => Literal::Group(left + right),
+                (Sub, Literal::Group(left), Literal::Group(right)) => Literal::Group(left - right),
+                (Mul, Literal::Group(left), Literal::Scalar(right)) => Literal::Group(left * right),
+                (Mul, Literal::Scalar(left), Literal::Group(right)) => Literal::Group(left * right),
+                (Add, Literal::Scalar(left), Literal::Scalar(right)) => Literal::Scalar(left + right),
+                (Sub, Literal::Scalar(left), Literal::Scalar(right)) => Literal::Scalar(left - right),
+                (Mul, Literal::Scalar(left), Literal::Scalar(right)) => Literal::Scalar(left * right),
+                (Lt, Literal::Scalar(left), Literal::Scalar(right)) => Literal::Boolean(left.is_less_than(&right)),
+                (Lte, Literal::Scalar(left), Literal::Scalar(right)) => {
+                    Literal::Boolean(left.is_less_than_or_equal(&right))
+                }
+                (Gt, Literal::Scalar(left), Literal::Scalar(right)) => Literal::Boolean(left.is_greater_than(&right)),
+                (Gte, Literal::Scalar(left), Literal::Scalar(right)) => {
+                    Literal::Boolean(left.is_greater_than_or_equal(&right))
+                }
+                _ => unreachable!("Type checking guarantees that `{operation}` is a valid operation."),
+            };
+            Ok(Value::from_console(literal))
+        }
+        _ => unreachable!("Type checking guarantees that `{operation}` is a valid operation."),
+    }
+}
+
+/// Evaluates a unary operation.
+pub(crate) fn evaluate_unary(op: UnaryOperation, receiver: Value, span: Span) -> Result<Value> {
+    use UnaryOperation::*;
+
+    let operation = format!("{receiver}.{}()", op.as_str());
+    match receiver {
+        Value::Const(receiver) => {
+            let result = match op {
+                Abs => receiver.abs(span),
+                AbsWrapped => receiver.abs_wrapped(span),
+                Negate => receiver.neg(span),
+                Not => receiver.not(span),
+                _ => unreachable!("Type checking guarantees that `{operation}` is a valid operation."),
+            };
+            result.map(Value::Const).map_err(|_| InterpreterError::overflow(operation, span).into())
+        }
+        Value::Literal(receiver) => {
+            let literal = match (op, receiver) {
+                (Double, Literal::Field(field)) => Literal::Field(field.double()),
+                (Double, Literal::Group(group)) => Literal::Group(group.double()),
+                (Inverse, Literal::Field(field)) => match field.inverse() {
+                    Ok(inverse) => Literal::Field(inverse),
+                    Err(err) => return Err(InterpreterError::operation_failed(operation, err, span).into()),
+                },
+                (Negate, Literal::Field(field)) => Literal::Field(-field),
+                (Negate, Literal::Group(group)) => Literal::Group(-group),
+                (Negate, Literal::Scalar(scalar)) => Literal::Scalar(-scalar),
+                (Square, Literal::Field(field)) => Literal::Field(field.square()),
+                (SquareRoot, Literal::Field(field)) => match field.square_root() {
+                    Ok(root) => Literal::Field(root),
+                    Err(err) => return Err(InterpreterError::operation_failed(operation, err, span).into()),
+                },
+                (ToXCoordinate, Literal::Group(group)) => Literal::Field(group.to_x_coordinate()),
+                (ToYCoordinate, Literal::Group(group)) => Literal::Field(group.to_y_coordinate()),
+                _ => unreachable!("Type checking guarantees that `{operation}` is a valid operation."),
+            };
+            Ok(Value::from_console(literal))
+        }
+        _ => unreachable!("Type checking guarantees that `{operation}` is a valid operation."),
+    }
+}
+
+/// Evaluates a cast with the semantics of snarkVM, which fails if the value does not fit in the type.
+pub(crate) fn evaluate_cast(value: Value, type_: &Type, span: Span) -> Result<Value> {
+    let operation = format!("{value} as {type_}");
+    let (literal, literal_type) = match (value.to_console(), literal_type(type_)) {
+        (Some(literal), Some(literal_type)) => (literal, literal_type),
+        _ => unreachable!("Type checking guarantees that `{operation}` is a valid cast."),
+    };
+    match literal.cast(literal_type) {
+        Ok(literal) => Ok(Value::from_console(literal)),
+        Err(err) => Err(InterpreterError::operation_failed(operation, err, span).into()),
+    }
+}
@@ -0,0 +1,305 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Flow, Interpreter, Value};
+
+use leo_ast::*;
+use leo_errors::{InterpreterError, Result};
+use leo_span::{Span, Symbol};
+
+impl Interpreter<'_> {
+    /// Runs a block in a new scope.
+    pub(crate) fn execute_block(&mut self, block: &Block) -> Result<Flow> {
+        self.frame_mut().scopes.push(Default::default());
+        let flow = self.execute_statements(&block.statements);
+        self.frame_mut().scopes.pop();
+        flow
+    }
+
+    /// Runs statements until one of them returns, or leaves the innermost loop.
+    fn execute_statements(&mut self, statements: &[Statement]) -> Result<Flow> {
+        for statement in statements {
+            match self.execute_statement(statement)? {
+                Flow::Next => {}
+                flow => return Ok(flow),
+            }
+        }
+        Ok(Flow::Next)
+    }
+
+    /// Runs a statement, reporting it to the hook of the interpreter if there is one.
+    pub(crate) fn execute_statement(&mut self, statement: &Statement) -> Result<Flow> {
+        if let Some(mut hook) = self.hook.take() {
+            let result = hook.before_statement(self, statement);
+            self.hook = Some(hook);
+            if let Err(error) = result {
+                // The hook chose to stop the run, so there is no failure to report.
+                self.failure_reported = true;
+                return Err(error);
+            }
+        }
+
+        let flow = self.execute_statement_kind(statement);
+        if let Err(error) = &flow {
+            // Only the innermost statement that fails is reported, while its frames are still running.
+            if !self.failure_reported {
+                self.failure_reported = true;
+                if let Some(mut hook) = self.hook.take() {
+                    hook.on_failure(self, statement, error);
+                    self.hook = Some(hook);
+                }
+            }
+        }
+        flow
+    }
+
+    /// Runs a statement of any kind.
+    fn execute_statement_kind(&mut self, statement: &Statement) -> Result<Flow> {
+        match statement {
+            Statement::Assert(assert) => self.execute_assert(&assert.variant, assert.span),
+            Statement::Assign(assign) => {
+                let value = self.evaluate_expression(&assign.value)?;
+                self.assign(&assign.place, value)?;
+                Ok(Flow::Next)
+            }
+            Statement::Block(block) => self.execute_block(block),
+            Statement::Break(_) => Ok(Flow::Break),
+            Statement::Conditional(conditional) => match self.evaluate_condition(&conditional.condition)? {
+                true => self.execute_block(&conditional.then),
+                false => match &conditional.otherwise {
+                    Some(otherwise) => self.execute_statement(otherwise),
+                    None => Ok(Flow::Next),
+                },
+            },
+            Statement::Console(console) => {
+                let variant = match &console.function {
+                    ConsoleFunction::Assert(expression) => AssertVariant::Assert(expression.clone()),
+                    ConsoleFunction::AssertEq(left, right) => AssertVariant::AssertEq(left.clone(), right.clone()),
+                    ConsoleFunction::AssertNeq(left, right) => AssertVariant::AssertNeq(left.clone(), right.clone()),
+                };
+                self.execute_assert(&variant, console.span)
+            }
+            Statement::Const(const_) => {
+                let value = self.evaluate_expression(&const_.value)?;
+                self.define(const_.place.name, value);
+                Ok(Flow::Next)
+            }
+            Statement::Continue(_) => Ok(Flow::Continue),
+            Statement::Definition(definition) => {
+                let value = self.evaluate_expression(&definition.value)?;
+                match (&definition.place, value) {
+                    (Expression::Identifier(identifier), value) => self.define(identifier.name, value),
+                    (Expression::Tuple(tuple), Value::Tuple(values)) => {
+                        for (element, value) in tuple.elements.iter().zip(values) {
+                            match element {
+                                Expression::Identifier(identifier) => self.define(identifier.name, value),
+                                _ => unreachable!("Parsing guarantees that tuple definitions only declare variables."),
+                            }
+                        }
+                    }
+                    _ => unreachable!("Type checking guarantees that definitions declare variables."),
+                }
+                Ok(Flow::Next)
+            }
+            Statement::Expression(expression) => {
+                self.evaluate_expression(&expression.expression)?;
+                Ok(Flow::Next)
+            }
+            Statement::Iteration(iteration) => self.execute_iteration(iteration),
+            Statement::Match(match_) => self.execute_match(match_),
+            Statement::Return(return_) => {
+                let value = self.evaluate_expression(&return_.expression)?;
+                if let Some(arguments) = &return_.finalize_arguments {
+                    let arguments = self.evaluate_expressions(arguments)?;
+                    self.schedule_finalize(arguments);
+                }
+                Ok(Flow::Return(value))
+            }
+            Statement::While(while_) => self.execute_while(while_),
+        }
+    }
+
+    /// Runs an assertion, failing if it does not hold.
+    fn execute_assert(&mut self, variant: &AssertVariant, span: Span) -> Result<Flow> {
+        match variant {
+            AssertVariant::Assert(expression) => {
+                if !self.evaluate_condition(expression)? {
+                    return Err(InterpreterError::assertion_failed(span).into());
+                }
+            }
+            AssertVariant::AssertEq(left, right) => {
+                let (left, right) = (self.evaluate_expression(left)?, self.evaluate_expression(right)?);
+                if left != right {
+                    return Err(InterpreterError::assert_eq_failed(left, right, span).into());
+                }
+            }
+            AssertVariant::AssertNeq(left, right) => {
+                let (left, right) = (self.evaluate_expression(left)?, self.evaluate_expression(right)?);
+                if left == right {
+                    return Err(InterpreterError::assert_neq_failed(left, right, span).into());
+                }
+            }
+        }
+        Ok(Flow::Next)
+    }
+
+    /// Runs a `for` loop over its range.
+    fn execute_iteration(&mut self, iteration: &IterationStatement) -> Result<Flow> {
+        let integer_type = match &iteration.type_ {
+            Type::Integer(integer_type) => *integer_type,
+            _ => unreachable!("Type checking guarantees that loop variables are integers."),
+        };
+        let bound = |interpreter: &mut Self, expression: &Expression| -> Result<i128> {
+            match interpreter.evaluate_expression(expression)? {
+                Value::Const(value) => i128::try_from(value),
+                _ => unreachable!("Type checking guarantees that loop bounds are integers."),
+            }
+        };
+        let start = bound(self, &iteration.start)?;
+        let stop = bound(self, &iteration.stop)?;
+        let stop = if iteration.inclusive { stop.saturating_add(1) } else { stop };
+
+        for index in start..stop {
+            let value = Value::integer(integer_type, index).expect("The index is in the range of the loop.");
+            self.frame_mut().scopes.push(std::iter::once((iteration.variable.name, value)).collect());
+            let flow = self.execute_block(&iteration.block);
+            self.frame_mut().scopes.pop();
+            match flow? {
+                Flow::Next | Flow::Continue => {}
+                Flow::Break => break,
+                flow @ Flow::Return(_) => return Ok(flow),
+            }
+        }
+        Ok(Flow::Next)
+    }
+
+    /// Runs a `while` loop, failing if its condition still holds once it has run as many times as its bound.
+    fn execute_while(&mut self, while_: &WhileStatement) -> Result<Flow> {
+        let bound = match self.evaluate_expression(&while_.bound)?.as_index() {
+            Some(bound) => bound,
+            None => unreachable!("Type checking guarantees that the bound of a loop is a non-negative integer."),
+        };
+
+        for _ in 0..bound {
+            if !self.evaluate_condition(&while_.condition)? {
+                return Ok(Flow::Next);
+            }
+            match self.execute_block(&while_.block)? {
+                Flow::Next | Flow::Continue => {}
+                Flow::Break => return Ok(Flow::Next),
+                flow @ Flow::Return(_) => return Ok(flow),
+            }
+        }
+
+        match self.evaluate_condition(&while_.condition)? {
+            true => Err(InterpreterError::loop_bound_exceeded(bound, while_.span).into()),
+            false => Ok(Flow::Next),
+        }
+    }
+
+    /// Runs the arm of a `match` statement whose pattern matches the value.
+    fn execute_match(&mut self, match_: &MatchStatement) -> Result<Flow> {
+        let (variant, payload) = match self.evaluate_expression(&match_.expression)? {
+            Value::Variant(_, variant, payload) => (variant, payload),
+            _ => unreachable!("Type checking guarantees that the value of a match is an enum."),
+        };
+
+        for arm in &match_.arms {
+            let scope = match &arm.pattern {
+                MatchPattern::Variant { variant: pattern, bindings, .. } if pattern.name == variant => bindings
+                    .iter()
+                    .zip(payload.iter())
+                    .filter_map(|(binding, value)| binding.map(|binding| (binding.name, value.clone())))
+                    .collect(),
+                MatchPattern::Variant { .. } => continue,
+                MatchPattern::Wildcard(_) => Default::default(),
+            };
+            self.frame_mut().scopes.push(scope);
+            let flow = self.execute_block(&arm.block);
+            self.frame_mut().scopes.pop();
+            return flow;
+        }
+        unreachable!("Type checking guarantees that match statements are exhaustive.")
+    }
+
+    /// Declares a variable in the innermost scope.
+    fn define(&mut self, name: Symbol, value: Value) {
+        self.frame_mut().scopes.last_mut().expect("A block is running.").insert(name, value);
+    }
+
+    /// Assigns a value to a variable, or to a member or element of one.
+    fn assign(&mut self, place: &Expression, value: Value) -> Result<()> {
+        // Evaluate the indices of the place before borrowing the variable.
+        let mut path = Vec::new();
+        let mut inner = place;
+        let name = loop {
+            match inner {
+                Expression::Identifier(identifier) => break identifier.name,
+                Expression::Access(AccessExpression::Member(access)) => {
+                    path.push(Place::Member(access.name.name));
+                    inner = &access.inner;
+                }
+                Expression::Access(AccessExpression::Tuple(access)) => {
+                    path.push(Place::Element(access.index.value()));
+                    inner = &access.tuple;
+                }
+                Expression::Access(AccessExpression::Array(access)) => {
+                    path.push(Place::Index(self.evaluate_expression(&access.index)?, access.span));
+                    inner = &access.array;
+                }
+                _ => unreachable!("Type checking guarantees that assignments are to variables."),
+            }
+        };
+
+        let mut target = match self.frame_mut().scopes.iter_mut().rev().find_map(|scope| scope.get_mut(&name)) {
+            Some(target) => target,
+            None => unreachable!("Type checking guarantees that assigned variables are declared."),
+        };
+        for place in path.into_iter().rev() {
+            target = match (place, target) {
+                (Place::Member(member), Value::Struct(_, members)) => match members.get_mut(&member) {
+                    Some(target) => target,
+                    None => unreachable!("Type checking guarantees that members exist."),
+                },
+                (Place::Element(index), Value::Tuple(elements)) => match elements.get_mut(index) {
+                    Some(target) => target,
+                    None => unreachable!("Type checking guarantees that tuple indices are in bounds."),
+                },
+                (Place::Index(index, span), Value::Array(elements)) => {
+                    let length = elements.len();
+                    // A negative index is out of bounds, like an index past the end.
+                    match index.as_index().and_then(|position| elements.get_mut(position)) {
+                        Some(target) => target,
+                        None => return Err(InterpreterError::array_index_out_of_bounds(index, length, span).into()),
+                    }
+                }
+                _ => unreachable!("Type checking guarantees that places have the types they are accessed as."),
+            };
+        }
+        *target = value;
+        Ok(())
+    }
+}
+
+/// A step from a variable to the part of it that is assigned.
+enum Place {
+    /// A member of a struct.
+    Member(Symbol),
+    /// An element of a tuple.
+    Element(usize),
+    /// An element of an array, at an index that may be out of bounds.
+    Index(Value, Span),
+}
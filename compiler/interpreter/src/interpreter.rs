@@ -0,0 +1,386 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{literal_type, CurrentNetwork, MappingStore, Value};
+
+use leo_ast::{Enum, Function, Program, ProgramScope, Statement, Struct, Type};
+use leo_errors::{InterpreterError, LeoError, Result};
+use leo_passes::TypeTable;
+use leo_span::{sym, Symbol};
+
+use snarkvm_console::{
+    program::{
+        Entry,
+        Identifier as ConsoleIdentifier,
+        Literal,
+        Owner,
+        Plaintext,
+        ProgramID,
+        Record,
+        Value as ConsoleValue,
+    },
+    types::Address,
+};
+
+use indexmap::IndexMap;
+use std::str::FromStr;
+
+/// The address of the development account, which calls the transitions unless another caller is set.
+const DEFAULT_CALLER: &str = "aleo1rhgdu77hgyqd3xjj8ucu3jj9r2krwz6mnzyd80gncr5fxcwlh5rsvzp9px";
+
+/// What happens after a statement has run.
+#[derive(Debug)]
+pub(crate) enum Flow {
+    /// The next statement runs.
+    Next,
+    /// The innermost loop stops.
+    Break,
+    /// The innermost loop moves on to its next iteration.
+    Continue,
+    /// The function returns the value.
+    Return(Value),
+}
+
+/// A call of a function, or of a finalize block, that is running.
+#[derive(Clone, Debug)]
+pub struct Frame {
+    /// The program that declares the function.
+    pub program: Symbol,
+    /// The name of the function.
+    pub function: Symbol,
+    /// Whether the frame runs the finalize block of the function.
+    pub is_finalize: bool,
+    /// The address that called the function, i.e. `self.caller`.
+    pub caller: Value,
+    /// The scopes of the local variables, from the outermost to the innermost.
+    pub scopes: Vec<IndexMap<Symbol, Value>>,
+}
+
+/// Observes the statements that an interpreter runs, e.g. to debug the program.
+pub trait StatementHook {
+    /// Called before a statement runs. If an error is returned, then the run fails with it.
+    fn before_statement(&mut self, interpreter: &Interpreter, statement: &Statement) -> Result<()>;
+
+    /// Called once when a run fails, with the statement that failed and the frames it failed in.
+    fn on_failure(&mut self, interpreter: &Interpreter, statement: &Statement, error: &LeoError);
+}
+
+/// A finalize block that runs once the transition has finished.
+#[derive(Clone, Debug)]
+struct PendingFinalize {
+    program: Symbol,
+    function: Symbol,
+    arguments: Vec<Value>,
+}
+
+/// A tree-walking interpreter for type checked Leo programs.
+///
+/// The interpreter follows the source of the program: only the branch of a conditional that is taken runs.
+/// Compiled programs evaluate every branch, so an operation that fails in a branch that is not taken
+/// only fails once the program is compiled.
+pub struct Interpreter<'a> {
+    /// The program being interpreted, which includes the programs it imports.
+    program: &'a Program,
+    /// The types of the expressions of the program, which resolve its unsuffixed literals.
+    type_table: &'a TypeTable,
+    /// The mappings of the programs, which persist from one run to the next.
+    pub store: MappingStore,
+    /// The account that runs the transitions, i.e. `self.signer`.
+    pub signer: Address<CurrentNetwork>,
+    /// The height of the block that the finalize blocks run in, i.e. `block.height`.
+    pub block_height: u32,
+    /// The calls that are running, from the outermost to the innermost.
+    pub(crate) frames: Vec<Frame>,
+    /// The finalize blocks to run once the transition has finished, in order.
+    finalizers: Vec<PendingFinalize>,
+    /// The number of random values that have been sampled, which seeds the next one.
+    pub(crate) random_counter: u64,
+    /// The hook that observes the statements as they run, if there is one.
+    pub(crate) hook: Option<Box<dyn StatementHook + 'a>>,
+    /// Whether the failure of the current run has been reported to the hook.
+    pub(crate) failure_reported: bool,
+}
+
+impl<'a> Interpreter<'a> {
+    /// Returns a new interpreter for a program that has been type checked, with an empty mapping store.
+    pub fn new(program: &'a Program, type_table: &'a TypeTable) -> Self {
+        Self {
+            program,
+            type_table,
+            store: MappingStore::default(),
+            signer: Address::from_str(DEFAULT_CALLER).unwrap(),
+            block_height: 0,
+            frames: Vec::new(),
+            finalizers: Vec::new(),
+            random_counter: 0,
+            hook: None,
+            failure_reported: false,
+        }
+    }
+
+    /// Sets the hook that observes the statements as they run, or removes it.
+    pub fn set_hook(&mut self, hook: Option<Box<dyn StatementHook + 'a>>) {
+        self.hook = hook;
+    }
+
+    /// Returns the calls that are running, from the outermost to the innermost.
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    /// Returns the name of the main program.
+    pub fn program_name(&self) -> Symbol {
+        *self.program.program_scopes.keys().next().expect("Parsing guarantees that there is a program scope.")
+    }
+
+    /// Runs a function of the main program, followed by the finalize blocks that it triggers.
+    /// Returns the outputs of the function. If the run fails, then the mapping store is left as it was.
+    pub fn run(&mut self, function: Symbol, inputs: Vec<Value>) -> Result<Vec<Value>> {
+        let program = self.program_name();
+        let expected = self.function(program, function)?.input.len();
+        if inputs.len() != expected {
+            return Err(InterpreterError::incorrect_number_of_inputs(function, expected, inputs.len()).into());
+        }
+
+        let snapshot = self.store.clone();
+        self.finalizers.clear();
+        self.failure_reported = false;
+        let caller = Value::Literal(Literal::Address(self.signer));
+        let result = self.call_function(program, function, inputs, caller).and_then(|output| {
+            for finalize in std::mem::take(&mut self.finalizers) {
+                self.run_finalize(finalize)?;
+            }
+            Ok(output)
+        });
+        self.frames.clear();
+
+        match result {
+            Ok(Value::Tuple(outputs)) => Ok(outputs),
+            Ok(Value::Unit) => Ok(Vec::new()),
+            Ok(output) => Ok(vec![output]),
+            Err(err) => {
+                self.store = snapshot;
+                Err(err)
+            }
+        }
+    }
+
+    /// Parses the inputs of a function of the main program from their Aleo representation, e.g. `1u32`.
+    pub fn parse_inputs(&self, function: Symbol, inputs: &[String]) -> Result<Vec<Value>> {
+        let function = self.function(self.program_name(), function)?;
+        if inputs.len() != function.input.len() {
+            return Err(InterpreterError::incorrect_number_of_inputs(
+                function.identifier,
+                function.input.len(),
+                inputs.len(),
+            )
+            .into());
+        }
+        function.input.iter().zip(inputs).map(|(input, string)| self.parse_input(string, &input.type_())).collect()
+    }
+
+    /// Parses a value of the given type from its Aleo representation.
+    pub fn parse_input(&self, input: &str, type_: &Type) -> Result<Value> {
+        let value = match ConsoleValue::<CurrentNetwork>::from_str(input.trim()) {
+            Ok(ConsoleValue::Plaintext(plaintext)) => self.plaintext_value(&plaintext, type_),
+            Ok(ConsoleValue::Record(record)) => self.record_value(&record, type_),
+            Ok(ConsoleValue::Future(_)) | Err(_) => None,
+        };
+        value.ok_or_else(|| InterpreterError::invalid_input(input, type_).into())
+    }
+
+    /// Returns the value of a plaintext, if it has the given type.
+    fn plaintext_value(&self, plaintext: &Plaintext<CurrentNetwork>, type_: &Type) -> Option<Value> {
+        match (plaintext, type_) {
+            (Plaintext::Literal(literal, _), _) if literal_type(type_) == Some(literal.to_type()) => {
+                Some(Value::from_console(literal.clone()))
+            }
+            (Plaintext::Array(elements, _), Type::Array(array_type)) if elements.len() == array_type.length() => {
+                elements
+                    .iter()
+                    .map(|element| self.plaintext_value(element, array_type.element_type()))
+                    .collect::<Option<_>>()
+                    .map(Value::Array)
+            }
+            (Plaintext::Struct(members, _), Type::Identifier(identifier)) => {
+                let struct_ = self.find_struct(identifier.name).filter(|struct_| !struct_.is_record)?;
+                if members.len() != struct_.members.len() {
+                    return None;
+                }
+                struct_
+                    .members
+                    .iter()
+                    .map(|member| {
+                        let name = ConsoleIdentifier::from_str(&member.name().to_string()).ok()?;
+                        Some((member.name(), self.plaintext_value(members.get(&name)?, &member.type_)?))
+                    })
+                    .collect::<Option<_>>()
+                    .map(|members| Value::Struct(identifier.name, members))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a record, if it has the given type.
+    fn record_value(&self, record: &Record<CurrentNetwork, Plaintext<CurrentNetwork>>, type_: &Type) -> Option<Value> {
+        let name = match type_ {
+            Type::Identifier(identifier) => identifier.name,
+            _ => return None,
+        };
+        let struct_ = self.find_struct(name).filter(|struct_| struct_.is_record)?;
+        if record.data().len() + 1 != struct_.members.len() {
+            return None;
+        }
+        struct_
+            .members
+            .iter()
+            .map(|member| {
+                let value = match member.name() {
+                    sym::owner => match record.owner() {
+                        Owner::Public(address) => Value::Literal(Literal::Address(*address)),
+                        Owner::Private(plaintext) => self.plaintext_value(plaintext, &Type::Address)?,
+                    },
+                    name => {
+                        let name = ConsoleIdentifier::from_str(&name.to_string()).ok()?;
+                        match record.data().get(&name)? {
+                            Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext) => {
+                                self.plaintext_value(plaintext, &member.type_)?
+                            }
+                        }
+                    }
+                };
+                Some((member.name(), value))
+            })
+            .collect::<Option<_>>()
+            .map(|members| Value::Struct(name, members))
+    }
+
+    /// Calls a function of a program with the given arguments, returning its output.
+    pub(crate) fn call_function(
+        &mut self,
+        program: Symbol,
+        name: Symbol,
+        arguments: Vec<Value>,
+        caller: Value,
+    ) -> Result<Value> {
+        let function = self.function(program, name)?;
+        let scope = function.input.iter().map(|input| input.identifier().name).zip(arguments).collect();
+        self.frames.push(Frame { program, function: name, is_finalize: false, caller, scopes: vec![scope] });
+        let flow = self.execute_block(&function.block);
+        self.frames.pop();
+
+        match flow? {
+            Flow::Return(value) => Ok(value),
+            _ => Ok(Value::Unit),
+        }
+    }
+
+    /// Schedules the finalize block of the function that is running, to run once the transition has finished.
+    pub(crate) fn schedule_finalize(&mut self, arguments: Vec<Value>) {
+        let frame = self.frame();
+        let finalize = PendingFinalize { program: frame.program, function: frame.function, arguments };
+        self.finalizers.push(finalize);
+    }
+
+    /// Runs the finalize block of a function.
+    fn run_finalize(&mut self, finalize: PendingFinalize) -> Result<()> {
+        let function = self.function(finalize.program, finalize.function)?;
+        let block = match &function.finalize {
+            Some(block) => block,
+            None => unreachable!("Type checking guarantees that only functions with a finalize block finalize."),
+        };
+        let scope = block.input.iter().map(|input| input.identifier().name).zip(finalize.arguments).collect();
+        self.frames.push(Frame {
+            program: finalize.program,
+            function: finalize.function,
+            is_finalize: true,
+            caller: Value::Unit,
+            scopes: vec![scope],
+        });
+        let flow = self.execute_block(&block.block);
+        self.frames.pop();
+        flow.map(|_| ())
+    }
+
+    /// Returns the address of a program, which is the caller of the functions that it calls.
+    pub(crate) fn program_address(program: Symbol) -> Value {
+        let id = ProgramID::<CurrentNetwork>::from_str(&format!("{program}.aleo"));
+        match id.and_then(|id| id.to_address()) {
+            Ok(address) => Value::Literal(Literal::Address(address)),
+            Err(_) => unreachable!("Parsing guarantees that program names are valid."),
+        }
+    }
+
+    /// Returns the frame of the function that is running.
+    pub(crate) fn frame(&self) -> &Frame {
+        self.frames.last().expect("A function is running.")
+    }
+
+    /// Returns the frame of the function that is running, mutably.
+    pub(crate) fn frame_mut(&mut self) -> &mut Frame {
+        self.frames.last_mut().expect("A function is running.")
+    }
+
+    /// Returns the value of the innermost variable with the given name, if there is one.
+    pub fn variable(&self, name: Symbol) -> Option<&Value> {
+        self.frame().scopes.iter().rev().find_map(|scope| scope.get(&name))
+    }
+
+    /// Returns the type of an expression, as computed by type checking.
+    pub(crate) fn type_of(&self, id: &leo_ast::NodeID) -> Option<Type> {
+        self.type_table.get(id)
+    }
+
+    /// Returns the scope of a program, which is either the main program or one of the programs it imports.
+    pub(crate) fn program_scope(&self, name: Symbol) -> Option<&'a ProgramScope> {
+        self.program_scopes().into_iter().find(|scope| scope.program_id.name.name == name)
+    }
+
+    /// Returns the scopes of the main program and of the programs it imports.
+    fn program_scopes(&self) -> Vec<&'a ProgramScope> {
+        fn collect<'a>(program: &'a Program, scopes: &mut Vec<&'a ProgramScope>) {
+            scopes.extend(program.program_scopes.values());
+            program.imports.values().for_each(|(import, _)| collect(import, scopes));
+        }
+        let mut scopes = Vec::new();
+        collect(self.program, &mut scopes);
+        scopes
+    }
+
+    /// Returns a function of a program.
+    pub(crate) fn function(&self, program: Symbol, name: Symbol) -> Result<&'a Function> {
+        self.program_scope(program)
+            .and_then(|scope| scope.functions.iter().find(|(function, _)| *function == name))
+            .map(|(_, function)| function)
+            .ok_or_else(|| InterpreterError::function_not_found(program, name).into())
+    }
+
+    /// Returns the struct or record with the given name, preferring the one of the program that is running.
+    pub(crate) fn find_struct(&self, name: Symbol) -> Option<&'a Struct> {
+        self.find_item(|scope| scope.structs.iter().find(|(struct_, _)| *struct_ == name).map(|(_, struct_)| struct_))
+    }
+
+    /// Returns the enum with the given name, preferring the one of the program that is running.
+    pub(crate) fn find_enum(&self, name: Symbol) -> Option<&'a Enum> {
+        self.find_item(|scope| scope.enums.iter().find(|(enum_, _)| *enum_ == name).map(|(_, enum_)| enum_))
+    }
+
+    /// Returns the first item found in the program that is running, or else in any other program.
+    fn find_item<T>(&self, find: impl Fn(&'a ProgramScope) -> Option<&'a T>) -> Option<&'a T> {
+        let running = self.frames.last().and_then(|frame| self.program_scope(frame.program));
+        running.and_then(&find).or_else(|| self.program_scopes().into_iter().find_map(find))
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Value;
+
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+/// An in-memory store for the mappings of the interpreted programs.
+#[derive(Clone, Debug, Default)]
+pub struct MappingStore {
+    /// The entries of each mapping, keyed by the program and the name of the mapping.
+    /// The entries are keyed by the Aleo representation of their key, and kept in insertion order.
+    mappings: IndexMap<(Symbol, Symbol), IndexMap<String, (Value, Value)>>,
+}
+
+impl MappingStore {
+    /// Returns the value of `key` in the mapping, if there is one.
+    pub fn get(&self, program: Symbol, mapping: Symbol, key: &Value) -> Option<&Value> {
+        self.mappings.get(&(program, mapping)).and_then(|entries| entries.get(&key.to_string())).map(|(_, value)| value)
+    }
+
+    /// Sets the value of `key` in the mapping.
+    pub fn set(&mut self, program: Symbol, mapping: Symbol, key: Value, value: Value) {
+        self.mappings.entry((program, mapping)).or_default().insert(key.to_string(), (key, value));
+    }
+
+    /// Removes `key` from the mapping, returning its value if there was one.
+    pub fn remove(&mut self, program: Symbol, mapping: Symbol, key: &Value) -> Option<Value> {
+        self.mappings
+            .get_mut(&(program, mapping))
+            .and_then(|entries| entries.shift_remove(&key.to_string()))
+            .map(|(_, value)| value)
+    }
+
+    /// Returns `true` if the mapping has a value for `key`.
+    pub fn contains(&self, program: Symbol, mapping: Symbol, key: &Value) -> bool {
+        self.get(program, mapping, key).is_some()
+    }
+
+    /// Returns the entries of the mapping, in insertion order.
+    pub fn entries(&self, program: Symbol, mapping: Symbol) -> impl Iterator<Item = (&Value, &Value)> {
+        self.mappings.get(&(program, mapping)).into_iter().flat_map(|entries| entries.values().map(|(k, v)| (k, v)))
+    }
+}
@@ -0,0 +1,194 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{IntegerType, Literal as LeoLiteral, Node, Type};
+use leo_errors::{InterpreterError, Result};
+use leo_span::{Span, Symbol};
+
+use snarkvm_console::{
+    network::Testnet3,
+    program::{Literal, LiteralType, Plaintext},
+};
+
+use indexmap::IndexMap;
+use itertools::Itertools;
+use std::{fmt, str::FromStr};
+
+/// The network whose cryptographic primitives are used by the interpreter.
+pub type CurrentNetwork = Testnet3;
+
+/// A value computed by the interpreter.
+#[derive(Clone, Debug)]
+pub enum Value {
+    /// A boolean or an integer, which supports the operations of constant folding.
+    Const(leo_ast::Value),
+    /// A literal of any other type, e.g. a field or an address, which supports the operations of snarkVM.
+    Literal(Literal<CurrentNetwork>),
+    /// An array, e.g. `[1u8, 2u8]`.
+    Array(Vec<Value>),
+    /// A tuple, e.g. `(1u8, true)`.
+    Tuple(Vec<Value>),
+    /// A struct or record, with its members in declaration order.
+    Struct(Symbol, IndexMap<Symbol, Value>),
+    /// A variant of an enum with its payload, e.g. `Shape::Circle(1u32)`.
+    Variant(Symbol, Symbol, Vec<Value>),
+    /// The unit value, `()`.
+    Unit,
+}
+
+impl Value {
+    /// Returns the value of a literal whose type has been resolved.
+    pub fn from_literal(literal: &LeoLiteral) -> Result<Self> {
+        match literal {
+            LeoLiteral::Boolean(..) | LeoLiteral::Integer(..) => match leo_ast::Value::try_from(literal) {
+                Ok(value) => Ok(Value::Const(value)),
+                Err(err) => Err(InterpreterError::operation_failed(literal, err, literal.span()).into()),
+            },
+            LeoLiteral::String(..) => Self::parse_literal(&literal.to_string(), literal.span()),
+            _ => Self::parse_literal(&literal.to_string().replace('_', ""), literal.span()),
+        }
+    }
+
+    /// Parses a literal from its Aleo representation, e.g. `1field`.
+    fn parse_literal(string: &str, span: Span) -> Result<Self> {
+        match Literal::<CurrentNetwork>::from_str(string) {
+            Ok(literal) => Ok(Self::from_console(literal)),
+            Err(err) => Err(InterpreterError::operation_failed(string, err, span).into()),
+        }
+    }
+
+    /// Returns the value of a snarkVM literal.
+    pub fn from_console(literal: Literal<CurrentNetwork>) -> Self {
+        let span = Span::default();
+        Value::Const(match literal {
+            Literal::Boolean(value) => leo_ast::Value::Boolean(*value, span),
+            Literal::I8(value) => leo_ast::Value::I8(*value, span),
+            Literal::I16(value) => leo_ast::Value::I16(*value, span),
+            Literal::I32(value) => leo_ast::Value::I32(*value, span),
+            Literal::I64(value) => leo_ast::Value::I64(*value, span),
+            Literal::I128(value) => leo_ast::Value::I128(*value, span),
+            Literal::U8(value) => leo_ast::Value::U8(*value, span),
+            Literal::U16(value) => leo_ast::Value::U16(*value, span),
+            Literal::U32(value) => leo_ast::Value::U32(*value, span),
+            Literal::U64(value) => leo_ast::Value::U64(*value, span),
+            Literal::U128(value) => leo_ast::Value::U128(*value, span),
+            literal => return Value::Literal(literal),
+        })
+    }
+
+    /// Returns the value as a snarkVM literal, if it is one.
+    pub fn to_console(&self) -> Option<Literal<CurrentNetwork>> {
+        match self {
+            Value::Const(_) => Literal::from_str(&self.to_string()).ok(),
+            Value::Literal(literal) => Some(literal.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a snarkVM plaintext, if it has one.
+    /// Tuples, enums and the unit value only exist in Leo, so they have no plaintext.
+    pub fn to_plaintext(&self) -> Option<Plaintext<CurrentNetwork>> {
+        match self {
+            Value::Tuple(_) | Value::Variant(..) | Value::Unit => None,
+            _ => Plaintext::from_str(&self.to_string()).ok(),
+        }
+    }
+
+    /// Returns the boolean held by the value, if it is a boolean.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Const(leo_ast::Value::Boolean(value, _)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the integer held by the value as an index, if it is a non-negative integer.
+    pub fn as_index(&self) -> Option<usize> {
+        match self {
+            Value::Const(value)
+                if value.is_supported_const_fold_type() && !matches!(value, leo_ast::Value::Boolean(..)) =>
+            {
+                u128::try_from(value).ok().and_then(|index| usize::try_from(index).ok())
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the boolean value `value`.
+    pub fn boolean(value: bool) -> Self {
+        Value::Const(leo_ast::Value::Boolean(value, Span::default()))
+    }
+
+    /// Returns the integer `value` of the given type, if it is in the range of the type.
+    pub fn integer(type_: IntegerType, value: i128) -> Option<Self> {
+        let literal = LeoLiteral::Integer(type_, value.to_string(), Span::default(), Default::default());
+        leo_ast::Value::try_from(&literal).ok().map(Value::Const)
+    }
+}
+
+/// Returns the snarkVM type of a Leo type, if it is a literal type.
+pub fn literal_type(type_: &Type) -> Option<LiteralType> {
+    Some(match type_ {
+        Type::Address => LiteralType::Address,
+        Type::Boolean => LiteralType::Boolean,
+        Type::Field => LiteralType::Field,
+        Type::Group => LiteralType::Group,
+        Type::Scalar => LiteralType::Scalar,
+        Type::Signature => LiteralType::Signature,
+        Type::String => LiteralType::String,
+        Type::Integer(integer_type) => match integer_type {
+            IntegerType::I8 => LiteralType::I8,
+            IntegerType::I16 => LiteralType::I16,
+            IntegerType::I32 => LiteralType::I32,
+            IntegerType::I64 => LiteralType::I64,
+            IntegerType::I128 => LiteralType::I128,
+            IntegerType::U8 => LiteralType::U8,
+            IntegerType::U16 => LiteralType::U16,
+            IntegerType::U32 => LiteralType::U32,
+            IntegerType::U64 => LiteralType::U64,
+            IntegerType::U128 => LiteralType::U128,
+        },
+        _ => return None,
+    })
+}
+
+/// Values are equal if they have the same Aleo representation, as their types agree after type checking.
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for Value {}
+
+/// Values are displayed in their Aleo representation, e.g. `{ amount: 1u64 }`.
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Const(value @ leo_ast::Value::Boolean(..)) => write!(f, "{value}"),
+            Value::Const(value) => write!(f, "{value}{}", Type::from(value)),
+            Value::Literal(literal) => write!(f, "{literal}"),
+            Value::Array(elements) => write!(f, "[{}]", elements.iter().join(", ")),
+            Value::Tuple(elements) => write!(f, "({})", elements.iter().join(", ")),
+            Value::Struct(_, members) => {
+                write!(f, "{{ {} }}", members.iter().map(|(name, value)| format!("{name}: {value}")).join(", "))
+            }
+            Value::Variant(enum_, variant, payload) if payload.is_empty() => write!(f, "{enum_}::{variant}"),
+            Value::Variant(enum_, variant, payload) => write!(f, "{enum_}::{variant}({})", payload.iter().join(", ")),
+            Value::Unit => write!(f, "()"),
+        }
+    }
+}
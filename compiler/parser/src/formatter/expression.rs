@@ -0,0 +1,358 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_ast::{
+    AccessExpression,
+    BinaryOperation,
+    Expression,
+    GroupLiteral,
+    Literal,
+    Node,
+    StructVariableInitializer,
+    Type,
+    UnaryOperation,
+};
+
+/// The binding strength of an expression, from loosest to tightest.
+/// Mirrors the order in which the parser descends through the expression grammar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Precedence {
+    Ternary,
+    Or,
+    And,
+    Equality,
+    Ordering,
+    BitwiseXor,
+    BitwiseOr,
+    BitwiseAnd,
+    Shift,
+    Additive,
+    Multiplicative,
+    Exponential,
+    Cast,
+    Unary,
+    Postfix,
+}
+
+impl Precedence {
+    /// Returns the next tighter precedence level.
+    fn next(self) -> Self {
+        use Precedence::*;
+        match self {
+            Ternary => Or,
+            Or => And,
+            And => Equality,
+            Equality => Ordering,
+            Ordering => BitwiseXor,
+            BitwiseXor => BitwiseOr,
+            BitwiseOr => BitwiseAnd,
+            BitwiseAnd => Shift,
+            Shift => Additive,
+            Additive => Multiplicative,
+            Multiplicative => Exponential,
+            Exponential => Cast,
+            Cast => Unary,
+            Unary | Postfix => Postfix,
+        }
+    }
+}
+
+/// Returns the precedence and the infix operator of a binary operation,
+/// or `None` if the operation can only be written as a method call, e.g. `a.add_wrapped(b)`.
+fn infix_operator(op: BinaryOperation) -> Option<(Precedence, &'static str)> {
+    use BinaryOperation::*;
+    Some(match op {
+        Or => (Precedence::Or, "||"),
+        And => (Precedence::And, "&&"),
+        Eq => (Precedence::Equality, "=="),
+        Neq => (Precedence::Equality, "!="),
+        Lt => (Precedence::Ordering, "<"),
+        Lte => (Precedence::Ordering, "<="),
+        Gt => (Precedence::Ordering, ">"),
+        Gte => (Precedence::Ordering, ">="),
+        Xor => (Precedence::BitwiseXor, "^"),
+        BitwiseOr => (Precedence::BitwiseOr, "|"),
+        BitwiseAnd => (Precedence::BitwiseAnd, "&"),
+        Shl => (Precedence::Shift, "<<"),
+        Shr => (Precedence::Shift, ">>"),
+        Add => (Precedence::Additive, "+"),
+        Sub => (Precedence::Additive, "-"),
+        Mul => (Precedence::Multiplicative, "*"),
+        Div => (Precedence::Multiplicative, "/"),
+        Rem => (Precedence::Multiplicative, "%"),
+        Pow => (Precedence::Exponential, "**"),
+        _ => return None,
+    })
+}
+
+/// Returns the name of the method that performs a binary operation without an infix operator.
+fn binary_method(op: BinaryOperation) -> &'static str {
+    use BinaryOperation::*;
+    match op {
+        AddWrapped => "add_wrapped",
+        DivWrapped => "div_wrapped",
+        Mod => "mod",
+        MulWrapped => "mul_wrapped",
+        Nand => "nand",
+        Nor => "nor",
+        PowWrapped => "pow_wrapped",
+        RemWrapped => "rem_wrapped",
+        ShlWrapped => "shl_wrapped",
+        ShrWrapped => "shr_wrapped",
+        SubWrapped => "sub_wrapped",
+        _ => unreachable!("binary operations with an infix operator are not written as method calls"),
+    }
+}
+
+/// Returns the name of the method that performs a unary operation.
+fn unary_method(op: UnaryOperation) -> &'static str {
+    use UnaryOperation::*;
+    match op {
+        Abs => "abs",
+        AbsWrapped => "abs_wrapped",
+        Double => "double",
+        Inverse => "inv",
+        Square => "square",
+        SquareRoot => "square_root",
+        ToXCoordinate => "to_x_coordinate",
+        ToYCoordinate => "to_y_coordinate",
+        Negate | Not => unreachable!("negation is written with a prefix operator"),
+    }
+}
+
+impl Formatter<'_> {
+    /// Returns the canonical text of an expression.
+    pub(super) fn expression_to_string(&mut self, input: &Expression) -> String {
+        let prior = std::mem::replace(&mut self.disallow_struct_construction, false);
+        let text = self.expression_with_precedence(input, Precedence::Ternary);
+        self.disallow_struct_construction = prior;
+        text
+    }
+
+    /// Returns the canonical text of an expression in a position where struct initializers are not allowed,
+    /// i.e. the condition of an `if` statement or the upper bound of a `for` loop.
+    pub(super) fn condition_to_string(&mut self, input: &Expression) -> String {
+        let prior = std::mem::replace(&mut self.disallow_struct_construction, true);
+        let text = self.expression_with_precedence(input, Precedence::Ternary);
+        self.disallow_struct_construction = prior;
+        text
+    }
+
+    /// Returns the comma-separated canonical text of a list of expressions.
+    pub(super) fn expressions_to_string(&mut self, input: &[Expression]) -> String {
+        input.iter().map(|expr| self.expression_to_string(expr)).collect::<Vec<_>>().join(", ")
+    }
+
+    /// Returns the canonical text of a struct member initializer.
+    pub(super) fn struct_member_to_string(&mut self, input: &StructVariableInitializer) -> String {
+        match &input.expression {
+            Some(expr) => format!("{}: {}", input.identifier, self.expression_to_string(expr)),
+            None => input.identifier.to_string(),
+        }
+    }
+
+    /// Splits a chain of binary operations with the same infix operator, e.g. `a || b || c`,
+    /// into its operator and the text and span of each of its operands.
+    /// Returns `None` if the expression is not such a binary operation.
+    pub(super) fn binary_chain(&mut self, input: &Expression) -> Option<(&'static str, Vec<(String, Span)>)> {
+        let Expression::Binary(binary) = input else { return None };
+        let (precedence, operator) = infix_operator(binary.op)?;
+
+        // Collect the operands of the left-associative chain, from right to left.
+        let mut operands = vec![&*binary.right];
+        let mut left = &*binary.left;
+        while let Expression::Binary(inner) = left {
+            if inner.op != binary.op || matches!(precedence, Precedence::Equality | Precedence::Ordering) {
+                break;
+            }
+            operands.push(&inner.right);
+            left = &inner.left;
+        }
+        operands.push(left);
+        operands.reverse();
+
+        let prior = std::mem::replace(&mut self.disallow_struct_construction, false);
+        let operands = operands
+            .into_iter()
+            .map(|operand| (self.expression_with_precedence(operand, precedence.next()), operand.span()))
+            .collect();
+        self.disallow_struct_construction = prior;
+        Some((operator, operands))
+    }
+
+    /// Returns the text of an expression, parenthesized if it binds looser than `min`.
+    fn expression_with_precedence(&mut self, input: &Expression, min: Precedence) -> String {
+        let (text, precedence) = self.expression(input);
+        match precedence < min {
+            true => format!("({text})"),
+            false => text,
+        }
+    }
+
+    /// Returns the text of an expression along with its precedence.
+    fn expression(&mut self, input: &Expression) -> (String, Precedence) {
+        match input {
+            Expression::Access(access) => (self.access(access), Precedence::Postfix),
+            Expression::Array(array) => {
+                (format!("[{}]", self.expressions_to_string(&array.elements)), Precedence::Postfix)
+            }
+            Expression::Binary(binary) => match infix_operator(binary.op) {
+                Some((precedence, operator)) => {
+                    // Comparisons do not associate, while every other binary operator associates to the left.
+                    let left_min = match precedence {
+                        Precedence::Equality | Precedence::Ordering => precedence.next(),
+                        _ => precedence,
+                    };
+                    let left = self.expression_with_precedence(&binary.left, left_min);
+                    let right = self.expression_with_precedence(&binary.right, precedence.next());
+                    (format!("{left} {operator} {right}"), precedence)
+                }
+                None => {
+                    let left = self.expression_with_precedence(&binary.left, Precedence::Postfix);
+                    let right = self.expression_to_string(&binary.right);
+                    (format!("{left}.{}({right})", binary_method(binary.op)), Precedence::Postfix)
+                }
+            },
+            Expression::Call(call) => {
+                let function = self.expression_with_precedence(&call.function, Precedence::Postfix);
+                let arguments = self.expressions_to_string(&call.arguments);
+                let text = match &call.external {
+                    Some(external) => format!("{external}.leo/{function}({arguments})"),
+                    None => format!("{function}({arguments})"),
+                };
+                (text, Precedence::Postfix)
+            }
+            Expression::Cast(cast) => {
+                let expression = self.expression_with_precedence(&cast.expression, Precedence::Unary);
+                (format!("{expression} as {}", self.type_to_string(&cast.type_)), Precedence::Cast)
+            }
+            Expression::Struct(struct_) => {
+                let members =
+                    struct_.members.iter().map(|member| self.struct_member_to_string(member)).collect::<Vec<_>>();
+                let text = match members.is_empty() {
+                    true => format!("{} {{}}", struct_.name),
+                    false => format!("{} {{ {} }}", struct_.name, members.join(", ")),
+                };
+                match self.disallow_struct_construction {
+                    true => (format!("({text})"), Precedence::Postfix),
+                    false => (text, Precedence::Postfix),
+                }
+            }
+            Expression::Err(err) => (err.to_string(), Precedence::Postfix),
+            Expression::Identifier(identifier) => (identifier.to_string(), Precedence::Postfix),
+            Expression::Literal(literal) => self.literal(literal),
+            Expression::Ternary(ternary) => {
+                let condition = self.expression_with_precedence(&ternary.condition, Precedence::Or);
+                let if_true = self.expression_with_precedence(&ternary.if_true, Precedence::Ternary);
+                let if_false = self.expression_with_precedence(&ternary.if_false, Precedence::Ternary);
+                (format!("{condition} ? {if_true} : {if_false}"), Precedence::Ternary)
+            }
+            Expression::Tuple(tuple) => {
+                (format!("({})", self.expressions_to_string(&tuple.elements)), Precedence::Postfix)
+            }
+            Expression::Unary(unary) => {
+                let operator = match unary.op {
+                    UnaryOperation::Not => Some("!"),
+                    UnaryOperation::Negate => Some("-"),
+                    _ => None,
+                };
+                match operator {
+                    // Negated literals are written as method calls, since `-1i8` is parsed as a single literal.
+                    Some("-") if matches!(unary.receiver.as_ref(), Expression::Literal(_)) => {
+                        let receiver = self.expression_with_precedence(&unary.receiver, Precedence::Postfix);
+                        (format!("{receiver}.neg()"), Precedence::Postfix)
+                    }
+                    Some(operator) => {
+                        let receiver = self.expression_with_precedence(&unary.receiver, Precedence::Unary);
+                        (format!("{operator}{receiver}"), Precedence::Unary)
+                    }
+                    None => {
+                        let receiver = self.expression_with_precedence(&unary.receiver, Precedence::Postfix);
+                        (format!("{receiver}.{}()", unary_method(unary.op)), Precedence::Postfix)
+                    }
+                }
+            }
+            Expression::Unit(_) => ("()".to_string(), Precedence::Postfix),
+        }
+    }
+
+    /// Returns the text of an access expression.
+    fn access(&mut self, input: &AccessExpression) -> String {
+        match input {
+            AccessExpression::Array(access) => {
+                let array = self.expression_with_precedence(&access.array, Precedence::Postfix);
+                format!("{array}[{}]", self.expression_to_string(&access.index))
+            }
+            AccessExpression::AssociatedConstant(constant) => {
+                format!("{}::{}", self.type_to_string(&constant.ty), constant.name)
+            }
+            AccessExpression::AssociatedFunction(function) => {
+                let arguments = self.expressions_to_string(&function.arguments);
+                format!("{}::{}({arguments})", self.type_to_string(&function.ty), function.name)
+            }
+            AccessExpression::Member(access) => {
+                let inner = self.expression_with_precedence(&access.inner, Precedence::Postfix);
+                format!("{inner}.{}", access.name)
+            }
+            AccessExpression::MethodCall(call) => {
+                let receiver = self.expression_with_precedence(&call.receiver, Precedence::Postfix);
+                format!("{receiver}.{}({})", call.name, self.expressions_to_string(&call.arguments))
+            }
+            AccessExpression::Tuple(access) => {
+                let tuple = self.expression_with_precedence(&access.tuple, Precedence::Postfix);
+                format!("{tuple}.{}", access.index)
+            }
+        }
+    }
+
+    /// Returns the text of a literal along with its precedence.
+    fn literal(&self, input: &Literal) -> (String, Precedence) {
+        let text = match input {
+            Literal::Group(group) => match group.as_ref() {
+                GroupLiteral::Tuple(tuple) => format!("({}, {})group", tuple.x, tuple.y),
+                GroupLiteral::Single(..) => input.to_string(),
+            },
+            _ => input.to_string(),
+        };
+        // A negative literal starts with a unary minus.
+        match text.starts_with('-') {
+            true => (text, Precedence::Unary),
+            false => (text, Precedence::Postfix),
+        }
+    }
+
+    /// Returns the text of a type as it is written in source code.
+    pub(super) fn type_to_string(&self, input: &Type) -> String {
+        match input {
+            Type::Array(array) => format!("[{}; {}]", self.type_to_string(array.element_type()), array.array_length()),
+            Type::Boolean => "bool".to_string(),
+            Type::Generic(generic) => format!(
+                "{}<{}>",
+                generic.identifier,
+                generic.arguments.iter().map(|ty| self.type_to_string(ty)).collect::<Vec<_>>().join(", ")
+            ),
+            Type::Tuple(tuple) => {
+                format!(
+                    "({})",
+                    tuple.elements().iter().map(|ty| self.type_to_string(ty)).collect::<Vec<_>>().join(", ")
+                )
+            }
+            type_ => type_.to_string(),
+        }
+    }
+}
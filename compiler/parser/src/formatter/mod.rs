@@ -0,0 +1,229 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The formatter to convert Leo code text into its canonical layout.
+//!
+//! This module contains the [`format_source()`] method which parses the source text into a [`Program`],
+//! or a [`Module`] if it has no program scope, and prints it back out, re-attaching the comments that the parser discards.
+
+mod expression;
+
+mod program;
+
+mod statement;
+
+use crate::{tokenizer::*, ParserContext};
+
+use leo_ast::{Module, NodeBuilder, Program};
+use leo_errors::{emitter::Handler, Result};
+use leo_span::{span::BytePos, Span};
+
+use std::collections::VecDeque;
+
+/// The number of spaces used for each level of indentation.
+const INDENT_WIDTH: usize = 4;
+
+/// The line width above which struct initializers are split over several lines.
+const MAX_WIDTH: usize = 100;
+
+/// Formats the given source code text into the canonical Leo layout.
+///
+/// The `start_pos` must be the position the source was registered at in the source map.
+pub fn format_source(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    source: &str,
+    start_pos: BytePos,
+) -> Result<String> {
+    let tokens = crate::tokenize(source, start_pos)?;
+
+    // Collect the comments before the parser strips them out.
+    let comments = tokens
+        .iter()
+        .filter_map(|token| match &token.token {
+            Token::CommentLine(text) | Token::CommentBlock(text) => {
+                Some(Comment { text: text.trim_end().to_string(), span: token.span })
+            }
+            _ => None,
+        })
+        .collect();
+
+    // A file without a program scope is a module of the program.
+    let is_module = !tokens.iter().any(|token| token.token == Token::Program);
+
+    let mut parser = ParserContext::new(handler, node_builder, tokens);
+    let mut formatter = Formatter::new(source, start_pos, comments);
+    match is_module {
+        true => {
+            let module = parser.parse_module()?;
+            handler.last_err().map_err(|e| *e)?;
+            formatter.format_module(&module);
+        }
+        false => {
+            let program = parser.parse_program()?;
+            handler.last_err().map_err(|e| *e)?;
+            formatter.format_program(&program);
+        }
+    }
+
+    Ok(formatter.finish())
+}
+
+/// A comment found in the source text.
+struct Comment {
+    /// The text of the comment, including the `//` or `/* */` delimiters.
+    text: String,
+    /// The span of the comment in the source text.
+    span: Span,
+}
+
+/// Prints a [`Program`] in the canonical layout, interleaving the comments of the original source.
+struct Formatter<'a> {
+    /// The original source text.
+    source: &'a str,
+    /// The position of the source text in the source map.
+    start_pos: BytePos,
+    /// The comments that have not been printed yet, in source order.
+    comments: VecDeque<Comment>,
+    /// The formatted output.
+    output: String,
+    /// The current indentation level.
+    indent: usize,
+    /// Whether struct initializers must be parenthesized, e.g. in the condition of an `if` statement.
+    disallow_struct_construction: bool,
+}
+
+impl<'a> Formatter<'a> {
+    fn new(source: &'a str, start_pos: BytePos, comments: Vec<Comment>) -> Self {
+        Self {
+            source,
+            start_pos,
+            comments: comments.into(),
+            output: String::new(),
+            indent: 0,
+            disallow_struct_construction: false,
+        }
+    }
+
+    /// Prints the remaining comments and returns the formatted output.
+    fn finish(mut self) -> String {
+        while let Some(comment) = self.comments.pop_front() {
+            self.write_comment(comment);
+        }
+        let trimmed_len = self.output.trim_end().len();
+        self.output.truncate(trimmed_len);
+        self.output.push('\n');
+        self.output
+    }
+
+    /// Returns the number of columns taken up by the current indentation.
+    fn indent_width(&self) -> usize {
+        self.indent * INDENT_WIDTH
+    }
+
+    /// Writes a single line at the current indentation.
+    fn write_line(&mut self, line: &str) {
+        self.output.push_str(&" ".repeat(self.indent_width()));
+        self.output.push_str(line);
+        self.output.push('\n');
+    }
+
+    /// Writes an empty line, unless the previous line is empty or opens a block.
+    fn blank_line(&mut self) {
+        if !self.output.is_empty() && !self.output.ends_with("\n\n") && !self.output.ends_with("{\n") {
+            self.output.push('\n');
+        }
+    }
+
+    /// Returns `true` if the original source has an empty line right before `pos`.
+    fn blank_line_before(&self, pos: BytePos) -> bool {
+        let offset = (pos.0 - self.start_pos.0) as usize;
+        self.source[..offset].chars().rev().take_while(|c| c.is_whitespace()).filter(|c| *c == '\n').nth(1).is_some()
+    }
+
+    /// Writes a comment on its own line, keeping an empty line above it if the source had one.
+    fn write_comment(&mut self, comment: Comment) {
+        if self.blank_line_before(comment.span.lo) {
+            self.blank_line();
+        }
+        self.write_line(&comment.text);
+    }
+
+    /// Writes every comment that starts before `pos` on its own line.
+    fn leading_comments(&mut self, pos: BytePos) {
+        while self.comments.front().map_or(false, |comment| comment.span.lo < pos) {
+            let comment = self.comments.pop_front().unwrap();
+            self.write_comment(comment);
+        }
+    }
+
+    /// Writes the leading comments of a node that starts at `lo` and whose comments end before `limit`.
+    /// Keeps an empty line above the node if the source had one.
+    fn leading_trivia(&mut self, lo: BytePos, limit: BytePos) {
+        self.leading_comments(limit);
+        if self.blank_line_before(lo) {
+            self.blank_line();
+        }
+    }
+
+    /// Appends a comment that follows `pos` on the same line in the source to the last written line.
+    fn trailing_comment(&mut self, pos: BytePos) {
+        let same_line = self.comments.front().map_or(false, |comment| {
+            let start = (pos.0 - self.start_pos.0) as usize;
+            let end = (comment.span.lo.0 - self.start_pos.0) as usize;
+            comment.span.lo >= pos && !self.source[start..end].contains('\n')
+        });
+        if same_line {
+            let comment = self.comments.pop_front().unwrap();
+            self.output.pop();
+            self.output.push(' ');
+            self.output.push_str(&comment.text);
+            self.output.push('\n');
+        }
+    }
+
+    /// Formats a whole program, i.e. its imports and module declarations followed by its program scopes.
+    fn format_program(&mut self, program: &Program) {
+        let imports = program.imports.iter().map(|(name, (_, span))| (*span, format!("import {name}.leo;")));
+        self.format_declarations(
+            imports.chain(program.uses.iter().map(|use_| (use_.span, use_.to_string()))).collect(),
+        );
+
+        for program_scope in program.program_scopes.values() {
+            self.blank_line();
+            self.format_program_scope(program_scope);
+        }
+    }
+
+    /// Formats a module, i.e. its module declarations followed by its items.
+    fn format_module(&mut self, module: &Module) {
+        self.format_declarations(module.uses.iter().map(|use_| (use_.span, use_.to_string())).collect());
+
+        self.blank_line();
+        self.format_module_items(module);
+    }
+
+    /// Formats the single-line declarations at the top of a file, e.g. imports, in source order.
+    fn format_declarations(&mut self, mut declarations: Vec<(Span, String)>) {
+        declarations.sort_by_key(|(span, _)| span.lo);
+
+        for (span, line) in declarations.iter() {
+            self.leading_trivia(span.lo, span.lo);
+            self.write_line(line);
+            self.trailing_comment(span.hi);
+        }
+    }
+}
@@ -0,0 +1,323 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_ast::{
+    Block,
+    ConstDeclaration,
+    Enum,
+    Function,
+    GenericParameter,
+    Impl,
+    Input,
+    Mapping,
+    Mode,
+    Module,
+    Output,
+    ProgramScope,
+    Struct,
+    Variant,
+};
+use leo_span::sym;
+
+/// An item declared in a program scope.
+enum Item<'a> {
+    Const(&'a ConstDeclaration),
+    Enum(&'a Enum),
+    Function(&'a Function),
+    Impl(&'a Impl),
+    Mapping(&'a Mapping),
+    Struct(&'a Struct),
+}
+
+impl Item<'_> {
+    /// The position at which the item starts, including any annotations.
+    fn lo(&self) -> BytePos {
+        match self {
+            Item::Const(const_) => const_.span.lo,
+            Item::Enum(enum_) => enum_.span.lo,
+            Item::Function(function) => {
+                function.annotations.first().map_or(function.span.lo, |annotation| annotation.span.lo)
+            }
+            Item::Impl(impl_) => impl_.span.lo,
+            Item::Mapping(mapping) => mapping.span.lo,
+            Item::Struct(struct_) => struct_.span.lo,
+        }
+    }
+
+    /// Returns `true` if the item is printed on a single line.
+    fn is_single_line(&self) -> bool {
+        matches!(self, Item::Const(_) | Item::Mapping(_))
+    }
+}
+
+impl Formatter<'_> {
+    /// Formats a program scope, keeping its items in source order.
+    pub(super) fn format_program_scope(&mut self, input: &ProgramScope) {
+        self.leading_trivia(input.span.lo, input.span.lo);
+        self.write_line(&format!("program {} {{", input.program_id));
+
+        let items = input
+            .consts
+            .iter()
+            .map(|(_, c)| Item::Const(c))
+            .chain(input.structs.iter().map(|(_, s)| Item::Struct(s)))
+            .chain(input.enums.iter().map(|(_, e)| Item::Enum(e)))
+            .chain(input.impls.iter().map(|(_, i)| Item::Impl(i)))
+            .chain(input.mappings.iter().map(|(_, m)| Item::Mapping(m)))
+            .chain(input.functions.iter().map(|(_, f)| Item::Function(f)))
+            .collect::<Vec<_>>();
+        self.indent += 1;
+        self.format_items(items);
+        self.leading_comments(input.span.hi);
+        self.indent -= 1;
+
+        self.write_line("}");
+        self.trailing_comment(input.span.hi);
+    }
+
+    /// Formats the items of a module, keeping them in source order.
+    pub(super) fn format_module_items(&mut self, input: &Module) {
+        let items = input
+            .consts
+            .iter()
+            .map(|(_, c)| Item::Const(c))
+            .chain(input.structs.iter().map(|(_, s)| Item::Struct(s)))
+            .chain(input.enums.iter().map(|(_, e)| Item::Enum(e)))
+            .chain(input.impls.iter().map(|(_, i)| Item::Impl(i)))
+            .chain(input.functions.iter().map(|(_, f)| Item::Function(f)))
+            .collect::<Vec<_>>();
+
+        self.format_items(items);
+    }
+
+    /// Formats items in source order, separating multi-line items with an empty line.
+    fn format_items(&mut self, mut items: Vec<Item>) {
+        items.sort_by_key(|item| item.lo());
+
+        let mut previous: Option<&Item> = None;
+        for item in items.iter() {
+            // Multi-line items are always separated by an empty line.
+            if previous.map_or(false, |previous| !(previous.is_single_line() && item.is_single_line())) {
+                self.blank_line();
+            }
+            match item {
+                Item::Const(const_) => self.format_const(const_),
+                Item::Enum(enum_) => self.format_enum(enum_),
+                Item::Function(function) => self.format_function(function),
+                Item::Impl(impl_) => self.format_impl(impl_),
+                Item::Mapping(mapping) => self.format_mapping(mapping),
+                Item::Struct(struct_) => self.format_struct(struct_),
+            }
+            previous = Some(item);
+        }
+    }
+
+    /// Formats a struct or record declaration.
+    fn format_struct(&mut self, input: &Struct) {
+        self.leading_trivia(input.span.lo, input.span.lo);
+        let keyword = if input.is_record { "record" } else { "struct" };
+        let name = format!("{}{}", input.identifier, Self::generic_parameters_to_string(&input.generic_parameters));
+
+        if input.members.is_empty() {
+            self.write_line(&format!("{keyword} {name} {{}}"));
+        } else {
+            self.write_line(&format!("{keyword} {name} {{"));
+            self.indent += 1;
+            for member in input.members.iter() {
+                self.leading_trivia(member.span.lo, member.span.lo);
+                let type_ = self.type_to_string(&member.type_);
+                self.write_line(&format!("{}{}: {type_},", Self::mode_prefix(member.mode), member.identifier));
+                self.trailing_comment(member.span.hi);
+            }
+            self.leading_comments(input.span.hi);
+            self.indent -= 1;
+            self.write_line("}");
+        }
+        self.trailing_comment(input.span.hi);
+    }
+
+    /// Formats an enum declaration, with one variant per line.
+    fn format_enum(&mut self, input: &Enum) {
+        self.leading_trivia(input.span.lo, input.span.lo);
+
+        if input.variants.is_empty() {
+            self.write_line(&format!("enum {} {{}}", input.identifier));
+        } else {
+            self.write_line(&format!("enum {} {{", input.identifier));
+            self.indent += 1;
+            for variant in input.variants.iter() {
+                self.leading_trivia(variant.span.lo, variant.span.lo);
+                let line = match variant.payload.is_empty() {
+                    true => format!("{},", variant.identifier),
+                    false => {
+                        let payload =
+                            variant.payload.iter().map(|type_| self.type_to_string(type_)).collect::<Vec<_>>();
+                        format!("{}({}),", variant.identifier, payload.join(", "))
+                    }
+                };
+                self.write_line(&line);
+                self.trailing_comment(variant.span.hi);
+            }
+            self.leading_comments(input.span.hi);
+            self.indent -= 1;
+            self.write_line("}");
+        }
+        self.trailing_comment(input.span.hi);
+    }
+
+    /// Formats an `impl` block, keeping its items in source order.
+    fn format_impl(&mut self, input: &Impl) {
+        self.leading_trivia(input.span.lo, input.span.lo);
+
+        let items = input
+            .consts
+            .iter()
+            .map(|(_, c)| Item::Const(c))
+            .chain(input.functions.iter().map(|(_, f)| Item::Function(f)))
+            .collect::<Vec<_>>();
+        if items.is_empty() {
+            self.write_line(&format!("impl {} {{}}", input.target));
+        } else {
+            self.write_line(&format!("impl {} {{", input.target));
+            self.indent += 1;
+            self.format_items(items);
+            self.leading_comments(input.span.hi);
+            self.indent -= 1;
+            self.write_line("}");
+        }
+        self.trailing_comment(input.span.hi);
+    }
+
+    /// Formats a mapping declaration.
+    fn format_mapping(&mut self, input: &Mapping) {
+        self.leading_trivia(input.span.lo, input.span.lo);
+        self.write_line(&format!(
+            "mapping {}: {} => {};",
+            input.identifier,
+            self.type_to_string(&input.key_type),
+            self.type_to_string(&input.value_type)
+        ));
+        self.trailing_comment(input.span.hi);
+    }
+
+    /// Formats a function declaration, including its annotations and its finalize block.
+    fn format_function(&mut self, input: &Function) {
+        let lo = input.annotations.first().map_or(input.span.lo, |annotation| annotation.span.lo);
+        self.leading_trivia(lo, lo);
+
+        for annotation in input.annotations.iter() {
+            self.leading_comments(annotation.span.lo);
+            self.write_line(&annotation.to_string());
+        }
+        self.leading_comments(input.block.span.lo);
+
+        let variant = match input.variant {
+            Variant::Inline => "inline",
+            Variant::Standard => "function",
+            Variant::Transition => "transition",
+        };
+        let name = format!("{}{}", input.identifier, Self::generic_parameters_to_string(&input.generic_parameters));
+        self.format_signature_and_block(variant, name, &input.input, &input.output, &input.block);
+
+        if let Some(finalize) = &input.finalize {
+            self.blank_line();
+            self.leading_trivia(finalize.span.lo, finalize.block.span.lo);
+            self.format_signature_and_block(
+                "finalize",
+                finalize.identifier,
+                &finalize.input,
+                &finalize.output,
+                &finalize.block,
+            );
+        }
+    }
+
+    /// Formats the signature and the body of a function or finalize block.
+    /// Signatures that do not fit on a single line are written with one parameter per line.
+    fn format_signature_and_block(
+        &mut self,
+        keyword: &str,
+        identifier: impl std::fmt::Display,
+        input: &[Input],
+        output: &[Output],
+        block: &Block,
+    ) {
+        let input = input.iter().map(|input| self.input_to_string(input)).collect::<Vec<_>>();
+        let mut output = output.iter().map(|output| self.output_to_string(output)).collect::<Vec<_>>();
+        let output = match output.len() {
+            0 => String::new(),
+            1 => format!(" -> {}", output.remove(0)),
+            _ => format!(" -> ({})", output.join(", ")),
+        };
+
+        let header = format!("{keyword} {identifier}({}){output} ", input.join(", "));
+        // Account for the opening brace of the block.
+        if input.is_empty() || self.indent_width() + header.len() < MAX_WIDTH {
+            self.format_block(&header, block);
+        } else {
+            self.write_line(&format!("{keyword} {identifier}("));
+            self.indent += 1;
+            for input in input.iter() {
+                self.write_line(&format!("{input},"));
+            }
+            self.indent -= 1;
+            self.format_block(&format!("){output} "), block);
+        }
+    }
+
+    /// Returns the type parameters of a function or struct, e.g. `<T, U: Int>`, or nothing if there are none.
+    fn generic_parameters_to_string(generic_parameters: &[GenericParameter]) -> String {
+        match generic_parameters.is_empty() {
+            true => String::new(),
+            false => format!(
+                "<{}>",
+                generic_parameters.iter().map(|parameter| parameter.to_string()).collect::<Vec<_>>().join(", ")
+            ),
+        }
+    }
+
+    fn input_to_string(&self, input: &Input) -> String {
+        match input {
+            // The receiver of a method is written without its type.
+            Input::Internal(input) if input.identifier.name == sym::SelfLower => input.identifier.to_string(),
+            Input::Internal(input) => {
+                format!("{}{}: {}", Self::mode_prefix(input.mode), input.identifier, self.type_to_string(&input.type_))
+            }
+            Input::External(input) => {
+                format!("{}: {}.leo/{}.record", input.identifier, input.program_name, input.record)
+            }
+        }
+    }
+
+    fn output_to_string(&self, output: &Output) -> String {
+        match output {
+            Output::Internal(output) => {
+                format!("{}{}", Self::mode_prefix(output.mode), self.type_to_string(&output.type_))
+            }
+            Output::External(output) => format!("{}.leo/{}.record", output.program_name, output.record),
+        }
+    }
+
+    /// Returns the mode keyword followed by a space, or nothing if there is no mode.
+    fn mode_prefix(mode: Mode) -> String {
+        match mode {
+            Mode::None => String::new(),
+            mode => format!("{mode} "),
+        }
+    }
+}
@@ -0,0 +1,291 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_ast::{
+    AssertVariant,
+    AssignStatement,
+    BinaryOperation,
+    Block,
+    ConditionalStatement,
+    ConsoleFunction,
+    ConstDeclaration,
+    Expression,
+    IterationStatement,
+    MatchStatement,
+    Node,
+    ReturnStatement,
+    Statement,
+    WhileStatement,
+};
+
+impl Formatter<'_> {
+    /// Formats a block preceded by `header` on its opening line.
+    pub(super) fn format_block(&mut self, header: &str, block: &Block) {
+        let has_comments = self.comments.front().map_or(false, |comment| comment.span.lo < block.span.hi);
+        if block.statements.is_empty() && !has_comments {
+            self.write_line(&format!("{header}{{}}"));
+        } else {
+            self.write_line(&format!("{header}{{"));
+            self.format_block_body(block);
+            self.write_line("}");
+        }
+        self.trailing_comment(block.span.hi);
+    }
+
+    /// Formats the statements of a block, one indentation level deeper.
+    fn format_block_body(&mut self, block: &Block) {
+        self.trailing_comment(block.span.lo);
+        self.indent += 1;
+        for statement in block.statements.iter() {
+            self.format_statement(statement);
+        }
+        self.leading_comments(block.span.hi);
+        self.indent -= 1;
+    }
+
+    /// Formats a statement, along with the comments that precede or follow it.
+    fn format_statement(&mut self, input: &Statement) {
+        let span = input.span();
+        // The comments inside of a compound statement are attached to its nested statements,
+        // while those inside of an expression are placed by `write_statement`.
+        let limit = match input {
+            Statement::Assert(_) | Statement::Console(_) => span.hi,
+            _ => span.lo,
+        };
+        self.leading_trivia(span.lo, limit);
+
+        match input {
+            Statement::Assert(input) => {
+                let line = match &input.variant {
+                    AssertVariant::Assert(expr) => format!("assert({});", self.expression_to_string(expr)),
+                    AssertVariant::AssertEq(left, right) => {
+                        format!("assert_eq({}, {});", self.expression_to_string(left), self.expression_to_string(right))
+                    }
+                    AssertVariant::AssertNeq(left, right) => format!(
+                        "assert_neq({}, {});",
+                        self.expression_to_string(left),
+                        self.expression_to_string(right)
+                    ),
+                };
+                self.write_line(&line);
+            }
+            Statement::Assign(input) => self.format_assign(input),
+            Statement::Block(input) => self.format_block("", input),
+            Statement::Break(_) => self.write_line("break;"),
+            Statement::Conditional(input) => self.format_conditional(input, ""),
+            Statement::Console(input) => {
+                let line = match &input.function {
+                    ConsoleFunction::Assert(expr) => format!("console.assert({});", self.expression_to_string(expr)),
+                    ConsoleFunction::AssertEq(left, right) => format!(
+                        "console.assert_eq({}, {});",
+                        self.expression_to_string(left),
+                        self.expression_to_string(right)
+                    ),
+                    ConsoleFunction::AssertNeq(left, right) => format!(
+                        "console.assert_neq({}, {});",
+                        self.expression_to_string(left),
+                        self.expression_to_string(right)
+                    ),
+                };
+                self.write_line(&line);
+            }
+            Statement::Const(input) => self.format_const(input),
+            Statement::Continue(_) => self.write_line("continue;"),
+            Statement::Definition(input) => {
+                let type_ = match &input.type_ {
+                    Some(type_) => format!(": {}", self.type_to_string(type_)),
+                    None => String::new(),
+                };
+                let prefix =
+                    format!("{} {}{type_} = ", input.declaration_type, self.expression_to_string(&input.place),);
+                self.write_statement(&prefix, &input.value, ";");
+            }
+            Statement::Expression(input) => self.write_statement("", &input.expression, ";"),
+            Statement::Iteration(input) => self.format_iteration(input),
+            Statement::Match(input) => self.format_match(input),
+            Statement::Return(input) => self.format_return(input),
+            Statement::While(input) => self.format_while(input),
+        }
+
+        self.trailing_comment(span.hi);
+    }
+
+    /// Formats a constant declaration, either in a program scope or in a block.
+    pub(super) fn format_const(&mut self, input: &ConstDeclaration) {
+        self.leading_trivia(input.span.lo, input.span.lo);
+        let prefix = format!("const {}: {} = ", input.place, self.type_to_string(&input.type_));
+        self.write_statement(&prefix, &input.value, ";");
+        self.trailing_comment(input.span.hi);
+    }
+
+    /// Formats an assignment, restoring the compound operator of assignments like `x += 1u8`.
+    fn format_assign(&mut self, input: &AssignStatement) {
+        let place = self.expression_to_string(&input.place);
+        match &input.value {
+            // The parser desugars `x op= y` into `x = x op y`, where the binary expression spans the whole statement.
+            Expression::Binary(binary) if binary.span == input.span => match Self::compound_operator(binary.op) {
+                Some(operator) => self.write_statement(&format!("{place} {operator}= "), &binary.right, ";"),
+                None => self.write_statement(&format!("{place} = "), &input.value, ";"),
+            },
+            value => self.write_statement(&format!("{place} = "), value, ";"),
+        }
+    }
+
+    /// Returns the operator of the compound assignment that desugars into `op`, if it exists.
+    fn compound_operator(op: BinaryOperation) -> Option<&'static str> {
+        Some(match op {
+            BinaryOperation::Add => "+",
+            BinaryOperation::And => "&&",
+            BinaryOperation::BitwiseAnd => "&",
+            BinaryOperation::BitwiseOr => "|",
+            BinaryOperation::Div => "/",
+            BinaryOperation::Mul => "*",
+            BinaryOperation::Or => "||",
+            BinaryOperation::Pow => "**",
+            BinaryOperation::Rem => "%",
+            BinaryOperation::Shl => "<<",
+            BinaryOperation::Shr => ">>",
+            BinaryOperation::Sub => "-",
+            BinaryOperation::Xor => "^",
+            _ => return None,
+        })
+    }
+
+    /// Formats a conditional statement and its `else` branches.
+    fn format_conditional(&mut self, input: &ConditionalStatement, prefix: &str) {
+        let condition = self.condition_to_string(&input.condition);
+        self.write_line(&format!("{prefix}if {condition} {{"));
+        self.format_block_body(&input.then);
+
+        match input.otherwise.as_deref() {
+            None => self.write_line("}"),
+            Some(Statement::Conditional(otherwise)) => self.format_conditional(otherwise, "} else "),
+            Some(Statement::Block(otherwise)) => {
+                self.write_line("} else {");
+                self.format_block_body(otherwise);
+                self.write_line("}");
+            }
+            Some(otherwise) => {
+                self.write_line("} else {");
+                self.indent += 1;
+                self.format_statement(otherwise);
+                self.indent -= 1;
+                self.write_line("}");
+            }
+        }
+    }
+
+    /// Formats a `for` loop.
+    fn format_iteration(&mut self, input: &IterationStatement) {
+        let header = format!(
+            "for {}: {} in {}..{}{} ",
+            input.variable,
+            self.type_to_string(&input.type_),
+            self.expression_to_string(&input.start),
+            if input.inclusive { "=" } else { "" },
+            self.condition_to_string(&input.stop),
+        );
+        self.format_block(&header, &input.block);
+    }
+
+    /// Formats a bounded `while` loop.
+    fn format_while(&mut self, input: &WhileStatement) {
+        let header = format!(
+            "while {} bound {} ",
+            self.condition_to_string(&input.condition),
+            self.condition_to_string(&input.bound),
+        );
+        self.format_block(&header, &input.block);
+    }
+
+    /// Formats a `match` statement, with one arm per line.
+    fn format_match(&mut self, input: &MatchStatement) {
+        let expression = self.condition_to_string(&input.expression);
+        self.write_line(&format!("match {expression} {{"));
+        self.indent += 1;
+        for arm in input.arms.iter() {
+            self.leading_trivia(arm.span.lo, arm.span.lo);
+            self.format_block(&format!("{} => ", arm.pattern), &arm.block);
+        }
+        self.leading_comments(input.span.hi);
+        self.indent -= 1;
+        self.write_line("}");
+    }
+
+    /// Formats a return statement, including the arguments passed to the finalize block.
+    fn format_return(&mut self, input: &ReturnStatement) {
+        let suffix = match &input.finalize_arguments {
+            None => ";".to_string(),
+            Some(arguments) if arguments.is_empty() => " then finalize();".to_string(),
+            Some(arguments) => format!(" then finalize({});", self.expressions_to_string(arguments)),
+        };
+        match &input.expression {
+            Expression::Unit(_) => self.write_line(&format!("return{suffix}")),
+            expression => self.write_statement("return ", expression, &suffix),
+        }
+    }
+
+    /// Writes a statement of the form `<prefix><value><suffix>`.
+    /// Struct initializers that do not fit on a single line are written with one member per line,
+    /// and chains of binary operations with one operand per line.
+    fn write_statement(&mut self, prefix: &str, value: &Expression, suffix: &str) {
+        let line = format!("{prefix}{}{suffix}", self.expression_to_string(value));
+        if self.indent_width() + line.len() <= MAX_WIDTH {
+            self.leading_comments(value.span().hi);
+            return self.write_line(&line);
+        }
+
+        match value {
+            Expression::Struct(struct_) if !struct_.members.is_empty() => {
+                self.leading_comments(value.span().hi);
+                self.write_line(&format!("{prefix}{} {{", struct_.name));
+                self.indent += 1;
+                for member in struct_.members.iter() {
+                    let member = self.struct_member_to_string(member);
+                    self.write_line(&format!("{member},"));
+                }
+                self.indent -= 1;
+                self.write_line(&format!("}}{suffix}"));
+            }
+            _ => match self.binary_chain(value) {
+                // Keep the comments that surround each operand next to it.
+                Some((operator, operands)) => {
+                    let mut operands = operands.into_iter();
+                    let (first, span) = operands.next().unwrap();
+                    self.leading_comments(span.lo);
+                    self.write_line(&format!("{prefix}{first}"));
+                    self.trailing_comment(span.hi);
+
+                    self.indent += 1;
+                    let last = operands.len();
+                    for (i, (operand, span)) in operands.enumerate() {
+                        self.leading_comments(span.lo);
+                        let suffix = if i + 1 == last { suffix } else { "" };
+                        self.write_line(&format!("{operator} {operand}{suffix}"));
+                        self.trailing_comment(span.hi);
+                    }
+                    self.indent -= 1;
+                }
+                None => {
+                    self.leading_comments(value.span().hi);
+                    self.write_line(&line);
+                }
+            },
+        }
+    }
+}
@@ -31,9 +31,14 @@ pub(crate) use tokenizer::*;
 pub mod parser;
 pub use parser::*;
 
+pub mod formatter;
+pub use formatter::*;
+
 use leo_ast::{input::InputData, Ast, NodeBuilder, ProgramInput};
 use leo_errors::{emitter::Handler, Result};
 
+use std::path::Path;
+
 #[cfg(test)]
 mod test;
 
@@ -42,6 +47,17 @@ pub fn parse_ast(handler: &Handler, node_builder: &NodeBuilder, source: &str, st
     Ok(Ast::new(parser::parse(handler, node_builder, source, start_pos)?))
 }
 
+/// Creates a new AST from source code text, whose imports are found in the `imports` directory of `package_directory`.
+pub fn parse_ast_in_package(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    source: &str,
+    start_pos: BytePos,
+    package_directory: &Path,
+) -> Result<Ast> {
+    Ok(Ast::new(parser::parse_in_package(handler, node_builder, source, start_pos, Some(package_directory))?))
+}
+
 /// Parses program inputs from the input file path
 pub fn parse_program_inputs(
     handler: &Handler,
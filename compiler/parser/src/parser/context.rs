@@ -18,9 +18,9 @@ use crate::{tokenizer::*, Token};
 
 use leo_ast::*;
 use leo_errors::{emitter::Handler, ParserError, ParserWarning, Result};
-use leo_span::{Span, Symbol};
+use leo_span::{span::BytePos, Span, Symbol};
 
-use std::{fmt::Display, mem};
+use std::{fmt::Display, mem, path::PathBuf};
 
 /// Stores a program in tokenized format plus additional context.
 /// May be converted into a [`Program`] AST by parsing all tokens.
@@ -41,6 +41,12 @@ pub(crate) struct ParserContext<'a> {
     pub(crate) disallow_struct_construction: bool,
     /// true if parsing an identifier inside an input file.
     pub(crate) allow_identifier_underscores: bool,
+    /// The struct whose `impl` block is being parsed, if any. Its methods take the struct as the input `self`.
+    pub(crate) impl_target: Option<Identifier>,
+    /// The number of `{` that have been bumped and not yet closed by a `}`.
+    pub(crate) brace_depth: usize,
+    /// The package whose `imports` directory the imports are found in, or `None` for the current directory.
+    pub(crate) package_directory: Option<PathBuf>,
 }
 
 /// Dummy span used to appease borrow checker.
@@ -60,6 +66,9 @@ impl<'a> ParserContext<'a> {
             node_builder,
             disallow_struct_construction: false,
             allow_identifier_underscores: false,
+            impl_target: None,
+            brace_depth: 0,
+            package_directory: None,
             prev_token: token.clone(),
             token,
             tokens,
@@ -81,6 +90,13 @@ impl<'a> ParserContext<'a> {
         // Extract next token, or `Eof` if there was none.
         let next_token = self.tokens.pop().unwrap_or(SpannedToken { token: Token::Eof, span: self.token.span });
 
+        // Keep track of the braces that are open.
+        match self.token.token {
+            Token::LeftCurly => self.brace_depth += 1,
+            Token::RightCurly => self.brace_depth = self.brace_depth.saturating_sub(1),
+            _ => {}
+        }
+
         // Set the new token.
         self.prev_token = mem::replace(&mut self.token, next_token);
     }
@@ -131,6 +147,89 @@ impl<'a> ParserContext<'a> {
         !matches!(self.token.token, Token::Eof)
     }
 
+    /// Returns true if the current token starts an item, e.g. `function` or `struct`, and can never start a statement.
+    /// `const` is not included, as it also starts a statement.
+    pub(super) fn check_item_keyword(&self) -> bool {
+        matches!(
+            self.token.token,
+            Token::Import
+                | Token::Use
+                | Token::Program
+                | Token::Struct
+                | Token::Record
+                | Token::Enum
+                | Token::Impl
+                | Token::Mapping
+                | Token::At
+                | Token::Function
+                | Token::Transition
+                | Token::Inline
+        )
+    }
+
+    /// Skips the tokens of an item that failed to parse, so that parsing can resume at the next item.
+    /// `depth` is the number of open braces at the start of the item.
+    /// Stops after the `}` that closes the item, before the next item keyword,
+    /// before the `}` that closes the enclosing scope, or at the end of the file.
+    pub(super) fn recover_item(&mut self, depth: usize) {
+        while self.has_next() && !self.check_item_keyword() {
+            if self.brace_depth <= depth {
+                match self.token.token {
+                    Token::RightCurly => break,
+                    // A `const` item follows the end of another item, or the start of the scope.
+                    Token::Const
+                        if matches!(self.prev_token.token, Token::Semicolon | Token::LeftCurly | Token::RightCurly) =>
+                    {
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+            self.bump();
+            if self.brace_depth == depth && self.prev_token.token == Token::RightCurly {
+                break;
+            }
+        }
+    }
+
+    /// Skips the tokens of a statement that failed to parse, so that parsing can resume at the next statement.
+    /// Stops after the `;` that ends the statement, or before the `}` that closes the enclosing block,
+    /// the next item keyword, or the end of the file.
+    pub(super) fn recover_statement(&mut self) {
+        let mut depth = 0usize;
+        while self.has_next() && !self.check_item_keyword() {
+            match self.token.token {
+                Token::Semicolon if depth == 0 => {
+                    self.bump();
+                    break;
+                }
+                Token::LeftCurly => depth += 1,
+                Token::RightCurly if depth == 0 => break,
+                Token::RightCurly => depth -= 1,
+                _ => {}
+            }
+            self.bump();
+        }
+    }
+
+    /// Parses an item with `parse`.
+    /// If it fails, the error is emitted and the rest of the item is skipped, see [`Self::recover_item`].
+    pub(super) fn parse_item<T>(&mut self, parse: impl FnOnce(&mut Self) -> Result<T>) -> Option<T> {
+        let (tokens_left, depth) = (self.tokens.len(), self.brace_depth);
+        match parse(self) {
+            Ok(item) => Some(item),
+            Err(error) => {
+                self.handler.emit_err(error);
+                // Skip at least one token, so that the item is not parsed again.
+                if self.tokens.len() == tokens_left && self.has_next() {
+                    self.bump();
+                }
+                self.recover_item(depth);
+                None
+            }
+        }
+    }
+
     /// At the previous token, return and make an identifier with `name`.
     fn mk_ident_prev(&self, name: Symbol) -> Identifier {
         let span = self.prev_token.span;
@@ -183,7 +282,11 @@ impl<'a> ParserContext<'a> {
 
     /// Eats the expected `token`, or errors.
     pub(super) fn expect(&mut self, token: &Token) -> Result<Span> {
-        if self.eat(token) { Ok(self.prev_token.span) } else { self.unexpected(token) }
+        if self.eat(token) {
+            Ok(self.prev_token.span)
+        } else {
+            self.unexpected(token)
+        }
     }
 
     /// Eats one of the expected `tokens`, or errors.
@@ -248,6 +351,29 @@ impl<'a> ParserContext<'a> {
         self.parse_list(Delimiter::Bracket, Some(Token::Comma), f)
     }
 
+    /// Parse a list separated by `,` and delimited by angle brackets, e.g. `<T, U>`.
+    /// A `>>` closing two nested lists, as in `Pair<Pair<u32>>`, is split into two `>`s.
+    pub(super) fn parse_angle_comma_list<T>(
+        &mut self,
+        mut f: impl FnMut(&mut Self) -> Result<Option<T>>,
+    ) -> Result<(Vec<T>, bool, Span)> {
+        self.parse_list(Delimiter::AngleBracket, Some(Token::Comma), |p| {
+            let element = f(p)?;
+            p.split_shr();
+            Ok(element)
+        })
+    }
+
+    /// Splits a current `>>` token into two `>` tokens.
+    fn split_shr(&mut self) {
+        if self.check(&Token::Shr) {
+            let Span { lo, hi } = self.token.span;
+            let middle = BytePos(lo.0 + 1);
+            self.tokens.push(SpannedToken { token: Token::Gt, span: Span::new(middle, hi) });
+            self.token = SpannedToken { token: Token::Gt, span: Span::new(lo, middle) };
+        }
+    }
+
     /// Returns true if the current token is `(`.
     pub(super) fn peek_is_left_par(&self) -> bool {
         matches!(self.token.token, Token::LeftParen)
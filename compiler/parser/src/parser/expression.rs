@@ -276,6 +276,12 @@ impl ParserContext<'_> {
                     inner =
                         Expression::Literal(Literal::Integer(integer_type, format!("-{string}"), op_span + span, id));
                 }
+                Expression::Literal(Literal::Unsuffixed(string, span, id)) => {
+                    // Remove the negation from the operations.
+                    let (_, op_span) = ops.pop().unwrap();
+                    // Construct a negative unsuffixed literal.
+                    inner = Expression::Literal(Literal::Unsuffixed(format!("-{string}"), op_span + span, id));
+                }
                 Expression::Literal(Literal::Field(string, span, id)) => {
                     // Remove the negation from the operations.
                     // Note that
@@ -318,9 +324,19 @@ impl ParserContext<'_> {
         Ok(inner)
     }
 
+    /// Returns `true` if a call to the method `name` is parsed as a built-in operation.
+    pub(super) fn is_builtin_method(name: Symbol) -> bool {
+        UnaryOperation::from_symbol(name).is_some()
+            || BinaryOperation::from_symbol(name).is_some()
+            || CoreFunction::from_symbols(sym::Mapping, name).is_some()
+            || CoreFunction::from_symbols(sym::signature, name).is_some()
+    }
+
     // TODO: Parse method call expressions directly and later put them into a canonical form.
     /// Returns an [`Expression`] AST node if the next tokens represent a
     /// method call expression.
+    /// Calls to built-in operations are parsed into the corresponding expressions,
+    /// while any other call is a call to a method of a struct.
     fn parse_method_call_expression(&mut self, receiver: Expression, method: Identifier) -> Result<Expression> {
         // Parse the argument list.
         let (mut args, _, span) = self.parse_expr_tuple()?;
@@ -378,11 +394,19 @@ impl ParserContext<'_> {
                         id: self.node_builder.next_id(),
                     })))
                 }
-                _ => {
+                _ if Self::is_builtin_method(method.name) => {
                     // Either an invalid unary/binary operator, or more arguments given.
                     self.emit_err(ParserError::invalid_method_call(receiver, method, args.len(), span));
                     Ok(Expression::Err(ErrExpression { span, id: self.node_builder.next_id() }))
                 }
+                // Otherwise, the method is declared in the `impl` block of a struct, which is resolved during type checking.
+                _ => Ok(Expression::Access(AccessExpression::MethodCall(MethodCall {
+                    receiver: Box::new(receiver),
+                    name: method,
+                    arguments: args,
+                    span,
+                    id: self.node_builder.next_id(),
+                }))),
             }
         }
     }
@@ -702,7 +726,8 @@ impl ParserContext<'_> {
                         let int_ty = Self::token_to_int_type(suffix).expect("unknown int type token");
                         Expression::Literal(Literal::Integer(int_ty, value, full_span, self.node_builder.next_id()))
                     }
-                    None => return Err(ParserError::implicit_values_not_allowed(value, span).into()),
+                    // Literal without a suffix, e.g., `42`, whose type is inferred.
+                    None => Expression::Literal(Literal::Unsuffixed(value, span, self.node_builder.next_id())),
                 }
             }
             Token::True => Expression::Literal(Literal::Boolean(true, span, self.node_builder.next_id())),
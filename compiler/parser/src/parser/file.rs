@@ -15,7 +15,6 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use crate::parse_ast;
 use leo_errors::{CompilerError, ParserError, Result};
 use leo_span::{source_map::FileName, symbol::with_session_globals};
 
@@ -25,6 +24,7 @@ impl ParserContext<'_> {
     /// Returns a [`Program`] AST if all tokens can be consumed and represent a valid Leo program.
     pub fn parse_program(&mut self) -> Result<Program> {
         let mut imports = IndexMap::new();
+        let mut uses = Vec::new();
         let mut program_scopes = IndexMap::new();
 
         // TODO: Remove restrictions on multiple program scopes
@@ -33,21 +33,24 @@ impl ParserContext<'_> {
         while self.has_next() {
             match &self.token.token {
                 Token::Import => {
-                    let (id, import) = self.parse_import()?;
-                    imports.insert(id, import);
+                    if let Some((id, import)) = self.parse_item(|p| p.parse_import()) {
+                        imports.insert(id, import);
+                    }
                 }
+                Token::Use => uses.extend(self.parse_item(|p| p.parse_use())),
                 Token::Program => {
                     match parsed_program_scope {
                         // Only one program scope is allowed per file.
                         true => return Err(ParserError::only_one_program_scope_is_allowed(self.token.span).into()),
                         false => {
                             parsed_program_scope = true;
-                            let program_scope = self.parse_program_scope()?;
-                            program_scopes.insert(program_scope.program_id.name.name, program_scope);
+                            if let Some(program_scope) = self.parse_item(|p| p.parse_program_scope()) {
+                                program_scopes.insert(program_scope.program_id.name.name, program_scope);
+                            }
                         }
                     }
                 }
-                _ => return Err(Self::unexpected_item(&self.token, &[Token::Import, Token::Program]).into()),
+                _ => self.recover_unexpected_item(&[Token::Import, Token::Use, Token::Program]),
             }
         }
 
@@ -56,7 +59,64 @@ impl ParserContext<'_> {
             return Err(ParserError::missing_program_scope(self.token.span).into());
         }
 
-        Ok(Program { imports, program_scopes })
+        Ok(Program { imports, uses, program_scopes })
+    }
+
+    /// Returns a [`Module`] AST if all tokens can be consumed and represent a valid Leo module.
+    pub fn parse_module(&mut self) -> Result<Module> {
+        let mut module = Module::default();
+
+        while self.has_next() {
+            match &self.token.token {
+                Token::Use => module.uses.extend(self.parse_item(|p| p.parse_use())),
+                Token::Const => {
+                    if let Some(declaration) = self.parse_item(|p| p.parse_const_declaration_statement()) {
+                        module.consts.push((Symbol::intern(&declaration.place.to_string()), declaration));
+                    }
+                }
+                Token::Struct | Token::Record => module.structs.extend(self.parse_item(|p| p.parse_struct())),
+                Token::Enum => module.enums.extend(self.parse_item(|p| p.parse_enum())),
+                Token::Impl => module.impls.extend(self.parse_item(|p| p.parse_impl())),
+                Token::At | Token::Function | Token::Inline | Token::Transition => {
+                    if let Some((id, function)) = self.parse_item(|p| p.parse_function()) {
+                        // Transitions are the interface of the program, so they must be declared in its program scope.
+                        match function.variant {
+                            Variant::Transition => self.emit_err(ParserError::transition_in_module(function.span)),
+                            _ => module.functions.push((id, function)),
+                        }
+                    }
+                }
+                _ => self.recover_unexpected_item(&[
+                    Token::Use,
+                    Token::Const,
+                    Token::Struct,
+                    Token::Record,
+                    Token::Enum,
+                    Token::Impl,
+                    Token::At,
+                    Token::Function,
+                    Token::Inline,
+                ]),
+            }
+        }
+
+        Ok(module)
+    }
+
+    /// Parses a module declaration `use foo::bar;`.
+    pub(super) fn parse_use(&mut self) -> Result<UseDeclaration> {
+        // Parse `use`.
+        let start = self.expect(&Token::Use)?;
+
+        // Parse the path `foo::bar`.
+        let mut path = vec![self.expect_identifier()?];
+        while self.eat(&Token::DoubleColon) {
+            path.push(self.expect_identifier()?);
+        }
+
+        let end = self.expect(&Token::Semicolon)?;
+
+        Ok(UseDeclaration { path, span: start + end, id: self.node_builder.next_id() })
     }
 
     fn unexpected_item(token: &SpannedToken, expected: &[Token]) -> ParserError {
@@ -67,6 +127,14 @@ impl ParserContext<'_> {
         )
     }
 
+    /// Emits an error for the current token, which does not start any of the `expected` items, and skips to the next item.
+    fn recover_unexpected_item(&mut self, expected: &[Token]) {
+        self.emit_err(Self::unexpected_item(&self.token, expected));
+        let depth = self.brace_depth;
+        self.bump();
+        self.recover_item(depth);
+    }
+
     // TODO: remove import resolution from parser.
     /// Parses an import statement `import foo.leo;`.
     pub(super) fn parse_import(&mut self) -> Result<(Symbol, (Program, Span))> {
@@ -87,8 +155,10 @@ impl ParserContext<'_> {
 
         // Tokenize and parse import file.
         // Todo: move this to a different module.
-        let mut import_file_path =
-            std::env::current_dir().map_err(|err| CompilerError::cannot_open_cwd(err, self.token.span))?;
+        let mut import_file_path = match &self.package_directory {
+            Some(package_directory) => package_directory.clone(),
+            None => std::env::current_dir().map_err(|err| CompilerError::cannot_open_cwd(err, self.token.span))?,
+        };
         import_file_path.push("imports");
         import_file_path.push(format!("{}.leo", import_name.name));
 
@@ -109,9 +179,16 @@ impl ParserContext<'_> {
         let prg_sf = with_session_globals(|s| s.source_map.new_source(&program_string, name));
 
         // Use the parser to construct the imported abstract syntax tree (ast).
-        let program_ast = parse_ast(self.handler, self.node_builder, &prg_sf.src, prg_sf.start_pos)?;
-
-        Ok((import_name.name, (program_ast.into_repr(), start + end)))
+        // Its own imports are found in the same package.
+        let program = parse_in_package(
+            self.handler,
+            self.node_builder,
+            &prg_sf.src,
+            prg_sf.start_pos,
+            self.package_directory.as_deref(),
+        )?;
+
+        Ok((import_name.name, (program, start + end)))
     }
 
     /// Parsers a program scope `program foo.aleo { ... }`.
@@ -141,46 +218,49 @@ impl ParserContext<'_> {
         let mut consts: Vec<(Symbol, ConstDeclaration)> = Vec::new();
         let mut functions: Vec<(Symbol, Function)> = Vec::new();
         let mut structs: Vec<(Symbol, Struct)> = Vec::new();
+        let mut enums: Vec<(Symbol, Enum)> = Vec::new();
+        let mut impls: Vec<(Symbol, Impl)> = Vec::new();
         let mut mappings: Vec<(Symbol, Mapping)> = Vec::new();
 
         while self.has_next() {
             match &self.token.token {
                 Token::Const => {
-                    let declaration = self.parse_const_declaration_statement()?;
-                    consts.push((Symbol::intern(&declaration.place.to_string()), declaration));
-                }
-                Token::Struct | Token::Record => {
-                    let (id, struct_) = self.parse_struct()?;
-                    structs.push((id, struct_));
-                }
-                Token::Mapping => {
-                    let (id, mapping) = self.parse_mapping()?;
-                    mappings.push((id, mapping));
+                    if let Some(declaration) = self.parse_item(|p| p.parse_const_declaration_statement()) {
+                        consts.push((Symbol::intern(&declaration.place.to_string()), declaration));
+                    }
                 }
+                Token::Struct | Token::Record => structs.extend(self.parse_item(|p| p.parse_struct())),
+                Token::Enum => enums.extend(self.parse_item(|p| p.parse_enum())),
+                Token::Impl => impls.extend(self.parse_item(|p| p.parse_impl())),
+                Token::Mapping => mappings.extend(self.parse_item(|p| p.parse_mapping())),
                 Token::At | Token::Function | Token::Transition | Token::Inline => {
-                    let (id, function) = self.parse_function()?;
-                    functions.push((id, function));
+                    functions.extend(self.parse_item(|p| p.parse_function()))
                 }
                 Token::RightCurly => break,
-                _ => {
-                    return Err(Self::unexpected_item(&self.token, &[
-                        Token::Struct,
-                        Token::Record,
-                        Token::Mapping,
-                        Token::At,
-                        Token::Function,
-                        Token::Transition,
-                        Token::Inline,
-                    ])
-                    .into());
-                }
+                _ => self.recover_unexpected_item(&[
+                    Token::Struct,
+                    Token::Record,
+                    Token::Enum,
+                    Token::Impl,
+                    Token::Mapping,
+                    Token::At,
+                    Token::Function,
+                    Token::Transition,
+                    Token::Inline,
+                ]),
             }
         }
 
-        // Parse `}`.
-        let end = self.expect(&Token::RightCurly)?;
+        // Parse `}`. If it is missing, the items of the program scope are still kept.
+        let end = match self.expect(&Token::RightCurly) {
+            Ok(end) => end,
+            Err(error) => {
+                self.handler.emit_err(error);
+                self.prev_token.span
+            }
+        };
 
-        Ok(ProgramScope { program_id, consts, functions, structs, mappings, span: start + end })
+        Ok(ProgramScope { program_id, consts, functions, structs, enums, impls, mappings, span: start + end })
     }
 
     /// Returns a [`Vec<Member>`] AST node if the next tokens represent a struct member.
@@ -236,12 +316,14 @@ impl ParserContext<'_> {
         let is_record = matches!(&self.token.token, Token::Record);
         let start = self.expect_any(&[Token::Struct, Token::Record])?;
         let struct_name = self.expect_identifier()?;
+        let generic_parameters = self.parse_generic_parameters()?;
 
         self.expect(&Token::LeftCurly)?;
         let (members, end) = self.parse_struct_members()?;
 
         Ok((struct_name.name, Struct {
             identifier: struct_name,
+            generic_parameters,
             members,
             is_record,
             span: start + end,
@@ -249,6 +331,138 @@ impl ParserContext<'_> {
         }))
     }
 
+    /// Parses the generic parameters of a function or struct, e.g. `<T, U: Int, const N: u32>`, if there are any.
+    fn parse_generic_parameters(&mut self) -> Result<Vec<GenericParameter>> {
+        if !self.check(&Token::Lt) {
+            return Ok(Vec::new());
+        }
+        let (parameters, ..) = self.parse_angle_comma_list(|p| p.parse_generic_parameter().map(Some))?;
+        Ok(parameters)
+    }
+
+    /// Parses a type parameter, e.g. `T` or `T: Int`, or a const parameter, e.g. `const N: u32`.
+    fn parse_generic_parameter(&mut self) -> Result<GenericParameter> {
+        if self.check(&Token::Const) {
+            let start = self.expect(&Token::Const)?;
+            let identifier = self.expect_identifier()?;
+            self.expect(&Token::Colon)?;
+            let (type_, type_span) = self.parse_type()?;
+            return Ok(GenericParameter {
+                identifier,
+                bound: None,
+                const_type: Some(type_),
+                span: start + type_span,
+                id: self.node_builder.next_id(),
+            });
+        }
+
+        let identifier = self.expect_identifier()?;
+        let (bound, span) = match self.eat(&Token::Colon) {
+            false => (None, identifier.span),
+            true => {
+                let bound = self.expect_identifier()?;
+                match GenericBound::from_symbol(bound.name) {
+                    Some(generic_bound) => (Some(generic_bound), identifier.span + bound.span),
+                    None => return Err(ParserError::unknown_generic_bound(bound.name, bound.span).into()),
+                }
+            }
+        };
+        Ok(GenericParameter { identifier, bound, const_type: None, span, id: self.node_builder.next_id() })
+    }
+
+    /// Parses an enum definition, e.g., `enum Shape { Empty, Circle(u32), Rectangle(u32, u32) }`.
+    pub(super) fn parse_enum(&mut self) -> Result<(Symbol, Enum)> {
+        let start = self.expect(&Token::Enum)?;
+        let enum_name = self.expect_identifier()?;
+
+        self.expect(&Token::LeftCurly)?;
+        let mut variants = Vec::new();
+        while !self.check(&Token::RightCurly) {
+            variants.push(self.parse_variant()?);
+            if !self.eat(&Token::Comma) {
+                break;
+            }
+        }
+        let end = self.expect(&Token::RightCurly)?;
+
+        Ok((enum_name.name, Enum {
+            identifier: enum_name,
+            variants,
+            span: start + end,
+            id: self.node_builder.next_id(),
+        }))
+    }
+
+    /// Returns an [`EnumVariant`] AST node if the next tokens represent an enum variant, e.g. `Circle(u32)`.
+    fn parse_variant(&mut self) -> Result<EnumVariant> {
+        let identifier = self.expect_identifier()?;
+
+        let (payload, span) = match self.check(&Token::LeftParen) {
+            true => {
+                let (payload, _, span) =
+                    self.parse_paren_comma_list(|p| p.parse_type().map(|(type_, _)| Some(type_)))?;
+                (payload, identifier.span + span)
+            }
+            false => (Vec::new(), identifier.span),
+        };
+
+        Ok(EnumVariant { identifier, payload, span, id: self.node_builder.next_id() })
+    }
+
+    /// Parses an `impl` block, e.g. `impl Token { const MAX: u64 = 100u64; inline total(self) -> u64 { ... } }`.
+    pub(super) fn parse_impl(&mut self) -> Result<(Symbol, Impl)> {
+        let start = self.expect(&Token::Impl)?;
+        let target = self.expect_identifier()?;
+        self.expect(&Token::LeftCurly)?;
+
+        let mut consts = Vec::new();
+        let mut functions = Vec::new();
+        self.impl_target = Some(target);
+        while !self.check(&Token::RightCurly) {
+            match &self.token.token {
+                Token::Const => {
+                    let declaration = self.parse_const_declaration_statement()?;
+                    consts.push((declaration.place.name, declaration));
+                }
+                Token::At | Token::Function | Token::Inline | Token::Transition => {
+                    let (id, function) = self.parse_function()?;
+                    self.check_method(&function);
+                    functions.push((id, function));
+                }
+                _ => {
+                    return Err(Self::unexpected_item(&self.token, &[
+                        Token::Const,
+                        Token::At,
+                        Token::Function,
+                        Token::Inline,
+                        Token::RightCurly,
+                    ])
+                    .into());
+                }
+            }
+        }
+        self.impl_target = None;
+        let end = self.expect(&Token::RightCurly)?;
+
+        Ok((target.name, Impl { target, consts, functions, span: start + end, id: self.node_builder.next_id() }))
+    }
+
+    /// Emits an error if the function of an `impl` block cannot be declared there, or cannot be called as a method.
+    fn check_method(&self, function: &Function) {
+        // Transitions are the interface of the program, so they cannot be methods.
+        if matches!(function.variant, Variant::Transition) {
+            self.emit_err(ParserError::transition_in_impl(function.span));
+        }
+        if let Some(input) = function.input.iter().skip(1).find(|input| input.identifier().name == sym::SelfLower) {
+            self.emit_err(ParserError::receiver_must_be_first_input(input.span()));
+        }
+        // A method call is parsed as a built-in operation if the method has the name of one.
+        let name = function.name();
+        if function.is_method() && Self::is_builtin_method(name) {
+            self.emit_err(ParserError::method_name_is_reserved(name, function.identifier.span));
+        }
+    }
+
     /// Parses a mapping declaration, e.g. `mapping balances: address => u128`.
     pub(super) fn parse_mapping(&mut self) -> Result<(Symbol, Mapping)> {
         let start = self.expect(&Token::Mapping)?;
@@ -294,6 +508,18 @@ impl ParserContext<'_> {
 
     /// Returns a [`Input`] AST node if the next tokens represent a function output.
     fn parse_input(&mut self) -> Result<functions::Input> {
+        // Parse the receiver `self` of a method, whose type is the struct of the `impl` block.
+        if let (Token::SelfLower, Some(target)) = (&self.token.token, self.impl_target) {
+            let span = self.expect(&Token::SelfLower)?;
+            return Ok(functions::Input::Internal(FunctionInput {
+                identifier: Identifier { name: sym::SelfLower, span, id: self.node_builder.next_id() },
+                mode: Mode::None,
+                type_: Type::Identifier(Identifier { name: target.name, span, id: self.node_builder.next_id() }),
+                span,
+                id: self.node_builder.next_id(),
+            }));
+        }
+
         let mode = self.parse_mode()?;
         let name = self.expect_identifier()?;
         self.expect(&Token::Colon)?;
@@ -416,6 +642,9 @@ impl ParserContext<'_> {
         };
         let name = self.expect_identifier()?;
 
+        // Parse type parameters.
+        let generic_parameters = self.parse_generic_parameters()?;
+
         // Parse parameters.
         let (inputs, ..) = self.parse_paren_comma_list(|p| p.parse_input().map(Some))?;
 
@@ -478,6 +707,7 @@ impl ParserContext<'_> {
                 annotations,
                 variant,
                 name,
+                generic_parameters,
                 inputs,
                 output,
                 block,
@@ -22,12 +22,16 @@
 use crate::{tokenizer::*, Token};
 
 use leo_ast::*;
-use leo_errors::{emitter::Handler, Result};
-use leo_span::Span;
+use leo_errors::{emitter::Handler, CompilerError, Result};
+use leo_span::{symbol::with_session_globals, Span};
 
 use indexmap::IndexMap;
 use leo_span::span::BytePos;
-use std::unreachable;
+use std::{
+    collections::{HashSet, VecDeque},
+    path::{Path, PathBuf},
+    unreachable,
+};
 
 mod context;
 pub(super) use context::ParserContext;
@@ -40,11 +44,113 @@ pub(super) mod type_;
 
 /// Creates a new program from a given file path and source code text.
 pub fn parse(handler: &Handler, node_builder: &NodeBuilder, source: &str, start_pos: BytePos) -> Result<Program> {
+    parse_in_package(handler, node_builder, source, start_pos, None)
+}
+
+/// Creates a new program from source code text, whose imports are found in the `imports` directory of the package.
+/// The imports are found in the current directory if `package_directory` is `None`.
+pub fn parse_in_package(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    source: &str,
+    start_pos: BytePos,
+    package_directory: Option<&Path>,
+) -> Result<Program> {
     let mut tokens = ParserContext::new(handler, node_builder, crate::tokenize(source, start_pos)?);
+    tokens.package_directory = package_directory.map(Path::to_path_buf);
 
     tokens.parse_program()
 }
 
+/// Creates a new module from a given file path and source code text.
+pub fn parse_module(handler: &Handler, node_builder: &NodeBuilder, source: &str, start_pos: BytePos) -> Result<Module> {
+    let mut tokens = ParserContext::new(handler, node_builder, crate::tokenize(source, start_pos)?);
+
+    tokens.parse_module()
+}
+
+/// Parses the modules `use`d by the `program`, directly or through other modules,
+/// and declares their items in its program scope.
+/// A module `foo::bar` is the file `foo/bar.leo` in the `source_directory`.
+/// Returns the paths of the files of the modules.
+pub fn resolve_modules(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    program: &mut Program,
+    source_directory: &Path,
+) -> Result<Vec<PathBuf>> {
+    let mut queue: VecDeque<UseDeclaration> = program.uses.iter().cloned().collect();
+    let mut parsed = HashSet::new();
+    let mut modules = Vec::new();
+    let mut module_paths = Vec::new();
+
+    while let Some(declaration) = queue.pop_front() {
+        // Each module is declared once, no matter how many times it is used.
+        if !parsed.insert(declaration.symbols()) {
+            continue;
+        }
+
+        let segments = declaration.path.iter().map(|segment| segment.to_string()).collect::<Vec<_>>();
+        let relative_path = format!("{}.leo", segments.join("/"));
+        let module_path = source_directory.join(&relative_path);
+        if !module_path.is_file() {
+            return Err(CompilerError::module_not_found(segments.join("::"), relative_path, declaration.span).into());
+        }
+
+        // Register the module in the source map, and parse it.
+        let module_sf = with_session_globals(|s| s.source_map.load_file(&module_path))
+            .map_err(|e| CompilerError::file_read_error(&module_path, e))?;
+        let module = parse_module(handler, node_builder, &module_sf.src, module_sf.start_pos)?;
+
+        queue.extend(module.uses.iter().cloned());
+        modules.push(module);
+        module_paths.push(module_path);
+    }
+
+    // Note that parsing enforces that there is at most one program scope in a file.
+    if let Some(program_scope) = program.program_scopes.values_mut().next() {
+        // The items of the modules are declared before the items of the program scope.
+        for module in modules.into_iter().rev() {
+            program_scope.consts.splice(0..0, module.consts);
+            program_scope.structs.splice(0..0, module.structs);
+            program_scope.enums.splice(0..0, module.enums);
+            program_scope.impls.splice(0..0, module.impls);
+            program_scope.functions.splice(0..0, module.functions);
+        }
+    }
+
+    Ok(module_paths)
+}
+
+/// Declares the items of the `impl` blocks of the `program` as consts and functions of its program scope,
+/// named by [`Impl::item_name`]. This must run once the modules have been resolved.
+///
+/// The consts are declared after the consts of the program scope, so that they can use them,
+/// and the functions are declared before the functions of the program scope.
+pub fn declare_impls(program: &mut Program) -> Result<()> {
+    for program_scope in program.program_scopes.values_mut() {
+        let mut consts = Vec::new();
+        let mut functions = Vec::new();
+        for (target, mut impl_) in std::mem::take(&mut program_scope.impls) {
+            match program_scope.structs.iter().find(|(name, _)| *name == target) {
+                None => return Err(CompilerError::impl_target_not_found(target, impl_.target.span).into()),
+                Some((_, struct_)) if struct_.is_generic() => {
+                    return Err(CompilerError::impl_of_generic_struct(target, impl_.target.span).into());
+                }
+                Some(_) => {
+                    impl_.rename_items();
+                    consts.extend(impl_.consts);
+                    functions.extend(impl_.functions);
+                }
+            }
+        }
+        program_scope.consts.extend(consts);
+        program_scope.functions.splice(0..0, functions);
+    }
+
+    Ok(())
+}
+
 /// Parses an input file at the given file `path` and `source` code text.
 pub fn parse_input(
     handler: &Handler,
@@ -43,6 +43,10 @@ impl ParserContext<'_> {
             Token::Return => Ok(Statement::Return(self.parse_return_statement()?)),
             Token::If => Ok(Statement::Conditional(self.parse_conditional_statement()?)),
             Token::For => Ok(Statement::Iteration(Box::new(self.parse_loop_statement()?))),
+            Token::While => Ok(Statement::While(Box::new(self.parse_while_statement()?))),
+            Token::Break => Ok(Statement::Break(self.parse_break_statement()?)),
+            Token::Continue => Ok(Statement::Continue(self.parse_continue_statement()?)),
+            Token::Match => Ok(Statement::Match(self.parse_match_statement()?)),
             Token::Assert | Token::AssertEq | Token::AssertNeq => Ok(self.parse_assert_statement()?),
             Token::Let => Ok(Statement::Definition(self.parse_definition_statement()?)),
             Token::Const => Ok(Statement::Const(self.parse_const_declaration_statement()?)),
@@ -116,9 +120,8 @@ impl ParserContext<'_> {
             // Construct the span for the statement.
             let span = place.span() + value.span();
 
-            // Construct a copy of the lhs with a unique id.
-            let mut left = place.clone();
-            left.set_id(self.node_builder.next_id());
+            // Construct a copy of the lhs with unique ids.
+            let left = self.duplicate_place(&place);
 
             // Simplify complex assignments into simple assignments.
             // For example, `x += 1` becomes `x = x + 1`, while simple assignments like `x = y` remain unchanged.
@@ -167,14 +170,33 @@ impl ParserContext<'_> {
     }
 
     /// Returns a [`Block`] AST node if the next tokens represent a block of statements.
+    /// A statement that fails to parse is emitted as an error and left out of the block.
     pub(super) fn parse_block(&mut self) -> Result<Block> {
-        self.parse_list(Delimiter::Brace, None, |p| p.parse_statement().map(Some)).map(|(statements, _, span)| Block {
+        self.parse_list(Delimiter::Brace, None, |p| p.parse_block_statement()).map(|(statements, _, span)| Block {
             statements,
             span,
             id: self.node_builder.next_id(),
         })
     }
 
+    /// Returns a [`Statement`] AST node if the next tokens represent a statement of a block.
+    /// If they do not, the error is emitted and the statement is skipped, so that the rest of the block is still parsed.
+    fn parse_block_statement(&mut self) -> Result<Option<Statement>> {
+        // Items cannot be nested in a block, so the block is missing its `}`.
+        if !self.has_next() || self.check_item_keyword() {
+            return self.unexpected(Token::RightCurly);
+        }
+
+        match self.parse_statement() {
+            Ok(statement) => Ok(Some(statement)),
+            Err(error) => {
+                self.handler.emit_err(error);
+                self.recover_statement();
+                Ok(None)
+            }
+        }
+    }
+
     /// Returns a [`ReturnStatement`] AST node if the next tokens represent a return statement.
     fn parse_return_statement(&mut self) -> Result<ReturnStatement> {
         let start = self.expect(&Token::Return)?;
@@ -234,6 +256,53 @@ impl ParserContext<'_> {
         })
     }
 
+    /// Returns a [`MatchStatement`] AST node if the next tokens represent a match statement.
+    fn parse_match_statement(&mut self) -> Result<MatchStatement> {
+        let start = self.expect(&Token::Match)?;
+        self.disallow_struct_construction = true;
+        let expression = self.parse_conditional_expression()?;
+        self.disallow_struct_construction = false;
+
+        self.expect(&Token::LeftCurly)?;
+        let mut arms = Vec::new();
+        while !self.check(&Token::RightCurly) {
+            let pattern = self.parse_match_pattern()?;
+            self.expect(&Token::BigArrow)?;
+            let block = self.parse_block()?;
+            arms.push(MatchArm { span: pattern.span() + block.span, pattern, block, id: self.node_builder.next_id() });
+            // The arms may optionally be separated by commas.
+            self.eat(&Token::Comma);
+        }
+        let end = self.expect(&Token::RightCurly)?;
+
+        Ok(MatchStatement { expression, arms, span: start + end, id: self.node_builder.next_id() })
+    }
+
+    /// Returns a [`MatchPattern`] AST node if the next tokens represent the pattern of a match arm,
+    /// e.g. `_`, `Shape::Empty`, or `Shape::Rectangle(width, _)`.
+    fn parse_match_pattern(&mut self) -> Result<MatchPattern> {
+        if self.eat(&Token::Underscore) {
+            return Ok(MatchPattern::Wildcard(self.prev_token.span));
+        }
+
+        let ty = self.expect_identifier()?;
+        self.expect(&Token::DoubleColon)?;
+        let variant = self.expect_identifier()?;
+
+        let (bindings, end) = match self.check(&Token::LeftParen) {
+            true => {
+                let (bindings, _, end) = self.parse_paren_comma_list(|p| match p.eat(&Token::Underscore) {
+                    true => Ok(Some(None)),
+                    false => p.expect_identifier().map(|binding| Some(Some(binding))),
+                })?;
+                (bindings, end)
+            }
+            false => (Vec::new(), variant.span),
+        };
+
+        Ok(MatchPattern::Variant { ty, variant, bindings, span: ty.span + end })
+    }
+
     /// Returns an [`IterationStatement`] AST node if the next tokens represent an iteration statement.
     fn parse_loop_statement(&mut self) -> Result<IterationStatement> {
         let start_span = self.expect(&Token::For)?;
@@ -265,6 +334,36 @@ impl ParserContext<'_> {
         })
     }
 
+    /// Returns a [`WhileStatement`] AST node if the next tokens represent a bounded while loop.
+    fn parse_while_statement(&mut self) -> Result<WhileStatement> {
+        let start_span = self.expect(&Token::While)?;
+
+        // Parse the condition and the bound, `cond bound N`, where `bound` is a contextual keyword.
+        self.disallow_struct_construction = true;
+        let condition = self.parse_conditional_expression()?;
+        self.expect(&Token::Identifier(sym::bound))?;
+        let bound = self.parse_conditional_expression()?;
+        self.disallow_struct_construction = false;
+
+        let block = self.parse_block()?;
+
+        Ok(WhileStatement { span: start_span + block.span, condition, bound, block, id: self.node_builder.next_id() })
+    }
+
+    /// Returns a [`BreakStatement`] AST node if the next tokens represent a break statement.
+    fn parse_break_statement(&mut self) -> Result<BreakStatement> {
+        let start = self.expect(&Token::Break)?;
+        let end = self.expect(&Token::Semicolon)?;
+        Ok(BreakStatement { span: start + end, id: self.node_builder.next_id() })
+    }
+
+    /// Returns a [`ContinueStatement`] AST node if the next tokens represent a continue statement.
+    fn parse_continue_statement(&mut self) -> Result<ContinueStatement> {
+        let start = self.expect(&Token::Continue)?;
+        let end = self.expect(&Token::Semicolon)?;
+        Ok(ContinueStatement { span: start + end, id: self.node_builder.next_id() })
+    }
+
     /// Returns a [`ConsoleStatement`] AST node if the next tokens represent a console statement.
     #[allow(dead_code)]
     fn parse_console_statement(&mut self) -> Result<ConsoleStatement> {
@@ -340,10 +439,12 @@ impl ParserContext<'_> {
             _ => unreachable!("parse_definition_statement_ shouldn't produce this"),
         };
 
-        // Parse variable name and type.
+        // Parse variable name and type. The type may be omitted, in which case it is inferred.
         let place = self.parse_expression()?;
-        self.expect(&Token::Colon)?;
-        let type_ = self.parse_type()?.0;
+        let type_ = match self.eat(&Token::Colon) {
+            true => Some(self.parse_type()?.0),
+            false => None,
+        };
 
         self.expect(&Token::Assign)?;
         let value = self.parse_expression()?;
@@ -358,4 +459,82 @@ impl ParserContext<'_> {
             id: self.node_builder.next_id(),
         })
     }
+
+    /// Returns a copy of the place of an assignment, where the place and its subexpressions have fresh node ids.
+    fn duplicate_place(&self, place: &Expression) -> Expression {
+        match place {
+            Expression::Access(AccessExpression::Member(access)) => {
+                Expression::Access(AccessExpression::Member(MemberAccess {
+                    inner: Box::new(self.duplicate_place(&access.inner)),
+                    name: Identifier { id: self.node_builder.next_id(), ..access.name },
+                    span: access.span,
+                    id: self.node_builder.next_id(),
+                }))
+            }
+            Expression::Access(AccessExpression::Array(access)) => {
+                Expression::Access(AccessExpression::Array(ArrayAccess {
+                    array: Box::new(self.duplicate_place(&access.array)),
+                    index: Box::new(
+                        IdRefresher { node_builder: self.node_builder }.reconstruct_expression(*access.index.clone()).0,
+                    ),
+                    span: access.span,
+                    id: self.node_builder.next_id(),
+                }))
+            }
+            Expression::Access(AccessExpression::Tuple(access)) => {
+                Expression::Access(AccessExpression::Tuple(TupleAccess {
+                    tuple: Box::new(self.duplicate_place(&access.tuple)),
+                    index: access.index.clone(),
+                    span: access.span,
+                    id: self.node_builder.next_id(),
+                }))
+            }
+            _ => {
+                let mut place = place.clone();
+                place.set_id(self.node_builder.next_id());
+                place
+            }
+        }
+    }
+}
+
+/// Rebuilds an expression, giving each of its nodes a fresh id, so that the copy of an index can coexist with the original.
+struct IdRefresher<'a> {
+    node_builder: &'a NodeBuilder,
+}
+
+impl ExpressionReconstructor for IdRefresher<'_> {
+    type AdditionalOutput = ();
+
+    fn reconstruct_expression(&mut self, input: Expression) -> (Expression, Self::AdditionalOutput) {
+        let (mut expression, output) = match input {
+            Expression::Access(access) => self.reconstruct_access(access),
+            Expression::Array(array) => self.reconstruct_array(array),
+            Expression::Binary(binary) => self.reconstruct_binary(binary),
+            Expression::Call(call) => self.reconstruct_call(call),
+            Expression::Cast(cast) => self.reconstruct_cast(cast),
+            Expression::Struct(struct_) => self.reconstruct_struct_init(struct_),
+            Expression::Err(err) => self.reconstruct_err(err),
+            Expression::Identifier(identifier) => self.reconstruct_identifier(identifier),
+            Expression::Literal(value) => self.reconstruct_literal(value),
+            Expression::Ternary(ternary) => self.reconstruct_ternary(ternary),
+            Expression::Tuple(tuple) => self.reconstruct_tuple(tuple),
+            Expression::Unary(unary) => self.reconstruct_unary(unary),
+            Expression::Unit(unit) => self.reconstruct_unit(unit),
+        };
+        expression.set_id(self.node_builder.next_id());
+        (expression, output)
+    }
+
+    fn reconstruct_member_access(&mut self, input: MemberAccess) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Access(AccessExpression::Member(MemberAccess {
+                inner: Box::new(self.reconstruct_expression(*input.inner).0),
+                name: Identifier { id: self.node_builder.next_id(), ..input.name },
+                span: input.span,
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
 }
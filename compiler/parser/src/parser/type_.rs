@@ -79,7 +79,14 @@ impl ParserContext<'_> {
     /// Also returns the span of the parsed token.
     pub fn parse_type(&mut self) -> Result<(Type, Span)> {
         if let Some(ident) = self.eat_identifier() {
-            Ok((Type::Identifier(ident), ident.span))
+            match self.check(&Token::Lt) {
+                // Parse the type arguments of a generic struct, e.g. `Pair<u32>`.
+                true => {
+                    let (arguments, _, span) = self.parse_angle_comma_list(|p| p.parse_type().map(|(t, _)| Some(t)))?;
+                    Ok((Type::Generic(GenericType { identifier: ident, arguments }), ident.span + span))
+                }
+                false => Ok((Type::Identifier(ident), ident.span)),
+            }
         } else if self.token.token == Token::LeftSquare {
             // Parse the left bracket.
             self.expect(&Token::LeftSquare)?;
@@ -87,8 +94,11 @@ impl ParserContext<'_> {
             let (element_type, _) = self.parse_type()?;
             // Parse the semi-colon.
             self.expect(&Token::Semicolon)?;
-            // Parse the length.
-            let (length, _) = self.eat_whole_number()?;
+            // Parse the length, which is either a literal or a const parameter.
+            let length = match self.token.token {
+                Token::Identifier(_) => ArrayLength::Parameter(self.expect_identifier()?),
+                _ => ArrayLength::Constant(self.eat_whole_number()?.0),
+            };
             // Parse the right bracket.
             self.expect(&Token::RightSquare)?;
             // Return the array type.
@@ -214,6 +214,36 @@ impl Namespace for InputNamespace {
     }
 }
 
+struct FormatNamespace;
+
+/// Formats the given source text, returning the errors emitted by the parser on failure.
+fn format(source: &str, s: &SessionGlobals) -> Result<String, String> {
+    let sf = s.source_map.new_source(source, FileName::Custom("test".into()));
+    let (handler, buf) = Handler::new_with_buf();
+    let node_builder = NodeBuilder::default();
+    handler
+        .extend_if_error(crate::format_source(&handler, &node_builder, &sf.src, sf.start_pos))
+        .map_err(|_| buf.extract_errs().to_string())
+}
+
+impl Namespace for FormatNamespace {
+    fn parse_type(&self) -> ParseType {
+        ParseType::Whole
+    }
+
+    fn run_test(&self, test: Test) -> Result<Value, String> {
+        create_session_if_not_set_then(|s| {
+            let formatted = format(&test.content, s)?;
+            // Formatting an already formatted program must not change it.
+            let reformatted = format(&formatted, s)?;
+            if formatted != reformatted {
+                return Err(format!("formatting is not idempotent:\n{reformatted}"));
+            }
+            Ok(Value::String(formatted))
+        })
+    }
+}
+
 struct TestRunner;
 
 impl Runner for TestRunner {
@@ -225,6 +255,7 @@ impl Runner for TestRunner {
             "Serialize" => Box::new(SerializeNamespace),
             "Input" => Box::new(InputNamespace),
             "Token" => Box::new(TokenNamespace),
+            "Format" => Box::new(FormatNamespace),
             _ => return None,
         })
     }
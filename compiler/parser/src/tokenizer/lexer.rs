@@ -385,10 +385,13 @@ impl Token {
                     "assert_neq" => Token::AssertNeq,
                     "block" => Token::Block,
                     "bool" => Token::Bool,
+                    "break" => Token::Break,
                     "console" => Token::Console,
                     "const" => Token::Const,
                     "constant" => Token::Constant,
+                    "continue" => Token::Continue,
                     "else" => Token::Else,
+                    "enum" => Token::Enum,
                     "false" => Token::False,
                     "field" => Token::Field,
                     "finalize" => Token::Finalize,
@@ -401,12 +404,14 @@ impl Token {
                     "i64" => Token::I64,
                     "i128" => Token::I128,
                     "if" => Token::If,
+                    "impl" => Token::Impl,
                     "import" => Token::Import,
                     "in" => Token::In,
                     "inline" => Token::Inline,
                     "let" => Token::Let,
                     "leo" => Token::Leo,
                     "mapping" => Token::Mapping,
+                    "match" => Token::Match,
                     "private" => Token::Private,
                     "program" => Token::Program,
                     "public" => Token::Public,
@@ -425,6 +430,8 @@ impl Token {
                     "u32" => Token::U32,
                     "u64" => Token::U64,
                     "u128" => Token::U128,
+                    "use" => Token::Use,
+                    "while" => Token::While,
                     _ => Token::Identifier(Symbol::intern(&identifier)),
                 },
             ));
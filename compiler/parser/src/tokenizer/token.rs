@@ -113,19 +113,24 @@ pub enum Token {
     Assert,
     AssertEq,
     AssertNeq,
+    Break,
     Console,
     Const,
     Constant,
+    Continue,
     Else,
+    Enum,
     Finalize,
     For,
     Function,
     If,
+    Impl,
     Import,
     In,
     Inline,
     Let,
     Mapping,
+    Match,
     Private,
     Program,
 
@@ -136,6 +141,8 @@ pub enum Token {
     Struct,
     Then,
     Transition,
+    Use,
+    While,
 
     // Meta Tokens
     Block,
@@ -154,10 +161,13 @@ pub const KEYWORD_TOKENS: &[Token] = &[
     Token::AssertEq,
     Token::AssertNeq,
     Token::Bool,
+    Token::Break,
     Token::Console,
     Token::Const,
     Token::Constant,
+    Token::Continue,
     Token::Else,
+    Token::Enum,
     Token::False,
     Token::Field,
     Token::Finalize,
@@ -170,11 +180,13 @@ pub const KEYWORD_TOKENS: &[Token] = &[
     Token::I64,
     Token::I128,
     Token::If,
+    Token::Impl,
     Token::Import,
     Token::In,
     Token::Inline,
     Token::Let,
     Token::Mapping,
+    Token::Match,
     Token::Private,
     Token::Program,
     Token::Public,
@@ -193,6 +205,8 @@ pub const KEYWORD_TOKENS: &[Token] = &[
     Token::U32,
     Token::U64,
     Token::U128,
+    Token::Use,
+    Token::While,
 ];
 
 impl Token {
@@ -211,10 +225,13 @@ impl Token {
             Token::AssertNeq => sym::assert_neq,
             Token::Block => sym::block,
             Token::Bool => sym::bool,
+            Token::Break => sym::Break,
             Token::Console => sym::console,
             Token::Const => sym::Const,
             Token::Constant => sym::constant,
+            Token::Continue => sym::Continue,
             Token::Else => sym::Else,
+            Token::Enum => sym::Enum,
             Token::False => sym::False,
             Token::Field => sym::field,
             Token::Finalize => sym::finalize,
@@ -227,12 +244,14 @@ impl Token {
             Token::I64 => sym::i64,
             Token::I128 => sym::i128,
             Token::If => sym::If,
+            Token::Impl => sym::Impl,
             Token::Import => sym::import,
             Token::In => sym::In,
             Token::Inline => sym::inline,
             Token::Let => sym::Let,
             Token::Leo => sym::leo,
             Token::Mapping => sym::mapping,
+            Token::Match => sym::Match,
             Token::Private => sym::private,
             Token::Program => sym::program,
             Token::Public => sym::public,
@@ -251,6 +270,8 @@ impl Token {
             Token::U32 => sym::u32,
             Token::U64 => sym::u64,
             Token::U128 => sym::u128,
+            Token::Use => sym::Use,
+            Token::While => sym::While,
             _ => return None,
         })
     }
@@ -345,19 +366,24 @@ impl fmt::Display for Token {
             Assert => write!(f, "assert"),
             AssertEq => write!(f, "assert_eq"),
             AssertNeq => write!(f, "assert_neq"),
+            Break => write!(f, "break"),
             Console => write!(f, "console"),
             Const => write!(f, "const"),
             Constant => write!(f, "constant"),
+            Continue => write!(f, "continue"),
             Else => write!(f, "else"),
+            Enum => write!(f, "enum"),
             Finalize => write!(f, "finalize"),
             For => write!(f, "for"),
             Function => write!(f, "function"),
             If => write!(f, "if"),
+            Impl => write!(f, "impl"),
             Import => write!(f, "import"),
             In => write!(f, "in"),
             Inline => write!(f, "inline"),
             Let => write!(f, "let"),
             Mapping => write!(f, "mapping"),
+            Match => write!(f, "match"),
             Private => write!(f, "private"),
             Program => write!(f, "program"),
             Public => write!(f, "public"),
@@ -366,6 +392,8 @@ impl fmt::Display for Token {
             Struct => write!(f, "struct"),
             Then => write!(f, "then"),
             Transition => write!(f, "transition"),
+            Use => write!(f, "use"),
+            While => write!(f, "while"),
             Block => write!(f, "block"),
             Leo => write!(f, "leo"),
             Eof => write!(f, "<eof>"),
@@ -382,6 +410,8 @@ pub enum Delimiter {
     Brace,
     /// `[ ... ]`
     Bracket,
+    /// `< ... >`
+    AngleBracket,
 }
 
 impl Delimiter {
@@ -391,6 +421,7 @@ impl Delimiter {
             Self::Parenthesis => (Token::LeftParen, Token::RightParen),
             Self::Brace => (Token::LeftCurly, Token::RightCurly),
             Self::Bracket => (Token::LeftSquare, Token::RightSquare),
+            Self::AngleBracket => (Token::Lt, Token::Gt),
         }
     }
 }
@@ -0,0 +1,163 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::NodeID;
+use leo_span::{source_map::FileName, span::BytePos, symbol::with_session_globals, Span};
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// The prefixes of the errors that snarkVM reports when an instruction fails, followed by the instruction in parentheses.
+const FAILED_INSTRUCTION_PREFIXES: [&str; 2] = ["Failed to evaluate instruction (", "Failed to execute instruction ("];
+
+/// Where an instruction or register of the generated program comes from in the Leo source.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct SourceLocation {
+    /// The node that produced the instruction or register.
+    pub id: NodeID,
+    /// The span of the node.
+    pub span: Span,
+}
+
+/// An instruction of the generated program, together with where it comes from.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct InstructionDebugInfo {
+    /// The instruction, without its trailing semicolon.
+    pub instruction: String,
+    /// The source of the instruction.
+    pub location: SourceLocation,
+}
+
+/// The debug information of a closure, function or finalize block of the generated program.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+pub struct FunctionDebugInfo {
+    /// The source of each register, keyed by its name, e.g. `r3`.
+    pub registers: IndexMap<String, SourceLocation>,
+    /// The source of each instruction, in the order in which they are emitted.
+    pub instructions: Vec<InstructionDebugInfo>,
+}
+
+/// A source file that the spans of the debug information point into.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct DebugSourceFile {
+    /// The path of the file.
+    pub path: PathBuf,
+    /// The position at which the file started when the program was compiled.
+    pub start: BytePos,
+}
+
+/// Maps the instructions and registers of a generated program back to the Leo source that produced them,
+/// so that a failure of the program can be reported where it happens in the source.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+pub struct DebugInfo {
+    /// The checksum of the generated program, which the debug information is only valid for.
+    /// It is set by whoever writes the program, as the generated program is only final once it is written.
+    pub checksum: String,
+    /// The source files that the spans point into. These are only recorded once the program is compiled.
+    pub files: Vec<DebugSourceFile>,
+    /// The debug information of each closure and function, keyed by name.
+    pub functions: IndexMap<String, FunctionDebugInfo>,
+    /// The debug information of each finalize block, keyed by the name of its function.
+    pub finalize_blocks: IndexMap<String, FunctionDebugInfo>,
+}
+
+impl DebugInfo {
+    /// Records the source files that the spans point into, so that they can be found again in another session.
+    pub fn record_files(&mut self) {
+        let mut files: Vec<DebugSourceFile> = Vec::new();
+        for location in self.locations_mut() {
+            let file = with_session_globals(|s| s.source_map.span_to_location(location.span)).map(|l| l.source_file);
+            if let Some(file) = file {
+                if let FileName::Real(path) = &file.name {
+                    if files.iter().all(|recorded| recorded.start != file.start_pos) {
+                        files.push(DebugSourceFile { path: path.clone(), start: file.start_pos });
+                    }
+                }
+            }
+        }
+        files.sort_by_key(|file| file.start);
+        self.files = files;
+    }
+
+    /// Loads the recorded source files into the current session, and moves the spans to where the files now start.
+    pub fn load_files(&mut self) -> std::io::Result<()> {
+        // The new start of each file, in the order in which the files were recorded.
+        let mut starts = Vec::with_capacity(self.files.len());
+        for file in &self.files {
+            let source_file = with_session_globals(|s| s.source_map.load_file(&file.path))?;
+            starts.push((file.start, source_file.start_pos));
+        }
+
+        for location in self.locations_mut() {
+            // The span belongs to the last file that starts before it.
+            if let Some((old, new)) = starts.iter().rev().find(|(old, _)| *old <= location.span.lo) {
+                location.span = Span::new(location.span.lo - *old + *new, location.span.hi - *old + *new);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the source of the instruction that caused a failure reported by snarkVM, if it is an instruction of the program.
+    /// The error nests the instructions that were running, from those of the function that was called, to the one that failed.
+    /// Calls to the closures and functions of the program are followed, so that a failure in a called function is found.
+    /// Returns the location along with the reason for the failure.
+    pub fn locate_failure<'e>(&self, function: &str, error: &'e str) -> Option<(SourceLocation, &'e str)> {
+        // Split the error into the chain of instructions that were running and the reason for the failure.
+        let mut instructions = Vec::new();
+        let mut reason = error;
+        while let Some((instruction, inner)) = FAILED_INSTRUCTION_PREFIXES
+            .iter()
+            .find_map(|prefix| reason.strip_prefix(prefix))
+            .and_then(|rest| rest.split_once("): "))
+        {
+            instructions.push(instruction);
+            reason = inner;
+        }
+        let (failed, calls) = instructions.split_last()?;
+
+        // Find the function that the failing instruction belongs to. Calls to other programs cannot be followed.
+        let function = calls.iter().try_fold(function, |_, call| match tokens(call).as_slice() {
+            ["call", callee, ..] if !callee.contains('/') => Some(*callee),
+            _ => None,
+        })?;
+
+        let failed = tokens(failed);
+        let location = self
+            .functions
+            .get(function)?
+            .instructions
+            .iter()
+            .find(|info| tokens(&info.instruction) == failed)
+            .map(|info| info.location)?;
+        Some((location, reason))
+    }
+
+    /// Returns the locations of the instructions and registers.
+    fn locations_mut(&mut self) -> impl Iterator<Item = &mut SourceLocation> {
+        self.functions.values_mut().chain(self.finalize_blocks.values_mut()).flat_map(|function| {
+            function
+                .registers
+                .values_mut()
+                .chain(function.instructions.iter_mut().map(|instruction| &mut instruction.location))
+        })
+    }
+}
+
+/// Returns the tokens of an instruction, ignoring whitespace and the trailing semicolon.
+fn tokens(instruction: &str) -> Vec<&str> {
+    instruction.trim_end().trim_end_matches(';').split_whitespace().collect()
+}
@@ -14,9 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CallGraph, StructGraph, SymbolTable, TypeTable};
+use crate::{
+    CallGraph,
+    DebugInfo,
+    FunctionDebugInfo,
+    InstructionDebugInfo,
+    SourceLocation,
+    StructGraph,
+    SymbolTable,
+    TypeTable,
+};
 
-use leo_ast::{Function, Program, ProgramId};
+use leo_ast::{Function, Node, Program, ProgramId};
 use leo_span::Symbol;
 
 use indexmap::IndexMap;
@@ -53,6 +62,10 @@ pub struct CodeGenerator<'a> {
     pub(crate) program: &'a Program,
     // The program ID of the current program.
     pub(crate) program_id: Option<ProgramId>,
+    /// The sources of the instructions and registers of the generated program.
+    pub(crate) debug_info: DebugInfo,
+    /// The sources of the instructions and registers of the current function or finalize block.
+    pub(crate) function_debug_info: FunctionDebugInfo,
 }
 
 impl<'a> CodeGenerator<'a> {
@@ -80,6 +93,55 @@ impl<'a> CodeGenerator<'a> {
             futures: Vec::new(),
             program,
             program_id: None,
+            debug_info: DebugInfo::default(),
+            function_debug_info: FunctionDebugInfo::default(),
         }
     }
+
+    /// Records a node as the source of a register, unless the register already has one.
+    /// Nodes that the compiler generates have no source, and are not recorded.
+    pub(crate) fn record_register(&mut self, register: &str, node: &impl Node) {
+        if is_register(register) && !node.span().is_dummy() {
+            let location = SourceLocation { id: node.id(), span: node.span() };
+            self.function_debug_info.registers.entry(register.to_string()).or_insert(location);
+        }
+    }
+
+    /// Records the sources of the instructions generated for a statement.
+    /// An instruction comes from the expression that produced its destination register, if it has one, and from the statement otherwise.
+    /// Instructions of statements that the compiler generates have no source, and are not recorded.
+    pub(crate) fn record_instructions(&mut self, instructions: &str, statement: &impl Node) {
+        let statement = SourceLocation { id: statement.id(), span: statement.span() };
+        for instruction in instructions.lines().map(|line| line.trim().trim_end_matches(';')) {
+            if instruction.is_empty() {
+                continue;
+            }
+            let destinations: Vec<&str> = instruction
+                .split_whitespace()
+                .skip_while(|token| *token != "into")
+                .skip(1)
+                .take_while(|token| is_register(token))
+                .collect();
+            let location = match destinations
+                .iter()
+                .find_map(|register| self.function_debug_info.registers.get(*register))
+                .copied()
+            {
+                Some(location) => location,
+                None if !statement.span.is_dummy() => statement,
+                None => continue,
+            };
+            for register in destinations {
+                self.function_debug_info.registers.entry(register.to_string()).or_insert(location);
+            }
+            self.function_debug_info
+                .instructions
+                .push(InstructionDebugInfo { instruction: instruction.to_string(), location });
+        }
+    }
+}
+
+/// Returns whether an operand is a register, e.g. `r3`, rather than a literal or an access of a register.
+fn is_register(operand: &str) -> bool {
+    operand.strip_prefix('r').map_or(false, |index| !index.is_empty() && index.bytes().all(|b| b.is_ascii_digit()))
 }
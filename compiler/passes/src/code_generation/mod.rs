@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod debug_info;
+pub use debug_info::*;
+
 pub mod generator;
 pub use generator::*;
 
@@ -32,12 +35,12 @@ use leo_errors::Result;
 
 impl<'a> Pass for CodeGenerator<'a> {
     type Input = (&'a Ast, &'a SymbolTable, &'a TypeTable, &'a StructGraph, &'a CallGraph, &'a Program);
-    type Output = Result<String>;
+    type Output = Result<(String, DebugInfo)>;
 
     fn do_pass((ast, symbol_table, type_table, struct_graph, call_graph, program): Self::Input) -> Self::Output {
         let mut generator = Self::new(symbol_table, type_table, struct_graph, call_graph, program);
         let bytecode = generator.visit_program(ast.as_repr());
 
-        Ok(bytecode)
+        Ok((bytecode, generator.debug_info))
     }
 }
@@ -49,7 +49,7 @@ use std::fmt::Write as _;
 // a prototype. The production implementation will require a redesign of `Director`.
 impl<'a> CodeGenerator<'a> {
     pub(crate) fn visit_expression(&mut self, input: &'a Expression) -> (String, String) {
-        match input {
+        let (operand, instructions) = match input {
             Expression::Access(expr) => self.visit_access(expr),
             Expression::Array(expr) => self.visit_array(expr),
             Expression::Binary(expr) => self.visit_binary(expr),
@@ -63,7 +63,11 @@ impl<'a> CodeGenerator<'a> {
             Expression::Tuple(expr) => self.visit_tuple(expr),
             Expression::Unary(expr) => self.visit_unary(expr),
             Expression::Unit(expr) => self.visit_unit(expr),
-        }
+        };
+
+        // Record the expression as the source of its register, unless it passes on the register of another expression.
+        self.record_register(&operand, input);
+        (operand, instructions)
     }
 
     fn visit_identifier(&mut self, input: &'a Identifier) -> (String, String) {
@@ -154,6 +158,9 @@ impl<'a> CodeGenerator<'a> {
             input.elements.iter().map(|expr| self.visit_expression(expr)).fold(
                 (String::new(), String::new()),
                 |(mut operands, mut instructions), (operand, operand_instructions)| {
+                    if !operands.is_empty() {
+                        operands.push(' ');
+                    }
                     operands.push_str(&operand);
                     instructions.push_str(&operand_instructions);
                     (operands, instructions)
@@ -512,6 +519,9 @@ impl<'a> CodeGenerator<'a> {
             AccessExpression::Member(access) => self.visit_member_access(access),
             AccessExpression::AssociatedConstant(constant) => self.visit_associated_constant(constant),
             AccessExpression::AssociatedFunction(function) => self.visit_associated_function(function),
+            AccessExpression::MethodCall(_) => {
+                unreachable!("Method calls should not be in the AST at this phase of compilation.")
+            }
             AccessExpression::Tuple(_) => {
                 unreachable!("Tuple access should not be in the AST at this phase of compilation.")
             }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::CodeGenerator;
+use crate::{CodeGenerator, FunctionDebugInfo};
 
 use leo_ast::{functions, Function, Mapping, Mode, Program, ProgramScope, Struct, Type, Variant};
 
@@ -171,6 +171,7 @@ impl<'a> CodeGenerator<'a> {
         self.variable_mapping.insert(&sym::SelfLower, "self".to_string());
         self.variable_mapping.insert(&sym::block, "block".to_string());
         self.current_function = Some(function);
+        self.function_debug_info = FunctionDebugInfo::default();
 
         // Construct the header of the function.
         // If a function is a program function, generate an Aleo `function`,
@@ -186,6 +187,7 @@ impl<'a> CodeGenerator<'a> {
         for input in function.input.iter() {
             let register_string = format!("r{}", self.next_register);
             self.next_register += 1;
+            self.record_register(&register_string, input);
 
             let type_string = match input {
                 functions::Input::Internal(input) => {
@@ -209,6 +211,9 @@ impl<'a> CodeGenerator<'a> {
         //  Construct and append the function body.
         let block_string = self.visit_block(&function.block);
         function_string.push_str(&block_string);
+        self.debug_info
+            .functions
+            .insert(function.identifier.to_string(), std::mem::take(&mut self.function_debug_info));
 
         // If the finalize block exists, generate the appropriate bytecode.
         if !self.futures.is_empty() || function.finalize.is_some() {
@@ -240,6 +245,7 @@ impl<'a> CodeGenerator<'a> {
                 for input in finalize.input.iter() {
                     let register_string = format!("r{}", self.next_register);
                     self.next_register += 1;
+                    self.record_register(&register_string, input);
 
                     // TODO: Dedup code.
                     let type_string = match input {
@@ -272,6 +278,9 @@ impl<'a> CodeGenerator<'a> {
             if let Some(finalize) = &function.finalize {
                 function_string.push_str(&self.visit_block(&finalize.block));
             }
+            self.debug_info
+                .finalize_blocks
+                .insert(function.identifier.to_string(), std::mem::take(&mut self.function_debug_info));
 
             self.in_finalize = false;
         }
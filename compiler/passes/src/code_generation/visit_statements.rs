@@ -31,6 +31,7 @@ use leo_ast::{
     Output,
     ReturnStatement,
     Statement,
+    Type,
 };
 
 use itertools::Itertools;
@@ -38,7 +39,7 @@ use std::fmt::Write as _;
 
 impl<'a> CodeGenerator<'a> {
     fn visit_statement(&mut self, input: &'a Statement) -> String {
-        match input {
+        let instructions = match input {
             Statement::Assert(stmt) => self.visit_assert(stmt),
             Statement::Assign(stmt) => self.visit_assign(stmt),
             Statement::Block(stmt) => self.visit_block(stmt),
@@ -50,34 +51,45 @@ impl<'a> CodeGenerator<'a> {
             Statement::Definition(stmt) => self.visit_definition(stmt),
             Statement::Expression(stmt) => self.visit_expression_statement(stmt),
             Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Match(_) => {
+                unreachable!("`MatchStatement`s should not be in the AST at this phase of compilation.")
+            }
+            Statement::Break(_) | Statement::Continue(_) | Statement::While(_) => {
+                unreachable!("Loop unrolling removes loops and their control statements from the AST.")
+            }
             Statement::Return(stmt) => self.visit_return(stmt),
+        };
+
+        // Record the sources of the instructions. Those of nested statements are recorded as they are visited.
+        if !matches!(input, Statement::Block(_) | Statement::Conditional(_) | Statement::Iteration(_)) {
+            self.record_instructions(&instructions, input);
         }
+        instructions
     }
 
     fn visit_assert(&mut self, input: &'a AssertStatement) -> String {
         let mut generate_assert_instruction = |name: &str, left: &'a Expression, right: &'a Expression| {
             let (left_operand, left_instructions) = self.visit_expression(left);
             let (right_operand, right_instructions) = self.visit_expression(right);
-            let assert_instruction = format!("    {name} {left_operand} {right_operand};\n");
+            let assert_instruction = format!("{name} {left_operand} {right_operand}");
 
             // Concatenate the instructions.
             let mut instructions = left_instructions;
             instructions.push_str(&right_instructions);
-            instructions.push_str(&assert_instruction);
 
-            instructions
+            (instructions, assert_instruction)
         };
-        match &input.variant {
+        let (mut instructions, assert_instruction) = match &input.variant {
             AssertVariant::Assert(expr) => {
-                let (operand, mut instructions) = self.visit_expression(expr);
-                let assert_instruction = format!("    assert.eq {operand} true;\n");
-
-                instructions.push_str(&assert_instruction);
-                instructions
+                let (operand, instructions) = self.visit_expression(expr);
+                (instructions, format!("assert.eq {operand} true"))
             }
             AssertVariant::AssertEq(left, right) => generate_assert_instruction("assert.eq", left, right),
             AssertVariant::AssertNeq(left, right) => generate_assert_instruction("assert.neq", left, right),
-        }
+        };
+
+        instructions.push_str(&format!("    {assert_instruction};\n"));
+        instructions
     }
 
     fn visit_return(&mut self, input: &'a ReturnStatement) -> String {
@@ -122,6 +134,16 @@ impl<'a> CodeGenerator<'a> {
                                     // Only program functions have visibilities associated with their outputs.
                                     Mode::None
                                 };
+                                let operand = match self.is_transition_function {
+                                    true => operand.to_string(),
+                                    false => {
+                                        // The instructions of the outputs must follow those that compute them.
+                                        let (register, instruction) =
+                                            self.closure_output_register(operand, &output.type_);
+                                        expression_instructions.push_str(&instruction);
+                                        register
+                                    }
+                                };
                                 format!(
                                     "    output {} as {};\n",
                                     operand,
@@ -188,6 +210,18 @@ impl<'a> CodeGenerator<'a> {
         instructions
     }
 
+    /// Returns the register that a closure outputs for `operand`, and the instructions that compute it.
+    /// Closures can only output registers, so a literal, e.g. a constant, is first cast into a new register.
+    fn closure_output_register(&mut self, operand: &str, type_: &Type) -> (String, String) {
+        if operand.starts_with('r') {
+            return (operand.to_string(), String::new());
+        }
+        let destination_register = format!("r{}", self.next_register);
+        self.next_register += 1;
+        let instruction = format!("    cast {operand} into {destination_register} as {};\n", Self::visit_type(type_));
+        (destination_register, instruction)
+    }
+
     fn visit_definition(&mut self, _input: &'a DefinitionStatement) -> String {
         // TODO: If SSA is made optional, then conditionally enable codegen for DefinitionStatement
         // let (operand, expression_instructions) = self.visit_expression(&input.value);
@@ -14,8 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_ast::{Function, Input, Type, Variant};
-use leo_span::Span;
+use leo_ast::{Function, GenericParameter, Input, Type, Variant};
+use leo_span::{sym, Span};
 
 use serde::{Deserialize, Serialize};
 
@@ -41,10 +41,38 @@ pub struct FunctionSymbol {
     pub variant: Variant,
     /// The `Span` associated with the function.
     pub(crate) _span: Span,
+    /// The type parameters of the function.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) generic_parameters: Vec<GenericParameter>,
     /// The inputs to the function.
     pub(crate) input: Vec<Input>,
     /// Metadata associated with the finalize block.
     pub(crate) finalize: Option<FinalizeData>,
+    /// Is this function annotated with `@test`?
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub(crate) is_test: bool,
+}
+
+impl FunctionSymbol {
+    /// Returns the `Span` associated with the function.
+    pub fn span(&self) -> Span {
+        self._span
+    }
+
+    /// Returns `true` if the function is a method, i.e. its first input is `self`.
+    pub fn is_method(&self) -> bool {
+        self.input.first().map_or(false, |input| input.identifier().name == sym::SelfLower)
+    }
+
+    /// Returns the inputs to the function.
+    pub fn input(&self) -> &[Input] {
+        &self.input
+    }
+
+    /// Returns `true` if the function has a finalize block.
+    pub fn has_finalize(&self) -> bool {
+        self.finalize.is_some()
+    }
 }
 
 impl SymbolTable {
@@ -54,11 +82,13 @@ impl SymbolTable {
             output_type: func.output_type.clone(),
             variant: func.variant,
             _span: func.span,
+            generic_parameters: func.generic_parameters.clone(),
             input: func.input.clone(),
             finalize: func.finalize.as_ref().map(|finalize| FinalizeData {
                 input: finalize.input.clone(),
                 output_type: finalize.output_type.clone(),
             }),
+            is_test: func.is_test(),
         }
     }
 }
@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ConstantPropagationTable;
+
+use leo_ast::{Expression, Literal, Value};
+use leo_errors::emitter::Handler;
+use leo_span::Symbol;
+
+pub struct ConstantFolder<'a> {
+    /// The error handler.
+    pub(crate) handler: &'a Handler,
+    /// The variables of the current function body known to hold a literal.
+    pub(crate) constant_propagation_table: ConstantPropagationTable,
+}
+
+impl<'a> ConstantFolder<'a> {
+    /// Initializes a new `ConstantFolder`.
+    pub fn new(handler: &'a Handler) -> Self {
+        Self { handler, constant_propagation_table: Default::default() }
+    }
+
+    /// Returns the value of the variable `symbol`, if it is known at compile time.
+    pub(crate) fn lookup_value(&self, symbol: Symbol) -> Option<Value> {
+        match self.constant_propagation_table.lookup_constant(symbol) {
+            Some(Expression::Literal(literal)) => Self::literal_value(literal),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the literal, if it can be operated on at compile time.
+    pub(crate) fn literal_value(literal: &Literal) -> Option<Value> {
+        Value::try_from(literal).ok().filter(|value| value.is_supported_const_fold_type())
+    }
+}
@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ConstantFolder;
+
+use leo_ast::{
+    BinaryExpression,
+    BinaryOperation,
+    Expression,
+    ExpressionReconstructor,
+    Identifier,
+    Literal,
+    TernaryExpression,
+    UnaryExpression,
+    UnaryOperation,
+    Value,
+};
+use leo_errors::{FlattenError, Result};
+use leo_span::Span;
+
+impl ExpressionReconstructor for ConstantFolder<'_> {
+    /// The value of the reconstructed expression, if it is known at compile time.
+    type AdditionalOutput = Option<Value>;
+
+    /// Folds a binary expression over known values into a literal.
+    fn reconstruct_binary(&mut self, input: BinaryExpression) -> (Expression, Self::AdditionalOutput) {
+        let (left, left_value) = self.reconstruct_expression(*input.left);
+        let (right, right_value) = self.reconstruct_expression(*input.right);
+
+        if let (Some(left_value), Some(right_value)) = (left_value, right_value) {
+            if let Some(value) = self.fold_binary(input.op, left_value, right_value, input.span) {
+                return (Expression::Literal(Literal::from_value(value.clone(), input.id)), Some(value));
+            }
+        }
+
+        (
+            Expression::Binary(BinaryExpression {
+                left: Box::new(left),
+                right: Box::new(right),
+                op: input.op,
+                span: input.span,
+                id: input.id,
+            }),
+            None,
+        )
+    }
+
+    /// Looks up the value of the variable, leaving the identifier in place.
+    fn reconstruct_identifier(&mut self, input: Identifier) -> (Expression, Self::AdditionalOutput) {
+        (Expression::Identifier(input), self.lookup_value(input.name))
+    }
+
+    fn reconstruct_literal(&mut self, input: Literal) -> (Expression, Self::AdditionalOutput) {
+        let value = Self::literal_value(&input);
+        (Expression::Literal(input), value)
+    }
+
+    /// Replaces a ternary expression over a known condition with the selected branch.
+    fn reconstruct_ternary(&mut self, input: TernaryExpression) -> (Expression, Self::AdditionalOutput) {
+        let (condition, condition_value) = self.reconstruct_expression(*input.condition);
+        let (if_true, if_true_value) = self.reconstruct_expression(*input.if_true);
+        let (if_false, if_false_value) = self.reconstruct_expression(*input.if_false);
+
+        match condition_value {
+            Some(Value::Boolean(true, _)) => (if_true, if_true_value),
+            Some(Value::Boolean(false, _)) => (if_false, if_false_value),
+            _ => (
+                Expression::Ternary(TernaryExpression {
+                    condition: Box::new(condition),
+                    if_true: Box::new(if_true),
+                    if_false: Box::new(if_false),
+                    span: input.span,
+                    id: input.id,
+                }),
+                None,
+            ),
+        }
+    }
+
+    /// Folds a unary expression over a known value into a literal.
+    fn reconstruct_unary(&mut self, input: UnaryExpression) -> (Expression, Self::AdditionalOutput) {
+        let (receiver, receiver_value) = self.reconstruct_expression(*input.receiver);
+
+        if let Some(receiver_value) = receiver_value {
+            if let Some(value) = self.fold_unary(input.op, receiver_value, input.span) {
+                return (Expression::Literal(Literal::from_value(value.clone(), input.id)), Some(value));
+            }
+        }
+
+        (
+            Expression::Unary(UnaryExpression {
+                receiver: Box::new(receiver),
+                op: input.op,
+                span: input.span,
+                id: input.id,
+            }),
+            None,
+        )
+    }
+}
+
+impl ConstantFolder<'_> {
+    /// Evaluates a binary operation, emitting an error if it overflows or divides by zero.
+    /// Returns `None` if the operation cannot be evaluated at compile time.
+    fn fold_binary(&self, op: BinaryOperation, left: Value, right: Value, span: Span) -> Option<Value> {
+        use BinaryOperation::*;
+
+        if matches!(op, Div | DivWrapped | Mod | Rem | RemWrapped) && right.is_zero() {
+            self.handler.emit_err(FlattenError::division_by_zero(
+                Literal::from_value(left, Default::default()),
+                op,
+                Literal::from_value(right, Default::default()),
+                span,
+            ));
+            return None;
+        }
+
+        let result = match op {
+            Add => left.add(right, span),
+            AddWrapped => left.add_wrapped(right, span),
+            And | BitwiseAnd => left.bitand(right, span),
+            Div => left.div(right, span),
+            DivWrapped => left.div_wrapped(right, span),
+            Eq => left.eq(right, span),
+            Gte => left.ge(right, span),
+            Gt => left.gt(right, span),
+            Lte => left.le(right, span),
+            Lt => left.lt(right, span),
+            // The type checker only allows `mod` over unsigned integers, for which it is the remainder.
+            Mod => left.rem(right, span),
+            Mul => left.mul(right, span),
+            MulWrapped => left.mul_wrapped(right, span),
+            Neq => left.eq(right, span).and_then(|value| value.not(span)),
+            Or | BitwiseOr => left.bitor(right, span),
+            Pow => left.pow(right, span),
+            PowWrapped => left.pow_wrapped(right, span),
+            Rem => left.rem(right, span),
+            RemWrapped => left.rem_wrapped(right, span),
+            Shl => left.shl(right, span),
+            ShlWrapped => left.shl_wrapped(right, span),
+            Shr => left.shr(right, span),
+            ShrWrapped => left.shr_wrapped(right, span),
+            Sub => left.sub(right, span),
+            SubWrapped => left.sub_wrapped(right, span),
+            Xor => left.xor(right, span),
+            Nand | Nor => return None,
+        };
+
+        self.emit_if_err(result)
+    }
+
+    /// Evaluates a unary operation, emitting an error if it overflows.
+    /// Returns `None` if the operation cannot be evaluated at compile time.
+    fn fold_unary(&self, op: UnaryOperation, receiver: Value, span: Span) -> Option<Value> {
+        use UnaryOperation::*;
+
+        let result = match op {
+            Abs => receiver.abs(span),
+            AbsWrapped => receiver.abs_wrapped(span),
+            Negate => receiver.neg(span),
+            Not => receiver.not(span),
+            Double | Inverse | Square | SquareRoot | ToXCoordinate | ToYCoordinate => return None,
+        };
+
+        self.emit_if_err(result)
+    }
+
+    /// Emits the error of a failed operation, since the program would fail on it for every input.
+    fn emit_if_err(&self, result: Result<Value>) -> Option<Value> {
+        match result {
+            Ok(value) => Some(value),
+            Err(err) => {
+                self.handler.emit_err(err);
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,39 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ConstantFolder;
+
+use leo_ast::{AssignStatement, Expression, ExpressionReconstructor, Literal, Node, Statement, StatementReconstructor};
+
+impl StatementReconstructor for ConstantFolder<'_> {
+    /// Folds the right hand side of an assignment, recording the variable if its value is known.
+    fn reconstruct_assign(&mut self, input: AssignStatement) -> (Statement, Self::AdditionalOutput) {
+        let (value, folded) = self.reconstruct_expression(input.value);
+
+        if let (Expression::Identifier(identifier), Some(folded)) = (&input.place, folded) {
+            // Note that SSA guarantees that the variable is not assigned again.
+            let literal = Expression::Literal(Literal::from_value(folded, value.id()));
+            if let Err(err) = self.constant_propagation_table.insert_constant(identifier.name, literal) {
+                self.handler.emit_err(err);
+            }
+        }
+
+        (
+            Statement::Assign(Box::new(AssignStatement { place: input.place, value, span: input.span, id: input.id })),
+            Default::default(),
+        )
+    }
+}
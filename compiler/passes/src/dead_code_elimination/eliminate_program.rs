@@ -51,6 +51,7 @@ impl ProgramReconstructor for DeadCodeEliminator<'_> {
             annotations: input.annotations,
             variant: input.variant,
             identifier: input.identifier,
+            generic_parameters: input.generic_parameters,
             input: input.input,
             output: input.output,
             output_type: input.output_type,
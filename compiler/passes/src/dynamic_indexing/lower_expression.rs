@@ -0,0 +1,47 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::DynamicIndexLowerer;
+
+use leo_ast::*;
+
+impl ExpressionReconstructor for DynamicIndexLowerer<'_> {
+    type AdditionalOutput = ();
+
+    fn reconstruct_array_access(&mut self, input: ArrayAccess) -> (Expression, Self::AdditionalOutput) {
+        let array = self.reconstruct_expression(*input.array).0;
+        let index = self.reconstruct_expression(*input.index).0;
+
+        // An access at a constant index is left as it is.
+        if let Expression::Literal(_) = index {
+            return (
+                Expression::Access(AccessExpression::Array(ArrayAccess {
+                    array: Box::new(array),
+                    index: Box::new(index),
+                    span: input.span,
+                    id: input.id,
+                })),
+                Default::default(),
+            );
+        }
+
+        // Otherwise, check the bounds of the index, and select the element it refers to.
+        let array = self.hoist(array, "$array");
+        let index = self.hoist(index, "$index");
+        self.assert_in_bounds(&index, self.array_type(&array).0, input.span);
+        (self.select(&array, &index), Default::default())
+    }
+}
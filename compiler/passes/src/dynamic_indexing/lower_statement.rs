@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::DynamicIndexLowerer;
+
+use leo_ast::*;
+
+impl StatementReconstructor for DynamicIndexLowerer<'_> {
+    fn reconstruct_assign(&mut self, input: AssignStatement) -> (Statement, Self::AdditionalOutput) {
+        let value = self.reconstruct_expression(input.value).0;
+
+        // An assignment to a place with constant indices is left as it is.
+        if Self::first_dynamic_access(&input.place).is_none() {
+            return (
+                Statement::Assign(Box::new(AssignStatement {
+                    place: input.place,
+                    value,
+                    span: input.span,
+                    id: input.id,
+                })),
+                Default::default(),
+            );
+        }
+
+        // Otherwise, compute the dynamic indices of the place and check their bounds,
+        // and assign the value to each element that the place may refer to.
+        let place = self.hoist_indices(input.place);
+        let value = self.hoist(value, "$value");
+        let mut assignments = self
+            .assignments(place, value)
+            .into_iter()
+            .map(|(place, value)| {
+                Statement::Assign(Box::new(AssignStatement {
+                    place,
+                    value,
+                    span: input.span,
+                    id: self.node_builder.next_id(),
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        // The last assignment replaces the statement, and the others precede it.
+        // Note that there is at least one assignment, since arrays are not empty.
+        let last = assignments.pop().unwrap();
+        self.statements.extend(assignments);
+        (last, Default::default())
+    }
+
+    fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
+        // Set aside the statements that precede the enclosing statement, e.g. those of the condition of a conditional.
+        let preceding = std::mem::take(&mut self.statements);
+
+        let mut statements = Vec::with_capacity(input.statements.len());
+        for statement in input.statements {
+            let statement = self.reconstruct_statement(statement).0;
+            statements.append(&mut self.statements);
+            statements.push(statement);
+        }
+
+        self.statements = preceding;
+        (Block { statements, span: input.span, id: input.id }, Default::default())
+    }
+
+    fn reconstruct_conditional(&mut self, input: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
+        let condition = self.reconstruct_expression(input.condition).0;
+        let then = self.reconstruct_block(input.then).0;
+        // The checks of the condition of an `else if` are placed in a new block, so that they are only made in that branch.
+        let otherwise = input.otherwise.map(|statement| match *statement {
+            Statement::Block(block) => Box::new(Statement::Block(self.reconstruct_block(block).0)),
+            statement => {
+                let preceding = std::mem::take(&mut self.statements);
+                let span = statement.span();
+                let statement = self.reconstruct_statement(statement).0;
+                let mut statements = std::mem::replace(&mut self.statements, preceding);
+                match statements.is_empty() {
+                    true => Box::new(statement),
+                    false => {
+                        statements.push(statement);
+                        Box::new(Statement::Block(Block { statements, span, id: self.node_builder.next_id() }))
+                    }
+                }
+            }
+        });
+
+        (
+            Statement::Conditional(ConditionalStatement { condition, then, otherwise, span: input.span, id: input.id }),
+            Default::default(),
+        )
+    }
+}
+
+impl DynamicIndexLowerer<'_> {
+    /// Reconstructs the dynamic indices of the place, assigning each to a new variable if needed,
+    /// and checks that they are within the bounds of their arrays.
+    fn hoist_indices(&mut self, place: Expression) -> Expression {
+        match place {
+            Expression::Access(AccessExpression::Array(access)) => {
+                let is_dynamic = Self::is_dynamic(&access);
+                let array = self.hoist_indices(*access.array);
+                let index = match is_dynamic {
+                    true => {
+                        let index = self.reconstruct_expression(*access.index).0;
+                        let index = self.hoist(index, "$index");
+                        self.assert_in_bounds(&index, self.array_type(&array).0, access.span);
+                        index
+                    }
+                    false => *access.index,
+                };
+                Expression::Access(AccessExpression::Array(ArrayAccess {
+                    array: Box::new(array),
+                    index: Box::new(index),
+                    span: access.span,
+                    id: access.id,
+                }))
+            }
+            Expression::Access(AccessExpression::Member(access)) => {
+                Expression::Access(AccessExpression::Member(MemberAccess {
+                    inner: Box::new(self.hoist_indices(*access.inner)),
+                    ..access
+                }))
+            }
+            place => place,
+        }
+    }
+}
@@ -0,0 +1,301 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Assigner, TypeTable};
+
+use leo_ast::{
+    AccessExpression,
+    ArrayAccess,
+    AssertStatement,
+    AssertVariant,
+    BinaryExpression,
+    BinaryOperation,
+    DeclarationType,
+    DefinitionStatement,
+    Expression,
+    Identifier,
+    IntegerType,
+    Literal,
+    MemberAccess,
+    Node,
+    NodeBuilder,
+    NodeID,
+    Statement,
+    TernaryExpression,
+    Type,
+};
+use leo_span::Span;
+
+pub struct DynamicIndexLowerer<'a> {
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// A struct used to construct unique variable names.
+    pub(crate) assigner: &'a Assigner,
+    /// A mapping between node IDs and their types.
+    pub(crate) type_table: &'a TypeTable,
+    /// The statements that must precede the statement being reconstructed,
+    /// which compute the indices of its dynamic accesses and check their bounds.
+    pub(crate) statements: Vec<Statement>,
+}
+
+impl<'a> DynamicIndexLowerer<'a> {
+    /// Initializes a new `DynamicIndexLowerer`.
+    pub fn new(node_builder: &'a NodeBuilder, assigner: &'a Assigner, type_table: &'a TypeTable) -> Self {
+        Self { node_builder, assigner, type_table, statements: Vec::new() }
+    }
+
+    /// Returns `true` if the index of the access is not known at compile time.
+    pub(crate) fn is_dynamic(access: &ArrayAccess) -> bool {
+        !matches!(access.index.as_ref(), Expression::Literal(_))
+    }
+
+    /// Returns the access with a dynamic index that is closest to the variable that the place is rooted at, if any.
+    pub(crate) fn first_dynamic_access(place: &Expression) -> Option<&ArrayAccess> {
+        match place {
+            Expression::Access(AccessExpression::Array(access)) => {
+                Self::first_dynamic_access(&access.array).or_else(|| Self::is_dynamic(access).then_some(access))
+            }
+            Expression::Access(AccessExpression::Member(access)) => Self::first_dynamic_access(&access.inner),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the expression can be repeated without computing anything more than once,
+    /// i.e. it is a variable, a literal, or a member or constant element of one.
+    fn is_path(expression: &Expression) -> bool {
+        match expression {
+            Expression::Identifier(_) | Expression::Literal(_) => true,
+            Expression::Access(AccessExpression::Member(access)) => Self::is_path(&access.inner),
+            Expression::Access(AccessExpression::Tuple(access)) => Self::is_path(&access.tuple),
+            Expression::Access(AccessExpression::Array(access)) => {
+                !Self::is_dynamic(access) && Self::is_path(&access.array)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the type of the expression, as recorded during type checking.
+    fn type_of(&self, expression: &Expression) -> Type {
+        match self.type_table.get(&expression.id()) {
+            Some(type_) => type_,
+            None => unreachable!("Type checking guarantees that all expressions have a type."),
+        }
+    }
+
+    /// Returns a new node ID with the same type as the node `id`.
+    fn copy_id(&self, id: NodeID) -> NodeID {
+        let copy = self.node_builder.next_id();
+        if let Some(type_) = self.type_table.get(&id) {
+            self.type_table.insert(copy, type_);
+        }
+        copy
+    }
+
+    /// Constructs an expression of type `type_`, and records its type.
+    fn typed(&self, type_: Type, construct: impl FnOnce(NodeID) -> Expression) -> Expression {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, type_);
+        construct(id)
+    }
+
+    /// Constructs the integer literal `value` of type `type_`.
+    pub(crate) fn integer(&self, value: usize, type_: IntegerType) -> Expression {
+        self.typed(Type::Integer(type_), |id| {
+            Expression::Literal(Literal::Integer(type_, value.to_string(), Default::default(), id))
+        })
+    }
+
+    /// Constructs the binary expression `left op right` of type `type_`.
+    fn binary(&self, left: Expression, op: BinaryOperation, right: Expression, type_: Type) -> Expression {
+        self.typed(type_, |id| {
+            Expression::Binary(BinaryExpression {
+                left: Box::new(left),
+                right: Box::new(right),
+                op,
+                span: Default::default(),
+                id,
+            })
+        })
+    }
+
+    /// Constructs the ternary expression `condition ? if_true : if_false` of type `type_`.
+    fn ternary(&self, condition: Expression, if_true: Expression, if_false: Expression, type_: Type) -> Expression {
+        self.typed(type_, |id| {
+            Expression::Ternary(TernaryExpression {
+                condition: Box::new(condition),
+                if_true: Box::new(if_true),
+                if_false: Box::new(if_false),
+                span: Default::default(),
+                id,
+            })
+        })
+    }
+
+    /// Returns the condition `index == value`.
+    pub(crate) fn index_is(&self, index: &Expression, value: usize) -> Expression {
+        let literal = self.integer(value, self.index_type(index));
+        self.binary(index.clone(), BinaryOperation::Eq, literal, Type::Boolean)
+    }
+
+    /// Returns the integer type of the index.
+    fn index_type(&self, index: &Expression) -> IntegerType {
+        match self.type_of(index) {
+            Type::Integer(type_) => type_,
+            _ => unreachable!("Type checking guarantees that array indices are integers."),
+        }
+    }
+
+    /// Returns the length and the element type of the array.
+    pub(crate) fn array_type(&self, array: &Expression) -> (usize, Type) {
+        match self.type_of(array) {
+            Type::Array(array_type) => (array_type.length(), array_type.element_type().clone()),
+            _ => unreachable!("Type checking guarantees that only arrays are indexed."),
+        }
+    }
+
+    /// Assigns the expression to a new variable, which is returned, unless it can be repeated as it is.
+    pub(crate) fn hoist(&mut self, expression: Expression, name: &str) -> Expression {
+        if Self::is_path(&expression) {
+            return expression;
+        }
+
+        let type_ = self.type_of(&expression);
+        let identifier = Identifier::new(self.assigner.unique_symbol(name, "$"), self.node_builder.next_id());
+        self.type_table.insert(identifier.id, type_.clone());
+        self.statements.push(Statement::Definition(DefinitionStatement {
+            declaration_type: DeclarationType::Let,
+            place: Expression::Identifier(identifier),
+            type_: Some(type_),
+            value: expression,
+            span: Default::default(),
+            id: self.node_builder.next_id(),
+        }));
+        Expression::Identifier(identifier)
+    }
+
+    /// Asserts that the index is within the bounds of an array of the given length.
+    pub(crate) fn assert_in_bounds(&mut self, index: &Expression, length: usize, span: Span) {
+        let index_type = self.index_type(index);
+        let length = self.integer(length, index_type);
+        let mut condition = self.binary(index.clone(), BinaryOperation::Lt, length, Type::Boolean);
+        // A signed index must also not be negative.
+        if index_type.is_signed() {
+            let zero = self.integer(0, index_type);
+            let non_negative = self.binary(index.clone(), BinaryOperation::Gte, zero, Type::Boolean);
+            condition = self.binary(non_negative, BinaryOperation::And, condition, Type::Boolean);
+        }
+        self.statements.push(Statement::Assert(AssertStatement {
+            variant: AssertVariant::Assert(condition),
+            span,
+            id: self.node_builder.next_id(),
+        }));
+    }
+
+    /// Selects the element of the array at the dynamic index by comparing the index with each position of the array.
+    /// The last element is selected if the index matches no other position.
+    pub(crate) fn select(&self, array: &Expression, index: &Expression) -> Expression {
+        let (length, element_type) = self.array_type(array);
+        let index_type = self.index_type(index);
+        let element = |position: usize| {
+            self.typed(element_type.clone(), |id| {
+                Expression::Access(AccessExpression::Array(ArrayAccess {
+                    array: Box::new(array.clone()),
+                    index: Box::new(self.integer(position, index_type)),
+                    span: Default::default(),
+                    id,
+                }))
+            })
+        };
+        (0..length.saturating_sub(1)).rev().fold(element(length - 1), |selection, position| {
+            self.ternary(self.index_is(index, position), element(position), selection, element_type.clone())
+        })
+    }
+
+    /// Reads the value of a place whose indices are literals or variables.
+    pub(crate) fn read_place(&self, place: Expression) -> Expression {
+        match place {
+            Expression::Access(AccessExpression::Array(access)) => {
+                let is_dynamic = Self::is_dynamic(&access);
+                let array = self.read_place(*access.array);
+                match is_dynamic {
+                    true => self.select(&array, &access.index),
+                    false => Expression::Access(AccessExpression::Array(ArrayAccess {
+                        array: Box::new(array),
+                        id: self.copy_id(access.id),
+                        ..access
+                    })),
+                }
+            }
+            Expression::Access(AccessExpression::Member(access)) => {
+                Expression::Access(AccessExpression::Member(MemberAccess {
+                    inner: Box::new(self.read_place(*access.inner)),
+                    id: self.copy_id(access.id),
+                    ..access
+                }))
+            }
+            place => place,
+        }
+    }
+
+    /// Returns a copy of the place, with the index of its first dynamic access replaced by `position`.
+    fn place_at(&self, place: Expression, position: usize) -> Expression {
+        match place {
+            Expression::Access(AccessExpression::Array(access)) => {
+                let (array, index) = match Self::first_dynamic_access(&access.array) {
+                    Some(_) => (self.place_at(*access.array, position), *access.index),
+                    None => {
+                        let index_type = self.index_type(&access.index);
+                        (*access.array, self.integer(position, index_type))
+                    }
+                };
+                Expression::Access(AccessExpression::Array(ArrayAccess {
+                    array: Box::new(array),
+                    index: Box::new(index),
+                    span: access.span,
+                    id: self.copy_id(access.id),
+                }))
+            }
+            Expression::Access(AccessExpression::Member(access)) => {
+                Expression::Access(AccessExpression::Member(MemberAccess {
+                    inner: Box::new(self.place_at(*access.inner, position)),
+                    id: self.copy_id(access.id),
+                    ..access
+                }))
+            }
+            _ => unreachable!("A place with a dynamic access is an access of a variable."),
+        }
+    }
+
+    /// Lowers the assignment of `value` to a place whose indices are literals or variables
+    /// into assignments to places whose indices are all literals.
+    /// Each element that the first dynamic access may refer to keeps its value, unless the index refers to it.
+    pub(crate) fn assignments(&self, place: Expression, value: Expression) -> Vec<(Expression, Expression)> {
+        let (index, length) = match Self::first_dynamic_access(&place) {
+            Some(access) => ((*access.index).clone(), self.array_type(&access.array).0),
+            None => return vec![(place, value)],
+        };
+
+        let type_ = self.type_of(&value);
+        (0..length)
+            .flat_map(|position| {
+                let place = self.place_at(place.clone(), position);
+                let current = self.read_place(place.clone());
+                let value = self.ternary(self.index_is(&index, position), value.clone(), current, type_.clone());
+                self.assignments(place, value)
+            })
+            .collect()
+    }
+}
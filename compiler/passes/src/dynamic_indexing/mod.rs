@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The dynamic indexing pass lowers the accesses of arrays at indices that are not known at compile time
+//! into selections over all of the elements of the array. The pass runs after loop unrolling,
+//! which resolves the indices that are constant, so that the passes that follow only see constant indices.
+//!
+//! An element that is read at a dynamic index is selected by a chain of ternary expressions,
+//! and an element that is written at a dynamic index is written by a conditional assignment to each element.
+//! Each dynamic index is checked against the length of the array by an inserted assertion,
+//! so that an access that is out of bounds fails the circuit.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: [u8; 3], i: u32, v: u8) -> u8 {
+//!     a[i] = v;
+//!     return a[i + 1u32];
+//! }
+//! ```
+//!
+//! The dynamic indexing pass produces the following code.
+//! ```leo
+//! transition main(a: [u8; 3], i: u32, v: u8) -> u8 {
+//!     assert(i < 3u32);
+//!     a[0u32] = i == 0u32 ? v : a[0u32];
+//!     a[1u32] = i == 1u32 ? v : a[1u32];
+//!     a[2u32] = i == 2u32 ? v : a[2u32];
+//!     let $index$0: u32 = i + 1u32;
+//!     assert($index$0 < 3u32);
+//!     return $index$0 == 0u32 ? a[0u32] : $index$0 == 1u32 ? a[1u32] : a[2u32];
+//! }
+//! ```
+//!
+//! Note that an index or array that is not a variable, a literal, or an access of one, is first assigned to a new variable,
+//! so that it is only computed once.
+
+mod lower_expression;
+
+mod lower_program;
+
+mod lower_statement;
+
+pub mod lowerer;
+pub use lowerer::*;
+
+use crate::{Assigner, Pass, TypeTable};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for DynamicIndexLowerer<'a> {
+    type Input = (Ast, &'a NodeBuilder, &'a Assigner, &'a TypeTable);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, node_builder, assigner, type_table): Self::Input) -> Self::Output {
+        let mut reconstructor = DynamicIndexLowerer::new(node_builder, assigner, type_table);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
@@ -17,6 +17,9 @@
 use crate::Flattener;
 
 use leo_ast::{
+    AccessExpression,
+    AssociatedConstant,
+    AssociatedFunction,
     Expression,
     ExpressionReconstructor,
     Node,
@@ -30,6 +33,23 @@ use leo_ast::{
 impl ExpressionReconstructor for Flattener<'_> {
     type AdditionalOutput = Vec<Statement>;
 
+    /// Lowers a variant of an enum without a payload, e.g. `Shape::Empty`, into a struct init expression.
+    fn reconstruct_associated_constant(&mut self, input: AssociatedConstant) -> (Expression, Self::AdditionalOutput) {
+        match self.lookup_enum_type(&input.ty) {
+            Some(enum_) => self.enum_variant(&enum_, input.name.name, Vec::new(), input.id),
+            None => (Expression::Access(AccessExpression::AssociatedConstant(input)), Default::default()),
+        }
+    }
+
+    /// Lowers a variant of an enum with a payload, e.g. `Shape::Circle(r)`, into a struct init expression.
+    fn reconstruct_associated_function(&mut self, input: AssociatedFunction) -> (Expression, Self::AdditionalOutput) {
+        match self.lookup_enum_type(&input.ty) {
+            Some(enum_) => self.enum_variant(&enum_, input.name.name, input.arguments, input.id),
+            // Note that SSA guarantees that the arguments are identifiers, which are not flattened.
+            None => (Expression::Access(AccessExpression::AssociatedFunction(input)), Default::default()),
+        }
+    }
+
     /// Reconstructs a struct init expression, flattening any tuples in the expression.
     fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
         let mut statements = Vec::new();
@@ -89,8 +109,12 @@ impl ExpressionReconstructor for Flattener<'_> {
                     Type::Array(first_type) => self.ternary_array(first_type, &input.condition, &first, &second),
                     Type::Identifier(first_type) => {
                         // Get the struct definitions.
-                        let first_type = self.symbol_table.lookup_struct(first_type.name).unwrap();
-                        self.ternary_struct(first_type, &input.condition, &first, &second)
+                        // Note that enums are lowered into structs.
+                        let first_type = match self.symbol_table.lookup_struct(first_type.name) {
+                            Some(struct_) => struct_.clone(),
+                            None => self.symbol_table.lookup_enum(first_type.name).unwrap().to_struct(),
+                        };
+                        self.ternary_struct(&first_type, &input.condition, &first, &second)
                     }
                     Type::Tuple(first_type) => self.ternary_tuple(first_type, &input.condition, &first, &second),
                     _ => {
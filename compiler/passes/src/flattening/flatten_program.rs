@@ -16,9 +16,35 @@
 
 use crate::Flattener;
 
-use leo_ast::{Finalize, Function, ProgramReconstructor, StatementReconstructor};
+use leo_ast::{Finalize, Function, ProgramReconstructor, ProgramScope, Statement, StatementReconstructor};
 
 impl ProgramReconstructor for Flattener<'_> {
+    /// Flattens the functions of the program scope, lowering its enums into structs.
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        ProgramScope {
+            program_id: input.program_id,
+            structs: input
+                .structs
+                .into_iter()
+                .map(|(i, s)| (i, self.reconstruct_struct(s)))
+                .chain(input.enums.into_iter().map(|(i, e)| (i, e.to_struct())))
+                .collect(),
+            enums: Vec::new(),
+            impls: input.impls,
+            mappings: input.mappings.into_iter().map(|(i, m)| (i, self.reconstruct_mapping(m))).collect(),
+            functions: input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect(),
+            consts: input
+                .consts
+                .into_iter()
+                .map(|(i, c)| match self.reconstruct_const(c) {
+                    (Statement::Const(declaration), _) => (i, declaration),
+                    _ => unreachable!("`reconstruct_const` can only return `Statement::Const`"),
+                })
+                .collect(),
+            span: input.span,
+        }
+    }
+
     /// Flattens a function's body and finalize block, if it exists.
     fn reconstruct_function(&mut self, function: Function) -> Function {
         // First, flatten the finalize block. This allows us to initialize self.finalizes correctly.
@@ -57,6 +83,7 @@ impl ProgramReconstructor for Flattener<'_> {
             annotations: function.annotations,
             variant: function.variant,
             identifier: function.identifier,
+            generic_parameters: function.generic_parameters,
             input: function.input,
             output: function.output,
             output_type: function.output_type,
@@ -29,6 +29,7 @@ use leo_ast::{
     Expression,
     ExpressionReconstructor,
     IterationStatement,
+    MatchStatement,
     Node,
     ReturnStatement,
     Statement,
@@ -266,6 +267,11 @@ impl StatementReconstructor for Flattener<'_> {
         unreachable!("`IterationStatement`s should not be in the AST at this phase of compilation.");
     }
 
+    /// Static single assignment lowers `MatchStatement`s into `ConditionalStatement`s, so that the variables written in their arms get phi functions.
+    fn reconstruct_match(&mut self, _input: MatchStatement) -> (Statement, Self::AdditionalOutput) {
+        unreachable!("`MatchStatement`s should not be in the AST at this phase of compilation.");
+    }
+
     /// Transforms a return statement into an empty block statement.
     /// Stores the arguments to the return statement, which are later folded into a single return statement at the end of the function.
     fn reconstruct_return(&mut self, input: ReturnStatement) -> (Statement, Self::AdditionalOutput) {
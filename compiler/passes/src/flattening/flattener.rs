@@ -24,8 +24,10 @@ use leo_ast::{
     BinaryExpression,
     BinaryOperation,
     Block,
+    Enum,
     Expression,
     ExpressionReconstructor,
+    GroupLiteral,
     Identifier,
     IntegerType,
     Literal,
@@ -33,6 +35,7 @@ use leo_ast::{
     MemberAccess,
     Node,
     NodeBuilder,
+    NodeID,
     NonNegativeNumber,
     ReturnStatement,
     Statement,
@@ -46,6 +49,7 @@ use leo_ast::{
     Type,
     UnitExpression,
 };
+use leo_span::{sym, Symbol};
 
 pub struct Flattener<'a> {
     /// The symbol table associated with the program.
@@ -382,6 +386,176 @@ impl<'a> Flattener<'a> {
         (Expression::Identifier(identifier), statements)
     }
 
+    /// Returns the definition of the enum with type `type_`, if `type_` is an enum type.
+    pub(crate) fn lookup_enum_type(&self, type_: &Type) -> Option<Enum> {
+        match type_ {
+            Type::Identifier(identifier) => self.symbol_table.lookup_enum(identifier.name).cloned(),
+            _ => None,
+        }
+    }
+
+    /// Lowers the variant `variant` of an enum, holding `arguments`, into an instance of the struct the enum is lowered into.
+    /// The payload members of the other variants are set to zero.
+    /// For example, `Shape::Circle(r)` is lowered into `Shape { tag: 1u8, Circle_0: r, Rectangle_0: 0u32, Rectangle_1: 0u32 }`.
+    pub(crate) fn enum_variant(
+        &mut self,
+        enum_: &Enum,
+        variant: Symbol,
+        arguments: Vec<Expression>,
+        id: NodeID,
+    ) -> (Expression, Vec<Statement>) {
+        // Initialize a vector to accumulate any statements generated.
+        let mut statements = Vec::new();
+
+        // Note that type checking guarantees that the variant exists and that there is an argument for each value in its payload.
+        let tag = enum_.tag(variant).unwrap();
+        let payload_members: Vec<Symbol> =
+            (0..arguments.len()).map(|index| Enum::payload_member(variant, index)).collect();
+        let mut arguments = arguments.into_iter();
+
+        let members = enum_
+            .to_struct()
+            .members
+            .into_iter()
+            .map(|Member { identifier, type_, .. }| {
+                let expression = if identifier.name == sym::tag {
+                    let literal = Literal::Integer(
+                        IntegerType::U8,
+                        tag.to_string(),
+                        Default::default(),
+                        self.node_builder.next_id(),
+                    );
+                    self.type_table.insert(literal.id(), type_);
+                    Expression::Literal(literal)
+                } else if payload_members.contains(&identifier.name) {
+                    arguments.next().unwrap()
+                } else {
+                    let (expression, stmts) = self.zero_value(&type_);
+                    statements.extend(stmts);
+                    expression
+                };
+
+                StructVariableInitializer {
+                    identifier,
+                    expression: Some(expression),
+                    span: Default::default(),
+                    id: self.node_builder.next_id(),
+                }
+            })
+            .collect();
+
+        // Note that the type of `id` is the type of the enum, which is the type of the struct it is lowered into.
+        let (expr, stmts) = self.reconstruct_struct_init(StructExpression {
+            name: enum_.identifier,
+            members,
+            span: Default::default(),
+            id,
+        });
+
+        // Accumulate any statements generated.
+        statements.extend(stmts);
+
+        (expr, statements)
+    }
+
+    /// Constructs the zero value of a type held by the payload of an enum variant.
+    /// Values of composite types are assigned to new variables.
+    fn zero_value(&mut self, type_: &Type) -> (Expression, Vec<Statement>) {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, type_.clone());
+
+        let literal = match type_ {
+            Type::Address => Literal::Address(
+                "aleo1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq3ljyzc".to_string(),
+                Default::default(),
+                id,
+            ),
+            Type::Boolean => Literal::Boolean(false, Default::default(), id),
+            Type::Field => Literal::Field("0".to_string(), Default::default(), id),
+            Type::Group => Literal::Group(Box::new(GroupLiteral::Single("0".to_string(), Default::default(), id))),
+            Type::Integer(integer_type) => Literal::Integer(*integer_type, "0".to_string(), Default::default(), id),
+            Type::Scalar => Literal::Scalar("0".to_string(), Default::default(), id),
+            Type::Array(array_type) => {
+                let mut statements = Vec::new();
+                let elements = (0..array_type.length())
+                    .map(|_| {
+                        let (element, stmts) = self.zero_value(array_type.element_type());
+                        statements.extend(stmts);
+                        element
+                    })
+                    .collect();
+
+                let (identifier, statement) = self.unique_simple_assign_statement(Expression::Array(ArrayExpression {
+                    elements,
+                    span: Default::default(),
+                    id,
+                }));
+                statements.push(statement);
+
+                return (Expression::Identifier(identifier), statements);
+            }
+            Type::Identifier(identifier) => {
+                // Note that type checking guarantees that the type is either a struct or an enum.
+                let (expr, mut statements) = match self.symbol_table.lookup_struct(identifier.name) {
+                    Some(struct_) => {
+                        let mut statements = Vec::new();
+                        let members = struct_
+                            .members
+                            .iter()
+                            .map(|Member { identifier, type_, .. }| {
+                                let (expression, stmts) = self.zero_value(type_);
+                                statements.extend(stmts);
+                                StructVariableInitializer {
+                                    identifier: *identifier,
+                                    expression: Some(expression),
+                                    span: Default::default(),
+                                    id: self.node_builder.next_id(),
+                                }
+                            })
+                            .collect();
+
+                        let (expr, stmts) = self.reconstruct_struct_init(StructExpression {
+                            name: struct_.identifier,
+                            members,
+                            span: Default::default(),
+                            id,
+                        });
+                        statements.extend(stmts);
+
+                        (expr, statements)
+                    }
+                    None => {
+                        // The zero value of an enum is its first variant, holding zero values.
+                        let enum_ = self.symbol_table.lookup_enum(identifier.name).unwrap().clone();
+                        let mut statements = Vec::new();
+                        let arguments = enum_.variants[0]
+                            .payload
+                            .iter()
+                            .map(|type_| {
+                                let (argument, stmts) = self.zero_value(type_);
+                                statements.extend(stmts);
+                                argument
+                            })
+                            .collect();
+
+                        let (expr, stmts) = self.enum_variant(&enum_, enum_.variants[0].name(), arguments, id);
+                        statements.extend(stmts);
+
+                        (expr, statements)
+                    }
+                };
+
+                let (identifier, statement) = self.unique_simple_assign_statement(expr);
+                statements.push(statement);
+
+                return (Expression::Identifier(identifier), statements);
+            }
+            _ => unreachable!("Type checking guarantees that the payload of an enum variant is a plaintext type."),
+        };
+
+        (Expression::Literal(literal), Default::default())
+    }
+
     pub(crate) fn ternary_struct(
         &mut self,
         struct_: &Struct,
@@ -51,6 +51,8 @@ impl ProgramReconstructor for FunctionInliner<'_> {
         ProgramScope {
             program_id: input.program_id,
             structs: input.structs,
+            enums: input.enums,
+            impls: input.impls,
             mappings: input.mappings,
             functions,
             consts: input.consts,
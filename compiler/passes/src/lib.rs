@@ -23,12 +23,18 @@ pub use code_generation::*;
 pub mod common;
 pub use common::*;
 
+pub mod constant_folding;
+pub use constant_folding::*;
+
 pub mod dead_code_elimination;
 pub use dead_code_elimination::*;
 
 pub mod destructuring;
 pub use destructuring::*;
 
+pub mod dynamic_indexing;
+pub use dynamic_indexing::*;
+
 pub mod flattening;
 pub use flattening::*;
 
@@ -38,6 +44,12 @@ pub use function_inlining::*;
 pub mod loop_unrolling;
 pub use self::loop_unrolling::*;
 
+pub mod method_desugaring;
+pub use self::method_desugaring::*;
+
+pub mod monomorphization;
+pub use self::monomorphization::*;
+
 pub mod pass;
 pub use self::pass::*;
 
@@ -25,9 +25,16 @@ impl ExpressionReconstructor for Unroller<'_> {
     fn reconstruct_array_access(&mut self, input: ArrayAccess) -> (Expression, Self::AdditionalOutput) {
         // Reconstruct the index.
         let index = self.reconstruct_expression(*input.index).0;
-        // If the index is not a literal, then emit an error.
-        if !matches!(index, Expression::Literal(_)) {
-            self.emit_err(LoopUnrollerError::variable_array_access(input.span));
+        // If the index is a literal, then check that it is within the bounds of the array.
+        // Note that an index that is not a literal is checked when the access is lowered by the dynamic indexing pass.
+        if let Expression::Literal(Literal::Integer(_, value, ..)) = &index {
+            if let (Some(Type::Array(array_type)), Ok(value)) =
+                (self.type_table.get(&input.array.id()), value.replace('_', "").parse::<usize>())
+            {
+                if value >= array_type.length() {
+                    self.emit_err(LoopUnrollerError::array_index_out_of_bounds(value, array_type.length(), input.span));
+                }
+            }
         }
 
         (
@@ -48,4 +55,13 @@ impl ExpressionReconstructor for Unroller<'_> {
         }
         (Expression::Identifier(input), Default::default())
     }
+
+    fn reconstruct_literal(&mut self, input: Literal) -> (Expression, Self::AdditionalOutput) {
+        // Give an unsuffixed literal the type inferred for it during type checking, so that later passes see its type.
+        let literal = match self.type_table.get(&input.id()) {
+            Some(type_) => input.with_type(&type_),
+            None => input,
+        };
+        (Expression::Literal(literal), Default::default())
+    }
 }
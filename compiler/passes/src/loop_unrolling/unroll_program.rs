@@ -27,6 +27,8 @@ impl ProgramReconstructor for Unroller<'_> {
         ProgramScope {
             program_id: input.program_id,
             structs: input.structs,
+            enums: input.enums,
+            impls: input.impls,
             mappings: input.mappings,
             functions: input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect(),
             consts: Vec::new(),
@@ -71,6 +73,7 @@ impl ProgramReconstructor for Unroller<'_> {
             annotations: function.annotations,
             variant: function.variant,
             identifier: function.identifier,
+            generic_parameters: function.generic_parameters,
             input: function.input,
             output: function.output,
             output_type: function.output_type,
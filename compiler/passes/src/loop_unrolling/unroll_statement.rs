@@ -50,6 +50,19 @@ impl StatementReconstructor for Unroller<'_> {
         (block, Default::default())
     }
 
+    fn reconstruct_assign(&mut self, input: AssignStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Assign(Box::new(AssignStatement {
+                // Reconstruct the place, so that the indices of the array elements it assigns to are resolved.
+                place: self.reconstruct_expression(input.place).0,
+                value: self.reconstruct_expression(input.value).0,
+                span: input.span,
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_const(&mut self, input: ConstDeclaration) -> (Statement, Self::AdditionalOutput) {
         // Reconstruct the RHS expression to allow for constant propagation
         let reconstructed_value_expression = self.reconstruct_expression(input.value.clone()).0;
@@ -75,6 +88,10 @@ impl StatementReconstructor for Unroller<'_> {
     }
 
     fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
+        // If the type of the definition was not specified, then use the type inferred during type checking.
+        // Note that type checking guarantees that the type of every definition is either specified or inferred.
+        let type_ = input.type_.unwrap_or_else(|| self.type_table.get(&input.place.id()).unwrap());
+
         // Helper function to add  variables to symbol table
         let insert_variable = |symbol: Symbol, type_: Type, span: Span| {
             if let Err(err) = self.symbol_table.borrow_mut().insert_variable(symbol, VariableSymbol {
@@ -90,10 +107,10 @@ impl StatementReconstructor for Unroller<'_> {
         if self.is_unrolling {
             match &input.place {
                 Expression::Identifier(identifier) => {
-                    insert_variable(identifier.name, input.type_.clone(), input.span);
+                    insert_variable(identifier.name, type_.clone(), input.span);
                 }
                 Expression::Tuple(tuple_expression) => {
-                    let tuple_type = match input.type_ {
+                    let tuple_type = match type_ {
                         Type::Tuple(ref tuple_type) => tuple_type,
                         _ => unreachable!(
                             "Type checking guarantees that if the lhs is a tuple, its associated type is also a tuple."
@@ -104,7 +121,7 @@ impl StatementReconstructor for Unroller<'_> {
                             Expression::Identifier(identifier) => identifier,
                             _ => unreachable!("Type checking guarantees that if the lhs is a tuple, all of its elements are identifiers.")
                         };
-                        insert_variable(identifier.name, input.type_.clone(), input.span);
+                        insert_variable(identifier.name, type_.clone(), input.span);
                     });
                 }
                 _ => unreachable!(
@@ -118,7 +135,7 @@ impl StatementReconstructor for Unroller<'_> {
             Statement::Definition(DefinitionStatement {
                 declaration_type: input.declaration_type,
                 place: input.place,
-                type_: input.type_,
+                type_: Some(type_),
                 value: self.reconstruct_expression(input.value).0,
                 span: input.span,
                 id: input.id,
@@ -194,4 +211,72 @@ impl StatementReconstructor for Unroller<'_> {
             Default::default(),
         )
     }
+
+    fn reconstruct_while(&mut self, input: WhileStatement) -> (Statement, Self::AdditionalOutput) {
+        // A loop `while condition bound n { .. }` is unrolled as
+        // `for i: u32 in 0u32..n { if !condition { break; } .. }`.
+        let exit = Statement::Conditional(ConditionalStatement {
+            condition: self.not(input.condition.clone()),
+            then: Block {
+                statements: vec![Statement::Break(BreakStatement {
+                    span: input.span,
+                    id: self.node_builder.next_id(),
+                })],
+                span: input.span,
+                id: self.node_builder.next_id(),
+            },
+            otherwise: None,
+            span: input.span,
+            id: self.node_builder.next_id(),
+        });
+        let start_id = self.node_builder.next_id();
+        self.type_table.insert(start_id, Type::Integer(IntegerType::U32));
+        let iteration = IterationStatement {
+            variable: Identifier::new(Symbol::intern("$iteration"), self.node_builder.next_id()),
+            type_: Type::Integer(IntegerType::U32),
+            start: Literal(leo_ast::Literal::Integer(IntegerType::U32, "0".to_string(), input.span, start_id)),
+            start_value: Default::default(),
+            stop: input.bound,
+            stop_value: Default::default(),
+            inclusive: false,
+            block: Block {
+                statements: std::iter::once(exit).chain(input.block.statements).collect(),
+                span: input.block.span,
+                id: input.block.id,
+            },
+            span: input.span,
+            id: input.id,
+        };
+
+        // Check that the loop has ended after its last iteration, i.e. `if $running { assert(!condition); }`.
+        let statement = match self.reconstruct_iteration(iteration).0 {
+            Statement::Block(mut block) => {
+                let condition = self.reconstruct_expression(input.condition).0;
+                let check = Statement::Assert(AssertStatement {
+                    variant: AssertVariant::Assert(self.not(condition)),
+                    span: input.span,
+                    id: self.node_builder.next_id(),
+                });
+                block.statements.push(self.guard(Self::running_flag(input.id), vec![check], input.span));
+                Statement::Block(block)
+            }
+            statement => statement,
+        };
+
+        (statement, Default::default())
+    }
+}
+
+impl Unroller<'_> {
+    /// Returns the negation of the boolean `expression`.
+    fn not(&self, expression: Expression) -> Expression {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, Type::Boolean);
+        Expression::Unary(UnaryExpression {
+            span: expression.span(),
+            receiver: Box::new(expression),
+            op: UnaryOperation::Not,
+            id,
+        })
+    }
 }
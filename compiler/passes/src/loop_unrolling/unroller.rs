@@ -15,17 +15,26 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use leo_ast::{
+    AssignStatement,
     Block,
+    ConditionalStatement,
+    DeclarationType,
+    DefinitionStatement,
     Expression,
+    Identifier,
     IntegerType,
     IterationStatement,
     Literal,
+    MatchArm,
+    MatchStatement,
     NodeBuilder,
+    NodeID,
     Statement,
     StatementReconstructor,
     Type,
     Value,
 };
+use leo_span::{Span, Symbol};
 use std::cell::RefCell;
 
 use leo_errors::{emitter::Handler, loop_unroller::LoopUnrollerError};
@@ -37,6 +46,8 @@ use crate::{
     RangeIterator,
     SymbolTable,
     TypeTable,
+    VariableSymbol,
+    VariableType,
 };
 
 pub struct Unroller<'a> {
@@ -154,22 +165,41 @@ impl<'a> Unroller<'a> {
         self.symbol_table.borrow_mut().scopes.clear();
         self.symbol_table.borrow_mut().scope_index = 0;
 
+        // If the loop body contains a `break` or `continue`, then guard the statements that may be skipped.
+        let exits_early = Self::exits_early(&input.block.statements);
+        let mut input = input;
+        if exits_early {
+            input.block = self.guard_loop_body(input.id, input.block);
+        }
+
+        // Create a new block per iteration.
+        let mut statements: Vec<Statement> = match input.inclusive {
+            true => {
+                let iter = RangeIterator::new(start, stop, Clusivity::Inclusive);
+                iter.map(|iteration_count| self.unroll_single_iteration(&input, iteration_count)).collect()
+            }
+            false => {
+                let iter = RangeIterator::new(start, stop, Clusivity::Exclusive);
+                iter.map(|iteration_count| self.unroll_single_iteration(&input, iteration_count)).collect()
+            }
+        };
+
+        // The iterations are preceded by the definition of the flag that records whether the loop is still running.
+        if exits_early {
+            let running = Self::running_flag(input.id);
+            if let Err(err) = self.symbol_table.borrow_mut().insert_variable(running, VariableSymbol {
+                type_: Type::Boolean,
+                span: input.span,
+                declaration: VariableType::Mut,
+            }) {
+                self.handler.emit_err(err);
+            }
+            let value = self.boolean(true, input.span);
+            statements.insert(0, self.define_flag(running, value, input.span));
+        }
+
         // Create a block statement to replace the iteration statement.
-        // Creates a new block per iteration inside the outer block statement.
-        let iter_blocks = Statement::Block(Block {
-            span: input.span,
-            statements: match input.inclusive {
-                true => {
-                    let iter = RangeIterator::new(start, stop, Clusivity::Inclusive);
-                    iter.map(|iteration_count| self.unroll_single_iteration(&input, iteration_count)).collect()
-                }
-                false => {
-                    let iter = RangeIterator::new(start, stop, Clusivity::Exclusive);
-                    iter.map(|iteration_count| self.unroll_single_iteration(&input, iteration_count)).collect()
-                }
-            },
-            id: input.id,
-        });
+        let iter_blocks = Statement::Block(Block { span: input.span, statements, id: input.id });
 
         // Exit the scope of the loop body.
         self.exit_scope(previous_scope_index);
@@ -259,4 +289,169 @@ impl<'a> Unroller<'a> {
 
         block
     }
+
+    /// Returns the name of the flag that records whether the loop with the given ID is still running,
+    /// i.e. whether none of its iterations has reached a `break`.
+    pub(crate) fn running_flag(id: NodeID) -> Symbol {
+        Symbol::intern(&format!("$running${id}"))
+    }
+
+    /// Returns the name of the flag that records whether the current iteration of the loop with the given ID
+    /// is still active, i.e. whether it has not reached a `break` or `continue`.
+    fn active_flag(id: NodeID) -> Symbol {
+        Symbol::intern(&format!("$active${id}"))
+    }
+
+    /// Returns `true` if the statements may exit the current iteration of the enclosing loop
+    /// with a `break` or `continue`.
+    /// Note that a `break` or `continue` in a nested loop only exits that loop.
+    fn exits_early(statements: &[Statement]) -> bool {
+        statements.iter().any(|statement| match statement {
+            Statement::Break(_) | Statement::Continue(_) => true,
+            Statement::Block(block) => Self::exits_early(&block.statements),
+            Statement::Conditional(conditional) => {
+                Self::exits_early(&conditional.then.statements)
+                    || conditional
+                        .otherwise
+                        .as_ref()
+                        .map_or(false, |otherwise| Self::exits_early(std::slice::from_ref(&**otherwise)))
+            }
+            Statement::Match(match_) => match_.arms.iter().any(|arm| Self::exits_early(&arm.block.statements)),
+            _ => false,
+        })
+    }
+
+    /// Rewrites the body of a loop that contains a `break` or `continue`,
+    /// so that an iteration only runs while the loop is running.
+    /// For example, the body `{ a; if c { break; } b; }` becomes
+    /// `{ let $active = $running; if $active { a; if c { $running = false; $active = false; } if $active { b; } } }`.
+    /// Since the flags are ordinary variables, the conditionals are later lowered like any other.
+    fn guard_loop_body(&self, id: NodeID, block: Block) -> Block {
+        let (running, active) = (Self::running_flag(id), Self::active_flag(id));
+        let value = self.flag(running, block.span);
+        let statements = self.guard_statements(block.statements, running, active, block.span);
+        Block {
+            statements: vec![self.define_flag(active, value, block.span), self.guard(active, statements, block.span)],
+            span: block.span,
+            id: block.id,
+        }
+    }
+
+    /// Guards the statements that follow a statement that may exit the iteration early.
+    fn guard_statements(
+        &self,
+        statements: Vec<Statement>,
+        running: Symbol,
+        active: Symbol,
+        span: Span,
+    ) -> Vec<Statement> {
+        let mut guarded = Vec::with_capacity(statements.len());
+        let mut statements = statements.into_iter();
+        while let Some(statement) = statements.next() {
+            let exits_early = Self::exits_early(std::slice::from_ref(&statement));
+            guarded.push(self.guard_statement(statement, running, active));
+            if exits_early {
+                let rest = statements.collect::<Vec<_>>();
+                if !rest.is_empty() {
+                    let rest = self.guard_statements(rest, running, active, span);
+                    guarded.push(self.guard(active, rest, span));
+                }
+                break;
+            }
+        }
+        guarded
+    }
+
+    /// Replaces the `break` and `continue` statements in `statement` with updates to the flags of the loop.
+    fn guard_statement(&self, statement: Statement, running: Symbol, active: Symbol) -> Statement {
+        match statement {
+            Statement::Break(input) => Statement::Block(Block {
+                statements: vec![self.set_flag(running, false, input.span), self.set_flag(active, false, input.span)],
+                span: input.span,
+                id: input.id,
+            }),
+            Statement::Continue(input) => self.set_flag(active, false, input.span),
+            Statement::Block(block) => Statement::Block(Block {
+                statements: self.guard_statements(block.statements, running, active, block.span),
+                ..block
+            }),
+            Statement::Conditional(conditional) => Statement::Conditional(ConditionalStatement {
+                then: Block {
+                    statements: self.guard_statements(
+                        conditional.then.statements,
+                        running,
+                        active,
+                        conditional.then.span,
+                    ),
+                    ..conditional.then
+                },
+                otherwise: conditional
+                    .otherwise
+                    .map(|otherwise| Box::new(self.guard_statement(*otherwise, running, active))),
+                ..conditional
+            }),
+            Statement::Match(match_) => Statement::Match(MatchStatement {
+                arms: match_
+                    .arms
+                    .into_iter()
+                    .map(|arm| MatchArm {
+                        block: Block {
+                            statements: self.guard_statements(arm.block.statements, running, active, arm.block.span),
+                            ..arm.block
+                        },
+                        ..arm
+                    })
+                    .collect(),
+                ..match_
+            }),
+            statement => statement,
+        }
+    }
+
+    /// Returns the boolean literal `value`.
+    fn boolean(&self, value: bool, span: Span) -> Expression {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, Type::Boolean);
+        Expression::Literal(Literal::Boolean(value, span, id))
+    }
+
+    /// Returns a reference to the flag `name`.
+    pub(crate) fn flag(&self, name: Symbol, span: Span) -> Expression {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, Type::Boolean);
+        Expression::Identifier(Identifier { name, span, id })
+    }
+
+    /// Returns the statement `let name: bool = value;`.
+    fn define_flag(&self, name: Symbol, value: Expression, span: Span) -> Statement {
+        Statement::Definition(DefinitionStatement {
+            declaration_type: DeclarationType::Let,
+            place: self.flag(name, span),
+            type_: Some(Type::Boolean),
+            value,
+            span,
+            id: self.node_builder.next_id(),
+        })
+    }
+
+    /// Returns the statement `name = value;`.
+    fn set_flag(&self, name: Symbol, value: bool, span: Span) -> Statement {
+        Statement::Assign(Box::new(AssignStatement {
+            place: self.flag(name, span),
+            value: self.boolean(value, span),
+            span,
+            id: self.node_builder.next_id(),
+        }))
+    }
+
+    /// Returns the statement `if name { statements }`.
+    pub(crate) fn guard(&self, name: Symbol, statements: Vec<Statement>, span: Span) -> Statement {
+        Statement::Conditional(ConditionalStatement {
+            condition: self.flag(name, span),
+            then: Block { statements, span, id: self.node_builder.next_id() },
+            otherwise: None,
+            span,
+            id: self.node_builder.next_id(),
+        })
+    }
 }
@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::MethodDesugarer;
+
+use leo_ast::*;
+
+impl ExpressionReconstructor for MethodDesugarer<'_> {
+    type AdditionalOutput = ();
+
+    fn reconstruct_associated_constant(&mut self, input: AssociatedConstant) -> (Expression, Self::AdditionalOutput) {
+        // Replace a constant of an `impl` block, e.g. `Token::MAX`, with the constant it is declared as.
+        match self.item_name(&input.ty, &input.name) {
+            Some(name) => {
+                (Expression::Identifier(Identifier { name, span: input.span, id: input.id }), Default::default())
+            }
+            None => (Expression::Access(AccessExpression::AssociatedConstant(input)), Default::default()),
+        }
+    }
+
+    fn reconstruct_associated_function(&mut self, input: AssociatedFunction) -> (Expression, Self::AdditionalOutput) {
+        let arguments = input.arguments.into_iter().map(|arg| self.reconstruct_expression(arg).0).collect();
+        match self.item_name(&input.ty, &input.name) {
+            // Replace a call to a function of an `impl` block, e.g. `Token::new(1u64)`, with a call to the function it is declared as.
+            Some(name) if self.symbol_table.lookup_fn_symbol(name).is_some() => (
+                Expression::Call(CallExpression {
+                    function: Box::new(Expression::Identifier(Identifier {
+                        name,
+                        span: input.name.span,
+                        id: input.name.id,
+                    })),
+                    arguments,
+                    external: None,
+                    span: input.span,
+                    id: input.id,
+                }),
+                Default::default(),
+            ),
+            _ => (
+                Expression::Access(AccessExpression::AssociatedFunction(AssociatedFunction { arguments, ..input })),
+                Default::default(),
+            ),
+        }
+    }
+
+    fn reconstruct_method_call(&mut self, input: MethodCall) -> (Expression, Self::AdditionalOutput) {
+        // Look up the type of the receiver, which determines the function that is called.
+        let receiver_type = self.type_table.get(&input.receiver.id());
+        let name = match receiver_type.and_then(|type_| self.item_name(&type_, &input.name)) {
+            Some(name) => name,
+            None => unreachable!("Type checking guarantees that the receiver of a method call is a struct."),
+        };
+
+        // Pass the receiver as the first argument of the call.
+        let arguments = std::iter::once(*input.receiver)
+            .chain(input.arguments)
+            .map(|argument| self.reconstruct_expression(argument).0)
+            .collect();
+
+        (
+            Expression::Call(CallExpression {
+                function: Box::new(Expression::Identifier(Identifier {
+                    name,
+                    span: input.name.span,
+                    id: input.name.id,
+                })),
+                arguments,
+                external: None,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+}
@@ -0,0 +1,45 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{SymbolTable, TypeTable};
+
+use leo_ast::{Identifier, Impl, Type};
+use leo_span::Symbol;
+
+pub struct MethodDesugarer<'a> {
+    /// The symbol table for the program.
+    pub(crate) symbol_table: &'a SymbolTable,
+    /// A mapping between node IDs and their types.
+    pub(crate) type_table: &'a TypeTable,
+}
+
+impl<'a> MethodDesugarer<'a> {
+    /// Initializes a new `MethodDesugarer`.
+    pub fn new(symbol_table: &'a SymbolTable, type_table: &'a TypeTable) -> Self {
+        Self { symbol_table, type_table }
+    }
+
+    /// Returns the name that the item `name` of the `impl` block of `type_` is declared as,
+    /// if `type_` is a struct or record.
+    pub(crate) fn item_name(&self, type_: &Type, name: &Identifier) -> Option<Symbol> {
+        match type_ {
+            Type::Identifier(identifier) if self.symbol_table.lookup_struct(identifier.name).is_some() => {
+                Some(Impl::item_name(identifier.name, name.name))
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The method desugaring pass rewrites the uses of the items of `impl` blocks into uses of the functions and constants
+//! that the items are declared as, so that the passes that follow do not need to know about methods.
+//! The pass runs after type checking, which resolves the receiver of each method call.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! struct Token {
+//!     owner: address,
+//!     amount: u64,
+//! }
+//!
+//! impl Token {
+//!     const MAX: u64 = 1000u64;
+//!
+//!     inline total(self, bonus: u64) -> u64 {
+//!         return self.amount + bonus;
+//!     }
+//! }
+//!
+//! transition main(t: Token) -> u64 {
+//!     return t.total(Token::MAX);
+//! }
+//! ```
+//!
+//! The method desugaring pass produces the following code.
+//! ```leo
+//! struct Token {
+//!     owner: address,
+//!     amount: u64,
+//! }
+//!
+//! const Token__MAX: u64 = 1000u64;
+//!
+//! inline Token__total(self: Token, bonus: u64) -> u64 {
+//!     return self.amount + bonus;
+//! }
+//!
+//! transition main(t: Token) -> u64 {
+//!     return Token__total(t, Token__MAX);
+//! }
+//! ```
+//!
+//! Note that the items of the `impl` blocks are declared as functions and constants when the program is parsed.
+
+mod desugar_expression;
+
+mod desugar_program;
+
+mod desugar_statement;
+
+pub mod desugarer;
+pub use desugarer::*;
+
+use crate::{Pass, SymbolTable, TypeTable};
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for MethodDesugarer<'a> {
+    type Input = (Ast, &'a SymbolTable, &'a TypeTable);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, symbol_table, type_table): Self::Input) -> Self::Output {
+        let mut reconstructor = MethodDesugarer::new(symbol_table, type_table);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The monomorphization pass stamps out a concrete copy of each generic function and struct
+//! for every list of type arguments it is used with, and removes the generic definitions.
+//! The pass runs after type checking, which infers the type arguments and checks them against the bounds.
+//! Since the program no longer contains generics afterwards, the passes that follow do not need to know about them.
+//!
+//! The const parameters of a copy are replaced by their values, both in array lengths and in expressions,
+//! so that the loops bounded by a const parameter have constant bounds by the time the loops are unrolled.
+//!
+//! The copies are named after the generic definition, followed by `__` and the index of the copy.
+//! Consider the following Leo code.
+//! ```leo
+//! struct Pair<T> {
+//!     first: T,
+//!     second: T,
+//! }
+//!
+//! inline max<T: Int>(a: T, b: T) -> T {
+//!     return a > b ? a : b;
+//! }
+//!
+//! transition main(a: u8, b: u8, c: u32) -> Pair<u32> {
+//!     let d: u8 = max(a, b);
+//!     return Pair { first: max(c, d as u32), second: c };
+//! }
+//! ```
+//!
+//! The monomorphization pass produces the following code.
+//! ```leo
+//! struct Pair__0 {
+//!     first: u32,
+//!     second: u32,
+//! }
+//!
+//! inline max__0(a: u8, b: u8) -> u8 {
+//!     return a > b ? a : b;
+//! }
+//!
+//! inline max__1(a: u32, b: u32) -> u32 {
+//!     return a > b ? a : b;
+//! }
+//!
+//! transition main(a: u8, b: u8, c: u32) -> Pair__0 {
+//!     let d: u8 = max__0(a, b);
+//!     return Pair__0 { first: max__1(c, d as u32), second: c };
+//! }
+//! ```
+//!
+//! The expressions of each copy are given new node IDs, so that the type checker can record their concrete types.
+
+mod monomorphize_expression;
+
+mod monomorphize_program;
+
+mod monomorphize_statement;
+
+pub mod monomorphizer;
+pub use monomorphizer::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for Monomorphizer<'a> {
+    type Input = (Ast, &'a TypeTable, &'a NodeBuilder);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, type_table, node_builder): Self::Input) -> Self::Output {
+        let mut reconstructor = Monomorphizer::new(type_table, node_builder);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Monomorphizer;
+
+use leo_ast::*;
+
+use indexmap::IndexMap;
+use itertools::Itertools;
+
+impl ExpressionReconstructor for Monomorphizer<'_> {
+    type AdditionalOutput = ();
+
+    fn reconstruct_expression(&mut self, input: Expression) -> (Expression, Self::AdditionalOutput) {
+        let (mut expression, output) = match input {
+            Expression::Access(access) => self.reconstruct_access(access),
+            Expression::Array(array) => self.reconstruct_array(array),
+            Expression::Binary(binary) => self.reconstruct_binary(binary),
+            Expression::Call(call) => self.reconstruct_call(call),
+            Expression::Cast(cast) => self.reconstruct_cast(cast),
+            Expression::Struct(struct_) => self.reconstruct_struct_init(struct_),
+            Expression::Err(err) => self.reconstruct_err(err),
+            Expression::Identifier(identifier) => self.reconstruct_identifier(identifier),
+            Expression::Literal(value) => self.reconstruct_literal(value),
+            Expression::Ternary(ternary) => self.reconstruct_ternary(ternary),
+            Expression::Tuple(tuple) => self.reconstruct_tuple(tuple),
+            Expression::Unary(unary) => self.reconstruct_unary(unary),
+            Expression::Unit(unit) => self.reconstruct_unit(unit),
+        };
+        // The expressions of a copy need their own node IDs, since their types differ from those of the other copies.
+        if self.is_instance {
+            expression.set_id(self.node_builder.next_id());
+        }
+        (expression, output)
+    }
+
+    fn reconstruct_identifier(&mut self, input: Identifier) -> (Expression, Self::AdditionalOutput) {
+        // Replace a const parameter with its value, so that the copy has constant loop bounds.
+        match self.type_arguments.get(&input.name) {
+            Some(GenericArgument::Const(ArrayLength::Constant(length))) => (
+                Expression::Literal(Literal::Integer(IntegerType::U32, length.to_string(), input.span, input.id)),
+                Default::default(),
+            ),
+            _ => (Expression::Identifier(input), Default::default()),
+        }
+    }
+
+    fn reconstruct_call(&mut self, input: CallExpression) -> (Expression, Self::AdditionalOutput) {
+        let function = match *input.function {
+            Expression::Identifier(identifier)
+                if input.external.is_none() && self.generic_functions.contains_key(&identifier.name) =>
+            {
+                let generic = self.generic_functions[&identifier.name].clone();
+
+                // Infer the type arguments from the types the type checker recorded for the arguments and the call.
+                let mut arguments = generic
+                    .generic_parameters
+                    .iter()
+                    .map(|parameter| (parameter.name(), None))
+                    .collect::<IndexMap<_, _>>();
+                for (parameter, argument) in generic.input.iter().zip_eq(input.arguments.iter()) {
+                    if let Some(actual) = self.concrete_type(argument.id()) {
+                        parameter.type_().infer(&actual, &mut arguments);
+                    }
+                }
+                if let Some(actual) = self.concrete_type(input.id) {
+                    generic.output_type.infer(&actual, &mut arguments);
+                }
+
+                // Note that the type checker guarantees that every argument can be inferred.
+                let arguments = arguments
+                    .into_values()
+                    .map(|argument| match argument.expect("arguments are inferred") {
+                        GenericArgument::Type(type_) => GenericArgument::Type(self.monomorphize_type(&type_)),
+                        length => length,
+                    })
+                    .collect();
+                Expression::Identifier(Identifier { name: self.instance(identifier.name, arguments), ..identifier })
+            }
+            function => function,
+        };
+
+        (
+            Expression::Call(CallExpression {
+                function: Box::new(self.reconstruct_expression(function).0),
+                arguments: input.arguments.into_iter().map(|arg| self.reconstruct_expression(arg).0).collect(),
+                external: input.external,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
+        // Replace the name of a generic struct with the name of the copy for the type the type checker recorded.
+        let name = match self.generic_structs.contains_key(&input.name.name) {
+            true => match self.concrete_type(input.id).map(|type_| self.monomorphize_type(&type_)) {
+                Some(Type::Identifier(identifier)) => Identifier { name: identifier.name, ..input.name },
+                _ => unreachable!("The type checker guarantees that a generic struct has type arguments."),
+            },
+            false => input.name,
+        };
+
+        (
+            Expression::Struct(StructExpression {
+                name,
+                members: input
+                    .members
+                    .into_iter()
+                    .map(|member| StructVariableInitializer {
+                        identifier: member.identifier,
+                        expression: match member.expression {
+                            Some(expression) => Some(self.reconstruct_expression(expression).0),
+                            None => Some(self.reconstruct_expression(Expression::Identifier(member.identifier)).0),
+                        },
+                        span: member.span,
+                        id: member.id,
+                    })
+                    .collect(),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+}
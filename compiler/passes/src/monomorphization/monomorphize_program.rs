@@ -0,0 +1,201 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Instance, Monomorphizer};
+
+use leo_ast::*;
+use leo_span::Symbol;
+
+impl ProgramReconstructor for Monomorphizer<'_> {
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        // Set the generic definitions aside, since they are only reconstructed through their copies.
+        let (generic_functions, functions): (Vec<_>, Vec<_>) =
+            input.functions.into_iter().partition(|(_, function)| function.is_generic());
+        let (generic_structs, structs): (Vec<_>, Vec<_>) =
+            input.structs.into_iter().partition(|(_, struct_)| struct_.is_generic());
+        self.generic_functions = generic_functions.into_iter().collect();
+        self.generic_structs = generic_structs.into_iter().collect();
+        self.names = self
+            .generic_functions
+            .keys()
+            .chain(self.generic_structs.keys())
+            .chain(functions.iter().map(|(name, _)| name))
+            .chain(structs.iter().map(|(name, _)| name))
+            .chain(input.enums.iter().map(|(name, _)| name))
+            .cloned()
+            .collect();
+        self.instances.clear();
+        self.reconstructed = 0;
+
+        let structs = structs.into_iter().map(|(i, c)| (i, self.reconstruct_struct(c))).collect::<Vec<_>>();
+        let enums = input.enums.into_iter().map(|(i, e)| (i, self.reconstruct_enum(e))).collect::<Vec<_>>();
+        let mappings =
+            input.mappings.into_iter().map(|(id, mapping)| (id, self.reconstruct_mapping(mapping))).collect::<Vec<_>>();
+        let functions = functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect::<Vec<_>>();
+
+        // Reconstruct the copies of the generic definitions, which may in turn use further copies.
+        let mut instance_structs = Vec::new();
+        let mut instance_functions = Vec::new();
+        while self.reconstructed < self.instances.len() {
+            let Instance { generic, arguments, name } = &self.instances[self.reconstructed];
+            let (generic, arguments, name) = (*generic, arguments.clone(), *name);
+            self.reconstructed += 1;
+
+            if let Some(function) = self.generic_functions.get(&generic).cloned() {
+                instance_functions.push((name, self.reconstruct_instance_function(function, arguments, name)));
+            } else if let Some(struct_) = self.generic_structs.get(&generic).cloned() {
+                instance_structs.push((name, self.reconstruct_instance_struct(struct_, arguments, name)));
+            }
+        }
+
+        ProgramScope {
+            program_id: input.program_id,
+            structs: instance_structs.into_iter().chain(structs).collect(),
+            enums: enums.into_iter().collect(),
+            impls: input.impls,
+            mappings: mappings.into_iter().collect(),
+            functions: instance_functions.into_iter().chain(functions).collect(),
+            consts: input
+                .consts
+                .into_iter()
+                .map(|(i, c)| match self.reconstruct_const(c) {
+                    (Statement::Const(declaration), _) => (i, declaration),
+                    _ => unreachable!("`reconstruct_const` can only return `Statement::Const`"),
+                })
+                .collect(),
+            span: input.span,
+        }
+    }
+
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        let input_types = input
+            .input
+            .into_iter()
+            .map(|input| match input {
+                Input::Internal(input) => {
+                    Input::Internal(FunctionInput { type_: self.monomorphize_type(&input.type_), ..input })
+                }
+                external => external,
+            })
+            .collect();
+        let output_types = input
+            .output
+            .into_iter()
+            .map(|output| match output {
+                Output::Internal(output) => {
+                    Output::Internal(FunctionOutput { type_: self.monomorphize_type(&output.type_), ..output })
+                }
+                external => external,
+            })
+            .collect();
+
+        Function {
+            annotations: input.annotations,
+            variant: input.variant,
+            identifier: input.identifier,
+            generic_parameters: input.generic_parameters,
+            input: input_types,
+            output: output_types,
+            output_type: self.monomorphize_type(&input.output_type),
+            block: self.reconstruct_block(input.block).0,
+            finalize: input.finalize.map(|finalize| Finalize {
+                identifier: finalize.identifier,
+                input: finalize.input,
+                output: finalize.output,
+                output_type: finalize.output_type,
+                block: self.reconstruct_block(finalize.block).0,
+                span: finalize.span,
+                id: finalize.id,
+            }),
+            span: input.span,
+            id: input.id,
+        }
+    }
+
+    fn reconstruct_struct(&mut self, input: Struct) -> Struct {
+        Struct {
+            members: input
+                .members
+                .into_iter()
+                .map(|member| Member { type_: self.monomorphize_type(&member.type_), ..member })
+                .collect(),
+            ..input
+        }
+    }
+
+    fn reconstruct_enum(&mut self, input: Enum) -> Enum {
+        Enum {
+            variants: input
+                .variants
+                .into_iter()
+                .map(|variant| EnumVariant {
+                    payload: variant.payload.iter().map(|type_| self.monomorphize_type(type_)).collect(),
+                    ..variant
+                })
+                .collect(),
+            ..input
+        }
+    }
+
+    fn reconstruct_mapping(&mut self, input: Mapping) -> Mapping {
+        Mapping {
+            key_type: self.monomorphize_type(&input.key_type),
+            value_type: self.monomorphize_type(&input.value_type),
+            ..input
+        }
+    }
+}
+
+impl Monomorphizer<'_> {
+    /// Reconstructs the copy of a generic function with the given arguments.
+    fn reconstruct_instance_function(
+        &mut self,
+        function: Function,
+        arguments: Vec<GenericArgument>,
+        name: Symbol,
+    ) -> Function {
+        self.type_arguments =
+            function.generic_parameters.iter().map(|parameter| parameter.name()).zip(arguments).collect();
+        self.is_instance = true;
+
+        let identifier = Identifier { name, span: function.identifier.span, id: self.node_builder.next_id() };
+        let id = self.node_builder.next_id();
+        let instance =
+            self.reconstruct_function(Function { identifier, generic_parameters: Vec::new(), id, ..function });
+
+        self.is_instance = false;
+        self.type_arguments.clear();
+        instance
+    }
+
+    /// Reconstructs the copy of a generic struct with the given type arguments.
+    fn reconstruct_instance_struct(
+        &mut self,
+        struct_: Struct,
+        arguments: Vec<GenericArgument>,
+        name: Symbol,
+    ) -> Struct {
+        self.type_arguments =
+            struct_.generic_parameters.iter().map(|parameter| parameter.name()).zip(arguments).collect();
+
+        let identifier = Identifier { name, span: struct_.identifier.span, id: self.node_builder.next_id() };
+        let id = self.node_builder.next_id();
+        let instance = self.reconstruct_struct(Struct { identifier, generic_parameters: Vec::new(), id, ..struct_ });
+
+        self.type_arguments.clear();
+        instance
+    }
+}
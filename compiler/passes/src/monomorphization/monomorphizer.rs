@@ -0,0 +1,142 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::TypeTable;
+
+use leo_ast::{
+    ArrayType,
+    Function,
+    GenericArgument,
+    Identifier,
+    MappingType,
+    NodeBuilder,
+    NodeID,
+    Struct,
+    TupleType,
+    Type,
+};
+use leo_span::Symbol;
+
+use indexmap::{IndexMap, IndexSet};
+use itertools::Itertools;
+
+/// A concrete copy of a generic function or struct.
+pub struct Instance {
+    /// The name of the generic function or struct.
+    pub generic: Symbol,
+    /// The arguments of the copy.
+    pub arguments: Vec<GenericArgument>,
+    /// The name of the copy.
+    pub name: Symbol,
+}
+
+pub struct Monomorphizer<'a> {
+    /// A mapping between node IDs and their types.
+    pub(crate) type_table: &'a TypeTable,
+    /// A counter used to create unique NodeIDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// The generic functions of the current program scope.
+    pub(crate) generic_functions: IndexMap<Symbol, Function>,
+    /// The generic structs of the current program scope.
+    pub(crate) generic_structs: IndexMap<Symbol, Struct>,
+    /// The names of the functions and structs of the current program scope, used to avoid name collisions.
+    pub(crate) names: IndexSet<Symbol>,
+    /// The copies that have been named so far, in the order they were first used.
+    pub(crate) instances: Vec<Instance>,
+    /// The number of copies in `instances` that have been reconstructed.
+    pub(crate) reconstructed: usize,
+    /// The arguments of the copy currently being reconstructed.
+    pub(crate) type_arguments: IndexMap<Symbol, GenericArgument>,
+    /// Whether the copy currently being reconstructed needs new node IDs.
+    pub(crate) is_instance: bool,
+}
+
+impl<'a> Monomorphizer<'a> {
+    /// Initializes a new `Monomorphizer`.
+    pub fn new(type_table: &'a TypeTable, node_builder: &'a NodeBuilder) -> Self {
+        Self {
+            type_table,
+            node_builder,
+            generic_functions: Default::default(),
+            generic_structs: Default::default(),
+            names: Default::default(),
+            instances: Default::default(),
+            reconstructed: 0,
+            type_arguments: Default::default(),
+            is_instance: false,
+        }
+    }
+
+    /// Returns the name of the copy of the generic function or struct `generic` with the given arguments.
+    /// The copy is reconstructed once the definitions that use it have been.
+    pub(crate) fn instance(&mut self, generic: Symbol, arguments: Vec<GenericArgument>) -> Symbol {
+        if let Some(instance) = self.instances.iter().find(|instance| {
+            instance.generic == generic
+                && instance.arguments.iter().zip_eq(arguments.iter()).all(|(left, right)| left.eq_flat(right))
+        }) {
+            return instance.name;
+        }
+
+        let mut index = self.instances.iter().filter(|instance| instance.generic == generic).count();
+        let name = loop {
+            let name = Symbol::intern(&format!("{generic}__{index}"));
+            if self.names.insert(name) {
+                break name;
+            }
+            index += 1;
+        };
+        self.instances.push(Instance { generic, arguments, name });
+        name
+    }
+
+    /// Returns the type with the type parameters and const parameters replaced by their arguments,
+    /// and with each instantiation of a generic struct replaced by the corresponding copy.
+    pub(crate) fn monomorphize_type(&mut self, type_: &Type) -> Type {
+        match type_.substitute(&self.type_arguments) {
+            Type::Generic(generic_type) => {
+                let arguments = generic_type
+                    .arguments
+                    .iter()
+                    .map(|type_| GenericArgument::Type(self.monomorphize_type(type_)))
+                    .collect();
+                let name = self.instance(generic_type.identifier.name, arguments);
+                Type::Identifier(Identifier {
+                    name,
+                    span: generic_type.identifier.span,
+                    id: generic_type.identifier.id,
+                })
+            }
+            Type::Array(array_type) => {
+                let element_type = self.monomorphize_type(array_type.element_type());
+                Type::Array(ArrayType::map_element_type(&array_type, |_| element_type))
+            }
+            Type::Mapping(mapping_type) => Type::Mapping(MappingType {
+                key: Box::new(self.monomorphize_type(&mapping_type.key)),
+                value: Box::new(self.monomorphize_type(&mapping_type.value)),
+            }),
+            Type::Tuple(tuple_type) => Type::Tuple(TupleType::new(
+                tuple_type.elements().iter().map(|type_| self.monomorphize_type(type_)).collect(),
+            )),
+            type_ => type_,
+        }
+    }
+
+    /// Returns the concrete type the type checker recorded for a node of the original program.
+    /// The types recorded inside a generic definition may refer to its type parameters and const parameters.
+    pub(crate) fn concrete_type(&self, id: NodeID) -> Option<Type> {
+        self.type_table.get(&id).map(|type_| type_.substitute(&self.type_arguments))
+    }
+}
@@ -115,6 +115,7 @@ impl FunctionConsumer for StaticSingleAssigner<'_> {
             annotations: function.annotations,
             variant: function.variant,
             identifier: function.identifier,
+            generic_parameters: function.generic_parameters,
             input: function.input,
             output: function.output,
             output_type: function.output_type,
@@ -133,6 +134,8 @@ impl ProgramScopeConsumer for StaticSingleAssigner<'_> {
         ProgramScope {
             program_id: input.program_id,
             structs: input.structs.into_iter().map(|(i, s)| (i, self.consume_struct(s))).collect(),
+            enums: input.enums,
+            impls: input.impls,
             mappings: input.mappings,
             functions: input.functions.into_iter().map(|(i, f)| (i, self.consume_function(f))).collect(),
             consts: input.consts,
@@ -151,6 +154,7 @@ impl ProgramConsumer for StaticSingleAssigner<'_> {
                 .into_iter()
                 .map(|(name, (import, span))| (name, (self.consume_program(import), span)))
                 .collect(),
+            uses: input.uses,
             program_scopes: input
                 .program_scopes
                 .into_iter()
@@ -23,16 +23,20 @@ use leo_ast::{
     AssignStatement,
     AssociatedFunction,
     Block,
+    BreakStatement,
     CallExpression,
     ConditionalStatement,
     ConsoleStatement,
     ConstDeclaration,
+    ContinueStatement,
     DefinitionStatement,
     Expression,
     ExpressionConsumer,
     ExpressionStatement,
     Identifier,
     IterationStatement,
+    MatchPattern,
+    MatchStatement,
     Node,
     ReturnStatement,
     Statement,
@@ -40,6 +44,7 @@ use leo_ast::{
     TernaryExpression,
     TupleExpression,
     Type,
+    WhileStatement,
 };
 use leo_span::Symbol;
 
@@ -86,13 +91,16 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
 
     /// Consume all `AssignStatement`s, renaming as necessary.
     fn consume_assign(&mut self, assign: AssignStatement) -> Self::Output {
+        // An assignment to a member or element of a variable is an assignment of a new struct or array to the variable.
+        let (place, value) = self.lower_assignment(assign.place, assign.value);
+
         // First consume the right-hand-side of the assignment.
-        let (value, mut statements) = self.consume_expression(assign.value);
+        let (value, mut statements) = self.consume_expression(value);
 
         // Then assign a new unique name to the left-hand-side of the assignment.
         // Note that this order is necessary to ensure that the right-hand-side uses the correct name when consuming a complex assignment.
         self.is_lhs = true;
-        let place = match self.consume_expression(assign.place).0 {
+        let place = match self.consume_expression(Expression::Identifier(place)).0 {
             Expression::Identifier(identifier) => identifier,
             _ => panic!("Type checking guarantees that the left-hand-side of an assignment is an identifier."),
         };
@@ -381,6 +389,65 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
         unreachable!("`IterationStatement`s should not be in the AST at this phase of compilation.");
     }
 
+    fn consume_break(&mut self, _input: BreakStatement) -> Self::Output {
+        unreachable!("`BreakStatement`s should not be in the AST at this phase of compilation.");
+    }
+
+    fn consume_continue(&mut self, _input: ContinueStatement) -> Self::Output {
+        unreachable!("`ContinueStatement`s should not be in the AST at this phase of compilation.");
+    }
+
+    fn consume_while(&mut self, _input: WhileStatement) -> Self::Output {
+        unreachable!("`WhileStatement`s should not be in the AST at this phase of compilation.");
+    }
+
+    /// Consumes a `MatchStatement`, lowering it into a chain of `ConditionalStatement`s over the tag of the matched value.
+    /// For example, `match s { Shape::Circle(r) => { .. } _ => { .. } }` becomes
+    /// `if s.tag == 1u8 { let r = s.Circle_0; .. } else { .. }`, which is then consumed as a `ConditionalStatement`.
+    fn consume_match(&mut self, input: MatchStatement) -> Self::Output {
+        // Note that type checking guarantees that the matched value is of an enum type.
+        let enum_ = match self.type_table.get(&input.expression.id()).and_then(|type_| self.lookup_enum_type(&type_)) {
+            Some(enum_) => enum_,
+            None => unreachable!("Type checking guarantees that the matched value is of an enum type."),
+        };
+
+        // Consume the matched value, so that it is evaluated once.
+        let (scrutinee, mut statements) = self.consume_expression(input.expression);
+
+        // Construct the chain of conditionals, starting from the last arm.
+        // Note that type checking guarantees that the match is exhaustive, so the last arm is taken if no other arm is.
+        let mut arms = input.arms.into_iter().rev();
+        let last = match arms.next() {
+            Some(arm) => arm,
+            None => unreachable!("Type checking guarantees that a match has at least one arm."),
+        };
+        let mut chain = Statement::Block(self.match_arm_block(&enum_, &scrutinee, last));
+        for arm in arms {
+            let condition = match &arm.pattern {
+                MatchPattern::Variant { variant, .. } => self.match_arm_condition(&enum_, &scrutinee, variant.name),
+                MatchPattern::Wildcard(_) => {
+                    unreachable!("Type checking guarantees that a wildcard pattern is in the last arm of a match.")
+                }
+            };
+            let span = arm.span;
+            chain = Statement::Conditional(ConditionalStatement {
+                condition,
+                then: self.match_arm_block(&enum_, &scrutinee, arm),
+                otherwise: Some(Box::new(chain)),
+                span,
+                id: self.node_builder.next_id(),
+            });
+        }
+
+        statements.extend(match chain {
+            Statement::Block(block) => self.consume_block(block),
+            Statement::Conditional(conditional) => self.consume_conditional(conditional),
+            _ => unreachable!("The lowered match is either a block or a conditional statement."),
+        });
+
+        statements
+    }
+
     /// Reconstructs the expression associated with the return statement, returning a simplified `ReturnStatement`.
     /// Note that type checking guarantees that there is at most one `ReturnStatement` in a block.
     fn consume_return(&mut self, input: ReturnStatement) -> Self::Output {
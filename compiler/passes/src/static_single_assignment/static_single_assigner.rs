@@ -16,7 +16,33 @@
 
 use crate::{Assigner, RenameTable, SymbolTable, TypeTable};
 
-use leo_ast::{Expression, Identifier, Node, NodeBuilder, Statement};
+use leo_ast::{
+    AccessExpression,
+    ArrayAccess,
+    ArrayExpression,
+    BinaryExpression,
+    BinaryOperation,
+    Block,
+    DeclarationType,
+    DefinitionStatement,
+    Enum,
+    Expression,
+    Identifier,
+    IntegerType,
+    Literal,
+    MatchArm,
+    MatchPattern,
+    MemberAccess,
+    Node,
+    NodeBuilder,
+    Statement,
+    StructExpression,
+    StructVariableInitializer,
+    Type,
+};
+use leo_span::{sym, Symbol};
+
+use itertools::Itertools;
 
 pub struct StaticSingleAssigner<'a> {
     /// A counter used to generate unique node IDs.
@@ -84,4 +110,156 @@ impl<'a> StaticSingleAssigner<'a> {
 
         (place, statement)
     }
+
+    /// Constructs the condition under which the arm matching `variant` is taken, i.e. `scrutinee.tag == <tag>u8`.
+    pub(crate) fn match_arm_condition(&mut self, enum_: &Enum, scrutinee: &Expression, variant: Symbol) -> Expression {
+        // Note that type checking guarantees that the variant exists.
+        let tag = enum_.tag(variant).unwrap();
+        let left = self.member_access(scrutinee, sym::tag, Type::Integer(IntegerType::U8));
+        let right = Literal::Integer(IntegerType::U8, tag.to_string(), Default::default(), self.node_builder.next_id());
+        self.type_table.insert(right.id(), Type::Integer(IntegerType::U8));
+
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, Type::Boolean);
+        Expression::Binary(BinaryExpression {
+            left: Box::new(left),
+            right: Box::new(Expression::Literal(right)),
+            op: BinaryOperation::Eq,
+            span: Default::default(),
+            id,
+        })
+    }
+
+    /// Constructs the block of a `match` arm, prefixed with definitions of the bindings of its pattern.
+    /// For example, the arm `Shape::Rectangle(w, _) => { .. }` produces the block `{ let w = scrutinee.Rectangle_0; .. }`.
+    pub(crate) fn match_arm_block(&mut self, enum_: &Enum, scrutinee: &Expression, arm: MatchArm) -> Block {
+        let mut statements = Vec::new();
+
+        if let MatchPattern::Variant { variant, bindings, .. } = arm.pattern {
+            // Note that type checking guarantees that the variant exists and that there is a binding for each value in its payload.
+            let payload = &enum_.variant(variant.name).unwrap().payload;
+            for (index, (binding, type_)) in bindings.into_iter().zip_eq(payload.iter()).enumerate() {
+                if let Some(binding) = binding {
+                    let value = self.member_access(scrutinee, Enum::payload_member(variant.name, index), type_.clone());
+                    statements.push(Statement::Definition(DefinitionStatement {
+                        declaration_type: DeclarationType::Let,
+                        place: Expression::Identifier(binding),
+                        type_: Some(type_.clone()),
+                        value,
+                        span: binding.span,
+                        id: self.node_builder.next_id(),
+                    }));
+                }
+            }
+        }
+
+        statements.extend(arm.block.statements);
+        Block { statements, span: arm.block.span, id: arm.block.id }
+    }
+
+    /// Lowers an assignment of `value` to `place` into an assignment to the variable that `place` is rooted at.
+    /// A member or element of a variable is assigned by reconstructing the struct or array that contains it.
+    /// For example, `s.a = v` becomes `s = S { a: v, b: s.b }`, and `arr[1u8] = v` becomes `arr = [arr[0u32], v]`.
+    pub(crate) fn lower_assignment(&mut self, place: Expression, value: Expression) -> (Identifier, Expression) {
+        match place {
+            Expression::Identifier(identifier) => (identifier, value),
+            Expression::Access(AccessExpression::Member(access)) => {
+                let type_ = self.type_table.get(&access.inner.id());
+                // Note that type checking guarantees that the inner expression is a struct.
+                let (name, struct_) = match &type_ {
+                    Some(Type::Identifier(name)) => {
+                        (*name, self.symbol_table.lookup_struct(name.name).unwrap().clone())
+                    }
+                    _ => unreachable!(
+                        "Type checking guarantees that the inner expression of a member access is a struct."
+                    ),
+                };
+                let mut value = Some(value);
+                let members = struct_
+                    .members
+                    .iter()
+                    .map(|member| StructVariableInitializer {
+                        identifier: member.identifier,
+                        expression: Some(match member.name() == access.name.name {
+                            true => value.take().unwrap(),
+                            false => self.member_access(&access.inner, member.name(), member.type_.clone()),
+                        }),
+                        span: Default::default(),
+                        id: self.node_builder.next_id(),
+                    })
+                    .collect();
+
+                let id = self.node_builder.next_id();
+                self.type_table.insert(id, Type::Identifier(name));
+                let struct_ = Expression::Struct(StructExpression { name, members, span: Default::default(), id });
+                self.lower_assignment(*access.inner, struct_)
+            }
+            Expression::Access(AccessExpression::Array(access)) => {
+                let array_type = match self.type_table.get(&access.array.id()) {
+                    Some(Type::Array(array_type)) => array_type,
+                    _ => unreachable!(
+                        "Type checking guarantees that the inner expression of an array access is an array."
+                    ),
+                };
+                // Note that loop unrolling and dynamic index lowering guarantee that the index is a literal within the bounds of the array.
+                let index = match access.index.as_ref() {
+                    Expression::Literal(Literal::Integer(_, index, ..)) => {
+                        index.replace('_', "").parse::<usize>().unwrap()
+                    }
+                    _ => unreachable!("Dynamic index lowering guarantees that array indices are integer literals."),
+                };
+                let mut value = Some(value);
+                let elements = (0..array_type.length())
+                    .map(|i| match i == index {
+                        true => value.take().unwrap(),
+                        false => self.element_access(&access.array, i, array_type.element_type().clone()),
+                    })
+                    .collect();
+
+                let id = self.node_builder.next_id();
+                self.type_table.insert(id, Type::Array(array_type));
+                let array = Expression::Array(ArrayExpression { elements, span: Default::default(), id });
+                self.lower_assignment(*access.array, array)
+            }
+            _ => unreachable!(
+                "Type checking guarantees that the place of an assignment is a variable, or a member or element of one."
+            ),
+        }
+    }
+
+    /// Constructs the access of the element at `index` of type `type_` of the array `array`.
+    fn element_access(&mut self, array: &Expression, index: usize, type_: Type) -> Expression {
+        let index =
+            Literal::Integer(IntegerType::U32, index.to_string(), Default::default(), self.node_builder.next_id());
+        self.type_table.insert(index.id(), Type::Integer(IntegerType::U32));
+
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, type_);
+        Expression::Access(AccessExpression::Array(ArrayAccess {
+            array: Box::new(array.clone()),
+            index: Box::new(Expression::Literal(index)),
+            span: Default::default(),
+            id,
+        }))
+    }
+
+    /// Constructs the access of the member `name` of type `type_` of the struct `inner`.
+    fn member_access(&mut self, inner: &Expression, name: Symbol, type_: Type) -> Expression {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, type_);
+        Expression::Access(AccessExpression::Member(MemberAccess {
+            inner: Box::new(inner.clone()),
+            name: Identifier::new(name, self.node_builder.next_id()),
+            span: Default::default(),
+            id,
+        }))
+    }
+
+    /// Returns the definition of the enum with type `type_`, if `type_` is an enum type.
+    pub(crate) fn lookup_enum_type(&self, type_: &Type) -> Option<Enum> {
+        match type_ {
+            Type::Identifier(identifier) => self.symbol_table.lookup_enum(identifier.name).cloned(),
+            _ => None,
+        }
+    }
 }
@@ -17,19 +17,22 @@
 use crate::TypeChecker;
 
 use leo_ast::*;
-use leo_errors::{emitter::Handler, TypeCheckerError};
+use leo_errors::TypeCheckerError;
 use leo_span::{sym, Span};
 
 use itertools::Itertools;
 use snarkvm_console::network::{Network, Testnet3};
-use std::str::FromStr;
 
 fn return_incorrect_type(t1: Option<Type>, t2: Option<Type>, expected: &Option<Type>) -> Option<Type> {
     match (t1, t2) {
         (Some(t1), Some(t2)) if t1 == t2 => Some(t1),
         (Some(t1), Some(t2)) => {
             if let Some(expected) = expected {
-                if &t1 != expected { Some(t1) } else { Some(t2) }
+                if &t1 != expected {
+                    Some(t1)
+                } else {
+                    Some(t2)
+                }
             } else {
                 Some(t1)
             }
@@ -73,8 +76,11 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 let array_type = self.visit_expression(&access.array, &None);
                 self.assert_array_type(&array_type, access.array.span());
 
-                // Check that the index is an integer type.
-                let index_type = self.visit_expression(&access.index, &None);
+                // Check that the index is an integer type. An untyped index defaults to `u32`.
+                let index_type = match Self::is_untyped(&access.index) {
+                    true => self.visit_expression(&access.index, &Some(Type::Integer(IntegerType::U32))),
+                    false => self.visit_expression(&access.index, &None),
+                };
                 self.assert_int_type(&index_type, access.index.span());
 
                 // Get the element type of the array.
@@ -92,6 +98,34 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 return element_type;
             }
             AccessExpression::AssociatedFunction(access) => {
+                // Check whether the access constructs a variant of an enum, e.g. `Shape::Circle(1u32)`.
+                if let Some(enum_) = self.lookup_enum_type(&access.ty) {
+                    let payload = self.check_enum_variant(&enum_, &access.name);
+                    if let Some(payload) = &payload {
+                        if payload.len() != access.arguments.len() {
+                            self.emit_err(TypeCheckerError::incorrect_num_variant_values(
+                                access.name,
+                                payload.len(),
+                                access.arguments.len(),
+                                access.span(),
+                            ));
+                        }
+                    }
+                    // Check the types of the arguments against the payload of the variant.
+                    access.arguments.iter().enumerate().for_each(|(index, argument)| {
+                        let expected = payload.as_ref().and_then(|payload| payload.get(index).cloned());
+                        self.visit_expression(argument, &expected);
+                    });
+                    return Some(self.check_expected_struct(enum_.identifier, expected, access.span()));
+                }
+
+                // Check whether the access calls a function of the `impl` block of a struct, e.g. `Token::new(1u64)`.
+                if let Some((function, func)) = self.lookup_impl_function(&access.ty, &access.name) {
+                    let function = Identifier { name: function, span: access.name.span, id: access.name.id };
+                    let arguments = access.arguments.iter().collect::<Vec<_>>();
+                    return Some(self.check_call(function, func, &arguments, false, access.span(), expected));
+                }
+
                 // Check core struct name and function.
                 if let Some(core_instruction) = self.get_core_function_call(&access.ty, &access.name) {
                     // Check that operation is not restricted to finalize blocks.
@@ -158,32 +192,37 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             }
             AccessExpression::Member(access) => {
                 match *access.inner {
-                    // If the access expression is of the form `self.<name>`, then check the <name> is valid.
-                    Expression::Identifier(identifier) if identifier.name == sym::SelfLower => match access.name.name {
-                        sym::caller => {
-                            // Check that the operation is not invoked in a `finalize` block.
-                            if self.is_finalize {
-                                self.handler.emit_err(TypeCheckerError::invalid_operation_inside_finalize(
-                                    "self.caller",
-                                    access.name.span(),
-                                ))
+                    // If the access expression is of the form `self.<name>` outside of a method, then check the <name> is valid.
+                    Expression::Identifier(identifier)
+                        if identifier.name == sym::SelfLower
+                            && self.symbol_table.borrow().lookup_variable(sym::SelfLower).is_none() =>
+                    {
+                        match access.name.name {
+                            sym::caller => {
+                                // Check that the operation is not invoked in a `finalize` block.
+                                if self.is_finalize {
+                                    self.handler.emit_err(TypeCheckerError::invalid_operation_inside_finalize(
+                                        "self.caller",
+                                        access.name.span(),
+                                    ))
+                                }
+                                return Some(Type::Address);
                             }
-                            return Some(Type::Address);
-                        }
-                        sym::signer => {
-                            // Check that operation is not invoked in a `finalize` block.
-                            if self.is_finalize {
-                                self.handler.emit_err(TypeCheckerError::invalid_operation_inside_finalize(
-                                    "self.signer",
-                                    access.name.span(),
-                                ))
+                            sym::signer => {
+                                // Check that operation is not invoked in a `finalize` block.
+                                if self.is_finalize {
+                                    self.handler.emit_err(TypeCheckerError::invalid_operation_inside_finalize(
+                                        "self.signer",
+                                        access.name.span(),
+                                    ))
+                                }
+                                return Some(Type::Address);
+                            }
+                            _ => {
+                                self.emit_err(TypeCheckerError::invalid_self_access(access.name.span()));
                             }
-                            return Some(Type::Address);
-                        }
-                        _ => {
-                            self.emit_err(TypeCheckerError::invalid_self_access(access.name.span()));
                         }
-                    },
+                    }
                     // If the access expression is of the form `block.<name>`, then check the <name> is valid.
                     Expression::Identifier(identifier) if identifier.name == sym::block => match access.name.name {
                         sym::height => {
@@ -203,20 +242,15 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                     _ => {
                         // Check that the type of `inner` in `inner.name` is a struct.
                         match self.visit_expression(&access.inner, &None) {
-                            Some(Type::Identifier(identifier)) => {
-                                // Retrieve the struct definition associated with `identifier`.
-                                let struct_ = self.symbol_table.borrow().lookup_struct(identifier.name).cloned();
-                                if let Some(struct_) = struct_ {
+                            Some(type_ @ (Type::Identifier(_) | Type::Generic(_))) => {
+                                // Retrieve the struct definition associated with the type, and the types of its members.
+                                if let Some((struct_, members)) = self.lookup_struct_type(&type_) {
                                     // Check that `access.name` is a member of the struct.
-                                    match struct_.members.iter().find(|member| member.name() == access.name.name) {
+                                    match members.into_iter().find(|(name, _)| *name == access.name.name) {
                                         // Case where `access.name` is a member of the struct.
-                                        Some(Member { type_, .. }) => {
+                                        Some((_, type_)) => {
                                             // Check that the type of `access.name` is the same as `expected`.
-                                            return Some(self.assert_and_return_type(
-                                                type_.clone(),
-                                                expected,
-                                                access.span(),
-                                            ));
+                                            return Some(self.assert_and_return_type(type_, expected, access.span()));
                                         }
                                         // Case where `access.name` is not a member of the struct.
                                         None => {
@@ -244,7 +278,51 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                     }
                 }
             }
+            AccessExpression::MethodCall(call) => {
+                // Check that the receiver is a struct or record whose `impl` block declares the method.
+                let receiver_type = self.visit_expression(&call.receiver, &None)?;
+                match self.lookup_impl_function(&receiver_type, &call.name) {
+                    Some((function, func)) if func.is_method() => {
+                        let function = Identifier { name: function, span: call.name.span, id: call.name.id };
+                        let arguments =
+                            std::iter::once(&*call.receiver).chain(call.arguments.iter()).collect::<Vec<_>>();
+                        return Some(self.check_call(function, func, &arguments, false, call.span(), expected));
+                    }
+                    Some(_) => self.emit_err(TypeCheckerError::not_a_method(&receiver_type, call.name, call.span())),
+                    None => self.emit_err(TypeCheckerError::unknown_method(&receiver_type, call.name, call.span())),
+                }
+                // Check the arguments, so that their errors are reported.
+                call.arguments.iter().for_each(|argument| {
+                    self.visit_expression(argument, &None);
+                });
+            }
             AccessExpression::AssociatedConstant(access) => {
+                // Check whether the access is a variant of an enum without a payload, e.g. `Shape::Empty`.
+                if let Some(enum_) = self.lookup_enum_type(&access.ty) {
+                    if let Some(payload) = self.check_enum_variant(&enum_, &access.name) {
+                        if !payload.is_empty() {
+                            self.emit_err(TypeCheckerError::incorrect_num_variant_values(
+                                access.name,
+                                payload.len(),
+                                0,
+                                access.span(),
+                            ));
+                        }
+                    }
+                    return Some(self.check_expected_struct(enum_.identifier, expected, access.span()));
+                }
+
+                // Check whether the access is a constant of the `impl` block of a struct, e.g. `Token::MAX_SUPPLY`.
+                if let Type::Identifier(identifier) = &access.ty {
+                    if self.symbol_table.borrow().lookup_struct(identifier.name).is_some() {
+                        let constant = Impl::item_name(identifier.name, access.name.name);
+                        let type_ = self.symbol_table.borrow().lookup_variable(constant).map(|var| var.type_.clone());
+                        if let Some(type_) = type_ {
+                            return Some(self.assert_and_return_type(type_, expected, access.span()));
+                        }
+                    }
+                }
+
                 // Check associated constant type and constant name
                 if let Some(core_constant) = self.get_core_constant(&access.ty, &access.name) {
                     // Check return type if the expected type is known.
@@ -263,8 +341,18 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
 
     fn visit_array(&mut self, input: &'a ArrayExpression, additional: &Self::AdditionalInput) -> Self::Output {
         // Get the types of each element expression.
-        let element_types =
-            input.elements.iter().map(|element| self.visit_expression(element, &None)).collect::<Vec<_>>();
+        let mut element_types = vec![None; input.elements.len()];
+        for (i, element) in input.elements.iter().enumerate().filter(|(_, element)| !Self::is_untyped(element)) {
+            element_types[i] = self.visit_expression(element, &None);
+        }
+        // The untyped elements take the element type of the expected array type, or else the type of the other elements.
+        let inferred_type = match additional {
+            Some(Type::Array(array_type)) => Some(array_type.element_type().clone()),
+            _ => element_types.iter().flatten().next().cloned(),
+        };
+        for (i, element) in input.elements.iter().enumerate().filter(|(_, element)| Self::is_untyped(element)) {
+            element_types[i] = self.visit_expression(element, &inferred_type);
+        }
 
         // Construct the array type.
         let return_type = match element_types.len() {
@@ -311,8 +399,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             BinaryOperation::And | BinaryOperation::Or | BinaryOperation::Nand | BinaryOperation::Nor => {
                 // Only boolean types.
                 self.assert_bool_type(destination, input.span());
-                let t1 = self.visit_expression(&input.left, destination);
-                let t2 = self.visit_expression(&input.right, destination);
+                let (t1, t2) = self.visit_operands(&input.left, &input.right, destination);
 
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
@@ -322,8 +409,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             BinaryOperation::BitwiseAnd | BinaryOperation::BitwiseOr | BinaryOperation::Xor => {
                 //  Only boolean or integer types.
                 self.assert_bool_int_type(destination, input.span());
-                let t1 = self.visit_expression(&input.left, destination);
-                let t2 = self.visit_expression(&input.right, destination);
+                let (t1, t2) = self.visit_operands(&input.left, &input.right, destination);
 
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
@@ -333,8 +419,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             BinaryOperation::Add => {
                 // Only field, group, scalar, or integer types.
                 self.assert_field_group_scalar_int_type(destination, input.span());
-                let t1 = self.visit_expression(&input.left, destination);
-                let t2 = self.visit_expression(&input.right, destination);
+                let (t1, t2) = self.visit_operands(&input.left, &input.right, destination);
 
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
@@ -344,8 +429,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             BinaryOperation::Sub => {
                 // Only field, group, or integer types.
                 self.assert_field_group_int_type(destination, input.span());
-                let t1 = self.visit_expression(&input.left, destination);
-                let t2 = self.visit_expression(&input.right, destination);
+                let (t1, t2) = self.visit_operands(&input.left, &input.right, destination);
 
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
@@ -356,8 +440,12 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 // Operation returns field, group or integer types.
                 self.assert_field_group_int_type(destination, input.span());
 
-                let t1 = self.visit_expression(&input.left, &None);
-                let t2 = self.visit_expression(&input.right, &None);
+                // Note that the operands may have different types, so the destination type is only used if both are untyped.
+                let expected = match Self::is_untyped(&input.left) && Self::is_untyped(&input.right) {
+                    true => destination,
+                    false => &None,
+                };
+                let (t1, t2) = self.visit_operands(&input.left, &input.right, expected);
 
                 // Allow group * scalar multiplication.
                 match (t1, input.left.span(), t2, input.right.span()) {
@@ -379,6 +467,21 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
 
                         Some(Type::Field)
                     }
+                    (Some(type_ @ Type::Identifier(_)), _, other, other_span)
+                    | (other, other_span, Some(type_ @ Type::Identifier(_)), _)
+                        if self.is_generic_parameter(&type_) =>
+                    {
+                        // The type parameter must be bound to field or integer types, since `group * group` is not allowed.
+                        self.assert_field_int_type(&Some(type_.clone()), input.span());
+
+                        // Other type must be the same type parameter.
+                        self.assert_type(&other, &type_, other_span);
+
+                        // Operation returns the same type parameter.
+                        self.assert_type(destination, &type_, input.span());
+
+                        Some(type_)
+                    }
                     (Some(Type::Integer(integer_type)), _, other, other_span)
                     | (other, other_span, Some(Type::Integer(integer_type)), _) => {
                         // Other type must be the same integer type.
@@ -412,8 +515,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 // Only field or integer types.
                 self.assert_field_int_type(destination, input.span());
 
-                let t1 = self.visit_expression(&input.left, destination);
-                let t2 = self.visit_expression(&input.right, destination);
+                let (t1, t2) = self.visit_operands(&input.left, &input.right, destination);
 
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
@@ -424,8 +526,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 // Only integer types.
                 self.assert_int_type(destination, input.span());
 
-                let t1 = self.visit_expression(&input.left, destination);
-                let t2 = self.visit_expression(&input.right, destination);
+                let (t1, t2) = self.visit_operands(&input.left, &input.right, destination);
 
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
@@ -436,8 +537,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 // Only unsigned integer types.
                 self.assert_unsigned_int_type(destination, input.span());
 
-                let t1 = self.visit_expression(&input.left, destination);
-                let t2 = self.visit_expression(&input.right, destination);
+                let (t1, t2) = self.visit_operands(&input.left, &input.right, destination);
 
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
@@ -448,8 +548,12 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 // Operation returns field or integer types.
                 self.assert_field_int_type(destination, input.span());
 
-                let t1 = self.visit_expression(&input.left, &None);
-                let t2 = self.visit_expression(&input.right, &None);
+                // An untyped base takes the destination type, and an untyped exponent takes a default type.
+                let t1 = match Self::is_untyped(&input.left) {
+                    true => self.visit_expression(&input.left, destination),
+                    false => self.visit_expression(&input.left, &None),
+                };
+                let t2 = self.visit_expression(&input.right, &Self::magnitude_type(&t1, &input.right));
 
                 // Allow field ^ field.
                 match (t1, t2) {
@@ -473,6 +577,10 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                     }
                     (Some(left), right) => {
                         // Left type is checked to be an integer by above.
+                        // A type parameter must be bound to integer types, since `field ** field` requires a field exponent.
+                        if self.is_generic_parameter(&left) {
+                            self.assert_int_type(&Some(left.clone()), input.left.span());
+                        }
                         // Right type must be magnitude (u8, u16, u32).
                         self.assert_magnitude_type(&right, input.right.span());
 
@@ -491,8 +599,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             }
             BinaryOperation::Eq | BinaryOperation::Neq => {
                 // Assert first and second address, boolean, field, group, scalar, or integer types.
-                let t1 = self.visit_expression(&input.left, &None);
-                let t2 = self.visit_expression(&input.right, &None);
+                let (t1, t2) = self.visit_operands(&input.left, &input.right, &None);
 
                 // Check that the types of the operands are equal.
                 self.check_eq_types(&t1, &t2, input.span());
@@ -504,8 +611,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             }
             BinaryOperation::Lt | BinaryOperation::Gt | BinaryOperation::Lte | BinaryOperation::Gte => {
                 // Assert left and right are equal field, scalar, or integer types.
-                let t1 = self.visit_expression(&input.left, &None);
-                let t2 = self.visit_expression(&input.right, &None);
+                let (t1, t2) = self.visit_operands(&input.left, &input.right, &None);
 
                 match (&t1, &t2) {
                     (Some(Type::Address), _) | (_, Some(Type::Address)) => {
@@ -532,8 +638,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             | BinaryOperation::MulWrapped => {
                 // Only integer types.
                 self.assert_int_type(destination, input.span);
-                let t1 = self.visit_expression(&input.left, destination);
-                let t2 = self.visit_expression(&input.right, destination);
+                let (t1, t2) = self.visit_operands(&input.left, &input.right, destination);
 
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
@@ -546,7 +651,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             | BinaryOperation::ShrWrapped
             | BinaryOperation::PowWrapped => {
                 let t1 = self.visit_expression(&input.left, destination);
-                let t2 = self.visit_expression(&input.right, &None);
+                let t2 = self.visit_expression(&input.right, &Self::magnitude_type(&t1, &input.right));
 
                 // Assert left and destination are equal integer types.
                 self.assert_int_type(&t1, input.left.span());
@@ -569,54 +674,8 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 let func = self.symbol_table.borrow().lookup_fn_symbol(ident.name).cloned();
 
                 if let Some(func) = func {
-                    // Check that the call is valid.
-                    // Note that this unwrap is safe since we always set the variant before traversing the body of the function.
-                    match self.variant.unwrap() {
-                        // If the function is not a transition function, it can only call "inline" functions.
-                        Variant::Inline | Variant::Standard => {
-                            if !matches!(func.variant, Variant::Inline) {
-                                self.emit_err(TypeCheckerError::can_only_call_inline_function(input.span));
-                            }
-                        }
-                        // If the function is a transition function, then check that the call is not to another local transition function.
-                        Variant::Transition => {
-                            if matches!(func.variant, Variant::Transition) && input.external.is_none() {
-                                self.emit_err(TypeCheckerError::cannot_invoke_call_to_local_transition_function(
-                                    input.span,
-                                ));
-                            }
-                        }
-                    }
-
-                    // Check that the call is not to an external `inline` function.
-                    if func.variant == Variant::Inline && input.external.is_some() {
-                        self.emit_err(TypeCheckerError::cannot_call_external_inline_function(input.span));
-                    }
-
-                    let ret = self.assert_and_return_type(func.output_type, expected, input.span());
-
-                    // Check number of function arguments.
-                    if func.input.len() != input.arguments.len() {
-                        self.emit_err(TypeCheckerError::incorrect_num_args_to_call(
-                            func.input.len(),
-                            input.arguments.len(),
-                            input.span(),
-                        ));
-                    }
-
-                    // Check function argument types.
-                    func.input.iter().zip(input.arguments.iter()).for_each(|(expected, argument)| {
-                        self.visit_expression(argument, &Some(expected.type_()));
-                    });
-
-                    // Add the call to the call graph.
-                    let caller_name = match self.function {
-                        None => unreachable!("`self.function` is set every time a function is visited."),
-                        Some(func) => func,
-                    };
-                    self.call_graph.add_edge(caller_name, ident.name);
-
-                    Some(ret)
+                    let arguments = input.arguments.iter().collect::<Vec<_>>();
+                    Some(self.check_call(*ident, func, &arguments, input.external.is_some(), input.span(), expected))
                 } else {
                     self.emit_err(TypeCheckerError::unknown_sym("function", ident.name, ident.span()));
                     None
@@ -641,8 +700,55 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
     fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
         let struct_ = self.symbol_table.borrow().lookup_struct(input.name.name).cloned();
         if let Some(struct_) = struct_ {
+            // Infer the type arguments of a generic struct from the types of the members.
+            let type_arguments = struct_.is_generic().then(|| {
+                let members = struct_
+                    .members
+                    .iter()
+                    .filter_map(|Member { identifier, type_, .. }| {
+                        let actual = input.members.iter().find(|member| member.identifier.name == identifier.name)?;
+                        let actual_type = match &actual.expression {
+                            None => self.visit_identifier(&actual.identifier, &None),
+                            Some(expr) => self.visit_expression(expr, &None),
+                        };
+                        Some((type_.clone(), actual_type, actual.span()))
+                    })
+                    .collect::<Vec<_>>();
+                let declared = Type::Generic(GenericType {
+                    identifier: struct_.identifier,
+                    arguments: struct_
+                        .generic_parameters
+                        .iter()
+                        .map(|parameter| Type::Identifier(parameter.identifier))
+                        .collect(),
+                });
+                self.infer_type_arguments(
+                    &struct_.generic_parameters,
+                    members,
+                    (&declared, additional),
+                    struct_.name(),
+                    input.span(),
+                )
+            });
+
             // Check struct type name.
-            let ret = self.check_expected_struct(struct_.identifier, additional, input.name.span());
+            let ret = match &type_arguments {
+                Some(type_arguments) => {
+                    let type_ = Type::Generic(GenericType {
+                        identifier: struct_.identifier,
+                        arguments: type_arguments
+                            .values()
+                            .map(|argument| match argument {
+                                GenericArgument::Type(type_) => type_.clone(),
+                                // Note that structs cannot have const parameters.
+                                GenericArgument::Const(_) => Type::Err,
+                            })
+                            .collect(),
+                    });
+                    self.assert_and_return_type(type_, additional, input.name.span())
+                }
+                None => self.check_expected_struct(struct_.identifier, additional, input.name.span()),
+            };
 
             // Check number of struct members.
             if struct_.members.len() != input.members.len() {
@@ -654,15 +760,18 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             }
 
             // Check struct member types.
+            // Note that the members of a generic struct are checked when inferring the type arguments.
             struct_.members.iter().for_each(|Member { identifier, type_, .. }| {
                 // Lookup struct variable name.
                 if let Some(actual) = input.members.iter().find(|member| member.identifier.name == identifier.name) {
-                    match &actual.expression {
-                        // If `expression` is None, then the member uses the identifier shorthand, e.g. `Foo { a }`
-                        None => self.visit_identifier(&actual.identifier, &Some(type_.clone())),
-                        // Otherwise, visit the associated expression.
-                        Some(expr) => self.visit_expression(expr, &Some(type_.clone())),
-                    };
+                    if type_arguments.is_none() {
+                        match &actual.expression {
+                            // If `expression` is None, then the member uses the identifier shorthand, e.g. `Foo { a }`
+                            None => self.visit_identifier(&actual.identifier, &Some(type_.clone())),
+                            // Otherwise, visit the associated expression.
+                            Some(expr) => self.visit_expression(expr, &Some(type_.clone())),
+                        };
+                    }
                 } else {
                     self.emit_err(TypeCheckerError::missing_struct_member(
                         struct_.identifier,
@@ -694,73 +803,42 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_literal(&mut self, input: &'a Literal, expected: &Self::AdditionalInput) -> Self::Output {
-        fn parse_integer_literal<I: FromStr>(handler: &Handler, raw_string: &str, span: Span, type_string: &str) {
-            let string = raw_string.replace('_', "");
-            if string.parse::<I>().is_err() {
-                handler.emit_err(TypeCheckerError::invalid_int_value(string, type_string, span));
-            }
-        }
-
         Some(match input {
             Literal::Address(_, _, _) => self.assert_and_return_type(Type::Address, expected, input.span()),
             Literal::Boolean(_, _, _) => self.assert_and_return_type(Type::Boolean, expected, input.span()),
             Literal::Field(_, _, _) => self.assert_and_return_type(Type::Field, expected, input.span()),
-            Literal::Integer(integer_type, string, _, _) => match integer_type {
-                IntegerType::U8 => {
-                    parse_integer_literal::<u8>(self.handler, string, input.span(), "u8");
-                    self.assert_and_return_type(Type::Integer(IntegerType::U8), expected, input.span())
-                }
-                IntegerType::U16 => {
-                    parse_integer_literal::<u16>(self.handler, string, input.span(), "u16");
-                    self.assert_and_return_type(Type::Integer(IntegerType::U16), expected, input.span())
-                }
-                IntegerType::U32 => {
-                    parse_integer_literal::<u32>(self.handler, string, input.span(), "u32");
-                    self.assert_and_return_type(Type::Integer(IntegerType::U32), expected, input.span())
-                }
-                IntegerType::U64 => {
-                    parse_integer_literal::<u64>(self.handler, string, input.span(), "u64");
-                    self.assert_and_return_type(Type::Integer(IntegerType::U64), expected, input.span())
-                }
-                IntegerType::U128 => {
-                    parse_integer_literal::<u128>(self.handler, string, input.span(), "u128");
-                    self.assert_and_return_type(Type::Integer(IntegerType::U128), expected, input.span())
-                }
-                IntegerType::I8 => {
-                    parse_integer_literal::<i8>(self.handler, string, input.span(), "i8");
-                    self.assert_and_return_type(Type::Integer(IntegerType::I8), expected, input.span())
-                }
-                IntegerType::I16 => {
-                    parse_integer_literal::<i16>(self.handler, string, input.span(), "i16");
-                    self.assert_and_return_type(Type::Integer(IntegerType::I16), expected, input.span())
-                }
-                IntegerType::I32 => {
-                    parse_integer_literal::<i32>(self.handler, string, input.span(), "i32");
-                    self.assert_and_return_type(Type::Integer(IntegerType::I32), expected, input.span())
-                }
-                IntegerType::I64 => {
-                    parse_integer_literal::<i64>(self.handler, string, input.span(), "i64");
-                    self.assert_and_return_type(Type::Integer(IntegerType::I64), expected, input.span())
-                }
-                IntegerType::I128 => {
-                    parse_integer_literal::<i128>(self.handler, string, input.span(), "i128");
-                    self.assert_and_return_type(Type::Integer(IntegerType::I128), expected, input.span())
-                }
-            },
+            Literal::Integer(integer_type, string, _, _) => {
+                self.check_integer_literal(integer_type, string, input.span(), expected)
+            }
             Literal::Group(_) => self.assert_and_return_type(Type::Group, expected, input.span()),
             Literal::Scalar(_, _, _) => self.assert_and_return_type(Type::Scalar, expected, input.span()),
             Literal::String(_, _, _) => {
                 self.emit_err(TypeCheckerError::strings_are_not_supported(input.span()));
                 self.assert_and_return_type(Type::String, expected, input.span())
             }
+            // The type of an unsuffixed literal is the type expected by its context.
+            Literal::Unsuffixed(value, _, _) => match expected {
+                Some(Type::Integer(integer_type)) => {
+                    self.check_integer_literal(integer_type, value, input.span(), expected)
+                }
+                Some(type_ @ (Type::Field | Type::Group | Type::Scalar)) => type_.clone(),
+                Some(Type::Err) => return None,
+                Some(type_) => {
+                    self.emit_err(TypeCheckerError::unsuffixed_literal_type_mismatch(value, type_, input.span()));
+                    return None;
+                }
+                None => {
+                    self.emit_err(TypeCheckerError::could_not_infer_type(value, input.span()));
+                    return None;
+                }
+            },
         })
     }
 
     fn visit_ternary(&mut self, input: &'a TernaryExpression, expected: &Self::AdditionalInput) -> Self::Output {
         self.visit_expression(&input.condition, &Some(Type::Boolean));
 
-        let t1 = self.visit_expression(&input.if_true, expected);
-        let t2 = self.visit_expression(&input.if_false, expected);
+        let (t1, t2) = self.visit_operands(&input.if_true, &input.if_false, expected);
 
         return_incorrect_type(t1, t2, expected)
     }
@@ -24,6 +24,9 @@ use snarkvm_console::network::{Network, Testnet3};
 
 use std::collections::HashSet;
 
+/// The maximum number of variants of an enum, since the variant of a value is stored as a `u8`.
+const MAX_ENUM_VARIANTS: usize = u8::MAX as usize + 1;
+
 // TODO: Cleanup logic for tuples.
 
 impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
@@ -59,6 +62,9 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
         // Typecheck each struct definition.
         input.structs.iter().for_each(|(_, function)| self.visit_struct(function));
 
+        // Typecheck each enum definition.
+        input.enums.iter().for_each(|(_, enum_)| self.visit_enum(enum_));
+
         // Check that the struct dependency graph does not have any cycles.
         if let Err(DiGraphError::CycleDetected(path)) = self.struct_graph.post_order() {
             self.emit_err(TypeCheckerError::cyclic_struct_dependency(path));
@@ -104,6 +110,17 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_struct(&mut self, input: &'a Struct) {
+        // Bring the type parameters of the struct into scope.
+        // Records cannot be generic, since their types are part of the program's interface.
+        if input.is_record && input.is_generic() {
+            self.emit_err(TypeCheckerError::generic_record(input.name(), input.span()));
+        }
+        // Only functions can have const parameters, since struct types do not take array lengths as arguments.
+        if input.generic_parameters.iter().any(|parameter| parameter.is_const()) {
+            self.emit_err(TypeCheckerError::generic_struct_const_parameter(input.name(), input.span()));
+        }
+        self.enter_generic_parameters(&input.generic_parameters);
+
         // Check for conflicting struct/record member names.
         let mut used = HashSet::new();
         // TODO: Better span to target duplicate member.
@@ -151,6 +168,8 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
             // Note that we have already checked that each member is defined and valid.
             if let Type::Identifier(member_type) = type_ {
                 self.struct_graph.add_edge(input.identifier.name, member_type.name);
+            } else if let Type::Generic(member_type) = type_ {
+                self.struct_graph.add_edge(input.identifier.name, member_type.identifier.name);
             } else if let Type::Array(array_type) = type_ {
                 // Get the base element type.
                 let base_element_type = array_type.base_element_type();
@@ -165,6 +184,56 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
                 self.emit_err(TypeCheckerError::struct_cannot_have_member_mode(*span));
             }
         }
+
+        self.exit_generic_parameters();
+    }
+
+    fn visit_enum(&mut self, input: &'a Enum) {
+        // Check that the enum has at least one variant, and that each variant can be tagged with a `u8`.
+        if input.variants.is_empty() {
+            self.emit_err(TypeCheckerError::enum_has_no_variants(input.name(), input.span()));
+        } else if input.variants.len() > MAX_ENUM_VARIANTS {
+            self.emit_err(TypeCheckerError::enum_has_too_many_variants(input.name(), MAX_ENUM_VARIANTS, input.span()));
+        }
+
+        let mut used = HashSet::new();
+        for variant in input.variants.iter() {
+            // Check for conflicting variant names.
+            if !used.insert(variant.name()) {
+                self.emit_err(TypeCheckerError::duplicate_enum_variant(input.name(), variant.name(), variant.span()));
+            }
+
+            for type_ in variant.payload.iter() {
+                // Check that the payload type is defined.
+                if !self.assert_type_is_valid(type_, variant.span()) {
+                    continue;
+                }
+                // Check that the payload can be stored in a struct member.
+                let is_valid = match type_ {
+                    Type::Mapping(_) | Type::Signature | Type::String | Type::Tuple(_) | Type::Unit => false,
+                    Type::Identifier(identifier) => !self
+                        .symbol_table
+                        .borrow()
+                        .lookup_struct(identifier.name)
+                        .map_or(false, |struct_| struct_.is_record),
+                    _ => true,
+                };
+                if !is_valid {
+                    self.emit_err(TypeCheckerError::invalid_enum_payload_type(input.name(), type_, variant.span()));
+                }
+
+                // Since the enum is lowered into a struct, add its composite members to the struct dependency graph.
+                match type_ {
+                    Type::Identifier(member_type) => self.struct_graph.add_edge(input.name(), member_type.name),
+                    Type::Array(array_type) => {
+                        if let Type::Identifier(member_type) = array_type.base_element_type() {
+                            self.struct_graph.add_edge(input.name(), member_type.name);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
     }
 
     fn visit_mapping(&mut self, input: &'a Mapping) {
@@ -205,14 +274,27 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
 
     fn visit_function(&mut self, function: &'a Function) {
         // Check that the function's annotations are valid.
-        // Note that Leo does not natively support any specific annotations.
+        // Note that `@test` is the only annotation that Leo natively supports.
         for annotation in function.annotations.iter() {
-            // TODO: Change to compiler warning.
-            self.emit_err(TypeCheckerError::unknown_annotation(annotation, annotation.span))
+            match annotation.identifier.name {
+                // A test is executed without arguments, so it cannot take any inputs.
+                sym::test if !function.input.is_empty() => self
+                    .emit_err(TypeCheckerError::test_function_cannot_have_inputs(function.identifier, annotation.span)),
+                sym::test => {}
+                // TODO: Change to compiler warning.
+                _ => self.emit_err(TypeCheckerError::unknown_annotation(annotation, annotation.span)),
+            }
         }
 
         self.variant = Some(function.variant);
 
+        // Bring the type parameters of the function into scope.
+        // Transitions cannot be generic, since they are the entry points of the program.
+        if function.variant == Variant::Transition && function.is_generic() {
+            self.emit_err(TypeCheckerError::generic_transition(function.span()));
+        }
+        self.enter_generic_parameters(&function.generic_parameters);
+
         // Lookup function metadata in the symbol table.
         // Note that this unwrap is safe since function metadata is stored in a prior pass.
         let function_index = self.symbol_table.borrow().lookup_fn_symbol(function.identifier.name).unwrap().id;
@@ -232,6 +314,17 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
         // Create a new child scope for the function's parameters and body.
         let scope_index = self.create_child_scope();
 
+        // Add the const parameters to the function's scope, so that they can be used as values, e.g. as loop bounds.
+        function.generic_parameters.iter().filter(|parameter| parameter.is_const()).for_each(|parameter| {
+            if let Err(err) = self.symbol_table.borrow_mut().insert_variable(parameter.name(), VariableSymbol {
+                type_: Type::Integer(IntegerType::U32),
+                span: parameter.identifier.span,
+                declaration: VariableType::Const,
+            }) {
+                self.handler.emit_err(err);
+            }
+        });
+
         // Type check the function's parameters.
         function.input.iter().for_each(|input_var| {
             // Check that the type of input parameter is defined.
@@ -285,7 +378,11 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
                         // If the function is not a transition function, then it cannot output a record.
                         if let Type::Identifier(identifier) = function_output.type_ {
                             if !matches!(function.variant, Variant::Transition)
-                                && self.symbol_table.borrow().lookup_struct(identifier.name).unwrap().is_record
+                                && self
+                                    .symbol_table
+                                    .borrow()
+                                    .lookup_struct(identifier.name)
+                                    .map_or(false, |struct_| struct_.is_record)
                             {
                                 self.emit_err(TypeCheckerError::function_cannot_output_record(function_output.span));
                             }
@@ -353,8 +450,13 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
                     }
                     // Check that the input parameter is not a record.
                     if let Type::Identifier(identifier) = input_var.type_() {
-                        // Note that this unwrap is safe, as the type is defined.
-                        if self.symbol_table.borrow().lookup_struct(identifier.name).unwrap().is_record {
+                        // Note that the type may also be an enum.
+                        if self
+                            .symbol_table
+                            .borrow()
+                            .lookup_struct(identifier.name)
+                            .map_or(false, |struct_| struct_.is_record)
+                        {
                             self.emit_err(TypeCheckerError::finalize_cannot_take_record_as_input(input_var.span()))
                         }
                     }
@@ -394,8 +496,13 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
                     }
                     // Check that the output is not a record.
                     if let Type::Identifier(identifier) = output_type.type_() {
-                        // Note that this unwrap is safe, as the type is defined.
-                        if self.symbol_table.borrow().lookup_struct(identifier.name).unwrap().is_record {
+                        // Note that the type may also be an enum.
+                        if self
+                            .symbol_table
+                            .borrow()
+                            .lookup_struct(identifier.name)
+                            .map_or(false, |struct_| struct_.is_record)
+                        {
                             self.emit_err(TypeCheckerError::finalize_cannot_output_record(output_type.span()))
                         }
                     }
@@ -433,7 +540,8 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
         // Exit the function's scope.
         self.exit_scope(function_index);
 
-        // Unset the `variant`.
+        // Unset the `variant` and the type parameters.
         self.variant = None;
+        self.exit_generic_parameters();
     }
 }
@@ -33,13 +33,17 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
             Statement::Assert(stmt) => self.visit_assert(stmt),
             Statement::Assign(stmt) => self.visit_assign(stmt),
             Statement::Block(stmt) => self.visit_block(stmt),
+            Statement::Break(stmt) => self.visit_break(stmt),
             Statement::Conditional(stmt) => self.visit_conditional(stmt),
             Statement::Console(stmt) => self.visit_console(stmt),
             Statement::Const(stmt) => self.visit_const(stmt),
+            Statement::Continue(stmt) => self.visit_continue(stmt),
             Statement::Definition(stmt) => self.visit_definition(stmt),
             Statement::Expression(stmt) => self.visit_expression_statement(stmt),
             Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Match(stmt) => self.visit_match(stmt),
             Statement::Return(stmt) => self.visit_return(stmt),
+            Statement::While(stmt) => self.visit_while(stmt),
         }
     }
 
@@ -50,8 +54,7 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
                 self.assert_bool_type(&type_, expr.span());
             }
             AssertVariant::AssertEq(left, right) | AssertVariant::AssertNeq(left, right) => {
-                let t1 = self.visit_expression(left, &None);
-                let t2 = self.visit_expression(right, &None);
+                let (t1, t2) = self.visit_operands(left, right, &None);
 
                 // Check that the types are equal.
                 self.check_eq_types(&t1, &t2, input.span());
@@ -60,9 +63,10 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_assign(&mut self, input: &'a AssignStatement) {
-        let var_name = match input.place {
-            Expression::Identifier(id) => id,
-            _ => {
+        // The place is either a variable, or a member or element of one, e.g. `s.balance` or `arr[0u8]`.
+        let var_name = match Self::assignment_root(&input.place) {
+            Some(id) => id,
+            None => {
                 self.emit_err(TypeCheckerError::invalid_assignment_target(input.place.span()));
                 return;
             }
@@ -84,8 +88,15 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
             None
         };
 
-        if var_type.is_some() {
-            self.visit_expression(&input.value, &var_type);
+        // If the place is a member or element of the variable, then the value must have the type of the place.
+        let place_type = match (&input.place, var_type) {
+            (Expression::Identifier(_), var_type) => var_type,
+            (_, Some(_)) => self.visit_expression(&input.place, &None),
+            (_, None) => None,
+        };
+
+        if place_type.is_some() {
+            self.visit_expression(&input.value, &place_type);
         }
     }
 
@@ -99,6 +110,12 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         self.exit_scope(scope_index);
     }
 
+    fn visit_break(&mut self, input: &'a BreakStatement) {
+        if !self.is_loop {
+            self.emit_err(TypeCheckerError::loop_control_outside_loop("break", input.span()));
+        }
+    }
+
     fn visit_conditional(&mut self, input: &'a ConditionalStatement) {
         self.visit_expression(&input.condition, &Some(Type::Boolean));
 
@@ -201,32 +218,56 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         }
     }
 
+    fn visit_continue(&mut self, input: &'a ContinueStatement) {
+        if !self.is_loop {
+            self.emit_err(TypeCheckerError::loop_control_outside_loop("continue", input.span()));
+        }
+    }
+
     fn visit_definition(&mut self, input: &'a DefinitionStatement) {
-        // Check that the type of the definition is defined.
-        self.assert_type_is_valid(&input.type_, input.span);
+        let type_ = match &input.type_ {
+            Some(type_) => {
+                // Check that the type of the definition is defined.
+                self.assert_type_is_valid(type_, input.span);
+
+                // Check that the type of the definition is not a unit type, singleton tuple type, or nested tuple type.
+                match type_ {
+                    // If the type is an empty tuple, return an error.
+                    Type::Unit => self.emit_err(TypeCheckerError::lhs_must_be_identifier_or_tuple(input.span)),
+                    // If the type is a singleton tuple, return an error.
+                    Type::Tuple(tuple) => match tuple.length() {
+                        0 | 1 => unreachable!("Parsing guarantees that tuple types have at least two elements."),
+                        _ => {
+                            if tuple.elements().iter().any(|type_| matches!(type_, Type::Tuple(_))) {
+                                self.emit_err(TypeCheckerError::nested_tuple_type(input.span))
+                            }
+                        }
+                    },
+                    Type::Mapping(_) | Type::Err => unreachable!(
+                        "Parsing guarantees that `mapping` and `err` types are not present at this location in the AST."
+                    ),
+                    // Otherwise, the type is valid.
+                    _ => (), // Do nothing
+                }
 
-        // Check that the type of the definition is not a unit type, singleton tuple type, or nested tuple type.
-        match &input.type_ {
-            // If the type is an empty tuple, return an error.
-            Type::Unit => self.emit_err(TypeCheckerError::lhs_must_be_identifier_or_tuple(input.span)),
-            // If the type is a singleton tuple, return an error.
-            Type::Tuple(tuple) => match tuple.length() {
-                0 | 1 => unreachable!("Parsing guarantees that tuple types have at least two elements."),
-                _ => {
-                    if tuple.elements().iter().any(|type_| matches!(type_, Type::Tuple(_))) {
-                        self.emit_err(TypeCheckerError::nested_tuple_type(input.span))
-                    }
+                // Check the expression on the right-hand side.
+                self.visit_expression(&input.value, &Some(type_.clone()));
+                type_.clone()
+            }
+            // Otherwise, infer the type from the expression on the right-hand side.
+            // Note that if the type cannot be inferred, then an error has already been emitted.
+            None => match self.infer_type(&input.value) {
+                Some(type_ @ (Type::Unit | Type::Mapping(_))) => {
+                    self.emit_err(TypeCheckerError::variable_cannot_have_type(&type_, input.value.span()));
+                    Type::Err
                 }
+                Some(type_) => type_,
+                None => Type::Err,
             },
-            Type::Mapping(_) | Type::Err => unreachable!(
-                "Parsing guarantees that `mapping` and `err` types are not present at this location in the AST."
-            ),
-            // Otherwise, the type is valid.
-            _ => (), // Do nothing
-        }
+        };
 
-        // Check the expression on the right-hand side.
-        self.visit_expression(&input.value, &Some(input.type_.clone()));
+        // Record the type of the bindings, so that later passes see the inferred type.
+        self.type_table.insert(input.place.id(), type_.clone());
 
         // TODO: Dedup with unrolling pass.
         // Helper to insert the variables into the symbol table.
@@ -242,15 +283,15 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
 
         // Insert the variables into the symbol table.
         match &input.place {
-            Expression::Identifier(identifier) => {
-                insert_variable(identifier.name, input.type_.clone(), identifier.span)
-            }
+            Expression::Identifier(identifier) => insert_variable(identifier.name, type_, identifier.span),
             Expression::Tuple(tuple_expression) => {
-                let tuple_type = match &input.type_ {
+                let tuple_type = match type_ {
                     Type::Tuple(tuple_type) => tuple_type,
-                    _ => unreachable!(
-                        "Type checking guarantees that if the lhs is a tuple, its associated type is also a tuple."
-                    ),
+                    // If the type could not be inferred, then the bindings are given the erroneous type.
+                    Type::Err => TupleType::new(vec![Type::Err; tuple_expression.elements.len()]),
+                    type_ => {
+                        return self.emit_err(TypeCheckerError::type_should_be(type_, "tuple", input.value.span()));
+                    }
                 };
                 if tuple_expression.elements.len() != tuple_type.length() {
                     return self.emit_err(TypeCheckerError::incorrect_num_tuple_elements(
@@ -282,7 +323,8 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         // Expression statements can only be function calls.
         if !matches!(
             input.expression,
-            Expression::Call(_) | Expression::Access(AccessExpression::AssociatedFunction(_))
+            Expression::Call(_)
+                | Expression::Access(AccessExpression::AssociatedFunction(_) | AccessExpression::MethodCall(_))
         ) {
             self.emit_err(TypeCheckerError::expression_statement_must_be_function_call(input.span()));
         } else {
@@ -307,21 +349,7 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
             self.handler.emit_err(err);
         }
 
-        let prior_has_return = core::mem::take(&mut self.has_return);
-        let prior_has_finalize = core::mem::take(&mut self.has_finalize);
-
-        self.visit_block(&input.block);
-
-        if self.has_return {
-            self.emit_err(TypeCheckerError::loop_body_contains_return(input.span()));
-        }
-
-        if self.has_finalize {
-            self.emit_err(TypeCheckerError::loop_body_contains_finalize(input.span()));
-        }
-
-        self.has_return = prior_has_return;
-        self.has_finalize = prior_has_finalize;
+        self.visit_loop_body(&input.block, input.span());
 
         // Exit the scope.
         self.exit_scope(scope_index);
@@ -333,7 +361,7 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         match &input.start {
             Expression::Literal(literal) => {
                 // Note that this check is needed because the pass attempts to make progress, even though the literal may be invalid.
-                if let Ok(value) = Value::try_from(literal) {
+                if let Ok(value) = Value::try_from(&literal.clone().with_type(&input.type_)) {
                     input.start_value.replace(Some(value));
                 }
             }
@@ -353,7 +381,7 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         match &input.stop {
             Expression::Literal(literal) => {
                 // Note that this check is needed because the pass attempts to make progress, even though the literal may be invalid.
-                if let Ok(value) = Value::try_from(literal) {
+                if let Ok(value) = Value::try_from(&literal.clone().with_type(&input.type_)) {
                     input.stop_value.replace(Some(value));
                 }
             }
@@ -368,6 +396,112 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         }
     }
 
+    fn visit_match(&mut self, input: &'a MatchStatement) {
+        // Check that the matched value is of an enum type.
+        let enum_ = match self.visit_expression(&input.expression, &None) {
+            Some(type_) => {
+                let enum_ = self.lookup_enum_type(&type_);
+                if enum_.is_none() {
+                    self.emit_err(TypeCheckerError::match_on_non_enum(type_, input.expression.span()));
+                }
+                enum_
+            }
+            None => None,
+        };
+
+        // The variants that are not matched by the arms visited so far.
+        let mut unmatched: Vec<Symbol> =
+            enum_.iter().flat_map(|enum_| enum_.variants.iter().map(|variant| variant.name())).collect();
+
+        // The flags are set for the match if they are set for every arm.
+        let previous_has_return = core::mem::take(&mut self.has_return);
+        let previous_has_finalize = core::mem::take(&mut self.has_finalize);
+        let mut arms_have_return = !input.arms.is_empty();
+        let mut arms_have_finalize = !input.arms.is_empty();
+
+        for arm in input.arms.iter() {
+            // Check the pattern, getting the payload types of the matched variant.
+            let payload = match (&arm.pattern, &enum_) {
+                (MatchPattern::Variant { ty, variant, bindings, span }, Some(enum_)) => {
+                    if ty.name != enum_.name() {
+                        self.emit_err(TypeCheckerError::match_arm_wrong_enum(enum_.name(), ty.name, *span));
+                        None
+                    } else {
+                        let payload = self.check_enum_variant(enum_, variant);
+                        if let Some(payload) = &payload {
+                            // Check that the variant is not matched by a previous arm.
+                            match unmatched.iter().position(|name| *name == variant.name) {
+                                Some(index) => {
+                                    unmatched.remove(index);
+                                }
+                                None => self.emit_err(TypeCheckerError::unreachable_match_arm(&arm.pattern, *span)),
+                            }
+                            // Check that there is a binding for each value carried by the variant.
+                            if payload.len() != bindings.len() {
+                                self.emit_err(TypeCheckerError::incorrect_num_variant_values(
+                                    variant,
+                                    payload.len(),
+                                    bindings.len(),
+                                    *span,
+                                ));
+                            }
+                        }
+                        payload
+                    }
+                }
+                (MatchPattern::Wildcard(span), Some(_)) => {
+                    // The wildcard matches all of the remaining variants.
+                    if unmatched.is_empty() {
+                        self.emit_err(TypeCheckerError::unreachable_match_arm(&arm.pattern, *span));
+                    }
+                    unmatched.clear();
+                    None
+                }
+                _ => None,
+            };
+
+            self.has_return = false;
+            self.has_finalize = false;
+
+            // Create a new scope for the arm, containing the bindings of the pattern.
+            let scope_index = self.create_child_scope();
+
+            if let MatchPattern::Variant { bindings, .. } = &arm.pattern {
+                for (binding, type_) in bindings.iter().zip(payload.iter().flatten()) {
+                    if let Some(binding) = binding {
+                        if let Err(err) = self.symbol_table.borrow_mut().insert_variable(binding.name, VariableSymbol {
+                            type_: type_.clone(),
+                            span: binding.span,
+                            declaration: VariableType::Mut,
+                        }) {
+                            self.handler.emit_err(err);
+                        }
+                    }
+                }
+            }
+
+            arm.block.statements.iter().for_each(|stmt| self.visit_statement(stmt));
+
+            // Exit the scope for the arm.
+            self.exit_scope(scope_index);
+
+            arms_have_return &= self.has_return;
+            arms_have_finalize &= self.has_finalize;
+        }
+
+        // Check that every variant is matched.
+        if let Some(enum_) = &enum_ {
+            if !unmatched.is_empty() {
+                let variants = unmatched.iter().map(|variant| format!("`{}::{variant}`", enum_.name())).join(", ");
+                self.emit_err(TypeCheckerError::non_exhaustive_match(variants, input.span()));
+            }
+        }
+
+        // Restore the previous flags.
+        self.has_return = previous_has_return || arms_have_return;
+        self.has_finalize = previous_has_finalize || arms_have_finalize;
+    }
+
     fn visit_return(&mut self, input: &'a ReturnStatement) {
         // We can safely unwrap all self.parent instances because
         // statements should always have some parent block
@@ -431,4 +565,30 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
             }
         }
     }
+
+    fn visit_while(&mut self, input: &'a WhileStatement) {
+        self.visit_expression(&input.condition, &Some(Type::Boolean));
+
+        // Create a new scope for the loop body.
+        let scope_index = self.create_child_scope();
+
+        self.visit_loop_body(&input.block, input.span());
+
+        // Exit the scope.
+        self.exit_scope(scope_index);
+
+        // The bound must be known when the loop is unrolled.
+        self.visit_expression(&input.bound, &Some(Type::Integer(IntegerType::U32)));
+        match &input.bound {
+            Expression::Literal(_) => {}
+            Expression::Identifier(id) => {
+                if let Some(var) = self.symbol_table.borrow().lookup_variable(id.name) {
+                    if VariableType::Const != var.declaration {
+                        self.emit_err(TypeCheckerError::loop_bound_must_be_literal_or_const(id.span));
+                    }
+                }
+            }
+            _ => self.emit_err(TypeCheckerError::loop_bound_must_be_literal_or_const(input.bound.span())),
+        }
+    }
 }
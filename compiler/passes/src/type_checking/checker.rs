@@ -14,16 +14,42 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CallGraph, StructGraph, SymbolTable, TypeTable};
-
-use leo_ast::{CoreConstant, CoreFunction, Identifier, IntegerType, MappingType, Node, Type, Variant};
+use crate::{CallGraph, FunctionSymbol, StructGraph, SymbolTable, TypeTable};
+
+use leo_ast::{
+    AccessExpression,
+    ArrayLength,
+    BinaryOperation,
+    Block,
+    CoreConstant,
+    CoreFunction,
+    Enum,
+    Expression,
+    ExpressionVisitor,
+    GenericArgument,
+    GenericBound,
+    GenericParameter,
+    Identifier,
+    Impl,
+    IntegerType,
+    Literal,
+    MappingType,
+    Node,
+    StatementVisitor,
+    Struct,
+    TupleType,
+    Type,
+    UnaryOperation,
+    Variant,
+};
 use leo_errors::{emitter::Handler, TypeCheckerError};
 use leo_span::{Span, Symbol};
 
 use snarkvm_console::network::{Network, Testnet3};
 
+use indexmap::{IndexMap, IndexSet};
 use itertools::Itertools;
-use std::cell::RefCell;
+use std::{cell::RefCell, str::FromStr};
 
 pub struct TypeChecker<'a> {
     /// The symbol table for the program.
@@ -51,6 +77,12 @@ pub struct TypeChecker<'a> {
     pub(crate) is_imported: bool,
     /// Whether or not we are currently traversing a return statement.
     pub(crate) is_return: bool,
+    /// Whether or not we are currently traversing the body of a loop.
+    pub(crate) is_loop: bool,
+    /// The type parameters of the function or struct that we are currently traversing, and their bounds.
+    pub(crate) generic_parameters: IndexMap<Symbol, Option<GenericBound>>,
+    /// The const parameters of the function that we are currently traversing.
+    pub(crate) const_parameters: IndexSet<Symbol>,
 }
 
 const ADDRESS_TYPE: Type = Type::Address;
@@ -100,7 +132,8 @@ const MAGNITUDE_TYPES: [Type; 3] =
 impl<'a> TypeChecker<'a> {
     /// Returns a new type checker given a symbol table and error handler.
     pub fn new(symbol_table: SymbolTable, type_table: &'a TypeTable, handler: &'a Handler) -> Self {
-        let struct_names = symbol_table.structs.keys().cloned().collect();
+        // Note that enums are part of the struct graph, since they are lowered into structs.
+        let struct_names = symbol_table.structs.keys().chain(symbol_table.enums.keys()).cloned().collect();
 
         let function_names = symbol_table.functions.keys().cloned().collect();
 
@@ -118,6 +151,9 @@ impl<'a> TypeChecker<'a> {
             is_finalize: false,
             is_imported: false,
             is_return: false,
+            is_loop: false,
+            generic_parameters: IndexMap::new(),
+            const_parameters: IndexSet::new(),
         }
     }
 
@@ -151,9 +187,17 @@ impl<'a> TypeChecker<'a> {
     }
 
     /// Emits an error to the handler if the given type is invalid.
+    /// A type parameter is valid if every type satisfying its bound is valid.
     fn check_type(&self, is_valid: impl Fn(&Type) -> bool, error_string: String, type_: &Option<Type>, span: Span) {
         if let Some(type_) = type_ {
-            if !is_valid(type_) {
+            let is_valid_parameter = || match type_ {
+                Type::Identifier(identifier) => match self.generic_parameters.get(&identifier.name) {
+                    Some(Some(bound)) => bound.types().iter().all(&is_valid),
+                    _ => false,
+                },
+                _ => false,
+            };
+            if !is_valid(type_) && !is_valid_parameter() {
                 self.emit_err(TypeCheckerError::expected_one_type_of(error_string, type_, span));
             }
         }
@@ -1038,6 +1082,25 @@ impl<'a> TypeChecker<'a> {
         Type::Identifier(struct_)
     }
 
+    /// Returns the enum named by `type_`, if it exists.
+    pub(crate) fn lookup_enum_type(&self, type_: &Type) -> Option<Enum> {
+        match type_ {
+            Type::Identifier(identifier) => self.symbol_table.borrow().lookup_enum(identifier.name).cloned(),
+            _ => None,
+        }
+    }
+
+    /// Returns the payload types of the variant `variant` of `enum_`, emitting an error if the variant does not exist.
+    pub(crate) fn check_enum_variant(&self, enum_: &Enum, variant: &Identifier) -> Option<Vec<Type>> {
+        match enum_.variant(variant.name) {
+            Some(variant) => Some(variant.payload.clone()),
+            None => {
+                self.emit_err(TypeCheckerError::unknown_enum_variant(enum_.name(), variant.name, variant.span));
+                None
+            }
+        }
+    }
+
     /// Emits an error if the struct member is a record type.
     pub(crate) fn assert_member_is_not_record(&self, span: Span, parent: Symbol, type_: &Type) {
         match type_ {
@@ -1068,11 +1131,58 @@ impl<'a> TypeChecker<'a> {
                 is_valid = false;
                 self.emit_err(TypeCheckerError::strings_are_not_supported(span));
             }
+            // Type parameters in scope are valid.
+            Type::Identifier(identifier) if self.generic_parameters.contains_key(&identifier.name) => {}
             // Check that the named composite type has been defined.
-            Type::Identifier(identifier) if self.symbol_table.borrow().lookup_struct(identifier.name).is_none() => {
+            Type::Identifier(identifier)
+                if self.symbol_table.borrow().lookup_struct(identifier.name).is_none()
+                    && self.symbol_table.borrow().lookup_enum(identifier.name).is_none() =>
+            {
                 is_valid = false;
                 self.emit_err(TypeCheckerError::undefined_type(identifier.name, span));
             }
+            // Check that a generic struct is given type arguments.
+            Type::Identifier(identifier)
+                if self
+                    .symbol_table
+                    .borrow()
+                    .lookup_struct(identifier.name)
+                    .map_or(false, |struct_| struct_.is_generic()) =>
+            {
+                is_valid = false;
+                self.emit_err(TypeCheckerError::missing_type_arguments(identifier.name, span));
+            }
+            // Check that the type arguments of a generic struct are valid and satisfy its bounds.
+            Type::Generic(generic_type) => {
+                let struct_ = self.symbol_table.borrow().lookup_struct(generic_type.identifier.name).cloned();
+                match struct_ {
+                    None => {
+                        is_valid = false;
+                        self.emit_err(TypeCheckerError::undefined_type(generic_type.identifier.name, span));
+                    }
+                    Some(struct_) if !struct_.is_generic() => {
+                        is_valid = false;
+                        self.emit_err(TypeCheckerError::not_generic(struct_.name(), span));
+                    }
+                    Some(struct_) => {
+                        for type_ in generic_type.arguments.iter() {
+                            is_valid &= self.assert_type_is_valid(type_, span);
+                        }
+                        if struct_.generic_parameters.len() != generic_type.arguments.len() {
+                            is_valid = false;
+                            self.emit_err(TypeCheckerError::incorrect_num_type_arguments(
+                                struct_.name(),
+                                struct_.generic_parameters.len(),
+                                generic_type.arguments.len(),
+                                span,
+                            ));
+                        } else {
+                            is_valid &=
+                                self.check_generic_bounds(&struct_.generic_parameters, &generic_type.arguments, span);
+                        }
+                    }
+                }
+            }
             // Check that the constituent types of the tuple are valid.
             Type::Tuple(tuple_type) => {
                 for type_ in tuple_type.elements().iter() {
@@ -1087,11 +1197,20 @@ impl<'a> TypeChecker<'a> {
             // Check that the array element types are valid.
             Type::Array(array_type) => {
                 // Check that the array length is valid.
-                match array_type.length() {
-                    0 => self.emit_err(TypeCheckerError::array_empty(span)),
-                    1..=Testnet3::MAX_ARRAY_ELEMENTS => {}
-                    length => {
-                        self.emit_err(TypeCheckerError::array_too_large(length, Testnet3::MAX_ARRAY_ELEMENTS, span))
+                match array_type.array_length() {
+                    ArrayLength::Constant(length) => match length.value() {
+                        0 => self.emit_err(TypeCheckerError::array_empty(span)),
+                        1..=Testnet3::MAX_ARRAY_ELEMENTS => {}
+                        length => {
+                            self.emit_err(TypeCheckerError::array_too_large(length, Testnet3::MAX_ARRAY_ELEMENTS, span))
+                        }
+                    },
+                    // The length of each copy is checked once the const parameter is replaced by its argument.
+                    ArrayLength::Parameter(parameter) => {
+                        if !self.const_parameters.contains(&parameter.name) {
+                            is_valid = false;
+                            self.emit_err(TypeCheckerError::undefined_array_length(parameter, span));
+                        }
                     }
                 }
                 // Check that the array element type is valid.
@@ -1117,6 +1236,153 @@ impl<'a> TypeChecker<'a> {
         is_valid
     }
 
+    /// Returns `true` if `type_` satisfies `bound`.
+    /// A type parameter in scope satisfies `bound` if every type satisfying its own bound does.
+    pub(crate) fn satisfies_bound(&self, type_: &Type, bound: GenericBound) -> bool {
+        match type_ {
+            Type::Identifier(identifier) if self.generic_parameters.contains_key(&identifier.name) => {
+                match self.generic_parameters[&identifier.name] {
+                    Some(own_bound) => own_bound.types().iter().all(|type_| bound.is_satisfied_by(type_)),
+                    None => false,
+                }
+            }
+            _ => bound.is_satisfied_by(type_),
+        }
+    }
+
+    /// Emits an error for each type argument that does not satisfy the bound of its type parameter.
+    pub(crate) fn check_generic_bounds(&self, parameters: &[GenericParameter], arguments: &[Type], span: Span) -> bool {
+        let mut is_valid = true;
+        for (parameter, argument) in parameters.iter().zip(arguments.iter()) {
+            if let (Some(bound), false) = (parameter.bound, matches!(argument, Type::Err)) {
+                if !self.satisfies_bound(argument, bound) {
+                    is_valid = false;
+                    self.emit_err(TypeCheckerError::generic_bound_not_satisfied(argument, parameter, span));
+                }
+            }
+        }
+        is_valid
+    }
+
+    /// Infers the arguments of a generic function or struct.
+    /// Each of the `inputs` holds the declared type, the actual type, and the span of an argument or member,
+    /// and `output` holds the declared type of the result and its expected type, if known.
+    /// Emits an error for each argument that cannot be inferred, or that does not satisfy its bound.
+    pub(crate) fn infer_type_arguments(
+        &self,
+        parameters: &[GenericParameter],
+        inputs: Vec<(Type, Option<Type>, Span)>,
+        output: (&Type, &Option<Type>),
+        name: Symbol,
+        span: Span,
+    ) -> IndexMap<Symbol, GenericArgument> {
+        let mut arguments = parameters.iter().map(|parameter| (parameter.name(), None)).collect::<IndexMap<_, _>>();
+        for (declared, actual, span) in inputs {
+            if let Some(actual) = actual {
+                if !declared.infer(&actual, &mut arguments) {
+                    let inferred = arguments
+                        .iter()
+                        .filter_map(|(name, argument)| argument.clone().map(|argument| (*name, argument)))
+                        .collect();
+                    self.emit_err(TypeCheckerError::type_should_be(actual, declared.substitute(&inferred), span));
+                }
+            }
+        }
+
+        // The expected type of the result can only bind the parameters that do not appear in the inputs.
+        // Note that a mismatch with the expected type is reported by the caller.
+        if let (declared, Some(expected)) = output {
+            let mut with_output = arguments.clone();
+            if declared.infer(expected, &mut with_output) {
+                arguments = with_output;
+            }
+        }
+
+        let arguments = parameters
+            .iter()
+            .map(|parameter| match &arguments[&parameter.name()] {
+                Some(argument) => (parameter.name(), argument.clone()),
+                None if parameter.is_const() => {
+                    self.emit_err(TypeCheckerError::cannot_infer_const_argument(parameter.name(), name, span));
+                    (parameter.name(), GenericArgument::Const(ArrayLength::Parameter(parameter.identifier)))
+                }
+                None => {
+                    self.emit_err(TypeCheckerError::cannot_infer_type_argument(parameter.name(), name, span));
+                    (parameter.name(), GenericArgument::Type(Type::Err))
+                }
+            })
+            .collect::<IndexMap<_, _>>();
+
+        // Check the bounds of the type parameters.
+        let (type_parameters, type_arguments): (Vec<_>, Vec<_>) = parameters
+            .iter()
+            .filter_map(|parameter| match &arguments[&parameter.name()] {
+                GenericArgument::Type(type_) => Some((parameter.clone(), type_.clone())),
+                GenericArgument::Const(_) => None,
+            })
+            .unzip();
+        self.check_generic_bounds(&type_parameters, &type_arguments, span);
+        arguments
+    }
+
+    /// Returns `true` if the type is a type parameter in scope.
+    pub(crate) fn is_generic_parameter(&self, type_: &Type) -> bool {
+        matches!(type_, Type::Identifier(identifier) if self.generic_parameters.contains_key(&identifier.name))
+    }
+
+    /// Sets the type parameters and const parameters in scope, emitting an error if a name is used twice.
+    pub(crate) fn enter_generic_parameters(&mut self, parameters: &[GenericParameter]) {
+        self.exit_generic_parameters();
+        for parameter in parameters.iter() {
+            let is_duplicate = match &parameter.const_type {
+                Some(type_) => {
+                    if !matches!(type_, Type::Integer(IntegerType::U32)) {
+                        self.emit_err(TypeCheckerError::invalid_const_parameter_type(
+                            parameter.name(),
+                            type_,
+                            parameter.span,
+                        ));
+                    }
+                    self.generic_parameters.contains_key(&parameter.name())
+                        || !self.const_parameters.insert(parameter.name())
+                }
+                None => {
+                    self.const_parameters.contains(&parameter.name())
+                        || self.generic_parameters.insert(parameter.name(), parameter.bound).is_some()
+                }
+            };
+            if is_duplicate {
+                self.emit_err(TypeCheckerError::duplicate_generic_parameter(parameter.name(), parameter.span));
+            }
+        }
+    }
+
+    /// Removes the type parameters and const parameters from scope.
+    pub(crate) fn exit_generic_parameters(&mut self) {
+        self.generic_parameters.clear();
+        self.const_parameters.clear();
+    }
+
+    /// Returns the struct named by a struct type, and the types of its members.
+    /// The members of a generic struct are instantiated with the type arguments.
+    pub(crate) fn lookup_struct_type(&self, type_: &Type) -> Option<(Struct, Vec<(Symbol, Type)>)> {
+        let (identifier, arguments) = match type_ {
+            Type::Identifier(identifier) => (identifier, &[][..]),
+            Type::Generic(generic_type) => (&generic_type.identifier, &generic_type.arguments[..]),
+            _ => return None,
+        };
+        let struct_ = self.symbol_table.borrow().lookup_struct(identifier.name).cloned()?;
+        let arguments: IndexMap<Symbol, GenericArgument> = struct_
+            .generic_parameters
+            .iter()
+            .map(|parameter| parameter.name())
+            .zip(arguments.iter().cloned().map(GenericArgument::Type))
+            .collect();
+        let members =
+            struct_.members.iter().map(|member| (member.name(), member.type_.substitute(&arguments))).collect();
+        Some((struct_, members))
+    }
+
     /// Emits an error if the type is not a mapping.
     pub(crate) fn assert_mapping_type(&self, type_: &Option<Type>, span: Span) -> Option<MappingType> {
         self.check_type(|type_| matches!(type_, Type::Mapping(_)), "mapping".to_string(), type_, span);
@@ -1130,6 +1396,248 @@ impl<'a> TypeChecker<'a> {
     pub(crate) fn assert_array_type(&self, type_: &Option<Type>, span: Span) {
         self.check_type(|type_| matches!(type_, Type::Array(_)), "array".to_string(), type_, span);
     }
+
+    /// Returns `true` if the type of `expression` can only be inferred from its context, e.g. `1` or `1 + 2`.
+    pub(crate) fn is_untyped(expression: &Expression) -> bool {
+        match expression {
+            Expression::Literal(Literal::Unsuffixed(..)) => true,
+            Expression::Binary(binary) => match binary.op {
+                BinaryOperation::Eq
+                | BinaryOperation::Neq
+                | BinaryOperation::Lt
+                | BinaryOperation::Gt
+                | BinaryOperation::Lte
+                | BinaryOperation::Gte => false,
+                BinaryOperation::Pow
+                | BinaryOperation::PowWrapped
+                | BinaryOperation::Shl
+                | BinaryOperation::ShlWrapped
+                | BinaryOperation::Shr
+                | BinaryOperation::ShrWrapped => Self::is_untyped(&binary.left),
+                _ => Self::is_untyped(&binary.left) && Self::is_untyped(&binary.right),
+            },
+            Expression::Ternary(ternary) => Self::is_untyped(&ternary.if_true) && Self::is_untyped(&ternary.if_false),
+            Expression::Unary(unary) => match unary.op {
+                UnaryOperation::ToXCoordinate | UnaryOperation::ToYCoordinate => false,
+                _ => Self::is_untyped(&unary.receiver),
+            },
+            _ => false,
+        }
+    }
+
+    /// Visits two expressions that must have the same type, e.g. the operands of `a + 1`.
+    /// If the expected type is unknown and one of the expressions is untyped, its type is inferred from the other one.
+    pub(crate) fn visit_operands(
+        &mut self,
+        left: &'a Expression,
+        right: &'a Expression,
+        expected: &Option<Type>,
+    ) -> (Option<Type>, Option<Type>) {
+        if expected.is_some() {
+            return (self.visit_expression(left, expected), self.visit_expression(right, expected));
+        }
+        match (Self::is_untyped(left), Self::is_untyped(right)) {
+            (true, false) => {
+                let t2 = self.visit_expression(right, &None);
+                (self.visit_expression(left, &t2), t2)
+            }
+            (false, true) => {
+                let t1 = self.visit_expression(left, &None);
+                let t2 = self.visit_expression(right, &t1);
+                (t1, t2)
+            }
+            _ => (self.visit_expression(left, &None), self.visit_expression(right, &None)),
+        }
+    }
+
+    /// Visits an expression whose type is not known from its context, returning its inferred type.
+    /// Note that a tuple is otherwise only typed by its context, so the type of a tuple expression is inferred from its elements.
+    pub(crate) fn infer_type(&mut self, expression: &'a Expression) -> Option<Type> {
+        match expression {
+            Expression::Tuple(tuple) => {
+                let types: Vec<_> =
+                    tuple.elements.iter().map(|element| self.visit_expression(element, &None)).collect();
+                let type_ =
+                    types.into_iter().collect::<Option<Vec<_>>>().map(|types| Type::Tuple(TupleType::new(types)));
+                if let Some(type_) = &type_ {
+                    self.type_table.insert(expression.id(), type_.clone());
+                }
+                type_
+            }
+            _ => self.visit_expression(expression, &None),
+        }
+    }
+
+    /// Returns the type that the magnitude of `base ** magnitude` or `base << magnitude` is expected to have.
+    /// An untyped magnitude defaults to `u32`, unless the base is a field, in which case it must be a field.
+    pub(crate) fn magnitude_type(base: &Option<Type>, magnitude: &Expression) -> Option<Type> {
+        match (base, Self::is_untyped(magnitude)) {
+            (Some(Type::Field), true) => Some(Type::Field),
+            (_, true) => Some(Type::Integer(IntegerType::U32)),
+            (_, false) => None,
+        }
+    }
+
+    /// Checks that `value` is a valid integer of type `integer_type`, returning the type if it is the expected one.
+    pub(crate) fn check_integer_literal(
+        &mut self,
+        integer_type: &IntegerType,
+        value: &str,
+        span: Span,
+        expected: &Option<Type>,
+    ) -> Type {
+        fn parse_integer_literal<I: FromStr>(handler: &Handler, raw_string: &str, span: Span, type_string: &str) {
+            let string = raw_string.replace('_', "");
+            if string.parse::<I>().is_err() {
+                handler.emit_err(TypeCheckerError::invalid_int_value(string, type_string, span));
+            }
+        }
+
+        match integer_type {
+            IntegerType::U8 => parse_integer_literal::<u8>(self.handler, value, span, "u8"),
+            IntegerType::U16 => parse_integer_literal::<u16>(self.handler, value, span, "u16"),
+            IntegerType::U32 => parse_integer_literal::<u32>(self.handler, value, span, "u32"),
+            IntegerType::U64 => parse_integer_literal::<u64>(self.handler, value, span, "u64"),
+            IntegerType::U128 => parse_integer_literal::<u128>(self.handler, value, span, "u128"),
+            IntegerType::I8 => parse_integer_literal::<i8>(self.handler, value, span, "i8"),
+            IntegerType::I16 => parse_integer_literal::<i16>(self.handler, value, span, "i16"),
+            IntegerType::I32 => parse_integer_literal::<i32>(self.handler, value, span, "i32"),
+            IntegerType::I64 => parse_integer_literal::<i64>(self.handler, value, span, "i64"),
+            IntegerType::I128 => parse_integer_literal::<i128>(self.handler, value, span, "i128"),
+        }
+        self.assert_and_return_type(Type::Integer(*integer_type), expected, span)
+    }
+
+    /// Checks a call to the function `function` with the given arguments, and returns its output type.
+    /// A call to a method passes the receiver as the first argument.
+    pub(crate) fn check_call(
+        &mut self,
+        function: Identifier,
+        func: FunctionSymbol,
+        arguments: &[&'a Expression],
+        external: bool,
+        span: Span,
+        expected: &Option<Type>,
+    ) -> Type {
+        // Check that the call is valid.
+        // Note that this unwrap is safe since we always set the variant before traversing the body of the function.
+        match self.variant.unwrap() {
+            // If the function is not a transition function, it can only call "inline" functions.
+            Variant::Inline | Variant::Standard => {
+                if !matches!(func.variant, Variant::Inline) {
+                    self.emit_err(TypeCheckerError::can_only_call_inline_function(span));
+                }
+            }
+            // If the function is a transition function, then check that the call is not to another local transition function.
+            Variant::Transition => {
+                if matches!(func.variant, Variant::Transition) && !external {
+                    self.emit_err(TypeCheckerError::cannot_invoke_call_to_local_transition_function(span));
+                }
+            }
+        }
+
+        // Check that the call is not to an external `inline` function.
+        if func.variant == Variant::Inline && external {
+            self.emit_err(TypeCheckerError::cannot_call_external_inline_function(span));
+        }
+
+        // Check that the call is not to a test function, since tests are not part of the compiled program.
+        if func.is_test {
+            self.emit_err(TypeCheckerError::cannot_call_test_function(function, span));
+        }
+
+        // Infer the type arguments of a generic function from the types of the arguments.
+        let type_arguments = (!func.generic_parameters.is_empty()).then(|| {
+            let inputs = func
+                .input
+                .iter()
+                .zip(arguments.iter())
+                .map(|(parameter, argument)| {
+                    (parameter.type_(), self.visit_expression(argument, &None), argument.span())
+                })
+                .collect::<Vec<_>>();
+            self.infer_type_arguments(
+                &func.generic_parameters,
+                inputs,
+                (&func.output_type, expected),
+                function.name,
+                span,
+            )
+        });
+
+        let output_type = match &type_arguments {
+            Some(type_arguments) => func.output_type.substitute(type_arguments),
+            None => func.output_type,
+        };
+        let ret = self.assert_and_return_type(output_type, expected, span);
+
+        // Check number of function arguments.
+        if func.input.len() != arguments.len() {
+            self.emit_err(TypeCheckerError::incorrect_num_args_to_call(func.input.len(), arguments.len(), span));
+        }
+
+        // Check function argument types.
+        // Note that the arguments of a call to a generic function are checked when inferring the type arguments.
+        if type_arguments.is_none() {
+            func.input.iter().zip(arguments.iter()).for_each(|(expected, argument)| {
+                self.visit_expression(argument, &Some(expected.type_()));
+            });
+        }
+
+        // Add the call to the call graph.
+        let caller_name = match self.function {
+            None => unreachable!("`self.function` is set every time a function is visited."),
+            Some(func) => func,
+        };
+        self.call_graph.add_edge(caller_name, function.name);
+
+        ret
+    }
+
+    /// Returns the name and the symbol of the function that the item `name` of the `impl` block of `type_` is declared as,
+    /// if `type_` is a struct or record with such an item.
+    pub(crate) fn lookup_impl_function(&self, type_: &Type, name: &Identifier) -> Option<(Symbol, FunctionSymbol)> {
+        let identifier = match type_ {
+            Type::Identifier(identifier) => identifier,
+            _ => return None,
+        };
+        let symbol_table = self.symbol_table.borrow();
+        symbol_table.lookup_struct(identifier.name)?;
+        let function = Impl::item_name(identifier.name, name.name);
+        symbol_table.lookup_fn_symbol(function).map(|func| (function, func.clone()))
+    }
+
+    /// Type checks the body of a loop, which may neither return nor invoke the finalize block.
+    pub(crate) fn visit_loop_body(&mut self, block: &'a Block, span: Span) {
+        let prior_has_return = core::mem::take(&mut self.has_return);
+        let prior_has_finalize = core::mem::take(&mut self.has_finalize);
+        let prior_is_loop = core::mem::replace(&mut self.is_loop, true);
+
+        self.visit_block(block);
+
+        if self.has_return {
+            self.emit_err(TypeCheckerError::loop_body_contains_return(span));
+        }
+
+        if self.has_finalize {
+            self.emit_err(TypeCheckerError::loop_body_contains_finalize(span));
+        }
+
+        self.has_return = prior_has_return;
+        self.has_finalize = prior_has_finalize;
+        self.is_loop = prior_is_loop;
+    }
+
+    /// Returns the variable that an assignment to `place` writes to, if `place` is a valid assignment target.
+    /// A valid target is a variable, or a member or element of a valid target, e.g. `s.accounts[0u8].balance`.
+    pub(crate) fn assignment_root(place: &Expression) -> Option<Identifier> {
+        match place {
+            Expression::Identifier(identifier) => Some(*identifier),
+            Expression::Access(AccessExpression::Member(access)) => Self::assignment_root(&access.inner),
+            Expression::Access(AccessExpression::Array(access)) => Self::assignment_root(&access.array),
+            _ => None,
+        }
+    }
 }
 
 fn types_to_string(types: &[Type]) -> String {
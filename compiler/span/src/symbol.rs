@@ -206,6 +206,7 @@ symbols! {
     // types
     address,
     bool,
+    Enum: "enum",
     field,
     group,
     i8,
@@ -233,16 +234,20 @@ symbols! {
     assert,
     assert_eq,
     assert_neq,
+    bound,
+    Break: "break",
     caller,
     console,
     Const: "const",
     constant,
+    Continue: "continue",
     decrement,
     Else: "else",
     finalize,
     For: "for",
     function,
     If: "if",
+    Impl: "impl",
     In: "in",
     import,
     increment,
@@ -252,6 +257,7 @@ symbols! {
     leo,
     main,
     mapping,
+    Match: "match",
     Mut: "mut",
     Return: "return",
     SelfLower: "self",
@@ -261,15 +267,21 @@ symbols! {
     then,
     transition,
     Type: "type",
+    Use: "use",
+    While: "while",
 
     aleo,
     public,
     private,
     owner,
     _nonce,
+    tag,
     program,
     block,
     height,
+
+    // annotations
+    test,
 }
 
 /// An interned string.
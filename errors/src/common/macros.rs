@@ -69,6 +69,30 @@ macro_rules! create_messages {
                 }
             }
 
+            #[inline(always)]
+            fn message(&self) -> &str {
+                match self {
+                    Self::Formatted(formatted) => &formatted.backtrace.message,
+                    Self::Backtraced(backtraced) => &backtraced.message
+                }
+            }
+
+            #[inline(always)]
+            fn help(&self) -> Option<&str> {
+                match self {
+                    Self::Formatted(formatted) => formatted.backtrace.help.as_deref(),
+                    Self::Backtraced(backtraced) => backtraced.help.as_deref()
+                }
+            }
+
+            #[inline(always)]
+            fn span(&self) -> Option<leo_span::Span> {
+                match self {
+                    Self::Formatted(formatted) => Some(formatted.span),
+                    Self::Backtraced(_) => None
+                }
+            }
+
             #[inline(always)]
             fn code_mask() -> i32 {
                 $code_mask
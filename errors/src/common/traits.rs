@@ -25,6 +25,15 @@ pub trait LeoMessageCode: Sized {
     /// Returns the prefixed warning identifier.
     fn warning_code(&self) -> String;
 
+    /// Returns the message without its code, location, or help.
+    fn message(&self) -> &str;
+
+    /// Returns the help message if it exists.
+    fn help(&self) -> Option<&str>;
+
+    /// Returns the span the message points to, if it is a formatted message.
+    fn span(&self) -> Option<leo_span::Span>;
+
     /// Returns the messages's exit code mask, as to avoid conflicts.
     fn code_mask() -> i32;
 
@@ -18,7 +18,13 @@ use crate::LeoWarning;
 
 use super::LeoError;
 use core::{default::Default, fmt};
-use std::{cell::RefCell, rc::Rc};
+use leo_span::{symbol::with_session_globals, Span};
+use serde::{Deserialize, Serialize};
+use std::{
+    cell::RefCell,
+    io::{Stdout, Write},
+    rc::Rc,
+};
 
 /// Types that are sinks for compiler errors.
 pub trait Emitter {
@@ -53,6 +59,134 @@ impl Emitter for StderrEmitter {
     }
 }
 
+/// The severity of a diagnostic.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// An error, which fails the command.
+    Error,
+    /// A warning.
+    Warning,
+}
+
+/// Where a diagnostic points to in a source file. Lines and columns start at 1.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct DiagnosticSpan {
+    /// The path of the file.
+    pub file: String,
+    /// The line on which the span starts.
+    pub line_start: usize,
+    /// The column at which the span starts.
+    pub column_start: usize,
+    /// The line on which the span ends.
+    pub line_end: usize,
+    /// The column at which the span ends.
+    pub column_end: usize,
+}
+
+impl DiagnosticSpan {
+    /// Returns where `span` points to, if it points into a source file of the current session.
+    fn new(span: Span) -> Option<Self> {
+        if span.is_dummy() {
+            return None;
+        }
+        let location = with_session_globals(|s| s.source_map.span_to_location(span))?;
+        Some(Self {
+            file: location.source_file.name.to_string(),
+            line_start: location.line_start,
+            column_start: location.col_start,
+            line_end: location.line_stop,
+            column_end: location.col_stop,
+        })
+    }
+}
+
+/// An error or warning, in a form that tools can read.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    /// The code of the error or warning, e.g. `EPAR0370005`.
+    /// Errors that do not come from Leo, e.g. those of snarkVM, have no code.
+    pub code: Option<String>,
+    /// Whether this is an error or a warning.
+    pub severity: Severity,
+    /// The message, without the code, location, or help.
+    pub message: String,
+    /// The help message, if there is one.
+    pub help: Option<String>,
+    /// Where the diagnostic points to, if it points into a source file.
+    pub span: Option<DiagnosticSpan>,
+}
+
+impl From<&LeoError> for Diagnostic {
+    fn from(err: &LeoError) -> Self {
+        let code = match err {
+            LeoError::Anyhow(_) | LeoError::LastErrorCode(_) => None,
+            err => Some(err.error_code()),
+        };
+        Self {
+            code,
+            severity: Severity::Error,
+            message: err.message(),
+            help: err.help().map(str::to_string),
+            span: err.span().and_then(DiagnosticSpan::new),
+        }
+    }
+}
+
+impl From<&LeoWarning> for Diagnostic {
+    fn from(warning: &LeoWarning) -> Self {
+        Self {
+            code: Some(warning.error_code()),
+            severity: Severity::Warning,
+            message: warning.message().to_string(),
+            help: warning.help().map(str::to_string),
+            span: warning.span().and_then(DiagnosticSpan::new),
+        }
+    }
+}
+
+/// An `Emitter` that writes each error and warning as a line of JSON, see [`Diagnostic`].
+pub struct JsonEmitter<W = Stdout> {
+    /// The sink to which the lines are written.
+    writer: W,
+    /// Exit code of the last emitted error.
+    last_error_code: Option<i32>,
+}
+
+impl<W: Write> JsonEmitter<W> {
+    /// Returns a new JSON emitter that writes to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self { writer, last_error_code: None }
+    }
+
+    /// Returns the sink to which the lines were written.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Writes `diagnostic` as a line of JSON.
+    fn emit(&mut self, diagnostic: Diagnostic) {
+        let line = serde_json::to_string(&diagnostic).expect("diagnostics always serialize to JSON");
+        // A diagnostic that cannot be written has nowhere else to go, so the failure is ignored.
+        let _ = writeln!(self.writer, "{line}").and_then(|_| self.writer.flush());
+    }
+}
+
+impl<W: Write> Emitter for JsonEmitter<W> {
+    fn emit_err(&mut self, err: LeoError) {
+        self.last_error_code = Some(err.exit_code());
+        self.emit(Diagnostic::from(&err));
+    }
+
+    fn last_emitted_err_code(&self) -> Option<i32> {
+        self.last_error_code
+    }
+
+    fn emit_warning(&mut self, warning: LeoWarning) {
+        self.emit(Diagnostic::from(&warning));
+    }
+}
+
 /// A buffer of `T`s.
 #[derive(Debug)]
 pub struct Buffer<T>(Vec<T>);
@@ -184,6 +318,11 @@ impl Handler {
         Self { inner }
     }
 
+    /// Construct a `Handler` that writes each error and warning to `writer` as a line of JSON.
+    pub fn new_json(writer: impl Write + 'static) -> Self {
+        Self::new(Box::new(JsonEmitter::new(writer)))
+    }
+
     /// Construct a `Handler` that will append to `buf`.
     pub fn new_with_buf() -> (Self, BufferEmitter) {
         let buf = BufferEmitter::default();
@@ -258,8 +397,8 @@ impl Handler {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ParserError;
-    use leo_span::{symbol::create_session_if_not_set_then, Span};
+    use crate::{ParserError, ParserWarning};
+    use leo_span::{source_map::FileName, span::BytePos, symbol::create_session_if_not_set_then, Span};
 
     #[test]
     fn fresh_no_errors() {
@@ -296,4 +435,49 @@ mod tests {
             Handler::with(|_| Ok(())).unwrap();
         })
     }
+
+    #[test]
+    fn json_emitter_writes_a_line_per_diagnostic() {
+        create_session_if_not_set_then(|s| {
+            let file = s
+                .source_map
+                .new_source("program test.aleo {\n    const x: u8 = 1u8;\n}\n", FileName::Custom("main.leo".into()));
+            // The span of `const` on the second line.
+            let span = Span::new(file.start_pos + BytePos(24), file.start_pos + BytePos(29));
+
+            let warning: LeoWarning = ParserWarning::deprecated("const", "Use `constant` instead.", span).into();
+            let err: LeoError = ParserError::unexpected_eof(Span::default()).into();
+            let (warning_code, error_code, exit_code) = (warning.error_code(), err.error_code(), err.exit_code());
+
+            let mut emitter = JsonEmitter::new(Vec::new());
+            emitter.emit_warning(warning);
+            emitter.emit_err(err);
+            assert_eq!(emitter.last_emitted_err_code(), Some(exit_code));
+
+            let output = String::from_utf8(emitter.into_inner()).unwrap();
+            let diagnostics: Vec<Diagnostic> = output.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+            assert_eq!(diagnostics, vec![
+                Diagnostic {
+                    code: Some(warning_code),
+                    severity: Severity::Warning,
+                    message: "The keyword `const` is deprecated.".to_string(),
+                    help: Some("Use `constant` instead.".to_string()),
+                    span: Some(DiagnosticSpan {
+                        file: "main.leo".to_string(),
+                        line_start: 2,
+                        column_start: 5,
+                        line_end: 2,
+                        column_end: 10,
+                    }),
+                },
+                Diagnostic {
+                    code: Some(error_code),
+                    severity: Severity::Error,
+                    message: "unexpected EOF".to_string(),
+                    help: None,
+                    span: None,
+                },
+            ]);
+        })
+    }
 }
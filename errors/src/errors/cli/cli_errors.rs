@@ -178,4 +178,151 @@ create_messages!(
         msg: format!("Failed to write file.\nIO Error: {error}"),
         help: None,
     }
+
+    @backtraced
+    unformatted_files {
+        args: (count: impl Display),
+        msg: format!("{count} file(s) would be changed by the formatter."),
+        help: Some("Run `leo fmt` to format the files.".to_string()),
+    }
+
+    @backtraced
+    invalid_lsp_message {
+        args: (error: impl Display),
+        msg: format!("Failed to parse a language server message.\nError: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    offline_registry {
+        args: (registry: impl Display),
+        msg: format!("Cannot fetch dependencies from the registry `{registry}` in offline mode."),
+        help: Some("Use a local directory as the registry, or run without `--offline`.".to_string()),
+    }
+
+    @backtraced
+    failed_to_run_tests {
+        args: (error: impl Display),
+        msg: format!("Failed to run the tests.\nSnarkVM Error: {error}"),
+        help: None,
+    }
+
+    @formatted
+    test_failed {
+        args: (name: impl Display, error: impl Display),
+        msg: format!("The test `{name}` failed: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    tests_failed {
+        args: (failed: impl Display, total: impl Display),
+        msg: format!("{failed} of {total} test(s) failed."),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_write_debug_info {
+        args: (error: impl Display),
+        msg: format!("Failed to write the debug information of the program: {error}"),
+        help: None,
+    }
+
+    @formatted
+    execution_failed {
+        args: (name: impl Display, error: impl Display),
+        msg: format!("The execution of `{name}` failed: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_write_abi {
+        args: (error: impl Display),
+        msg: format!("Failed to write the interface of the program: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_generate_bindings {
+        args: (error: impl Display),
+        msg: format!("Failed to generate the bindings of the program: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_parse_message {
+        args: (error: impl Display),
+        msg: format!("Failed to parse the message as an Aleo value: {error}"),
+        help: Some("Pass `--raw` to sign the bytes of the message instead.".to_string()),
+    }
+
+    @backtraced
+    failed_to_sign_message {
+        args: (error: impl Display),
+        msg: format!("Failed to sign the message.\nSnarkVM Error: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    invalid_signature {
+        args: (address: impl Display),
+        msg: format!("The signature is not a valid signature of the message by `{address}`."),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_decrypt_record {
+        args: (error: impl Display),
+        msg: format!("Failed to decrypt the record: {error}"),
+        help: Some("A record can only be decrypted with the view key of its owner.".to_string()),
+    }
+
+    @backtraced
+    failed_to_query {
+        args: (url: impl Display, error: impl Display),
+        msg: format!("Failed to query `{url}`: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    program_not_found {
+        args: (program: impl Display, endpoint: impl Display),
+        msg: format!("The program `{program}` is not deployed to {endpoint}."),
+        help: None,
+    }
+
+    @backtraced
+    mapping_not_found {
+        args: (mapping: impl Display, program: impl Display),
+        msg: format!("The program `{program}` does not have a mapping `{mapping}`."),
+        help: None,
+    }
+
+    @backtraced
+    mapping_key_not_set {
+        args: (key: impl Display, mapping: impl Display),
+        msg: format!("The key `{key}` is not set in the mapping `{mapping}`."),
+        help: None,
+    }
+
+    @backtraced
+    transaction_not_found {
+        args: (id: impl Display, endpoint: impl Display),
+        msg: format!("The transaction `{id}` was not found on {endpoint}."),
+        help: None,
+    }
+
+    @backtraced
+    unknown_template {
+        args: (template: impl Display),
+        msg: format!("`{template}` is neither a bundled example, a directory nor a git repository."),
+        help: Some("Run `leo example --list` to list the bundled examples.".to_string()),
+    }
+
+    @backtraced
+    failed_to_load_template {
+        args: (template: impl Display, error: impl Display),
+        msg: format!("Failed to load the template `{template}`: {error}"),
+        help: None,
+    }
 );
@@ -70,4 +70,32 @@ create_messages!(
         msg: format!("The program scope name `{program_scope_name}` must match `{file_name}`."),
         help: None,
     }
+
+    @formatted
+    module_not_found {
+        args: (module: impl Display, file_path: impl Display),
+        msg: format!("Attempted to use a module `{module}` that does not exist."),
+        help: Some(format!("Create the module at `src/{file_path}`.")),
+    }
+
+    @formatted
+    impl_target_not_found {
+        args: (target: impl Display),
+        msg: format!("Attempted to implement `{target}`, which is not a struct or record of the program."),
+        help: None,
+    }
+
+    @formatted
+    impl_of_generic_struct {
+        args: (target: impl Display),
+        msg: format!("The generic struct `{target}` cannot have an `impl` block."),
+        help: Some("Declare the methods as generic functions taking the struct instead.".to_string()),
+    }
+
+    @backtraced
+    program_interface_not_found {
+        args: (program_name: impl Display),
+        msg: format!("The interface of `{program_name}` is unknown, since it has no type-checked program scope."),
+        help: None,
+    }
 );
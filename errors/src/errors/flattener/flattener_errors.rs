@@ -53,4 +53,12 @@ create_messages!(
         ),
         help: None,
     }
+
+    /// For when a constant operation divides by zero.
+    @formatted
+    division_by_zero {
+        args: (left: impl Display, op: impl Display, right: impl Display),
+        msg: format!("The const operation `{left} {op} {right}` divides by zero."),
+        help: None,
+    }
 );
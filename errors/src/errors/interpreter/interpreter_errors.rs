@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::fmt::{Debug, Display};
+
+create_messages!(
+    /// InterpreterError enum that represents all the errors for the `leo-interpreter` crate.
+    InterpreterError,
+    code_mask: 8000i32,
+    code_prefix: "INT",
+
+    @backtraced
+    function_not_found {
+        args: (program: impl Display, function: impl Display),
+        msg: format!("The program `{program}` has no function named `{function}`."),
+        help: None,
+    }
+
+    @backtraced
+    incorrect_number_of_inputs {
+        args: (function: impl Display, expected: impl Display, found: impl Display),
+        msg: format!("The function `{function}` expects {expected} input(s), but {found} were provided."),
+        help: None,
+    }
+
+    @backtraced
+    invalid_input {
+        args: (input: impl Display, type_: impl Display),
+        msg: format!("The input `{input}` is not a valid value of type `{type_}`."),
+        help: None,
+    }
+
+    @formatted
+    assertion_failed {
+        args: (),
+        msg: "The assertion failed.",
+        help: None,
+    }
+
+    @formatted
+    assert_eq_failed {
+        args: (left: impl Display, right: impl Display),
+        msg: format!("The assertion failed: `{left}` is not equal to `{right}`."),
+        help: None,
+    }
+
+    @formatted
+    assert_neq_failed {
+        args: (left: impl Display, right: impl Display),
+        msg: format!("The assertion failed: `{left}` is equal to `{right}`."),
+        help: None,
+    }
+
+    @formatted
+    division_by_zero {
+        args: (left: impl Display, op: impl Display, right: impl Display),
+        msg: format!("The operation `{left} {op} {right}` divides by zero."),
+        help: None,
+    }
+
+    @formatted
+    overflow {
+        args: (operation: impl Display),
+        msg: format!("The operation `{operation}` overflows."),
+        help: None,
+    }
+
+    @formatted
+    operation_failed {
+        args: (operation: impl Display, error: impl Display),
+        msg: format!("The operation `{operation}` failed: {error}"),
+        help: None,
+    }
+
+    @formatted
+    array_index_out_of_bounds {
+        args: (index: impl Display, length: impl Display),
+        msg: format!("The array index `{index}` is out of bounds for an array of length `{length}`."),
+        help: None,
+    }
+
+    @formatted
+    mapping_key_not_found {
+        args: (mapping: impl Display, key: impl Display),
+        msg: format!("The mapping `{mapping}` has no value for the key `{key}`."),
+        help: None,
+    }
+
+    @formatted
+    loop_bound_exceeded {
+        args: (bound: impl Display),
+        msg: format!("The condition of the loop still holds after {bound} iteration(s)."),
+        help: Some("Increase the bound of the loop.".to_string()),
+    }
+
+    @formatted
+    external_program_not_found {
+        args: (program: impl Display),
+        msg: format!("The program `{program}` is not imported, so its functions cannot be interpreted."),
+        help: None,
+    }
+
+    @backtraced
+    interrupted {
+        args: (),
+        msg: "The run was stopped by the debugger.",
+        help: None,
+    }
+);
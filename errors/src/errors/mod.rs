@@ -17,6 +17,8 @@
 /// Contains the ASG error definitions.
 use crate::LeoMessageCode;
 
+use leo_span::Span;
+
 /// Contains the AST error definitions.
 pub mod ast;
 pub use self::ast::*;
@@ -37,6 +39,10 @@ pub use self::flattener::*;
 pub mod input;
 pub use self::input::*;
 
+/// Contains the Interpreter error definitions.
+pub mod interpreter;
+pub use self::interpreter::*;
+
 pub mod loop_unroller;
 pub use self::loop_unroller::*;
 
@@ -84,6 +90,9 @@ pub enum LeoError {
     /// Represents a Flatten Error in a Leo Error.
     #[error(transparent)]
     FlattenError(#[from] FlattenError),
+    /// Represents an Interpreter Error in a Leo Error.
+    #[error(transparent)]
+    InterpreterError(#[from] InterpreterError),
     /// Purely for just exiting with the correct status code and
     /// not re-displaying an error.
     #[error("")]
@@ -108,6 +117,7 @@ impl LeoError {
             TypeCheckerError(error) => error.error_code(),
             LoopUnrollerError(error) => error.error_code(),
             FlattenError(error) => error.error_code(),
+            InterpreterError(error) => error.error_code(),
             LastErrorCode(_) => unreachable!(),
             Anyhow(_) => unimplemented!(), // todo: implement error codes for snarkvm errors.
         }
@@ -127,10 +137,69 @@ impl LeoError {
             TypeCheckerError(error) => error.exit_code(),
             LoopUnrollerError(error) => error.exit_code(),
             FlattenError(error) => error.exit_code(),
+            InterpreterError(error) => error.exit_code(),
             LastErrorCode(code) => *code,
             Anyhow(_) => unimplemented!(), // todo: implement exit codes for snarkvm errors.
         }
     }
+
+    /// Returns the message of the error, without its code, location, or help.
+    pub fn message(&self) -> String {
+        use LeoError::*;
+
+        match self {
+            AstError(error) => error.message().to_string(),
+            CompilerError(error) => error.message().to_string(),
+            CliError(error) => error.message().to_string(),
+            InputError(error) => error.message().to_string(),
+            ParserError(error) => error.message().to_string(),
+            PackageError(error) => error.message().to_string(),
+            TypeCheckerError(error) => error.message().to_string(),
+            LoopUnrollerError(error) => error.message().to_string(),
+            FlattenError(error) => error.message().to_string(),
+            InterpreterError(error) => error.message().to_string(),
+            LastErrorCode(_) => String::new(),
+            Anyhow(error) => error.to_string(),
+        }
+    }
+
+    /// Returns the help message of the error if it exists.
+    pub fn help(&self) -> Option<&str> {
+        use LeoError::*;
+
+        match self {
+            AstError(error) => error.help(),
+            CompilerError(error) => error.help(),
+            CliError(error) => error.help(),
+            InputError(error) => error.help(),
+            ParserError(error) => error.help(),
+            PackageError(error) => error.help(),
+            TypeCheckerError(error) => error.help(),
+            LoopUnrollerError(error) => error.help(),
+            FlattenError(error) => error.help(),
+            InterpreterError(error) => error.help(),
+            LastErrorCode(_) | Anyhow(_) => None,
+        }
+    }
+
+    /// Returns the span the error points to, if it has one.
+    pub fn span(&self) -> Option<Span> {
+        use LeoError::*;
+
+        match self {
+            AstError(error) => error.span(),
+            CompilerError(error) => error.span(),
+            CliError(error) => error.span(),
+            InputError(error) => error.span(),
+            ParserError(error) => error.span(),
+            PackageError(error) => error.span(),
+            TypeCheckerError(error) => error.span(),
+            LoopUnrollerError(error) => error.span(),
+            FlattenError(error) => error.span(),
+            InterpreterError(error) => error.span(),
+            LastErrorCode(_) | Anyhow(_) => None,
+        }
+    }
 }
 
 /// The LeoWarning type that contains all sub error types.
@@ -151,6 +220,33 @@ impl LeoWarning {
             ParserWarning(warning) => warning.warning_code(),
         }
     }
+
+    /// Returns the message of the warning, without its code, location, or help.
+    pub fn message(&self) -> &str {
+        use LeoWarning::*;
+
+        match self {
+            ParserWarning(warning) => warning.message(),
+        }
+    }
+
+    /// Returns the help message of the warning if it exists.
+    pub fn help(&self) -> Option<&str> {
+        use LeoWarning::*;
+
+        match self {
+            ParserWarning(warning) => warning.help(),
+        }
+    }
+
+    /// Returns the span the warning points to, if it has one.
+    pub fn span(&self) -> Option<Span> {
+        use LeoWarning::*;
+
+        match self {
+            ParserWarning(warning) => warning.span(),
+        }
+    }
 }
 
 /// A global result type for all Leo crates, that defaults the errors to be a LeoError.
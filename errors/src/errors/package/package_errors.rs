@@ -313,4 +313,131 @@ create_messages!(
         msg: format!("IO error env file from the provided file path - {error}"),
         help: None,
     }
+
+    @backtraced
+    missing_main_file {
+        args: (),
+        msg: "The `src/` directory does not contain a `main.leo` file.".to_string(),
+        help: Some("Add a `main.leo` file declaring the program scope to the `src/` directory.".to_string()),
+    }
+
+    /// For when the dependencies declared in a manifest cannot be read.
+    @backtraced
+    failed_to_read_dependencies {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to read the dependencies declared in `{path}`: {error}"),
+        help: Some("Dependencies are declared as `\"name\": { \"version\": \"0.1.0\" }` or `\"name\": { \"path\": \"../name\" }`.".to_string()),
+    }
+
+    /// For when the dependencies cannot be written to the manifest.
+    @backtraced
+    failed_to_write_manifest {
+        args: (error: impl Display),
+        msg: format!("Failed to write the manifest file: {error}"),
+        help: None,
+    }
+
+    /// For when the lock file cannot be read.
+    @backtraced
+    failed_to_read_lock_file {
+        args: (error: impl Display),
+        msg: format!("Failed to read the lock file: {error}"),
+        help: Some("Remove `leo.lock` to resolve the dependencies again.".to_string()),
+    }
+
+    /// For when the lock file cannot be written.
+    @backtraced
+    failed_to_write_lock_file {
+        args: (error: impl Display),
+        msg: format!("Failed to write the lock file: {error}"),
+        help: None,
+    }
+
+    /// For when a dependency does not exist at its source.
+    @backtraced
+    dependency_not_found {
+        args: (name: impl Display, source: impl Display),
+        msg: format!("The dependency `{name}` was not found at {source}."),
+        help: None,
+    }
+
+    /// For when a dependency cannot be fetched.
+    @backtraced
+    failed_to_fetch_dependency {
+        args: (name: impl Display, error: impl Display),
+        msg: format!("Failed to fetch the dependency `{name}`: {error}"),
+        help: None,
+    }
+
+    /// For when dependencies import each other.
+    @backtraced
+    dependency_cycle {
+        args: (cycle: impl Display),
+        msg: format!("The dependencies form a cycle: {cycle}."),
+        help: None,
+    }
+
+    /// For when two packages depend on the same program from different sources.
+    @backtraced
+    conflicting_dependency {
+        args: (name: impl Display, first: impl Display, second: impl Display),
+        msg: format!("The dependency `{name}` is required from both {first} and {second}."),
+        help: Some("Every package must depend on the same version of a program.".to_string()),
+    }
+
+    /// For when the program of a dependency does not match the checksum in the lock file.
+    @backtraced
+    checksum_mismatch {
+        args: (name: impl Display, expected: impl Display, found: impl Display),
+        msg: format!("The checksum of the dependency `{name}` is `{found}`, but `{expected}` is locked."),
+        help: Some("The published program has changed. Remove it from `leo.lock` if the change is expected.".to_string()),
+    }
+
+    /// For when a package fetched from a registry has a path dependency.
+    @backtraced
+    unresolvable_path_dependency {
+        args: (name: impl Display, dependent: impl Display),
+        msg: format!("The path dependency `{name}` of the registry package `{dependent}` cannot be resolved."),
+        help: Some("Packages published to a registry can only depend on other published packages.".to_string()),
+    }
+
+    /// For when removing a dependency that is not declared.
+    @backtraced
+    dependency_not_declared {
+        args: (name: impl Display),
+        msg: format!("The package does not depend on `{name}`."),
+        help: None,
+    }
+
+    /// For when the program of a dependency has an IO error in the imports directory.
+    @backtraced
+    io_error_dependency_file {
+        args: (name: impl Display, error: impl ErrorArg),
+        msg: format!("IO error for the program of the dependency `{name}` in the imports directory: {error}"),
+        help: None,
+    }
+
+    /// For when the `.env` file of a package does not have a private key.
+    @backtraced
+    missing_private_key {
+        args: (path: impl Debug),
+        msg: format!("The environment file {path:?} does not set a `PRIVATE_KEY`."),
+        help: Some("Run `leo account new --write` or `leo account import <PRIVATE_KEY> --write` to set one.".to_string()),
+    }
+
+    /// For when the private key in the `.env` file of a package cannot be parsed.
+    @backtraced
+    failed_to_parse_private_key {
+        args: (error: impl Display),
+        msg: format!("Failed to parse the private key of the environment file: {error}"),
+        help: None,
+    }
+
+    /// For when a file of the `src/` directory is neither the main file nor a module used by the program.
+    @backtraced
+    unused_source_file {
+        args: (path: impl Debug),
+        msg: format!("The source file {path:?} is not `main.leo`, and the program does not `use` it as a module."),
+        help: Some("Add a `use` declaration for it, e.g. `use math;` for `src/math.leo`, or move it out of the `src/` directory.".to_string()),
+    }
 );
@@ -298,4 +298,39 @@ create_messages!(
         msg: format!("An array {kind} must have at least one element."),
         help: None,
     }
+
+    @formatted
+    unknown_generic_bound {
+        args: (bound: impl Display),
+        msg: format!("Unknown bound `{bound}` on a type parameter."),
+        help: Some("The bounds are `Int`, `SignedInt`, `UnsignedInt`, `Magnitude`, `BoolInt`, `FieldInt`, `FieldGroup`, `FieldGroupInt`, `FieldGroupSignedInt`, `FieldScalarInt`, and `FieldGroupScalarInt`.".to_string()),
+    }
+
+    @formatted
+    transition_in_module {
+        args: (),
+        msg: format!("A module cannot declare a transition."),
+        help: Some("Transitions must be declared in the program scope of `main.leo`.".to_string()),
+    }
+
+    @formatted
+    transition_in_impl {
+        args: (),
+        msg: format!("An `impl` block cannot declare a transition."),
+        help: Some("Declare the method as an `inline` or standard `function` instead.".to_string()),
+    }
+
+    @formatted
+    receiver_must_be_first_input {
+        args: (),
+        msg: format!("`self` must be the first input of a method."),
+        help: None,
+    }
+
+    @formatted
+    method_name_is_reserved {
+        args: (name: impl Display),
+        msg: format!("A method cannot be named `{name}`, since `.{name}()` calls a built-in operation."),
+        help: Some("Rename the method.".to_string()),
+    }
 );
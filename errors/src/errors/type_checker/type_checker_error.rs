@@ -712,4 +712,214 @@ create_messages!(
         msg: format!("An array cannot have a record as an element type"),
         help: None,
     }
+
+    @formatted
+    enum_has_no_variants {
+        args: (enum_: impl Display),
+        msg: format!("Enum `{enum_}` must have at least one variant"),
+        help: None,
+    }
+
+    @formatted
+    enum_has_too_many_variants {
+        args: (enum_: impl Display, max: impl Display),
+        msg: format!("Enum `{enum_}` cannot have more than {max} variants"),
+        help: None,
+    }
+
+    @formatted
+    duplicate_enum_variant {
+        args: (enum_: impl Display, variant: impl Display),
+        msg: format!("Enum `{enum_}` has more than one variant named `{variant}`"),
+        help: None,
+    }
+
+    @formatted
+    invalid_enum_payload_type {
+        args: (enum_: impl Display, type_: impl Display),
+        msg: format!("Enum `{enum_}` cannot carry a value of type `{type_}`"),
+        help: Some("The payload of a variant cannot be a record, a mapping, a tuple, a signature, or a string.".to_string()),
+    }
+
+    @formatted
+    unknown_enum_variant {
+        args: (enum_: impl Display, variant: impl Display),
+        msg: format!("Enum `{enum_}` has no variant named `{variant}`"),
+        help: None,
+    }
+
+    @formatted
+    incorrect_num_variant_values {
+        args: (variant: impl Display, expected: impl Display, received: impl Display),
+        msg: format!("Variant `{variant}` carries {expected} values, but {received} were given"),
+        help: None,
+    }
+
+    @formatted
+    match_on_non_enum {
+        args: (type_: impl Display),
+        msg: format!("Expected a value of an enum type, found one of type `{type_}`"),
+        help: Some("Only values of enum types can be matched.".to_string()),
+    }
+
+    @formatted
+    match_arm_wrong_enum {
+        args: (expected: impl Display, found: impl Display),
+        msg: format!("Expected a variant of enum `{expected}`, found a variant of `{found}`"),
+        help: None,
+    }
+
+    @formatted
+    unreachable_match_arm {
+        args: (pattern: impl Display),
+        msg: format!("The arm `{pattern}` is unreachable, since its variants are matched by a previous arm"),
+        help: None,
+    }
+
+    @formatted
+    non_exhaustive_match {
+        args: (variants: impl Display),
+        msg: format!("The match does not cover the variants: {variants}"),
+        help: Some("Add an arm for each missing variant, or a wildcard arm `_ => { ... }`.".to_string()),
+    }
+
+    @formatted
+    generic_transition {
+        args: (),
+        msg: format!("A transition cannot have type parameters"),
+        help: Some("Move the generic code into an `inline` function, and call it from the transition.".to_string()),
+    }
+
+    @formatted
+    generic_record {
+        args: (record: impl Display),
+        msg: format!("The record `{record}` cannot have type parameters"),
+        help: None,
+    }
+
+    @formatted
+    duplicate_generic_parameter {
+        args: (parameter: impl Display),
+        msg: format!("The type parameter `{parameter}` is declared more than once"),
+        help: None,
+    }
+
+    @formatted
+    missing_type_arguments {
+        args: (struct_: impl Display),
+        msg: format!("The generic struct `{struct_}` is used without type arguments"),
+        help: Some(format!("Write the type arguments after the name, e.g. `{struct_}<u32>`.")),
+    }
+
+    @formatted
+    not_generic {
+        args: (struct_: impl Display),
+        msg: format!("The struct `{struct_}` does not have type parameters"),
+        help: None,
+    }
+
+    @formatted
+    incorrect_num_type_arguments {
+        args: (struct_: impl Display, expected: impl Display, received: impl Display),
+        msg: format!("Struct `{struct_}` expected `{expected}` type arguments, but got `{received}`"),
+        help: None,
+    }
+
+    @formatted
+    generic_bound_not_satisfied {
+        args: (type_: impl Display, parameter: impl Display),
+        msg: format!("The type `{type_}` does not satisfy the bound `{parameter}`"),
+        help: None,
+    }
+
+    @formatted
+    cannot_infer_type_argument {
+        args: (parameter: impl Display, name: impl Display),
+        msg: format!("Could not infer the type of the type parameter `{parameter}` of `{name}`"),
+        help: Some("A type parameter must appear in the types of the inputs or output of its function, or of the members of its struct.".to_string()),
+    }
+
+    @formatted
+    invalid_const_parameter_type {
+        args: (parameter: impl Display, type_: impl Display),
+        msg: format!("The const parameter `{parameter}` has type `{type_}`, but const parameters must have type `u32`"),
+        help: None,
+    }
+
+    @formatted
+    generic_struct_const_parameter {
+        args: (struct_: impl Display),
+        msg: format!("The struct `{struct_}` cannot have const parameters"),
+        help: Some("Only functions can have const parameters.".to_string()),
+    }
+
+    @formatted
+    undefined_array_length {
+        args: (length: impl Display),
+        msg: format!("The array length `{length}` is not a const parameter in scope"),
+        help: Some("Declare it as a const parameter of the function, e.g. `<const N: u32>`.".to_string()),
+    }
+
+    @formatted
+    cannot_infer_const_argument {
+        args: (parameter: impl Display, name: impl Display),
+        msg: format!("Could not infer the value of the const parameter `{parameter}` of `{name}`"),
+        help: Some("A const parameter must appear as an array length in the types of the inputs or output of its function.".to_string()),
+    }
+
+    @formatted
+    test_function_cannot_have_inputs {
+        args: (name: impl Display),
+        msg: format!("The test function `{name}` cannot have inputs"),
+        help: Some("Tests are executed without arguments, define the values they need in their body instead.".to_string()),
+    }
+
+    @formatted
+    cannot_call_test_function {
+        args: (name: impl Display),
+        msg: format!("Cannot call the test function `{name}`"),
+        help: Some("Test functions are only compiled by `leo test`. Move the shared logic into a separate function.".to_string()),
+    }
+
+    @formatted
+    could_not_infer_type {
+        args: (value: impl Display),
+        msg: format!("Could not infer the type of `{value}`"),
+        help: Some(format!("Add a type suffix to the literal, e.g. `{value}u32`, or annotate the type of the variable it is assigned to.")),
+    }
+
+    @formatted
+    unsuffixed_literal_type_mismatch {
+        args: (value: impl Display, type_: impl Display),
+        msg: format!("The literal `{value}` cannot have type `{type_}`"),
+        help: Some("A literal without a type suffix can only have an integer, field, group, or scalar type.".to_string()),
+    }
+
+    @formatted
+    variable_cannot_have_type {
+        args: (type_: impl Display),
+        msg: format!("A variable cannot have type `{type_}`"),
+        help: None,
+    }
+
+    @formatted
+    unknown_method {
+        args: (type_: impl Display, method: impl Display),
+        msg: format!("The type `{type_}` has no method `{method}`"),
+        help: Some("Methods are declared in the `impl` block of a struct or record.".to_string()),
+    }
+
+    @formatted
+    not_a_method {
+        args: (type_: impl Display, function: impl Display),
+        msg: format!("`{type_}::{function}` does not take `self`, so it cannot be called as a method"),
+        help: Some(format!("Call it as `{type_}::{function}(...)` instead.")),
+    }
+
+    @formatted
+    loop_control_outside_loop {
+        args: (keyword: impl Display),
+        msg: format!("`{keyword}` can only be used in the body of a loop"),
+        help: None,
+    }
 );
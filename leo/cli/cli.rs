@@ -15,7 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::cli::{commands::*, context::*, helpers::*};
-use leo_errors::Result;
+use leo_errors::{CliError, LeoError, Result};
 
 use clap::Parser;
 use colored::Colorize;
@@ -36,6 +36,18 @@ pub struct CLI {
 
     #[clap(long, global = true, help = "Optional path to Leo program root folder")]
     path: Option<PathBuf>,
+
+    #[clap(
+        long,
+        global = true,
+        value_enum,
+        default_value_t = MessageFormat::Human,
+        help = "The format of errors and warnings, `json` writes one JSON object per line to stdout"
+    )]
+    message_format: MessageFormat,
+
+    #[clap(long, global = true, help = "Optional file to which `--message-format json` writes, instead of stdout")]
+    message_output: Option<PathBuf>,
 }
 
 ///Leo compiler and package manager
@@ -53,14 +65,29 @@ enum Commands {
     },
     #[clap(about = "Create a new Leo example package in a new directory")]
     Example {
-        #[clap(subcommand)]
+        #[clap(flatten)]
         command: Example,
     },
+    #[clap(about = "Add a dependency to the current package")]
+    Add {
+        #[clap(flatten)]
+        command: Add,
+    },
+    #[clap(about = "Remove a dependency from the current package")]
+    Remove {
+        #[clap(flatten)]
+        command: Remove,
+    },
     #[clap(about = "Compile the current package as a program")]
     Build {
         #[clap(flatten)]
         command: Build,
     },
+    #[clap(about = "Generate typed client bindings for the transitions, records and structs of the program")]
+    Bindings {
+        #[clap(flatten)]
+        command: Bindings,
+    },
     #[clap(about = "Clean the output directory")]
     Clean {
         #[clap(flatten)]
@@ -71,11 +98,51 @@ enum Commands {
         #[clap(flatten)]
         command: Run,
     },
+    #[clap(about = "Interpret a transition with input variables, without generating a proof")]
+    Eval {
+        #[clap(flatten)]
+        command: Eval,
+    },
+    #[clap(about = "Debug a transition with input variables, stepping through its statements")]
+    Debug {
+        #[clap(flatten)]
+        command: Debug,
+    },
+    #[clap(about = "Run the tests of the current package")]
+    Test {
+        #[clap(flatten)]
+        command: Test,
+    },
     #[clap(about = "Execute a program with input variables")]
     Execute {
         #[clap(flatten)]
         command: Execute,
     },
+    #[clap(about = "Deploy a program to a node")]
+    Deploy {
+        #[clap(flatten)]
+        command: Deploy,
+    },
+    #[clap(about = "Query mapping values, deployed programs and transactions from the network")]
+    Query {
+        #[clap(flatten)]
+        command: Query,
+    },
+    #[clap(about = "Operate a local development node")]
+    Node {
+        #[clap(subcommand)]
+        command: Node,
+    },
+    #[clap(about = "Format the Leo files of the current package")]
+    Fmt {
+        #[clap(flatten)]
+        command: Fmt,
+    },
+    #[clap(about = "Run the Leo language server over stdio")]
+    Lsp {
+        #[clap(flatten)]
+        command: Lsp,
+    },
     #[clap(about = "Update the Leo CLI")]
     Update {
         #[clap(flatten)]
@@ -97,19 +164,32 @@ pub fn handle_error<T>(res: Result<T>) -> T {
 pub fn run_with_args(cli: CLI) -> Result<()> {
     if !cli.quiet {
         // Init logger with optional debug flag.
-        logger::init_logger("leo", match cli.debug {
-            false => 1,
-            true => 2,
-        })?;
+        logger::init_logger(
+            "leo",
+            match cli.debug {
+                false => 1,
+                true => 2,
+            },
+            cli.message_format,
+        )?;
     }
 
     // Get custom root folder and create context for it.
     // If not specified, default context will be created in cwd.
-    let context = handle_error(Context::new(cli.path));
+    let message_format = cli.message_format;
+    let context =
+        Context { message_format, message_output: cli.message_output, ..handle_error(Context::new(cli.path)) };
+    // The file of the JSON messages only has those of this command.
+    if let (MessageFormat::Json, Some(path)) = (message_format, &context.message_output) {
+        std::fs::File::create(path).map_err(CliError::cli_io_error)?;
+    }
+    let messages = context.clone();
 
-    match cli.command {
+    let result = match cli.command {
         Commands::Account { command } => command.try_execute(context),
         Commands::New { command } => command.try_execute(context),
+        Commands::Add { command } => command.try_execute(context),
+        Commands::Remove { command } => command.try_execute(context),
         Commands::Build { command } => {
             // Enter tracing span
             let span = command.log_span();
@@ -126,10 +206,29 @@ pub fn run_with_args(cli: CLI) -> Result<()> {
 
             command.try_execute(context)
         }
+        Commands::Bindings { command } => command.try_execute(context),
         Commands::Clean { command } => command.try_execute(context),
         Commands::Example { command } => command.try_execute(context),
         Commands::Run { command } => command.try_execute(context),
+        Commands::Eval { command } => command.try_execute(context),
+        Commands::Debug { command } => command.try_execute(context),
+        Commands::Test { command } => command.try_execute(context),
         Commands::Execute { command } => command.try_execute(context),
+        Commands::Deploy { command } => command.try_execute(context),
+        Commands::Query { command } => command.try_execute(context),
+        Commands::Node { command } => command.try_execute(context),
+        Commands::Fmt { command } => command.try_execute(context),
+        Commands::Lsp { command } => command.try_execute(context),
         Commands::Update { command } => command.try_execute(context),
+    };
+
+    // The error that ends the command is reported in the same format as the other errors.
+    match result {
+        Err(err) if message_format == MessageFormat::Json && !matches!(err, LeoError::LastErrorCode(_)) => {
+            let code = err.exit_code();
+            messages.handler()?.emit_err(err);
+            Err(LeoError::LastErrorCode(code))
+        }
+        result => result,
     }
 }
@@ -16,10 +16,22 @@
 
 use super::*;
 use leo_package::root::Env;
-use snarkvm::prelude::{Address, PrivateKey, ViewKey};
+use snarkvm::prelude::{
+    Address,
+    Ciphertext,
+    Field,
+    Plaintext,
+    PrivateKey,
+    Record,
+    Signature,
+    ToFields,
+    Value,
+    ViewKey,
+};
 
 use rand::SeedableRng;
 use rand_chacha::ChaChaRng;
+use std::str::FromStr;
 
 /// Commands to manage Aleo accounts.
 #[derive(Parser, Debug)]
@@ -41,6 +53,36 @@ pub enum Account {
         #[clap(short = 'w', long)]
         write: bool,
     },
+    /// Sign a message with the private key of the .env file.
+    Sign {
+        /// The message to sign, as an Aleo value, e.g. `5field` or `{ amount: 1u64 }`.
+        #[clap(short = 'm', long)]
+        message: String,
+        /// Sign the bytes of the message, rather than the message as an Aleo value.
+        #[clap(short = 'r', long)]
+        raw: bool,
+    },
+    /// Verify the signature of a message.
+    Verify {
+        /// The address of the signer.
+        #[clap(short = 'a', long)]
+        address: Address<CurrentNetwork>,
+        /// The signature of the message.
+        #[clap(short = 's', long)]
+        signature: Signature<CurrentNetwork>,
+        /// The message that was signed, as an Aleo value.
+        #[clap(short = 'm', long)]
+        message: String,
+        /// Verify the signature of the bytes of the message, rather than the message as an Aleo value.
+        #[clap(short = 'r', long)]
+        raw: bool,
+    },
+    /// Decrypt a record with the view key of the .env file.
+    Decrypt {
+        /// The record ciphertext, e.g. `record1...`.
+        #[clap(short = 'c', long)]
+        ciphertext: Record<CurrentNetwork, Ciphertext<CurrentNetwork>>,
+    },
 }
 
 impl Command for Account {
@@ -86,6 +128,20 @@ impl Command for Account {
                     write_to_env_file(private_key, &ctx)?;
                 }
             }
+            Account::Sign { message, raw } => {
+                let private_key = Env::<CurrentNetwork>::private_key(&ctx.dir()?)?;
+                println!("{}", sign_message(&private_key, &message, raw)?);
+            }
+            Account::Verify { address, signature, message, raw } => {
+                if !verify_message(&address, &signature, &message, raw)? {
+                    return Err(CliError::invalid_signature(address).into());
+                }
+                tracing::info!("✅ The signature of the message by {address} is valid");
+            }
+            Account::Decrypt { ciphertext } => {
+                let private_key = Env::<CurrentNetwork>::private_key(&ctx.dir()?)?;
+                println!("{}", decrypt_record(&private_key, &ciphertext)?);
+            }
         }
         Ok(())
     }
@@ -115,3 +171,45 @@ fn write_to_env_file(private_key: PrivateKey<CurrentNetwork>, ctx: &Context) ->
     tracing::info!("✅ Private Key written to {}", program_dir.join(".env").display());
     Ok(())
 }
+
+// Sign the message as `sign.verify` expects it: the fields of the Aleo value, or the raw bytes.
+pub(crate) fn sign_message(
+    private_key: &PrivateKey<CurrentNetwork>,
+    message: &str,
+    raw: bool,
+) -> Result<Signature<CurrentNetwork>> {
+    let rng = &mut ChaChaRng::from_entropy();
+    let signature = match raw {
+        true => private_key.sign_bytes(message.as_bytes(), rng),
+        false => private_key.sign(&message_fields(message)?, rng),
+    };
+    Ok(signature.map_err(CliError::failed_to_sign_message)?)
+}
+
+// Verify the signature of the message by the address, signed with `sign_message`.
+pub(crate) fn verify_message(
+    address: &Address<CurrentNetwork>,
+    signature: &Signature<CurrentNetwork>,
+    message: &str,
+    raw: bool,
+) -> Result<bool> {
+    Ok(match raw {
+        true => signature.verify_bytes(address, message.as_bytes()),
+        false => signature.verify(address, &message_fields(message)?),
+    })
+}
+
+// Decrypt the record with the view key of the private key, which must be the one of its owner.
+pub(crate) fn decrypt_record(
+    private_key: &PrivateKey<CurrentNetwork>,
+    ciphertext: &Record<CurrentNetwork, Ciphertext<CurrentNetwork>>,
+) -> Result<Record<CurrentNetwork, Plaintext<CurrentNetwork>>> {
+    let view_key = ViewKey::try_from(private_key)?;
+    Ok(ciphertext.decrypt(&view_key).map_err(CliError::failed_to_decrypt_record)?)
+}
+
+// Parse the message as an Aleo value and return its fields.
+fn message_fields(message: &str) -> Result<Vec<Field<CurrentNetwork>>> {
+    let value = Value::<CurrentNetwork>::from_str(message).map_err(CliError::failed_to_parse_message)?;
+    Ok(value.to_fields().map_err(CliError::failed_to_parse_message)?)
+}
@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::cli::helpers::registry::open_registry;
+
+use leo_package::dependencies::{Dependencies, DependencySource, Resolver};
+
+use std::path::PathBuf;
+
+/// Add a dependency to the current package
+#[derive(Parser, Debug)]
+#[group(skip)]
+pub struct Add {
+    #[clap(name = "NAME", help = "The name of the program to depend on")]
+    pub(crate) name: String,
+
+    #[clap(long, help = "The version of the program in the registry", required_unless_present = "path")]
+    pub(crate) version: Option<String>,
+
+    #[clap(long, help = "The path to a local Leo package", conflicts_with = "version")]
+    pub(crate) path: Option<PathBuf>,
+
+    #[clap(
+        long,
+        env = "LEO_REGISTRY",
+        help = "The registry to fetch dependencies from, either a URL or a directory. Defaults to `~/.aleo/registry`."
+    )]
+    pub(crate) registry: Option<String>,
+}
+
+impl Command for Add {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let package_path = context.dir()?;
+
+        let source = match (self.path, self.version) {
+            (Some(path), _) => DependencySource::Path { path },
+            (None, Some(version)) => DependencySource::Registry { version },
+            (None, None) => unreachable!("Clap requires either a version or a path."),
+        };
+
+        // Declare the dependency in the manifest, replacing a previous declaration of it.
+        let mut dependencies = Dependencies::read_from(&package_path)?;
+        let previous = dependencies.insert(self.name.clone(), source.clone());
+        Dependencies::write_to(&package_path, &dependencies)?;
+
+        // Fetch the dependency, restoring the manifest if it cannot be resolved.
+        let registry = open_registry(self.registry.as_deref(), false);
+        if let Err(error) = Resolver::resolve(&package_path, registry.as_ref()) {
+            match previous {
+                Some(previous) => dependencies.insert(self.name.clone(), previous),
+                None => dependencies.shift_remove(&self.name),
+            };
+            Dependencies::write_to(&package_path, &dependencies)?;
+            return Err(error);
+        }
+
+        tracing::info!("✅ Added '{}' from {source}", self.name.bold());
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::cli::helpers::client_bindings::{rust_bindings, typescript_bindings};
+
+use leo_compiler::Abi;
+
+use std::path::PathBuf;
+
+/// The languages that bindings can be generated for.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BindingsLanguage {
+    Rust,
+    Typescript,
+}
+
+impl BindingsLanguage {
+    /// Returns the extension of the files of the language.
+    fn extension(&self) -> &'static str {
+        match self {
+            BindingsLanguage::Rust => "rs",
+            BindingsLanguage::Typescript => "ts",
+        }
+    }
+}
+
+/// Generates typed client bindings for the transitions, records and structs of the program, from `build/abi.json`.
+#[derive(Parser, Debug)]
+#[group(skip)]
+pub struct Bindings {
+    #[clap(long, value_enum, help = "The language of the bindings.")]
+    pub(crate) lang: BindingsLanguage,
+
+    #[clap(
+        short,
+        long,
+        help = "The file to write the bindings to. Defaults to `build/bindings/<program>.<extension>`."
+    )]
+    pub(crate) output: Option<PathBuf>,
+
+    #[clap(flatten)]
+    pub(crate) compiler_options: BuildOptions,
+}
+
+impl Command for Bindings {
+    type Input = <Build as Command>::Output;
+    type Output = PathBuf;
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, context: Context) -> Result<Self::Input> {
+        (Build { options: self.compiler_options.clone() }).execute(context)
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        // Read the interface of the program, that the build has written.
+        let build_directory = BuildDirectory::open(&context.dir()?)?;
+        let abi = std::fs::read_to_string(build_directory.join(ABI_FILE_NAME))
+            .map_err(CliError::failed_to_generate_bindings)?;
+        let abi: Abi = serde_json::from_str(&abi).map_err(CliError::failed_to_generate_bindings)?;
+
+        let bindings = match self.lang {
+            BindingsLanguage::Rust => rust_bindings(&abi)?,
+            BindingsLanguage::Typescript => typescript_bindings(&abi)?,
+        };
+
+        // Write the bindings, by default next to the interface.
+        let output = self.output.unwrap_or_else(|| {
+            let name = abi.program.strip_suffix(".aleo").unwrap_or(&abi.program);
+            build_directory.join("bindings").join(format!("{name}.{}", self.lang.extension()))
+        });
+        if let Some(parent) = output.parent() {
+            std::fs::create_dir_all(parent).map_err(CliError::failed_to_generate_bindings)?;
+        }
+        std::fs::write(&output, bindings).map_err(CliError::failed_to_generate_bindings)?;
+
+        tracing::info!("✅ Generated the {:?} bindings of '{}' at {}", self.lang, abi.program, output.display());
+        Ok(output)
+    }
+}
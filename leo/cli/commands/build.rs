@@ -15,15 +15,17 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use crate::cli::helpers::registry::open_registry;
 
 use leo_ast::{NodeBuilder, Struct};
 use leo_compiler::{Compiler, CompilerOptions, InputAst, OutputOptions};
 use leo_package::{
-    build::BuildDirectory,
+    build::{BuildDirectory, ABI_FILE_NAME, DEBUG_INFO_FILE_NAME},
+    dependencies::Resolver,
     imports::ImportsDirectory,
     inputs::InputFile,
     outputs::OutputsDirectory,
-    source::SourceDirectory,
+    source::{MainFile, SourceDirectory},
 };
 use leo_span::{symbol::with_session_globals, Symbol};
 
@@ -41,7 +43,7 @@ use std::{
 impl From<BuildOptions> for CompilerOptions {
     fn from(options: BuildOptions) -> Self {
         let mut out_options = Self {
-            build: leo_compiler::BuildOptions { dce_enabled: options.enable_dce },
+            build: leo_compiler::BuildOptions { dce_enabled: options.enable_dce, tests_enabled: false },
             output: OutputOptions {
                 symbol_table_spans_enabled: options.enable_symbol_table_spans,
                 initial_symbol_table: options.enable_initial_symbol_table_snapshot,
@@ -53,6 +55,7 @@ impl From<BuildOptions> for CompilerOptions {
                 unrolled_ast: options.enable_unrolled_ast_snapshot,
                 ssa_ast: options.enable_ssa_ast_snapshot,
                 flattened_ast: options.enable_flattened_ast_snapshot,
+                const_folded_ast: options.enable_const_folded_ast_snapshot,
                 destructured_ast: options.enable_destructured_ast_snapshot,
                 inlined_ast: options.enable_inlined_ast_snapshot,
                 dce_ast: options.enable_dce_ast_snapshot,
@@ -64,6 +67,7 @@ impl From<BuildOptions> for CompilerOptions {
             out_options.output.unrolled_ast = true;
             out_options.output.ssa_ast = true;
             out_options.output.flattened_ast = true;
+            out_options.output.const_folded_ast = true;
             out_options.output.destructured_ast = true;
             out_options.output.inlined_ast = true;
             out_options.output.dce_ast = true;
@@ -75,6 +79,7 @@ impl From<BuildOptions> for CompilerOptions {
 
 /// Compile and build program command.
 #[derive(Parser, Debug)]
+#[group(skip)]
 pub struct Build {
     #[clap(flatten)]
     pub(crate) options: BuildOptions,
@@ -107,7 +112,7 @@ impl Command for Build {
         let build_directory = BuildDirectory::open(&package_path)?;
 
         // Initialize error handler
-        let handler = Handler::default();
+        let handler = context.handler()?;
 
         // Initialize a node counter.
         let node_builder = NodeBuilder::default();
@@ -118,35 +123,38 @@ impl Command for Build {
         // Check the source files.
         SourceDirectory::check_files(&source_files)?;
 
-        // Store all struct declarations made in the source files.
-        let mut structs = IndexMap::new();
-
-        // Compile all .leo files into .aleo files.
-        for file_path in source_files.into_iter() {
-            structs.extend(compile_leo_file(
-                file_path,
-                &package_path,
-                program_id,
-                &outputs_directory,
-                &build_directory,
-                &handler,
-                self.options.clone(),
-                false,
-            )?);
-        }
+        // Fetch the dependencies declared in the manifest into the imports directory.
+        let registry = open_registry(self.options.registry.as_deref(), self.options.offline);
+        let lock_file = Resolver::resolve(&package_path, registry.as_ref())?;
+
+        // Compile `main.leo` into an .aleo file, the modules of the program are loaded through its `use` declarations.
+        let mut structs = compile_leo_file(
+            package_path.join(MainFile::filename()),
+            &source_files,
+            program_id,
+            &outputs_directory,
+            &build_directory,
+            &handler,
+            self.options.clone(),
+            false,
+        )?;
 
         if !ImportsDirectory::is_empty(&package_path)? {
             // Create Aleo build/imports/ directory.
             let build_imports_directory = ImportsDirectory::create(&build_directory)?;
 
-            // Fetch paths to all .leo files in the imports directory.
-            let import_files = ImportsDirectory::files(&package_path)?;
+            // Fetch paths to all .leo files in the imports directory, with the dependencies in dependency order.
+            let mut import_files = ImportsDirectory::files(&package_path)?;
+            import_files.sort_by_key(|path| {
+                let name = path.file_stem().and_then(|name| name.to_str()).unwrap_or_default();
+                lock_file.position(name).unwrap_or(usize::MAX)
+            });
 
             // Compile all .leo files into .aleo files.
             for file_path in import_files.into_iter() {
                 structs.extend(compile_leo_file(
                     file_path,
-                    &package_path,
+                    &[],
                     program_id,
                     &outputs_directory,
                     &build_imports_directory,
@@ -168,7 +176,7 @@ impl Command for Build {
 
             // TODO: This is a hack to notify the user that something is wrong with the input file. Redesign.
             leo_parser::parse_input(&handler, &node_builder, &input_sf.src, input_sf.start_pos)
-                .map_err(|_e| println!("Warning: Failed to parse input file"))
+                .map_err(|_e| tracing::warn!("Failed to parse input file"))
                 .ok()
         } else {
             None
@@ -200,10 +208,11 @@ impl Command for Build {
 }
 
 /// Compiles a Leo file in the `src/` directory.
+/// The other `source_files` of the directory must be the modules of the main program, and are empty for an import.
 #[allow(clippy::too_many_arguments)]
 fn compile_leo_file(
     file_path: PathBuf,
-    _package_path: &Path,
+    source_files: &[PathBuf],
     program_id: &ProgramID<Testnet3>,
     outputs: &Path,
     build: &Path,
@@ -229,6 +238,9 @@ fn compile_leo_file(
         false => format!("main.{}", program_id.network()),
     });
 
+    // Only the main program is run directly, so only its instructions are mapped back to the source.
+    let write_debug_info = options.enable_debug_info && !is_import;
+
     // Create a new instance of the Leo compiler.
     let mut compiler = Compiler::new(
         program_name,
@@ -242,12 +254,32 @@ fn compile_leo_file(
     // Compile the Leo program into Aleo instructions.
     let (symbol_table, instructions) = compiler.compile()?;
 
+    // The other files of the source directory are the modules that the main program uses.
+    if !is_import {
+        SourceDirectory::check_modules(source_files, &compiler.modules)?;
+    }
+
     // Write the instructions.
     std::fs::File::create(&aleo_file_path)
         .map_err(CliError::failed_to_load_instructions)?
         .write_all(instructions.as_bytes())
         .map_err(CliError::failed_to_load_instructions)?;
 
+    // Write the sources of the instructions next to them.
+    if write_debug_info {
+        // The debug information is only valid for the instructions it was built with.
+        compiler.debug_info.checksum = Resolver::checksum(&instructions);
+        let debug_info =
+            serde_json::to_string_pretty(&compiler.debug_info).map_err(CliError::failed_to_write_debug_info)?;
+        std::fs::write(build.join(DEBUG_INFO_FILE_NAME), debug_info).map_err(CliError::failed_to_write_debug_info)?;
+    }
+
+    // Write the interface of the program, which its clients are built against.
+    if !is_import {
+        let abi = compiler.abi()?.to_json_string().map_err(CliError::failed_to_write_abi)?;
+        std::fs::write(build.join(ABI_FILE_NAME), abi).map_err(CliError::failed_to_write_abi)?;
+    }
+
     tracing::info!("✅ Compiled '{}' into Aleo instructions", file_name);
     Ok(symbol_table.structs)
 }
@@ -18,6 +18,7 @@ use super::*;
 
 /// Clean outputs folder command
 #[derive(Parser, Debug)]
+#[group(skip)]
 pub struct Clean {}
 
 impl Command for Clean {
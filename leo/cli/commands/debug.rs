@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_interpreter::Debugger;
+
+/// Interprets a transition of the current package, stopping before its statements to inspect its state.
+#[derive(Parser, Debug)]
+#[group(skip)]
+pub struct Debug {
+    #[clap(name = "NAME", help = "The name of the transition to debug.", default_value = "main")]
+    pub(crate) name: String,
+
+    #[clap(name = "INPUTS", help = "The inputs to the transition.")]
+    pub(crate) inputs: Vec<String>,
+
+    #[clap(
+        short = 'b',
+        long = "break",
+        help = "Stop before the statements on this line. Without breakpoints, the run stops before its first statement."
+    )]
+    pub(crate) breakpoints: Vec<usize>,
+
+    #[clap(flatten)]
+    pub(crate) compiler_options: BuildOptions,
+}
+
+impl Command for Debug {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        tracing::info!("Type `help` for a list of commands.\n");
+        let debugger = Debugger::new(std::io::stdin().lock(), std::io::stdout(), self.breakpoints);
+        eval::interpret(&context, &self.name, &self.inputs, &self.compiler_options, Some(Box::new(debugger)))
+    }
+}
@@ -15,21 +15,38 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use crate::cli::helpers::devnet::DEVNET_ENDPOINT;
+
+use snarkvm::{circuit::AleoV0, package::Package, prelude::ProgramID};
+
+use std::path::Path;
 
 /// Deploys an Aleo program.
 #[derive(Parser, Debug)]
-pub struct Deploy;
+#[group(skip)]
+pub struct Deploy {
+    #[clap(
+        name = "ENDPOINT",
+        help = "The endpoint to broadcast the deployment to. Defaults to the node started by `leo node start`.",
+        default_value = DEVNET_ENDPOINT,
+        long
+    )]
+    endpoint: String,
+
+    #[clap(flatten)]
+    pub(crate) compiler_options: BuildOptions,
+}
 
 impl Command for Deploy {
-    type Input = ();
+    type Input = <Build as Command>::Output;
     type Output = ();
 
     fn log_span(&self) -> Span {
         tracing::span!(tracing::Level::INFO, "Leo")
     }
 
-    fn prelude(&self, _: Context) -> Result<Self::Input> {
-        Ok(())
+    fn prelude(&self, context: Context) -> Result<Self::Input> {
+        (Build { options: self.compiler_options.clone() }).execute(context)
     }
 
     fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
@@ -37,21 +54,19 @@ impl Command for Deploy {
         let path = context.dir()?;
         let build_directory = BuildDirectory::open(&path).map_err(|_| CliError::needs_leo_build())?;
 
-        // Change the cwd to the Leo build/ directory to deploy aleo files.
-        std::env::set_current_dir(&build_directory)
-            .map_err(|err| PackageError::failed_to_set_cwd(build_directory.display(), err))?;
+        // Assemble the deployment from the build/ directory and broadcast it.
+        let program_id = broadcast(&build_directory, &self.endpoint)?;
 
-        // Unset the Leo panic hook.
-        let _ = std::panic::take_hook();
-
-        // Call the `node` command.
-        println!();
-        let command = SnarkVMDeploy::try_parse_from([ALEO_CLI_COMMAND]).map_err(CliError::failed_to_parse_aleo_node)?;
-        let res = command.parse().map_err(CliError::failed_to_execute_aleo_node)?;
-
-        // Log the output of the `node` command.
-        tracing::info!("{}", res);
+        tracing::info!("✅ Deployed '{}' to {}", program_id.to_string().bold(), self.endpoint);
 
         Ok(())
     }
 }
+
+/// Computes the deployment of the Aleo package in `build_directory` and broadcasts it to `endpoint`.
+pub(crate) fn broadcast(build_directory: &Path, endpoint: &str) -> Result<ProgramID<CurrentNetwork>> {
+    let package = Package::<CurrentNetwork>::open(build_directory).map_err(CliError::failed_to_execute_deploy)?;
+    let url = format!("{}/testnet3/deploy", endpoint.trim_end_matches('/'));
+    let deployment = package.deploy::<AleoV0>(Some(url)).map_err(CliError::failed_to_execute_deploy)?;
+    Ok(*deployment.program_id())
+}
@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_compiler::Compiler;
+use leo_interpreter::{Interpreter, StatementHook};
+use leo_package::source::MainFile;
+use leo_span::Symbol;
+
+/// Interprets a transition of the current package on its inputs, without compiling it or generating a proof.
+#[derive(Parser, Debug)]
+#[group(skip)]
+pub struct Eval {
+    #[clap(name = "NAME", help = "The name of the transition to run.", default_value = "main")]
+    pub(crate) name: String,
+
+    #[clap(name = "INPUTS", help = "The inputs to the transition.")]
+    pub(crate) inputs: Vec<String>,
+
+    #[clap(flatten)]
+    pub(crate) compiler_options: BuildOptions,
+}
+
+impl Command for Eval {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        interpret(&context, &self.name, &self.inputs, &self.compiler_options, None)
+    }
+}
+
+/// Type checks the program of the current package, and interprets one of its transitions on the given inputs.
+/// If a hook is given, then it observes the statements as they run.
+pub(crate) fn interpret(
+    context: &Context,
+    name: &str,
+    inputs: &[String],
+    options: &BuildOptions,
+    hook: Option<Box<dyn StatementHook + '_>>,
+) -> Result<()> {
+    // Get the package path and the program id.
+    let path = context.dir()?;
+    let manifest = context.open_manifest()?;
+    let program_id = manifest.program_id();
+
+    // Check the program. Its control flow is left intact, so that only the branches that are taken run.
+    let handler = context.handler()?;
+    let mut compiler = Compiler::new(
+        program_id.name().to_string(),
+        program_id.network().to_string(),
+        &handler,
+        path.join(MainFile::filename()),
+        OutputsDirectory::create(&path)?,
+        Some(options.clone().into()),
+    );
+    compiler.parse_program()?;
+    compiler.front_end_stages()?;
+
+    // Run the transition, followed by its finalize block.
+    let mut interpreter = Interpreter::new(&compiler.ast.ast, compiler.type_table());
+    interpreter.set_hook(hook);
+    let function = Symbol::intern(name);
+    let inputs = interpreter.parse_inputs(function, inputs)?;
+    let outputs = interpreter.run(function, inputs)?;
+
+    tracing::info!("✅ Interpreted '{name}'");
+    if !outputs.is_empty() {
+        tracing::info!("\n➡️  Output{}\n", if outputs.len() == 1 { "" } else { "s" });
+        outputs.iter().for_each(|output| tracing::info!(" • {output}"));
+    }
+    Ok(())
+}
@@ -15,118 +15,55 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use crate::cli::helpers::templates::EXAMPLES;
 
-use std::fs;
-
-/// The example programs that can be generated.
+/// Create a new package from one of the examples bundled with Leo.
 #[derive(Parser, Debug)]
-pub enum Example {
-    #[clap(name = "lottery", about = "A public lottery program")]
-    Lottery,
-    #[clap(name = "tictactoe", about = "A standard tic-tac-toe game program")]
-    TicTacToe,
-    #[clap(name = "token", about = "A transparent & shielded custom token program")]
-    Token,
+#[group(skip)]
+pub struct Example {
+    #[clap(name = "NAME", help = "The name of the example.", required_unless_present = "list")]
+    pub(crate) name: Option<String>,
+
+    #[clap(long, help = "List the bundled examples.")]
+    pub(crate) list: bool,
 }
 
 impl Command for Example {
-    type Input = <New as Command>::Output;
+    type Input = ();
     type Output = ();
 
     fn prelude(&self, context: Context) -> Result<Self::Input> {
-        // Run leo new EXAMPLE_NAME
-        (New { name: self.name() }).execute(context)
+        match &self.name {
+            // Run leo new EXAMPLE_NAME --template EXAMPLE_NAME
+            Some(name) if !self.list => {
+                if !EXAMPLES.iter().any(|example| example.name == name) {
+                    return Err(CliError::unknown_template(name).into());
+                }
+                (New { name: name.clone(), template: Some(name.clone()) }).execute(context)
+            }
+            _ => Ok(()),
+        }
     }
 
     fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output>
     where
         Self: Sized,
     {
-        let package_dir = context.dir()?;
-
-        // Write the main file.
-        let main_file_path = package_dir.join("src").join("main.leo");
-        fs::write(main_file_path, self.main_file_string()).map_err(CliError::failed_to_write_file)?;
-
-        // Write the input file.
-        let input_file_path = package_dir.join("inputs").join(format!("{}.in", self.name()));
-        fs::write(input_file_path, self.input_file_string()).map_err(CliError::failed_to_write_file)?;
-
-        // Write the README file.
-        let readme_file_path = package_dir.join("README.md");
-        let readme_file_path_string = readme_file_path.display().to_string();
-        fs::write(readme_file_path, self.readme_file_string()).map_err(CliError::failed_to_write_file)?;
-
-        // Write the run.sh file.
-        let run_file_path = package_dir.join("run.sh");
-        fs::write(run_file_path, self.run_file_string()).map_err(CliError::failed_to_write_file)?;
-
-        tracing::info!(
-            "🚀 To run the '{}' program follow the instructions at {}",
-            self.name().bold(),
-            readme_file_path_string
-        );
-
-        Ok(())
-    }
-}
-
-impl Example {
-    fn name(&self) -> String {
-        match self {
-            Example::Lottery => "lottery".to_string(),
-            Example::TicTacToe => "tictactoe".to_string(),
-            Example::Token => "token".to_string(),
-        }
-    }
-
-    fn main_file_string(&self) -> String {
-        match self {
-            Self::Lottery => {
-                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/lottery/src/main.leo")).to_string()
+        match self.name {
+            Some(name) if !self.list => {
+                let readme_file_path = context.dir()?.join("README.md");
+                tracing::info!(
+                    "🚀 To run the '{}' program follow the instructions at {}",
+                    name.bold(),
+                    readme_file_path.display()
+                );
             }
-            Self::TicTacToe => {
-                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/tictactoe/src/main.leo")).to_string()
-            }
-            Self::Token => {
-                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/token/src/main.leo")).to_string()
+            _ => {
+                for example in EXAMPLES {
+                    println!(" {:>14}  {}", example.name.cyan().bold(), example.about);
+                }
             }
         }
-    }
-
-    fn input_file_string(&self) -> String {
-        match self {
-            Self::Lottery => {
-                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/lottery/inputs/lottery.in")).to_string()
-            }
-            Self::TicTacToe => {
-                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/tictactoe/inputs/tictactoe.in")).to_string()
-            }
-            Self::Token => {
-                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/token/inputs/token.in")).to_string()
-            }
-        }
-    }
-
-    fn readme_file_string(&self) -> String {
-        match self {
-            Self::Lottery => {
-                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/lottery/README.md")).to_string()
-            }
-            Self::TicTacToe => {
-                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/tictactoe/README.md")).to_string()
-            }
-            Self::Token => include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/token/README.md")).to_string(),
-        }
-    }
-
-    fn run_file_string(&self) -> String {
-        match self {
-            Self::Lottery => include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/lottery/run.sh")).to_string(),
-            Self::TicTacToe => {
-                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/tictactoe/run.sh")).to_string()
-            }
-            Self::Token => include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/token/run.sh")).to_string(),
-        }
+        Ok(())
     }
 }
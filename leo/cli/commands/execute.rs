@@ -15,11 +15,13 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use crate::cli::helpers::debug_info::locate_failure;
 
 use snarkvm::cli::Execute as SnarkVMExecute;
 
 /// Build, Prove and Run Leo program with inputs
 #[derive(Parser, Debug)]
+#[group(skip)]
 pub struct Execute {
     #[clap(name = "NAME", help = "The name of the program to execute.", default_value = "main")]
     name: String,
@@ -48,13 +50,15 @@ impl Command for Execute {
     }
 
     fn prelude(&self, context: Context) -> Result<Self::Input> {
-        (Build { options: self.compiler_options.clone() }).execute(context)
+        // Map the instructions back to the source, so that a failure can be reported where it happens.
+        let options = BuildOptions { enable_debug_info: true, ..self.compiler_options.clone() };
+        (Build { options }).execute(context)
     }
 
     fn apply(self, context: Context, input: Self::Input) -> Result<Self::Output> {
         // If input values are provided, then run the program with those inputs.
         // Otherwise, use the input file.
-        let mut inputs = match self.inputs.is_empty() {
+        let inputs = match self.inputs.is_empty() {
             true => match input {
                 (Some(input_ast), circuits) => input_ast.program_inputs(&self.name, circuits),
                 _ => Vec::new(),
@@ -63,10 +67,10 @@ impl Command for Execute {
         };
 
         // Compose the `execute` command.
-        let mut arguments = vec![SNARKVM_COMMAND.to_string(), self.name];
+        let mut arguments = vec![SNARKVM_COMMAND.to_string(), self.name.clone()];
 
         // Add the program inputs to the arguments.
-        arguments.append(&mut inputs);
+        arguments.extend(inputs.iter().cloned());
 
         // Add the compiler options to the arguments.
         if self.compiler_options.offline {
@@ -88,10 +92,15 @@ impl Command for Execute {
         // Unset the Leo panic hook
         let _ = std::panic::take_hook();
 
-        // Call the `execute` command.
-        println!();
+        // Call the `execute` command. In JSON mode, stdout only has the JSON messages.
+        if context.message_format == MessageFormat::Human {
+            println!();
+        }
         let command = SnarkVMExecute::try_parse_from(&arguments).map_err(CliError::failed_to_parse_execute)?;
-        let res = command.parse().map_err(CliError::failed_to_execute_execute)?;
+        let res = command.parse().map_err(|error| {
+            locate_failure(&path, &self.name, &inputs, &error.to_string())
+                .unwrap_or_else(|| CliError::failed_to_execute_execute(error).into())
+        })?;
 
         // Log the output of the `execute` command.
         tracing::info!("{}", res);
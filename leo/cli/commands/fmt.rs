@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_ast::NodeBuilder;
+use leo_package::{imports::ImportsDirectory, source::SourceDirectory};
+use leo_span::{source_map::FileName, symbol::with_session_globals};
+
+use std::fs;
+
+/// Format the Leo files of the current package
+#[derive(Parser, Debug)]
+#[group(skip)]
+pub struct Fmt {
+    #[clap(long, help = "Check that the files are formatted without rewriting them")]
+    pub(crate) check: bool,
+}
+
+impl Command for Fmt {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        // Get the package path.
+        let package_path = context.dir()?;
+
+        // Fetch paths to all .leo files in the source and imports directories.
+        let mut file_paths = SourceDirectory::files(&package_path)?;
+        if !ImportsDirectory::is_empty(&package_path)? {
+            file_paths.extend(ImportsDirectory::files(&package_path)?);
+        }
+
+        // Initialize error handler.
+        let handler = context.handler()?;
+
+        let mut unformatted = 0;
+        for file_path in file_paths.into_iter() {
+            let source = fs::read_to_string(&file_path).map_err(|e| CompilerError::file_read_error(&file_path, e))?;
+
+            // Register the source in the source map and format it.
+            let source_file =
+                with_session_globals(|s| s.source_map.new_source(&source, FileName::Real(file_path.clone())));
+            let formatted =
+                leo_parser::format_source(&handler, &NodeBuilder::default(), &source_file.src, source_file.start_pos)?;
+
+            if formatted == source {
+                continue;
+            }
+
+            match self.check {
+                true => {
+                    tracing::info!("❌ '{}' is not formatted", file_path.display());
+                    unformatted += 1;
+                }
+                false => {
+                    fs::write(&file_path, formatted).map_err(CliError::failed_to_write_file)?;
+                    tracing::info!("✅ Formatted '{}'", file_path.display());
+                }
+            }
+        }
+
+        match unformatted {
+            0 => Ok(()),
+            count => Err(CliError::unformatted_files(count).into()),
+        }
+    }
+}
@@ -0,0 +1,367 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::cli::CurrentNetwork;
+
+use leo_ast::{
+    AssignStatement,
+    Ast,
+    Expression,
+    ExpressionVisitor,
+    Node,
+    NodeBuilder,
+    NodeID,
+    ProgramVisitor,
+    StatementVisitor,
+    StructExpression,
+    Type,
+    Variant,
+};
+use leo_errors::{LeoError, LeoWarning};
+use leo_passes::{Pass, SymbolTable, SymbolTableCreator, TypeChecker, TypeTable, VariableSymbol};
+use leo_span::{
+    source_map::{FileName, SourceFile},
+    span::{BytePos, Pos},
+    symbol::{with_session_globals, SessionGlobals, SESSION_GLOBALS},
+    Span,
+    Symbol,
+};
+
+use leo_package::source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME};
+
+use snarkvm::file::Manifest;
+
+use std::{path::Path, rc::Rc};
+
+/// A Leo document that has been parsed and type checked.
+pub struct CheckedDocument {
+    /// The URI of the document.
+    uri: String,
+    /// The source of the document in the source map.
+    source_file: Rc<SourceFile>,
+    /// The AST of the document, without the items and statements that failed to parse.
+    ast: Option<Ast>,
+    /// The symbol table of the document, if it could be created.
+    symbol_table: Option<SymbolTable>,
+    /// The types of the expressions that were type checked.
+    type_table: TypeTable,
+    /// The errors emitted while checking the document.
+    errors: Vec<LeoError>,
+    /// The warnings emitted while checking the document.
+    warnings: Vec<LeoWarning>,
+}
+
+impl CheckedDocument {
+    /// Parses and type checks the `text` of the document at `uri`, then calls `f` on the result.
+    /// Each check runs in a fresh session, so that the source map does not grow with every edit.
+    pub fn with<R>(uri: &str, text: &str, f: impl FnOnce(&Self) -> R) -> R {
+        let path = uri_to_path(uri);
+
+        // The imports of the document are found in the package of the document.
+        let package = path.as_deref().and_then(|path| {
+            path.ancestors()
+                .find(|dir| dir.join(Manifest::<CurrentNetwork>::file_name()).exists())
+                .map(Path::to_path_buf)
+        });
+
+        let name = match path {
+            Some(path) => FileName::Real(path),
+            None => FileName::Custom(uri.to_string()),
+        };
+        SESSION_GLOBALS.set(&SessionGlobals::default(), || f(&Self::check(uri, name, text, package.as_deref())))
+    }
+
+    /// Runs the front end of the compiler on `text`, collecting its errors and warnings.
+    /// The imports are found in the `package` of the document, or in the current directory if it has none.
+    fn check(uri: &str, name: FileName, text: &str, package: Option<&Path>) -> Self {
+        let (handler, buf) = Handler::new_with_buf();
+        let node_builder = NodeBuilder::default();
+        let type_table = TypeTable::default();
+        let source_file = with_session_globals(|s| s.source_map.new_source(text, name));
+
+        let path = match &source_file.name {
+            FileName::Real(path) => Some(path.clone()),
+            FileName::Custom(_) => None,
+        };
+        let ast = match path.as_deref() {
+            // A module is only parsed, its items are checked as part of the program that uses it.
+            Some(path) if is_module(path) => {
+                emit(
+                    &handler,
+                    leo_parser::parse_module(&handler, &node_builder, &source_file.src, source_file.start_pos),
+                );
+                None
+            }
+            _ => {
+                let (src, start_pos) = (&source_file.src, source_file.start_pos);
+                let ast = match package {
+                    Some(package) => leo_parser::parse_ast_in_package(&handler, &node_builder, src, start_pos, package),
+                    None => leo_parser::parse_ast(&handler, &node_builder, src, start_pos),
+                };
+                emit(&handler, ast).map(|mut ast| {
+                    // Modules are resolved relative to the directory of the document.
+                    let source_directory = path.as_deref().and_then(Path::parent).unwrap_or_else(|| Path::new(""));
+                    emit(
+                        &handler,
+                        leo_parser::resolve_modules(&handler, &node_builder, &mut ast.ast, source_directory),
+                    );
+                    emit(&handler, leo_parser::declare_impls(&mut ast.ast));
+                    ast
+                })
+            }
+        };
+        // The AST is checked even if it has syntax errors, so that the types of the parts that parsed are known.
+        // The passes have their own handler, as they fail once their handler has emitted any error.
+        let (pass_handler, pass_buf) = Handler::new_with_buf();
+        let symbol_table =
+            ast.as_ref().and_then(|ast| emit(&pass_handler, SymbolTableCreator::do_pass((ast, &pass_handler))));
+        if let (Some(ast), Some(symbol_table)) = (&ast, &symbol_table) {
+            // The type table is filled in even if type checking fails.
+            emit(&pass_handler, TypeChecker::do_pass((ast, &pass_handler, symbol_table.clone(), &type_table)));
+        }
+
+        // The errors of the passes are only reported once the document parses,
+        // as the statements that failed to parse are missing from the AST.
+        let mut errors = buf.extract_errs().into_inner();
+        if errors.is_empty() {
+            errors = pass_buf.extract_errs().into_inner();
+        }
+        let mut warnings = buf.extract_warnings().into_inner();
+        warnings.extend(pass_buf.extract_warnings().into_inner());
+
+        Self { uri: uri.to_string(), source_file, ast, symbol_table, type_table, errors, warnings }
+    }
+
+    /// Returns the errors and warnings of the document.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        let errors = self.errors.iter().map(|error| {
+            let code = match error {
+                LeoError::Anyhow(_) | LeoError::LastErrorCode(_) => None,
+                error => Some(error.error_code()),
+            };
+            self.diagnostic(DiagnosticSeverity::Error, code, error.span(), error.message(), error.help())
+        });
+        let warnings = self.warnings.iter().map(|warning| {
+            let code = Some(warning.error_code());
+            self.diagnostic(DiagnosticSeverity::Warning, code, warning.span(), warning.message().to_string(), {
+                warning.help()
+            })
+        });
+        errors.chain(warnings).collect()
+    }
+
+    fn diagnostic(
+        &self,
+        severity: DiagnosticSeverity,
+        code: Option<String>,
+        span: Option<Span>,
+        message: String,
+        help: Option<&str>,
+    ) -> Diagnostic {
+        // Messages without a location in this document, e.g. those found in an import, are shown at its start.
+        let range = span.and_then(|span| self.range(span)).unwrap_or_default();
+        let message = match help {
+            Some(help) => format!("{message}\n{help}"),
+            None => message,
+        };
+        Diagnostic { range, severity, code, source: "leo".to_string(), message }
+    }
+
+    /// Describes the program item named by the identifier at `position`,
+    /// or else shows the type of the innermost expression at `position`.
+    pub fn hover(&self, position: Position) -> Option<Hover> {
+        let pos = self.byte_pos(position)?;
+
+        if let Some((name, span)) = self.identifier_at(pos) {
+            if let Some(description) = self.describe_item(name) {
+                return Some(Hover::new(description, self.range(span)));
+            }
+        }
+
+        let mut finder = ExpressionFinder { pos, found: None };
+        finder.visit_program(self.ast.as_ref()?.as_repr());
+        let (span, id) = finder.found?;
+        Some(Hover::new(self.type_table.get(&id)?, self.range(span)))
+    }
+
+    /// Returns the definition of the struct, record, enum, mapping, or function named by the identifier at `position`.
+    pub fn definition(&self, position: Position) -> Option<Location> {
+        let (name, _) = self.identifier_at(self.byte_pos(position)?)?;
+        let symbol_table = self.symbol_table.as_ref()?;
+
+        let span = if let Some(struct_) = symbol_table.lookup_struct(name) {
+            struct_.identifier.span
+        } else if let Some(enum_) = symbol_table.lookup_enum(name) {
+            enum_.identifier.span
+        } else if let Some(function) = symbol_table.lookup_fn_symbol(name) {
+            function.span()
+        } else if let Some(VariableSymbol { type_: Type::Mapping(_), span, .. }) = symbol_table.lookup_variable(name) {
+            *span
+        } else {
+            return None;
+        };
+
+        let location = with_session_globals(|s| s.source_map.span_to_location(span))?;
+        let uri = match &location.source_file.name {
+            _ if location.source_file.start_pos == self.source_file.start_pos => self.uri.clone(),
+            FileName::Real(path) => path_to_uri(path),
+            FileName::Custom(_) => return None,
+        };
+        Some(Location { uri, range: Range::from(&location) })
+    }
+
+    /// Returns the declaration of the program item `name`, if it exists.
+    fn describe_item(&self, name: Symbol) -> Option<String> {
+        let symbol_table = self.symbol_table.as_ref()?;
+
+        if let Some(struct_) = symbol_table.lookup_struct(name) {
+            let keyword = if struct_.is_record { "record" } else { "struct" };
+            let members = struct_.members.iter().map(|member| member.to_string()).collect::<Vec<_>>();
+            Some(format!("{keyword} {} {{ {} }}", struct_.identifier, members.join(", ")))
+        } else if let Some(enum_) = symbol_table.lookup_enum(name) {
+            let variants = enum_.variants.iter().map(|variant| variant.to_string()).collect::<Vec<_>>();
+            Some(format!("enum {} {{ {} }}", enum_.identifier, variants.join(", ")))
+        } else if let Some(function) = symbol_table.lookup_fn_symbol(name) {
+            let keyword = match function.variant {
+                Variant::Inline => "inline",
+                Variant::Standard => "function",
+                Variant::Transition => "transition",
+            };
+            Some(format!("{keyword} {name}"))
+        } else if let Some(VariableSymbol { type_: Type::Mapping(mapping), .. }) = symbol_table.lookup_variable(name) {
+            Some(format!("mapping {name}: {} => {}", mapping.key, mapping.value))
+        } else {
+            None
+        }
+    }
+
+    /// Converts a span into a range, if the span is in this document.
+    fn range(&self, span: Span) -> Option<Range> {
+        let location = with_session_globals(|s| s.source_map.span_to_location(span))?;
+        (location.source_file.start_pos == self.source_file.start_pos).then(|| Range::from(&location))
+    }
+
+    /// Converts a position into a byte position in the source map.
+    fn byte_pos(&self, position: Position) -> Option<BytePos> {
+        let src = &self.source_file.src;
+        let mut lines = src.split_inclusive('\n');
+        let line_start = lines.by_ref().take(position.line as usize).map(str::len).sum::<usize>();
+        let line = lines.next().unwrap_or_default();
+
+        // Positions count characters in UTF-16 code units.
+        let mut units = 0;
+        let mut offset = 0;
+        for c in line.chars().take_while(|c| *c != '\n') {
+            if units >= position.character as usize {
+                break;
+            }
+            units += c.len_utf16();
+            offset += c.len_utf8();
+        }
+
+        let offset = line_start + offset;
+        (offset <= src.len()).then(|| self.source_file.start_pos + BytePos::from_usize(offset))
+    }
+
+    /// Returns the identifier that touches `pos`, along with its span.
+    fn identifier_at(&self, pos: BytePos) -> Option<(Symbol, Span)> {
+        let src = &self.source_file.src;
+        let offset = (pos - self.source_file.start_pos).to_usize();
+        let is_identifier_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+
+        let start = src[..offset].rfind(|c| !is_identifier_char(c)).map_or(0, |i| i + 1);
+        let end = src[offset..].find(|c| !is_identifier_char(c)).map_or(src.len(), |i| offset + i);
+        let name = &src[start..end];
+        if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            return None;
+        }
+
+        let span = Span::new(
+            self.source_file.start_pos + BytePos::from_usize(start),
+            self.source_file.start_pos + BytePos::from_usize(end),
+        );
+        Some((Symbol::intern(name), span))
+    }
+}
+
+/// Emits the error of a compiler pass, unless the pass already emitted it.
+fn emit<T>(handler: &Handler, result: Result<T>) -> Option<T> {
+    match result {
+        Ok(value) => Some(value),
+        Err(LeoError::LastErrorCode(_)) => None,
+        Err(error) => {
+            handler.emit_err(error);
+            None
+        }
+    }
+}
+
+/// Returns `true` if the file at `path` is a module of a program, i.e. a file in `src/` other than `main.leo`.
+fn is_module(path: &Path) -> bool {
+    !path.ends_with(MAIN_FILENAME) && path.ancestors().skip(1).any(|dir| dir.ends_with(SOURCE_DIRECTORY_NAME))
+}
+
+/// Finds the innermost expression that contains a position.
+struct ExpressionFinder {
+    /// The position to look for.
+    pos: BytePos,
+    /// The span and the id of the innermost expression found so far.
+    found: Option<(Span, NodeID)>,
+}
+
+impl<'a> ExpressionVisitor<'a> for ExpressionFinder {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_expression(&mut self, input: &'a Expression, additional: &Self::AdditionalInput) -> Self::Output {
+        let span = input.span();
+        if self.pos < span.lo || span.hi < self.pos {
+            return;
+        }
+        // Nested expressions are visited after the expressions that contain them.
+        self.found = Some((span, input.id()));
+
+        match input {
+            Expression::Access(access) => self.visit_access(access, additional),
+            Expression::Array(array) => self.visit_array(array, additional),
+            Expression::Binary(binary) => self.visit_binary(binary, additional),
+            Expression::Call(call) => self.visit_call(call, additional),
+            Expression::Cast(cast) => self.visit_cast(cast, additional),
+            Expression::Struct(struct_) => self.visit_struct_init(struct_, additional),
+            Expression::Ternary(ternary) => self.visit_ternary(ternary, additional),
+            Expression::Tuple(tuple) => self.visit_tuple(tuple, additional),
+            Expression::Unary(unary) => self.visit_unary(unary, additional),
+            Expression::Err(_) | Expression::Identifier(_) | Expression::Literal(_) | Expression::Unit(_) => {}
+        }
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().filter_map(|member| member.expression.as_ref()).for_each(|expression| {
+            self.visit_expression(expression, additional);
+        });
+    }
+}
+
+impl<'a> StatementVisitor<'a> for ExpressionFinder {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        self.visit_expression(&input.place, &());
+        self.visit_expression(&input.value, &());
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for ExpressionFinder {}
@@ -0,0 +1,265 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The subset of the Language Server Protocol spoken by the Leo language server,
+//! along with the JSON-RPC framing of its messages.
+
+use leo_errors::{CliError, Result};
+use leo_span::source_map::SpanLocation;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    io::{BufRead, Write},
+    path::{Path, PathBuf},
+};
+
+/// The error code of a request for a method the server does not support.
+pub const METHOD_NOT_FOUND: i64 = -32601;
+
+/// The error code of a request with malformed parameters.
+pub const INVALID_PARAMS: i64 = -32602;
+
+/// A JSON-RPC message sent by the client.
+#[derive(Debug, Deserialize)]
+pub struct Message {
+    /// The id of a request, or `None` if the message is a notification.
+    #[serde(default)]
+    pub id: Option<Value>,
+    /// The method to invoke, or `None` if the message is a response.
+    #[serde(default)]
+    pub method: Option<String>,
+    /// The parameters of the method.
+    #[serde(default)]
+    pub params: Value,
+}
+
+/// The error returned in the response to a failed request.
+#[derive(Debug, Serialize)]
+pub struct ResponseError {
+    pub code: i64,
+    pub message: String,
+}
+
+/// Reads the next message from `reader`, or returns `None` once the stream is closed.
+pub fn read_message(reader: &mut impl BufRead) -> Result<Option<Message>> {
+    // Read the header, which ends with an empty line.
+    let mut content_length = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).map_err(CliError::cli_io_error)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("Content-Length") {
+                content_length = Some(value.trim().parse::<usize>().map_err(CliError::invalid_lsp_message)?);
+            }
+        }
+    }
+    let content_length =
+        content_length.ok_or_else(|| CliError::invalid_lsp_message("missing `Content-Length` header"))?;
+
+    // Read the content.
+    let mut content = vec![0; content_length];
+    reader.read_exact(&mut content).map_err(CliError::cli_io_error)?;
+    Ok(Some(serde_json::from_slice(&content).map_err(CliError::invalid_lsp_message)?))
+}
+
+/// Writes `message` to `writer`, preceded by its header.
+pub fn write_message(writer: &mut impl Write, message: &Value) -> Result<()> {
+    let content = message.to_string();
+    write!(writer, "Content-Length: {}\r\n\r\n{content}", content.len()).map_err(CliError::cli_io_error)?;
+    writer.flush().map_err(CliError::cli_io_error)?;
+    Ok(())
+}
+
+/// A zero-based line and UTF-16 character offset in a document.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Position {
+    pub line: u32,
+    pub character: u32,
+}
+
+/// A range between two positions in a document.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Range {
+    pub start: Position,
+    pub end: Position,
+}
+
+impl From<&SpanLocation> for Range {
+    /// Converts the one-based lines and columns of a location into a range.
+    fn from(location: &SpanLocation) -> Self {
+        let position = |line: usize, col: usize| Position {
+            line: line.saturating_sub(1) as u32,
+            character: col.saturating_sub(1) as u32,
+        };
+        Self {
+            start: position(location.line_start, location.col_start),
+            end: position(location.line_stop, location.col_stop),
+        }
+    }
+}
+
+/// A range in a document identified by its URI.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Location {
+    pub uri: String,
+    pub range: Range,
+}
+
+/// The severity of a diagnostic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiagnosticSeverity {
+    Error = 1,
+    Warning = 2,
+}
+
+impl Serialize for DiagnosticSeverity {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(*self as u8)
+    }
+}
+
+/// An error or warning to show in a document.
+#[derive(Clone, Debug, Serialize)]
+pub struct Diagnostic {
+    pub range: Range,
+    pub severity: DiagnosticSeverity,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    pub source: String,
+    pub message: String,
+}
+
+/// The parameters of the `textDocument/publishDiagnostics` notification.
+#[derive(Debug, Serialize)]
+pub struct PublishDiagnosticsParams {
+    pub uri: String,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// The Markdown contents of a hover.
+#[derive(Debug, Serialize)]
+pub struct MarkupContent {
+    pub kind: String,
+    pub value: String,
+}
+
+/// The result of a `textDocument/hover` request.
+#[derive(Debug, Serialize)]
+pub struct Hover {
+    pub contents: MarkupContent,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range: Option<Range>,
+}
+
+impl Hover {
+    /// Creates a hover that shows `code` as a Leo code block.
+    pub fn new(code: impl std::fmt::Display, range: Option<Range>) -> Self {
+        Self { contents: MarkupContent { kind: "markdown".to_string(), value: format!("```leo\n{code}\n```") }, range }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TextDocumentIdentifier {
+    pub uri: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TextDocumentItem {
+    pub uri: String,
+    pub text: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidOpenTextDocumentParams {
+    pub text_document: TextDocumentItem,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TextDocumentContentChangeEvent {
+    pub text: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidChangeTextDocumentParams {
+    pub text_document: TextDocumentIdentifier,
+    pub content_changes: Vec<TextDocumentContentChangeEvent>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidCloseTextDocumentParams {
+    pub text_document: TextDocumentIdentifier,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextDocumentPositionParams {
+    pub text_document: TextDocumentIdentifier,
+    pub position: Position,
+}
+
+/// Converts a `file://` URI into a file path.
+pub fn uri_to_path(uri: &str) -> Option<PathBuf> {
+    let path = uri.strip_prefix("file://")?;
+
+    // Decode the percent-encoded bytes.
+    let mut bytes = Vec::with_capacity(path.len());
+    let mut rest = path.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        match (byte, tail) {
+            (b'%', [high, low, tail @ ..]) => {
+                bytes.push(u8::from_str_radix(std::str::from_utf8(&[*high, *low]).ok()?, 16).ok()?);
+                rest = tail;
+            }
+            _ => {
+                bytes.push(byte);
+                rest = tail;
+            }
+        }
+    }
+    let path = String::from_utf8(bytes).ok()?;
+
+    // Windows paths are written as `file:///C:/...`.
+    match cfg!(windows) {
+        true => Some(PathBuf::from(path.trim_start_matches('/'))),
+        false => Some(PathBuf::from(path)),
+    }
+}
+
+/// Converts a file path into a `file://` URI.
+pub fn path_to_uri(path: &Path) -> String {
+    let path = path.to_string_lossy().replace('\\', "/");
+    let mut uri = String::from("file://");
+    if !path.starts_with('/') {
+        uri.push('/');
+    }
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' | b':' => uri.push(byte as char),
+            _ => uri.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    uri
+}
@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use indexmap::IndexMap;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::io::{BufRead, Write};
+
+/// A language server that checks the open documents on every change.
+#[derive(Default)]
+pub struct Server {
+    /// The text of the open documents, keyed by their URIs.
+    documents: IndexMap<String, String>,
+}
+
+impl Server {
+    /// Serves the messages read from `reader` until the client exits, writing the replies to `writer`.
+    pub fn run(&mut self, reader: &mut impl BufRead, writer: &mut impl Write) -> Result<()> {
+        while let Some(message) = read_message(reader)? {
+            match (message.method.as_deref(), message.id) {
+                (Some("exit"), _) => break,
+                // A request expects a response with the same id.
+                (Some(method), Some(id)) => {
+                    let response = match self.handle_request(method, message.params) {
+                        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+                        Err(error) => json!({ "jsonrpc": "2.0", "id": id, "error": error }),
+                    };
+                    write_message(writer, &response)?;
+                }
+                (Some(method), None) => {
+                    for notification in self.handle_notification(method, message.params) {
+                        write_message(writer, &notification)?;
+                    }
+                }
+                // The server does not send requests, so there are no responses to handle.
+                (None, _) => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the result of the request `method`.
+    fn handle_request(&self, method: &str, params: Value) -> Result<Value, ResponseError> {
+        match method {
+            "initialize" => Ok(json!({
+                "capabilities": {
+                    // The client sends the full text of a document on every change.
+                    "textDocumentSync": 1,
+                    "hoverProvider": true,
+                    "definitionProvider": true,
+                },
+                "serverInfo": { "name": "leo", "version": env!("CARGO_PKG_VERSION") },
+            })),
+            "shutdown" => Ok(Value::Null),
+            "textDocument/hover" => {
+                let params: TextDocumentPositionParams = parse_params(params)?;
+                let hover = self.with_document(&params.text_document.uri, |document| document.hover(params.position));
+                Ok(json!(hover.flatten()))
+            }
+            "textDocument/definition" => {
+                let params: TextDocumentPositionParams = parse_params(params)?;
+                let location =
+                    self.with_document(&params.text_document.uri, |document| document.definition(params.position));
+                Ok(json!(location.flatten()))
+            }
+            method => Err(ResponseError { code: METHOD_NOT_FOUND, message: format!("Unknown method `{method}`") }),
+        }
+    }
+
+    /// Handles the notification `method`, returning the notifications to send back to the client.
+    fn handle_notification(&mut self, method: &str, params: Value) -> Vec<Value> {
+        match method {
+            "textDocument/didOpen" => match parse_params::<DidOpenTextDocumentParams>(params) {
+                Ok(params) => self.update(params.text_document.uri, params.text_document.text),
+                Err(_) => vec![],
+            },
+            "textDocument/didChange" => match parse_params::<DidChangeTextDocumentParams>(params) {
+                // With full synchronization, the last change holds the whole text of the document.
+                Ok(mut params) => match params.content_changes.pop() {
+                    Some(change) => self.update(params.text_document.uri, change.text),
+                    None => vec![],
+                },
+                Err(_) => vec![],
+            },
+            "textDocument/didClose" => match parse_params::<DidCloseTextDocumentParams>(params) {
+                Ok(params) => {
+                    self.documents.shift_remove(&params.text_document.uri);
+                    vec![publish_diagnostics(params.text_document.uri, vec![])]
+                }
+                Err(_) => vec![],
+            },
+            // Other notifications, e.g. `initialized`, need no reply.
+            _ => vec![],
+        }
+    }
+
+    /// Stores the new text of a document and returns its diagnostics.
+    fn update(&mut self, uri: String, text: String) -> Vec<Value> {
+        let diagnostics = CheckedDocument::with(&uri, &text, |document| document.diagnostics());
+        self.documents.insert(uri.clone(), text);
+        vec![publish_diagnostics(uri, diagnostics)]
+    }
+
+    /// Checks the open document at `uri` and calls `f` on the result, if the document is open.
+    fn with_document<R>(&self, uri: &str, f: impl FnOnce(&CheckedDocument) -> R) -> Option<R> {
+        let text = self.documents.get(uri)?;
+        Some(CheckedDocument::with(uri, text, f))
+    }
+}
+
+/// Deserializes the parameters of a message.
+fn parse_params<T: DeserializeOwned>(params: Value) -> Result<T, ResponseError> {
+    serde_json::from_value(params)
+        .map_err(|error| ResponseError { code: INVALID_PARAMS, message: format!("Invalid parameters: {error}") })
+}
+
+/// Creates a `textDocument/publishDiagnostics` notification.
+fn publish_diagnostics(uri: String, diagnostics: Vec<Diagnostic>) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/publishDiagnostics",
+        "params": PublishDiagnosticsParams { uri, diagnostics },
+    })
+}
@@ -17,30 +17,57 @@
 pub mod account;
 pub use account::Account;
 
+pub mod add;
+pub use add::Add;
+
+pub mod bindings;
+pub use bindings::Bindings;
+
 pub mod build;
 pub use build::Build;
 
 pub mod clean;
 pub use clean::Clean;
 
+pub mod eval;
+pub use eval::Eval;
+
 pub mod example;
 pub use example::Example;
 
 pub mod execute;
 pub use execute::Execute;
 
-// pub mod deploy;
-// pub use deploy::Deploy;
+pub mod debug;
+pub use debug::Debug;
+
+pub mod deploy;
+pub use deploy::Deploy;
+
+pub mod fmt;
+pub use fmt::Fmt;
+
+pub mod lsp;
+pub use lsp::Lsp;
 
 pub mod new;
 pub use new::New;
 
-// pub mod node;
-// pub use node::Node;
+pub mod node;
+pub use node::Node;
+
+pub mod query;
+pub use query::Query;
+
+pub mod remove;
+pub use remove::Remove;
 
 pub mod run;
 pub use run::Run;
 
+pub mod test;
+pub use test::Test;
+
 pub mod update;
 pub use update::Update;
 
@@ -121,6 +148,14 @@ pub trait Command {
 pub struct BuildOptions {
     #[clap(long, help = "Enables offline mode.")]
     pub offline: bool,
+    #[clap(
+        long,
+        env = "LEO_REGISTRY",
+        help = "The registry to fetch dependencies from, either a URL or a directory. Defaults to `~/.aleo/registry`."
+    )]
+    pub registry: Option<String>,
+    #[clap(long, help = "Writes a `main.aleo.map` file, which maps the Aleo instructions back to the Leo source.")]
+    pub enable_debug_info: bool,
     #[clap(long, help = "Enable spans in AST snapshots.")]
     pub enable_symbol_table_spans: bool,
     #[clap(long, help = "Enables dead code elimination in the compiler.")]
@@ -145,6 +180,8 @@ pub struct BuildOptions {
     pub enable_ssa_ast_snapshot: bool,
     #[clap(long, help = "Writes AST snapshot of the flattened AST.")]
     pub enable_flattened_ast_snapshot: bool,
+    #[clap(long, help = "Writes AST snapshot of the constant folded AST.")]
+    pub enable_const_folded_ast_snapshot: bool,
     #[clap(long, help = "Writes AST snapshot of the destructured AST.")]
     pub enable_destructured_ast_snapshot: bool,
     #[clap(long, help = "Writes AST snapshot of the inlined AST.")]
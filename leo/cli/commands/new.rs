@@ -15,14 +15,22 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use crate::cli::helpers::templates::Template;
 
 use snarkvm::{cli::New as SnarkVMNew, file::AleoFile};
 
 /// Create new Leo project
 #[derive(Parser, Debug)]
+#[group(skip)]
 pub struct New {
     #[clap(name = "NAME", help = "Set package name")]
     pub(crate) name: String,
+
+    #[clap(
+        long,
+        help = "Create the package from a template: the name of a bundled example, a directory or a git repository."
+    )]
+    pub(crate) template: Option<String>,
 }
 
 impl Command for New {
@@ -38,6 +46,9 @@ impl Command for New {
     }
 
     fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        // Load the template first, so that no package is created if it cannot be found.
+        let template = self.template.as_deref().map(Template::load).transpose()?;
+
         // Call the `aleo new` command from the Aleo SDK.
         let command =
             SnarkVMNew::try_parse_from([SNARKVM_COMMAND, &self.name]).map_err(CliError::failed_to_parse_new)?;
@@ -86,6 +97,11 @@ impl Command for New {
         // Remove the Aleo file from the package directory.
         aleo_file.remove(&aleo_file_path).map_err(PackageError::failed_to_remove_aleo_file)?;
 
+        // Write the files of the template over the files of the new package.
+        if let Some(template) = template {
+            template.instantiate(&package_path, &self.name, "testnet3")?;
+        }
+
         Ok(())
     }
 }
@@ -15,8 +15,11 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use crate::cli::helpers::devnet::{Devnet, DEVNET_PORT};
 
-use aleo::commands::Node as AleoNode;
+use snarkvm::{file::AleoFile, package::Package};
+
+use std::net::TcpListener;
 
 /// Commands to operate a local development node.
 #[derive(Parser, Debug)]
@@ -26,6 +29,9 @@ pub enum Node {
         /// Skips deploying the local program at genesis.
         #[clap(long)]
         nodeploy: bool,
+        /// The port to listen on.
+        #[clap(long, default_value_t = DEVNET_PORT)]
+        port: u16,
     },
 }
 
@@ -42,36 +48,34 @@ impl Command for Node {
     }
 
     fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
-        // Compose the `aleo node` command.
-        let mut arguments = vec![ALEO_CLI_COMMAND.to_string()];
-
-        // Add arguments to the command.
         match self {
-            Node::Start { nodeploy } => {
-                arguments.push(String::from("start"));
+            Node::Start { nodeploy, port } => {
+                // Initialize the ledger.
+                let mut devnet = Devnet::load()?;
 
-                if nodeploy {
-                    arguments.push(String::from("--nodeploy"));
-                } else {
+                if !nodeploy {
                     // Open the Leo build/ directory
                     let path = context.dir()?;
                     let build_directory = BuildDirectory::open(&path).map_err(|_| CliError::needs_leo_build())?;
-
-                    // Change the cwd to the Leo build/ directory to deploy aleo files.
-                    std::env::set_current_dir(&build_directory)
-                        .map_err(|err| PackageError::failed_to_set_cwd(build_directory.display(), err))?;
+                    let package =
+                        Package::<CurrentNetwork>::open(&build_directory).map_err(CliError::failed_to_execute_node)?;
+
+                    // Deploy the imports of the local program, followed by the program itself.
+                    for program_id in package.program().imports().keys() {
+                        if !devnet.contains_program(program_id) {
+                            let import = AleoFile::open(&package.imports_directory(), program_id, false)
+                                .map_err(CliError::failed_to_execute_node)?;
+                            devnet.deploy_program(import.program())?;
+                        }
+                    }
+                    devnet.deploy_program(package.program())?;
                 }
+
+                // Serve the ledger until the node is stopped.
+                let listener = TcpListener::bind(("127.0.0.1", port)).map_err(CliError::failed_to_execute_node)?;
+                tracing::info!("🌐 Listening on http://localhost:{port}");
+                devnet.serve(listener)
             }
         }
-
-        // Call the `aleo node` command from the Aleo SDK.
-        println!();
-        let command = AleoNode::try_parse_from(&arguments).map_err(CliError::failed_to_parse_aleo_node)?;
-        let res = command.parse().map_err(CliError::failed_to_execute_aleo_node)?;
-
-        // Log the output of the `aleo node` command.
-        tracing::info!("{}", res);
-
-        Ok(())
     }
 }
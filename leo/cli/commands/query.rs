@@ -0,0 +1,131 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::cli::helpers::network::{self, format_plaintext, Client, HttpClient};
+
+use leo_compiler::Abi;
+use snarkvm::file::Manifest;
+
+/// Reads the state of a network: mapping values, deployed programs and transactions.
+#[derive(Parser, Debug)]
+#[group(skip)]
+pub struct Query {
+    #[clap(
+        long,
+        global = true,
+        help = "The endpoint of the network to query.",
+        default_value = "https://api.explorer.aleo.org/v1"
+    )]
+    pub(crate) endpoint: String,
+
+    #[clap(subcommand)]
+    pub(crate) command: QueryCommands,
+}
+
+/// The state that `leo query` reads.
+#[derive(Parser, Debug)]
+pub enum QueryCommands {
+    /// Reads the value of a key in a mapping.
+    Mapping {
+        /// The name of the mapping.
+        name: String,
+        /// The key to read, e.g. `aleo1...` or `1u64`.
+        key: String,
+        /// The program of the mapping. Defaults to the program of the package.
+        #[clap(long)]
+        program: Option<String>,
+    },
+    /// Fetches the source of a deployed program.
+    Program {
+        /// The program ID, e.g. `token.aleo`. Defaults to the program of the package.
+        id: Option<String>,
+    },
+    /// Fetches a transaction and the block that confirmed it.
+    Transaction {
+        /// The transaction ID, e.g. `at1...`.
+        id: String,
+    },
+}
+
+impl Command for Query {
+    type Input = ();
+    type Output = String;
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let client = HttpClient::new(&self.endpoint);
+        let output = match self.command {
+            QueryCommands::Mapping { name, key, program } => {
+                let program = program_id(&context, program)?;
+                query_mapping(&client, &context, &program, &name, &key)?
+            }
+            QueryCommands::Program { id } => {
+                let program = program_id(&context, id)?;
+                network::program(&client, &program)?
+                    .ok_or_else(|| CliError::program_not_found(&program, &self.endpoint))?
+            }
+            QueryCommands::Transaction { id } => {
+                let transaction = network::transaction(&client, &id)?
+                    .ok_or_else(|| CliError::transaction_not_found(&id, &self.endpoint))?;
+                match network::confirming_block(&client, &id)? {
+                    Some(block) => tracing::info!("✅ Confirmed in block {block}"),
+                    None => tracing::info!("⏳ Not yet confirmed"),
+                }
+                serde_json::to_string_pretty(&transaction).map_err(|err| CliError::failed_to_query(&id, err))?
+            }
+        };
+        println!("{output}");
+        Ok(output)
+    }
+}
+
+/// Returns the given program ID, or the one of the package.
+fn program_id(context: &Context, program: Option<String>) -> Result<String> {
+    match program {
+        Some(program) => Ok(program),
+        None => {
+            let manifest =
+                Manifest::<CurrentNetwork>::open(&context.dir()?).map_err(PackageError::failed_to_open_manifest)?;
+            Ok(manifest.program_id().to_string())
+        }
+    }
+}
+
+/// Reads the value of the key, decoding it with the structs of the package if it is the program of the package.
+fn query_mapping(client: &dyn Client, context: &Context, program: &str, mapping: &str, key: &str) -> Result<String> {
+    // The interface is written by `leo build`, the values are printed as they are served without it.
+    let abi = std::fs::read_to_string(context.dir()?.join(BUILD_DIRECTORY_NAME).join(ABI_FILE_NAME))
+        .ok()
+        .and_then(|abi| serde_json::from_str::<Abi>(&abi).ok())
+        .filter(|abi| abi.program == program);
+    let value_type = abi
+        .as_ref()
+        .and_then(|abi| abi.mappings.iter().find(|entry| entry.name == mapping))
+        .map(|types| types.value.as_str());
+    let structs = abi.as_ref().map(|abi| abi.structs.as_slice()).unwrap_or_default();
+
+    let value = network::mapping_value(client, program, mapping, key)?
+        .ok_or_else(|| CliError::mapping_key_not_set(key, mapping))?;
+    Ok(format_plaintext(&value, value_type, structs))
+}
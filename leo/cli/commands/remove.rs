@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::cli::helpers::registry::open_registry;
+
+use leo_package::dependencies::{Dependencies, Resolver};
+
+/// Remove a dependency from the current package
+#[derive(Parser, Debug)]
+#[group(skip)]
+pub struct Remove {
+    #[clap(name = "NAME", help = "The name of the program to remove")]
+    pub(crate) name: String,
+
+    #[clap(
+        long,
+        env = "LEO_REGISTRY",
+        help = "The registry to fetch dependencies from, either a URL or a directory. Defaults to `~/.aleo/registry`."
+    )]
+    pub(crate) registry: Option<String>,
+}
+
+impl Command for Remove {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let package_path = context.dir()?;
+
+        let mut dependencies = Dependencies::read_from(&package_path)?;
+        if dependencies.shift_remove(&self.name).is_none() {
+            return Err(PackageError::dependency_not_declared(&self.name).into());
+        }
+        Dependencies::write_to(&package_path, &dependencies)?;
+
+        // Resolve the remaining dependencies, which removes the programs that are no longer needed.
+        let registry = open_registry(self.registry.as_deref(), false);
+        Resolver::resolve(&package_path, registry.as_ref())?;
+
+        tracing::info!("✅ Removed '{}'", self.name.bold());
+        Ok(())
+    }
+}
@@ -15,11 +15,13 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use crate::cli::helpers::debug_info::locate_failure;
 
 use snarkvm::cli::Run as SnarkVMRun;
 
 /// Build, Prove and Run Leo program with inputs
 #[derive(Parser, Debug)]
+#[group(skip)]
 pub struct Run {
     #[clap(name = "NAME", help = "The name of the program to run.", default_value = "main")]
     name: String,
@@ -27,6 +29,9 @@ pub struct Run {
     #[clap(name = "INPUTS", help = "The inputs to the program. If none are provided, the input file is used.")]
     inputs: Vec<String>,
 
+    #[clap(long, help = "Interpret the program instead of running its Aleo instructions, without generating a proof.")]
+    interpret: bool,
+
     #[clap(flatten)]
     pub(crate) compiler_options: BuildOptions,
 }
@@ -40,13 +45,15 @@ impl Command for Run {
     }
 
     fn prelude(&self, context: Context) -> Result<Self::Input> {
-        (Build { options: self.compiler_options.clone() }).execute(context)
+        // Map the instructions back to the source, so that a failure can be reported where it happens.
+        let options = BuildOptions { enable_debug_info: true, ..self.compiler_options.clone() };
+        (Build { options }).execute(context)
     }
 
     fn apply(self, context: Context, input: Self::Input) -> Result<Self::Output> {
         // If input values are provided, then run the program with those inputs.
         // Otherwise, use the input file.
-        let mut inputs = match self.inputs.is_empty() {
+        let inputs = match self.inputs.is_empty() {
             true => match input {
                 (Some(input_ast), circuits) => input_ast.program_inputs(&self.name, circuits),
                 _ => Vec::new(),
@@ -54,11 +61,16 @@ impl Command for Run {
             false => self.inputs,
         };
 
+        // Interpret the program on the inputs, rather than running the compiled program.
+        if self.interpret {
+            return eval::interpret(&context, &self.name, &inputs, &self.compiler_options, None);
+        }
+
         // Compose the `run` command.
-        let mut arguments = vec![SNARKVM_COMMAND.to_string(), self.name];
+        let mut arguments = vec![SNARKVM_COMMAND.to_string(), self.name.clone()];
 
         // Add the program inputs to the arguments.
-        arguments.append(&mut inputs);
+        arguments.extend(inputs.iter().cloned());
 
         // Open the Leo build/ directory
         let path = context.dir()?;
@@ -71,10 +83,15 @@ impl Command for Run {
         // Unset the Leo panic hook
         let _ = std::panic::take_hook();
 
-        // Call the `run` command.
-        println!();
+        // Call the `run` command. In JSON mode, stdout only has the JSON messages.
+        if context.message_format == MessageFormat::Human {
+            println!();
+        }
         let command = SnarkVMRun::try_parse_from(&arguments).map_err(CliError::failed_to_parse_run)?;
-        let res = command.parse().map_err(CliError::failed_to_execute_run)?;
+        let res = command.parse().map_err(|error| {
+            locate_failure(&path, &self.name, &inputs, &error.to_string())
+                .unwrap_or_else(|| CliError::failed_to_execute_run(error).into())
+        })?;
 
         // Log the output of the `run` command.
         tracing::info!("{}", res);
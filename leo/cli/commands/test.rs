@@ -0,0 +1,156 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_compiler::{Compiler, CompilerOptions};
+use leo_package::{build::BuildDirectory, imports::ImportsDirectory, outputs::OutputsDirectory, source::MainFile};
+use leo_span::Symbol;
+
+use snarkvm::{
+    circuit::AleoV0,
+    file::AleoFile,
+    prelude::{Identifier, PrivateKey, Program, Request, Value},
+    synthesizer::{Authorization, Process},
+};
+
+use rand::SeedableRng;
+use rand_chacha::ChaChaRng;
+use std::str::FromStr;
+
+/// Compiles the `@test` functions of the current package and runs them.
+#[derive(Parser, Debug)]
+#[group(skip)]
+pub struct Test {
+    #[clap(name = "FILTER", help = "Only run the tests whose name contains this string.")]
+    pub(crate) filter: Option<String>,
+
+    #[clap(flatten)]
+    pub(crate) compiler_options: BuildOptions,
+}
+
+impl Command for Test {
+    type Input = <Build as Command>::Output;
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, context: Context) -> Result<Self::Input> {
+        // Build the package first, so that its imports are compiled.
+        (Build { options: self.compiler_options.clone() }).execute(context)
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        // Get the package path and the program id.
+        let path = context.dir()?;
+        let manifest = context.open_manifest()?;
+        let program_id = manifest.program_id();
+
+        // Compile the program again, this time with the tests compiled as transitions.
+        let handler = context.handler()?;
+        let outputs_directory = OutputsDirectory::create(&path)?;
+        let options = CompilerOptions {
+            build: leo_compiler::BuildOptions { dce_enabled: self.compiler_options.enable_dce, tests_enabled: true },
+            ..Default::default()
+        };
+        let mut compiler = Compiler::new(
+            program_id.name().to_string(),
+            program_id.network().to_string(),
+            &handler,
+            path.join(MainFile::filename()),
+            outputs_directory,
+            Some(options),
+        );
+        compiler.parse_program()?;
+        let tests: Vec<Symbol> = compiler
+            .tests()
+            .into_iter()
+            .filter(|name| self.filter.as_ref().map_or(true, |filter| name.to_string().contains(filter.as_str())))
+            .collect();
+        let (symbol_table, struct_graph, call_graph) = compiler.compiler_stages()?;
+        let bytecode = compiler.code_generation_pass(&symbol_table, &struct_graph, &call_graph)?;
+
+        // Load the compiled program and its imports into a local process.
+        let program = Program::<CurrentNetwork>::from_str(&bytecode).map_err(CliError::failed_to_run_tests)?;
+        let process = load_process(&path, &program)?;
+
+        // Tests do not spend credits, so any account can run them. Use the one of the package if it has one.
+        let rng = &mut ChaChaRng::from_entropy();
+        let private_key = match dotenvy::from_path(path.join(".env")).and_then(|_| dotenvy::var("PRIVATE_KEY")) {
+            Ok(private_key) => PrivateKey::from_str(&private_key),
+            Err(_) => PrivateKey::new(rng),
+        }
+        .map_err(CliError::failed_to_run_tests)?;
+
+        // Run each test, reporting whether it passed.
+        tracing::info!("Running {} test(s)\n", tests.len());
+        let mut failed = 0;
+        for test in tests.iter() {
+            // The request is signed directly rather than authorized by the process, since authorizing synthesizes
+            // the circuit of the test, which panics on a failed assertion over constants.
+            let result = Identifier::from_str(&test.to_string())
+                .and_then(|function_name| {
+                    Request::sign(&private_key, *program.id(), function_name, std::iter::empty::<Value<_>>(), &[], rng)
+                })
+                .and_then(|request| process.evaluate::<AleoV0>(Authorization::new(request)));
+            match result {
+                Ok(_) => tracing::info!("✅ Passed '{test}'"),
+                Err(error) => {
+                    failed += 1;
+                    let error = error.to_string();
+                    match compiler.debug_info.locate_failure(&test.to_string(), &error) {
+                        Some((location, reason)) => {
+                            handler.emit_err(CliError::test_failed(test, reason, location.span))
+                        }
+                        None => tracing::error!("❌ Failed '{test}': {error}"),
+                    }
+                }
+            }
+        }
+
+        match failed {
+            0 => {
+                tracing::info!("\nAll {} test(s) passed", tests.len());
+                Ok(())
+            }
+            _ => Err(CliError::tests_failed(failed, tests.len()).into()),
+        }
+    }
+}
+
+/// Returns a process with the given program and the programs it imports from the build directory.
+pub(crate) fn load_process(
+    path: &std::path::Path,
+    program: &Program<CurrentNetwork>,
+) -> Result<Process<CurrentNetwork>> {
+    let mut process = Process::load().map_err(CliError::failed_to_run_tests)?;
+
+    // Add the imports of the program, which are compiled into the build directory.
+    let imports: Vec<_> = program.imports().keys().filter(|import| import.to_string() != "credits.aleo").collect();
+    if !imports.is_empty() {
+        let imports_directory = ImportsDirectory::create(&BuildDirectory::open(path)?)?;
+        for import in imports {
+            let import_file =
+                AleoFile::open(&imports_directory, import, false).map_err(CliError::failed_to_run_tests)?;
+            process.add_program(import_file.program()).map_err(CliError::failed_to_run_tests)?;
+        }
+    }
+
+    process.add_program(program).map_err(CliError::failed_to_run_tests)?;
+    Ok(process)
+}
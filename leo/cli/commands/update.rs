@@ -19,6 +19,7 @@ use crate::cli::helpers::updater::Updater;
 
 /// Update Leo to the latest version
 #[derive(Debug, Parser)]
+#[group(skip)]
 pub struct Update {
     /// Lists all available versions of Leo
     #[clap(short = 'l', long)]
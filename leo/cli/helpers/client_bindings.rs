@@ -0,0 +1,586 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_compiler::{Abi, AbiField, AbiMode, AbiParameter, AbiStruct, AbiTransition};
+use leo_errors::{CliError, Result};
+
+use std::fmt::Write;
+
+/// The helpers that every Rust binding starts with, they convert between Rust values and the values of a program.
+const RUST_PRELUDE: &str = r#"use snarkvm::prelude::{
+    bail, Address, Entry, Field, Group, Identifier, Literal, Plaintext, Record, Result, Scalar, Signature, Testnet3,
+    Value,
+};
+use std::str::FromStr;
+
+/// The network that the program is deployed on.
+pub type CurrentNetwork = Testnet3;
+
+/// A value that is passed to the program as a literal, and read back from the plaintexts it outputs.
+pub trait LeoValue: Sized {
+    /// Returns the value as it is written in the inputs of the program, e.g. `1u8` or `{x: 1u8, y: 2u8}`.
+    fn to_literal_string(&self) -> String;
+
+    /// Returns the value as it is written in a record entry, with `visibility` after each of its literals.
+    fn to_entry_string(&self, visibility: &str) -> String {
+        format!("{}.{visibility}", self.to_literal_string())
+    }
+
+    /// Reads the value from a plaintext of the program.
+    fn from_plaintext(plaintext: &Plaintext<CurrentNetwork>) -> Result<Self>;
+}
+
+macro_rules! leo_literal {
+    ($type_:ty, $variant:ident, $suffix:literal, |$literal:ident| $value:expr) => {
+        impl LeoValue for $type_ {
+            fn to_literal_string(&self) -> String {
+                format!("{}{}", self, $suffix)
+            }
+
+            fn from_plaintext(plaintext: &Plaintext<CurrentNetwork>) -> Result<Self> {
+                match plaintext {
+                    Plaintext::Literal(Literal::$variant($literal), _) => Ok($value),
+                    _ => bail!("Expected a literal of type `{}`, found `{plaintext}`", stringify!($variant)),
+                }
+            }
+        }
+    };
+}
+
+leo_literal!(bool, Boolean, "", |literal| **literal);
+leo_literal!(u8, U8, "u8", |literal| **literal);
+leo_literal!(u16, U16, "u16", |literal| **literal);
+leo_literal!(u32, U32, "u32", |literal| **literal);
+leo_literal!(u64, U64, "u64", |literal| **literal);
+leo_literal!(u128, U128, "u128", |literal| **literal);
+leo_literal!(i8, I8, "i8", |literal| **literal);
+leo_literal!(i16, I16, "i16", |literal| **literal);
+leo_literal!(i32, I32, "i32", |literal| **literal);
+leo_literal!(i64, I64, "i64", |literal| **literal);
+leo_literal!(i128, I128, "i128", |literal| **literal);
+leo_literal!(Address<CurrentNetwork>, Address, "", |literal| *literal);
+leo_literal!(Field<CurrentNetwork>, Field, "", |literal| *literal);
+leo_literal!(Group<CurrentNetwork>, Group, "", |literal| *literal);
+leo_literal!(Scalar<CurrentNetwork>, Scalar, "", |literal| *literal);
+leo_literal!(Signature<CurrentNetwork>, Signature, "", |literal| **literal);
+
+impl<T: LeoValue, const N: usize> LeoValue for [T; N] {
+    fn to_literal_string(&self) -> String {
+        format!("[{}]", self.iter().map(LeoValue::to_literal_string).collect::<Vec<_>>().join(", "))
+    }
+
+    fn to_entry_string(&self, visibility: &str) -> String {
+        format!("[{}]", self.iter().map(|element| element.to_entry_string(visibility)).collect::<Vec<_>>().join(", "))
+    }
+
+    fn from_plaintext(plaintext: &Plaintext<CurrentNetwork>) -> Result<Self> {
+        let elements = match plaintext {
+            Plaintext::Array(elements, _) => elements.iter().map(T::from_plaintext).collect::<Result<Vec<_>>>()?,
+            _ => bail!("Expected an array, found `{plaintext}`"),
+        };
+        match elements.try_into() {
+            Ok(array) => Ok(array),
+            Err(elements) => bail!("Expected an array of {N} elements, found {}", elements.len()),
+        }
+    }
+}
+
+/// Reads the member `name` of a struct.
+fn member<T: LeoValue>(plaintext: &Plaintext<CurrentNetwork>, name: &str) -> Result<T> {
+    match plaintext {
+        Plaintext::Struct(members, _) => match members.get(&Identifier::from_str(name)?) {
+            Some(member) => T::from_plaintext(member),
+            None => bail!("The struct `{plaintext}` has no member `{name}`"),
+        },
+        _ => bail!("Expected a struct, found `{plaintext}`"),
+    }
+}
+
+/// Reads the entry `name` of a decrypted record.
+fn entry<T: LeoValue>(record: &Record<CurrentNetwork, Plaintext<CurrentNetwork>>, name: &str) -> Result<T> {
+    match record.data().get(&Identifier::from_str(name)?) {
+        Some(Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext)) => {
+            T::from_plaintext(plaintext)
+        }
+        None => bail!("The record has no entry `{name}`"),
+    }
+}
+
+/// Reads an output of a transition that is not a record.
+fn plaintext_output<T: LeoValue>(value: &Value<CurrentNetwork>) -> Result<T> {
+    match value {
+        Value::Plaintext(plaintext) => T::from_plaintext(plaintext),
+        _ => bail!("Expected a plaintext, found `{value}`"),
+    }
+}
+
+/// Reads an output of a transition that is a record.
+fn record_output(value: &Value<CurrentNetwork>) -> Result<&Record<CurrentNetwork, Plaintext<CurrentNetwork>>> {
+    match value {
+        Value::Record(record) => Ok(record),
+        _ => bail!("Expected a decrypted record, found `{value}`"),
+    }
+}
+"#;
+
+/// How the visibility of a record entry is written after each of its values in the TypeScript bindings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Visibility<'a> {
+    /// The value is not a record entry.
+    None,
+    /// The visibility is the `visibility` parameter of the function that formats the value, e.g. `.private`.
+    Parameter,
+    /// The visibility of the record entry, e.g. `private`.
+    Mode(&'a str),
+}
+
+impl Visibility<'_> {
+    /// Returns what is written after each value.
+    fn suffix(&self) -> String {
+        match self {
+            Visibility::None => String::new(),
+            Visibility::Parameter => "${visibility}".to_string(),
+            Visibility::Mode(mode) => format!(".{mode}"),
+        }
+    }
+
+    /// Returns the argument that is passed on to the function that formats a struct.
+    fn argument(&self) -> String {
+        match self {
+            Visibility::None => String::new(),
+            Visibility::Parameter => ", visibility".to_string(),
+            Visibility::Mode(mode) => format!(", \".{mode}\""),
+        }
+    }
+}
+
+/// A type of the interface of a program, as it is written in `abi.json`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum BindingType<'a> {
+    Address,
+    Boolean,
+    Field,
+    Group,
+    Scalar,
+    Signature,
+    /// An integer type, e.g. `u8`.
+    Integer(&'a str),
+    /// An array type, e.g. `[u8; 4]`.
+    Array(Box<BindingType<'a>>, usize),
+    /// A struct of the program.
+    Struct(&'a str),
+    /// A record of the program.
+    Record(&'a str),
+    /// A record of another program, e.g. `token.aleo/token`.
+    ExternalRecord,
+}
+
+impl<'a> BindingType<'a> {
+    /// Parses a type of the interface, or returns `None` if values of the type cannot be passed to a program.
+    fn parse(type_: &'a str, abi: &Abi) -> Option<Self> {
+        let binding_type = match type_ {
+            "address" => Self::Address,
+            "boolean" => Self::Boolean,
+            "field" => Self::Field,
+            "group" => Self::Group,
+            "scalar" => Self::Scalar,
+            "signature" => Self::Signature,
+            "u8" | "u16" | "u32" | "u64" | "u128" | "i8" | "i16" | "i32" | "i64" | "i128" => Self::Integer(type_),
+            _ if type_.starts_with('[') && type_.ends_with(']') => {
+                let (element, length) = type_[1..type_.len() - 1].rsplit_once(';')?;
+                Self::Array(Box::new(Self::parse(element.trim(), abi)?), length.trim().parse().ok()?)
+            }
+            _ if type_.contains(".aleo/") => Self::ExternalRecord,
+            _ if abi.structs.iter().any(|struct_| struct_.name == type_) => Self::Struct(type_),
+            _ if abi.records.iter().any(|record| record.name == type_) => Self::Record(type_),
+            _ => return None,
+        };
+        Some(binding_type)
+    }
+
+    /// Returns the type of the values of this type in the Rust bindings.
+    fn rust_type(&self) -> String {
+        match self {
+            Self::Address => "Address<CurrentNetwork>".to_string(),
+            Self::Boolean => "bool".to_string(),
+            Self::Field => "Field<CurrentNetwork>".to_string(),
+            Self::Group => "Group<CurrentNetwork>".to_string(),
+            Self::Scalar => "Scalar<CurrentNetwork>".to_string(),
+            Self::Signature => "Signature<CurrentNetwork>".to_string(),
+            Self::Integer(integer) => integer.to_string(),
+            Self::Array(element, length) => format!("[{}; {length}]", element.rust_type()),
+            Self::Struct(name) | Self::Record(name) => upper_camel_case(name),
+            Self::ExternalRecord => "Record<CurrentNetwork, Plaintext<CurrentNetwork>>".to_string(),
+        }
+    }
+
+    /// Returns the type of the values of this type in the TypeScript bindings.
+    fn typescript_type(&self) -> String {
+        match self {
+            Self::Boolean => "boolean".to_string(),
+            Self::Field | Self::Scalar => "bigint".to_string(),
+            Self::Integer("u64" | "u128" | "i64" | "i128") => "bigint".to_string(),
+            Self::Integer(_) => "number".to_string(),
+            Self::Array(element, _) => format!("{}[]", element.typescript_type()),
+            Self::Struct(name) | Self::Record(name) => upper_camel_case(name),
+            Self::Address | Self::Group | Self::Signature | Self::ExternalRecord => "string".to_string(),
+        }
+    }
+
+    /// Returns the contents of a TypeScript template literal that formats `value` as it is written in the inputs of the program.
+    fn typescript_template(&self, value: &str, visibility: Visibility) -> String {
+        let suffix = visibility.suffix();
+        match self {
+            Self::Address | Self::Group | Self::Signature | Self::ExternalRecord | Self::Boolean => {
+                format!("${{{value}}}{suffix}")
+            }
+            Self::Field => format!("${{{value}}}field{suffix}"),
+            Self::Scalar => format!("${{{value}}}scalar{suffix}"),
+            Self::Integer(integer) => format!("${{{value}}}{integer}{suffix}"),
+            Self::Array(element, _) => format!(
+                "[${{{value}.map((element) => {}).join(\", \")}}]",
+                element.typescript_expression("element", visibility)
+            ),
+            Self::Struct(name) | Self::Record(name) => {
+                format!("${{format{}({value}{})}}", upper_camel_case(name), visibility.argument())
+            }
+        }
+    }
+
+    /// Returns a TypeScript expression that formats `value` as it is written in the inputs of the program.
+    fn typescript_expression(&self, value: &str, visibility: Visibility) -> String {
+        match (self, visibility) {
+            (Self::Address | Self::Group | Self::Signature | Self::ExternalRecord, Visibility::None) => {
+                value.to_string()
+            }
+            (Self::Struct(name) | Self::Record(name), _) => {
+                format!("format{}({value}{})", upper_camel_case(name), visibility.argument())
+            }
+            _ => format!("`{}`", self.typescript_template(value, visibility)),
+        }
+    }
+
+    /// Returns `true` if values of the type are passed by value in the Rust bindings, rather than by reference.
+    fn is_copy(&self) -> bool {
+        matches!(
+            self,
+            Self::Address
+                | Self::Boolean
+                | Self::Field
+                | Self::Group
+                | Self::Scalar
+                | Self::Signature
+                | Self::Integer(_)
+        )
+    }
+}
+
+/// Returns the Rust bindings of the program, see `leo bindings`.
+pub fn rust_bindings(abi: &Abi) -> Result<String> {
+    let mut bindings = format!("//! Bindings for the `{}` program, generated by `leo bindings`.\n\n", abi.program);
+    // Not every program uses all of the helpers.
+    bindings.push_str("#![allow(dead_code)]\n\n");
+    bindings.push_str(RUST_PRELUDE);
+    writeln!(bindings, "\n/// The ID of the program.\npub const PROGRAM_ID: &str = \"{}\";", abi.program).unwrap();
+
+    for struct_ in &abi.structs {
+        let name = upper_camel_case(&struct_.name);
+        bindings.push('\n');
+        rust_struct_definition(&mut bindings, abi, struct_, "struct")?;
+        let template =
+            struct_.fields.iter().map(|field| format!("{}: {{}}", field.name)).collect::<Vec<_>>().join(", ");
+        let literals = struct_.fields.iter().map(|field| format!("self.{}.to_literal_string()", field.name));
+        let entries = struct_.fields.iter().map(|field| format!("self.{}.to_entry_string(visibility)", field.name));
+        let members = struct_.fields.iter().map(|field| format!("{0}: member(plaintext, \"{0}\")?", field.name));
+        writeln!(
+            bindings,
+            "
+impl LeoValue for {name} {{
+    fn to_literal_string(&self) -> String {{
+        format!(\"{{{{{template}}}}}\", {})
+    }}
+
+    fn to_entry_string(&self, visibility: &str) -> String {{
+        format!(\"{{{{{template}}}}}\", {})
+    }}
+
+    fn from_plaintext(plaintext: &Plaintext<CurrentNetwork>) -> Result<Self> {{
+        Ok(Self {{ {} }})
+    }}
+}}",
+            literals.collect::<Vec<_>>().join(", "),
+            entries.collect::<Vec<_>>().join(", "),
+            members.collect::<Vec<_>>().join(", "),
+        )
+        .unwrap();
+    }
+
+    for record in &abi.records {
+        let name = upper_camel_case(&record.name);
+        bindings.push('\n');
+        rust_struct_definition(&mut bindings, abi, record, "record")?;
+        let entries = record.fields.iter().map(|field| match field.name.as_str() {
+            "owner" => "owner: **record.owner()".to_string(),
+            name => format!("{name}: entry(record, \"{name}\")?"),
+        });
+        writeln!(
+            bindings,
+            "
+impl {name} {{
+    /// Returns the record as it is written in the inputs of the program.
+    pub fn to_record_string(&self) -> String {{
+        format!(\"{{{{{}, _nonce: {{}}.public}}}}\", {}, self._nonce)
+    }}
+
+    /// Returns the record as an input of the program.
+    pub fn to_value(&self) -> Result<Value<CurrentNetwork>> {{
+        Value::from_str(&self.to_record_string())
+    }}
+
+    /// Reads the record from a decrypted record of the program.
+    pub fn from_record(record: &Record<CurrentNetwork, Plaintext<CurrentNetwork>>) -> Result<Self> {{
+        Ok(Self {{ {}, _nonce: *record.nonce() }})
+    }}
+}}",
+            record.fields.iter().map(|field| format!("{}: {{}}", field.name)).collect::<Vec<_>>().join(", "),
+            record
+                .fields
+                .iter()
+                .map(|field| format!("self.{}.to_entry_string(\"{}\")", field.name, record_field_mode(field)))
+                .collect::<Vec<_>>()
+                .join(", "),
+            entries.collect::<Vec<_>>().join(", "),
+        )
+        .unwrap();
+    }
+
+    for transition in &abi.transitions {
+        rust_transition(&mut bindings, abi, transition)?;
+    }
+    Ok(bindings)
+}
+
+/// Writes the definition of a struct or record to the Rust bindings.
+fn rust_struct_definition(bindings: &mut String, abi: &Abi, struct_: &AbiStruct, kind: &str) -> Result<()> {
+    writeln!(bindings, "/// The `{}` {kind} of the program.", struct_.name).unwrap();
+    writeln!(bindings, "#[derive(Clone, Debug, PartialEq, Eq)]").unwrap();
+    writeln!(bindings, "pub struct {} {{", upper_camel_case(&struct_.name)).unwrap();
+    for field in &struct_.fields {
+        writeln!(bindings, "    pub {}: {},", field.name, binding_type(&field.type_, abi)?.rust_type()).unwrap();
+    }
+    if kind == "record" {
+        writeln!(bindings, "    pub _nonce: Group<CurrentNetwork>,").unwrap();
+    }
+    writeln!(bindings, "}}").unwrap();
+    Ok(())
+}
+
+/// Writes the functions that build the inputs of a transition, and read its outputs, to the Rust bindings.
+fn rust_transition(bindings: &mut String, abi: &Abi, transition: &AbiTransition) -> Result<()> {
+    let mut parameters = Vec::with_capacity(transition.inputs.len());
+    let mut inputs = Vec::with_capacity(transition.inputs.len());
+    for input in &transition.inputs {
+        let name = input.name.as_deref().unwrap_or_default();
+        let type_ = binding_type(&input.type_, abi)?;
+        parameters.push(match type_.is_copy() {
+            true => format!("{name}: {}", type_.rust_type()),
+            false => format!("{name}: &{}", type_.rust_type()),
+        });
+        inputs.push(match type_ {
+            BindingType::Record(_) => format!("{name}.to_record_string()"),
+            BindingType::ExternalRecord => format!("{name}.to_string()"),
+            _ => format!("{name}.to_literal_string()"),
+        });
+    }
+    let body = match inputs.is_empty() {
+        true => "Ok(Vec::new())".to_string(),
+        false => format!("[{}].iter().map(|input| Value::from_str(input)).collect()", inputs.join(", ")),
+    };
+    writeln!(
+        bindings,
+        "
+/// Returns the inputs of the `{0}` transition.
+pub fn {0}_inputs({1}) -> Result<Vec<Value<CurrentNetwork>>> {{
+    {body}
+}}",
+        transition.name,
+        parameters.join(", "),
+    )
+    .unwrap();
+
+    if transition.outputs.is_empty() {
+        return Ok(());
+    }
+    let mut types = Vec::with_capacity(transition.outputs.len());
+    let mut outputs = Vec::with_capacity(transition.outputs.len());
+    for (index, output) in transition.outputs.iter().enumerate() {
+        let type_ = binding_type(&output.type_, abi)?;
+        outputs.push(match &type_ {
+            BindingType::Record(name) => {
+                format!("{}::from_record(record_output(&outputs[{index}])?)", upper_camel_case(name))
+            }
+            BindingType::ExternalRecord => format!("record_output(&outputs[{index}]).cloned()"),
+            _ => format!("plaintext_output(&outputs[{index}])"),
+        });
+        types.push(type_.rust_type());
+    }
+    let (type_, output) = match types.len() {
+        1 => (types.remove(0), outputs.remove(0)),
+        _ => (format!("({})", types.join(", ")), format!("Ok(({}?))", outputs.join("?, "))),
+    };
+    writeln!(
+        bindings,
+        "
+/// Reads the outputs of the `{0}` transition.
+pub fn {0}_outputs(outputs: &[Value<CurrentNetwork>]) -> Result<{type_}> {{
+    if outputs.len() != {1} {{
+        bail!(\"Expected {1} output(s) of `{0}`, found {{}}\", outputs.len());
+    }}
+    {output}
+}}",
+        transition.name,
+        transition.outputs.len(),
+    )
+    .unwrap();
+    Ok(())
+}
+
+/// Returns the TypeScript bindings of the program, see `leo bindings`.
+pub fn typescript_bindings(abi: &Abi) -> Result<String> {
+    let mut bindings = format!("// Bindings for the `{}` program, generated by `leo bindings`.\n", abi.program);
+    writeln!(bindings, "\n/** The ID of the program. */\nexport const PROGRAM_ID = \"{}\";", abi.program).unwrap();
+
+    for (struct_, kind) in
+        abi.structs.iter().map(|struct_| (struct_, "struct")).chain(abi.records.iter().map(|record| (record, "record")))
+    {
+        let name = upper_camel_case(&struct_.name);
+        writeln!(bindings, "\n/** The `{}` {kind} of the program. */\nexport interface {name} {{", struct_.name)
+            .unwrap();
+        for field in &struct_.fields {
+            writeln!(bindings, "  {}: {};", field.name, binding_type(&field.type_, abi)?.typescript_type()).unwrap();
+        }
+        if kind == "record" {
+            writeln!(bindings, "  _nonce: string;").unwrap();
+        }
+        writeln!(bindings, "}}").unwrap();
+
+        // Each value of a record entry is followed by its visibility. The structs in an entry take it as a parameter.
+        let mut members = Vec::with_capacity(struct_.fields.len() + 1);
+        for field in &struct_.fields {
+            let visibility = match kind {
+                "record" => Visibility::Mode(record_field_mode(field)),
+                _ => Visibility::Parameter,
+            };
+            let value =
+                binding_type(&field.type_, abi)?.typescript_template(&format!("value.{}", field.name), visibility);
+            members.push(format!("{}: {value}", field.name));
+        }
+        let parameters = match kind {
+            "record" => {
+                members.push("_nonce: ${value._nonce}.public".to_string());
+                format!("value: {name}")
+            }
+            _ => format!("value: {name}, visibility = \"\""),
+        };
+        writeln!(
+            bindings,
+            "\n/** Returns the `{}` {kind} as it is written in the inputs of the program. */
+export function format{name}({parameters}): string {{
+  return `{{{}}}`;
+}}",
+            struct_.name,
+            members.join(", "),
+        )
+        .unwrap();
+    }
+
+    for transition in &abi.transitions {
+        let mut parameters = Vec::with_capacity(transition.inputs.len());
+        let mut inputs = Vec::with_capacity(transition.inputs.len());
+        for input in &transition.inputs {
+            let name = lower_camel_case(input.name.as_deref().unwrap_or_default());
+            let type_ = binding_type(&input.type_, abi)?;
+            parameters.push(format!("{name}: {}", type_.typescript_type()));
+            inputs.push(type_.typescript_expression(&name, Visibility::None));
+        }
+        writeln!(
+            bindings,
+            "\n/**
+ * Returns the inputs of the `{}` transition.
+ * Its outputs are {}.
+ */
+export function {}Inputs({}): string[] {{
+  return [{}];
+}}",
+            transition.name,
+            describe_outputs(&transition.outputs),
+            lower_camel_case(&transition.name),
+            parameters.join(", "),
+            inputs.join(", "),
+        )
+        .unwrap();
+    }
+    Ok(bindings)
+}
+
+/// Parses a type of the interface, or fails if values of the type cannot be passed to or read from a program.
+fn binding_type<'a>(type_: &'a str, abi: &Abi) -> Result<BindingType<'a>> {
+    BindingType::parse(type_, abi)
+        .ok_or_else(|| CliError::failed_to_generate_bindings(format!("the type `{type_}` is not supported")).into())
+}
+
+/// Returns the visibility with which a field of a record is written, e.g. `private`.
+fn record_field_mode(field: &AbiField) -> &'static str {
+    match field.mode {
+        Some(AbiMode::Constant) => "constant",
+        Some(AbiMode::Public) => "public",
+        Some(AbiMode::Private) | None => "private",
+    }
+}
+
+/// Describes the outputs of a transition, e.g. `a public \`u64\``.
+fn describe_outputs(outputs: &[AbiParameter]) -> String {
+    if outputs.is_empty() {
+        return "empty".to_string();
+    }
+    let mode = |output: &AbiParameter| match output.mode {
+        AbiMode::Constant => "constant",
+        AbiMode::Private => "private",
+        AbiMode::Public => "public",
+    };
+    outputs.iter().map(|output| format!("a {} `{}`", mode(output), output.type_)).collect::<Vec<_>>().join(", ")
+}
+
+/// Converts a name of the program, e.g. `token_id`, into `TokenId`.
+fn upper_camel_case(name: &str) -> String {
+    name.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut characters = word.chars();
+            characters
+                .next()
+                .map(|first| first.to_uppercase().chain(characters).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Converts a name of the program, e.g. `token_id`, into `tokenId`.
+fn lower_camel_case(name: &str) -> String {
+    let name = upper_camel_case(name);
+    let mut characters = name.chars();
+    characters.next().map(|first| first.to_lowercase().chain(characters).collect()).unwrap_or_default()
+}
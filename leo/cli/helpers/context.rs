@@ -15,29 +15,56 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use leo_errors::{CliError, PackageError, Result};
+use leo_errors::{emitter::Handler, CliError, PackageError, Result};
 use leo_package::build::{BuildDirectory, BUILD_DIRECTORY_NAME};
 
 use snarkvm::file::Manifest;
 
 use std::{
     env::current_dir,
-    fs::File,
+    fs::{File, OpenOptions},
     io::Write,
     path::{Path, PathBuf},
 };
 
+/// The format in which the errors and warnings of a command are reported.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MessageFormat {
+    /// Human-readable messages on stderr.
+    #[default]
+    Human,
+    /// A JSON object per message on stdout, or in the `--message-output` file.
+    Json,
+}
+
 /// Project context, manifest, current directory etc
 /// All the info that is relevant in most of the commands
 #[derive(Clone)]
 pub struct Context {
     /// Path at which the command is called, None when default
     pub path: Option<PathBuf>,
+    /// The format in which errors and warnings are reported
+    pub message_format: MessageFormat,
+    /// The file to which JSON messages are written, None for stdout
+    pub message_output: Option<PathBuf>,
 }
 
 impl Context {
     pub fn new(path: Option<PathBuf>) -> Result<Context> {
-        Ok(Context { path })
+        Ok(Context { path, message_format: MessageFormat::default(), message_output: None })
+    }
+
+    /// Returns a handler that reports errors and warnings in the message format of the command.
+    /// JSON messages are appended to the `message_output` file if there is one, and written to stdout otherwise.
+    pub fn handler(&self) -> Result<Handler> {
+        Ok(match (self.message_format, &self.message_output) {
+            (MessageFormat::Human, _) => Handler::default(),
+            (MessageFormat::Json, None) => Handler::new_json(std::io::stdout()),
+            (MessageFormat::Json, Some(path)) => {
+                let file = OpenOptions::new().create(true).append(true).open(path).map_err(CliError::cli_io_error)?;
+                Handler::new_json(file)
+            }
+        })
     }
 
     /// Returns the path to the Leo package.
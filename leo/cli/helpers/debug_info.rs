@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Traces the failures that snarkVM reports back to the Leo source, using the `main.aleo.map` written by the build.
+
+use crate::cli::{commands::test::load_process, CurrentNetwork};
+
+use leo_compiler::DebugInfo;
+use leo_errors::{CliError, LeoError};
+use leo_package::{
+    build::{BuildDirectory, DEBUG_INFO_FILE_NAME},
+    dependencies::Resolver,
+    outputs::MAIN_ALEO_FILE_NAME,
+    root::Env,
+};
+
+use snarkvm::{
+    circuit::AleoV0,
+    prelude::{Identifier, PrivateKey, Program, Request, Value},
+    synthesizer::Authorization,
+};
+
+use rand::SeedableRng;
+use rand_chacha::ChaChaRng;
+use std::{panic::AssertUnwindSafe, path::Path, str::FromStr};
+
+/// Returns the failure of a function of the main program, located where it happens in the Leo source.
+/// Returns `None` if the program was built without debug information, or if the failure cannot be located.
+/// Returns the error of the `.env` file if the failure must be reproduced, and the package has no account to do so.
+pub fn locate_failure(package_path: &Path, function: &str, inputs: &[String], error: &str) -> Option<LeoError> {
+    let build_directory = BuildDirectory::open(package_path).ok()?;
+    let debug_info = std::fs::read_to_string(build_directory.join(DEBUG_INFO_FILE_NAME)).ok()?;
+    let mut debug_info: DebugInfo = serde_json::from_str(&debug_info).ok()?;
+
+    // The debug information only describes the program it was built with.
+    let program = std::fs::read_to_string(build_directory.join(MAIN_ALEO_FILE_NAME)).ok()?;
+    if debug_info.checksum != Resolver::checksum(&program) {
+        tracing::warn!("The debug information is out of date, rebuild the program with `--enable-debug-info`");
+        return None;
+    }
+    debug_info.load_files().ok()?;
+
+    if let Some((location, reason)) = debug_info.locate_failure(function, error) {
+        return Some(CliError::execution_failed(function, reason, location.span).into());
+    }
+
+    // A failure found while proving, e.g. an unsatisfied circuit, does not name the instruction that failed.
+    // Evaluating the function instead reproduces the failure at its instruction.
+    // The request is signed with the account of the package, as `leo run` signs it.
+    let private_key = match Env::<CurrentNetwork>::private_key(package_path) {
+        Ok(private_key) => private_key,
+        Err(error) => return Some(error),
+    };
+    let error = evaluate(package_path, &program, function, inputs, &private_key)?;
+    let (location, reason) = debug_info.locate_failure(function, &error)?;
+    Some(CliError::execution_failed(function, reason, location.span).into())
+}
+
+/// Evaluates a function of the main program on its inputs, returning the error it fails with, if any.
+fn evaluate(
+    package_path: &Path,
+    program: &str,
+    function: &str,
+    inputs: &[String],
+    private_key: &PrivateKey<CurrentNetwork>,
+) -> Option<String> {
+    let program = Program::<CurrentNetwork>::from_str(program).ok()?;
+    let process = load_process(package_path, &program).ok()?;
+
+    let function_name = Identifier::from_str(function).ok()?;
+    let input_types = program.get_function(&function_name).ok()?.input_types();
+    let inputs = inputs.iter().map(|input| Value::from_str(input)).collect::<Result<Vec<_>, _>>().ok()?;
+
+    let rng = &mut ChaChaRng::from_entropy();
+    let request =
+        Request::sign(private_key, *program.id(), function_name, inputs.into_iter(), &input_types, rng).ok()?;
+
+    // snarkVM panics on some failures, e.g. an overflow, which then cannot be traced to their instruction.
+    // The panic is caught rather than silenced, since the panic hook is shared with the rest of the process.
+    let result = std::panic::catch_unwind(AssertUnwindSafe(|| process.evaluate::<AleoV0>(Authorization::new(request))));
+    result.ok()?.err().map(|error| error.to_string())
+}
@@ -0,0 +1,187 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A local development ledger, served over HTTP for `leo node start`.
+//!
+//! The ledger keeps its programs in memory and answers the subset of the node API
+//! that is needed to deploy programs and to prepare executions against it.
+//! It does not execute transactions or finalize blocks, so it has no mapping values or transactions to serve:
+//! the `mapping` and `transaction` routes that `leo query` reads are answered with `404 Not Found`.
+
+use super::*;
+use leo_errors::{CliError, Result};
+
+use snarkvm::{
+    circuit::AleoV0,
+    ledger::store::{helpers::memory::BlockMemory, BlockStore},
+    package::{DeployRequest, DeployResponse},
+    prelude::{Deployment, Process, Program, ProgramID},
+};
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    str::FromStr,
+};
+
+/// The port a local development node listens on by default.
+pub const DEVNET_PORT: u16 = 3030;
+
+/// The endpoint of a local development node listening on the default port.
+pub const DEVNET_ENDPOINT: &str = "http://localhost:3030";
+
+/// The largest request body that the node reads into memory, far more than any deployment takes.
+const MAX_BODY_LENGTH: usize = 16 * 1024 * 1024;
+
+/// The in-memory ledger of a local development node.
+pub struct Devnet {
+    /// The process holding every deployed program.
+    process: Process<CurrentNetwork>,
+    /// The (empty) block store, from which the state root is read.
+    blocks: BlockStore<CurrentNetwork, BlockMemory<CurrentNetwork>>,
+}
+
+impl Devnet {
+    /// Initializes a ledger that only contains `credits.aleo`.
+    pub fn load() -> Result<Self> {
+        let process = Process::load().map_err(CliError::failed_to_execute_node)?;
+        let blocks = BlockStore::open(None).map_err(CliError::failed_to_execute_node)?;
+        Ok(Self { process, blocks })
+    }
+
+    /// Returns `true` if the program with the given ID is deployed.
+    pub fn contains_program(&self, program_id: &ProgramID<CurrentNetwork>) -> bool {
+        self.process.contains_program(program_id)
+    }
+
+    /// Computes the deployment of a program and adds it to the ledger.
+    pub fn deploy_program(&mut self, program: &Program<CurrentNetwork>) -> Result<()> {
+        let deployment = self
+            .process
+            .deploy::<AleoV0, _>(program, &mut rand::thread_rng())
+            .map_err(CliError::failed_to_execute_node)?;
+        self.deploy(&deployment)
+    }
+
+    /// Verifies a deployment and adds its program to the ledger.
+    pub fn deploy(&mut self, deployment: &Deployment<CurrentNetwork>) -> Result<()> {
+        self.process
+            .verify_deployment::<AleoV0, _>(deployment, &mut rand::thread_rng())
+            .map_err(CliError::failed_to_execute_node)?;
+        self.process.load_deployment(deployment).map_err(CliError::failed_to_execute_node)?;
+        tracing::info!("✅ Deployed '{}'", deployment.program_id());
+        Ok(())
+    }
+
+    /// Answers the requests made to `listener`, one connection at a time.
+    pub fn serve(&mut self, listener: TcpListener) -> Result<()> {
+        for stream in listener.incoming() {
+            let stream = stream.map_err(CliError::cli_io_error)?;
+            // A malformed request must not stop the node.
+            if let Err(error) = self.respond(stream) {
+                tracing::warn!("{error}");
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads a single HTTP request from `stream` and writes its response.
+    fn respond(&mut self, stream: TcpStream) -> Result<()> {
+        let mut reader = BufReader::new(stream.try_clone().map_err(CliError::cli_io_error)?);
+
+        // Read the request line, e.g. `GET /testnet3/latest/stateRoot HTTP/1.1`.
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).map_err(CliError::cli_io_error)?;
+        let mut parts = request_line.split_whitespace();
+        let (method, path) = (parts.next().unwrap_or_default(), parts.next().unwrap_or_default());
+
+        // Read the headers, which end with an empty line.
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line).map_err(CliError::cli_io_error)? == 0 || line.trim_end().is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                if name.eq_ignore_ascii_case("Content-Length") {
+                    content_length = value.trim().parse().unwrap_or_default();
+                }
+            }
+        }
+        let (status, body) = match content_length {
+            length if length > MAX_BODY_LENGTH => {
+                (413, json_string(format!("The request body exceeds {MAX_BODY_LENGTH} bytes")))
+            }
+            length => {
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).map_err(CliError::cli_io_error)?;
+                self.route(method, path, &body)
+            }
+        };
+        tracing::info!("{method} {path} - {status}");
+
+        let reason = match status {
+            200 => "OK",
+            400 => "Bad Request",
+            413 => "Payload Too Large",
+            _ => "Not Found",
+        };
+        let mut stream = stream;
+        write!(
+            stream,
+            "HTTP/1.1 {status} {reason}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )
+        .map_err(CliError::cli_io_error)?;
+        stream.flush().map_err(CliError::cli_io_error)?;
+        Ok(())
+    }
+
+    /// Returns the status and the JSON body of the response to a request.
+    /// The routes that are not served, e.g. `GET /testnet3/program/{id}/mapping/{name}/{key}`, are not found.
+    fn route(&mut self, method: &str, path: &str, body: &[u8]) -> (u16, String) {
+        let segments = path.split('/').filter(|segment| !segment.is_empty()).collect::<Vec<_>>();
+        let result = match (method, segments.as_slice()) {
+            ("GET", ["testnet3", "latest", "stateRoot"]) => serde_json::to_string(&self.blocks.current_state_root()),
+            ("GET", ["testnet3", "program", program_id]) => {
+                match ProgramID::from_str(program_id).and_then(|program_id| self.process.get_program(program_id)) {
+                    Ok(program) => serde_json::to_string(program),
+                    Err(error) => return (404, json_string(error)),
+                }
+            }
+            ("POST", ["testnet3", "deploy"]) => {
+                let request = match serde_json::from_slice::<DeployRequest<CurrentNetwork>>(body) {
+                    Ok(request) => request,
+                    Err(error) => return (400, json_string(error)),
+                };
+                if let Err(error) = self.deploy(request.deployment()) {
+                    return (400, json_string(error));
+                }
+                serde_json::to_string(&DeployResponse::new(request.deployment().clone()))
+            }
+            _ => return (404, json_string(format!("Unknown route '{method} {path}'"))),
+        };
+        match result {
+            Ok(body) => (200, body),
+            Err(error) => (400, json_string(error)),
+        }
+    }
+}
+
+/// Encodes a message as a JSON string.
+fn json_string(message: impl std::fmt::Display) -> String {
+    serde_json::Value::String(message.to_string()).to_string()
+}
@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use super::context::MessageFormat;
 use leo_errors::Result;
 
 use colored::Colorize;
@@ -200,15 +201,19 @@ where
 }
 
 /// Initialize logger with custom format and verbosity.
-pub fn init_logger(_app_name: &'static str, verbosity: usize) -> Result<()> {
+pub fn init_logger(_app_name: &'static str, verbosity: usize, message_format: MessageFormat) -> Result<()> {
     // This line enables Windows 10 ANSI coloring API.
     #[cfg(target_family = "windows")]
     ansi_term::enable_ansi_support().map_err(|_| leo_errors::CliError::failed_to_enable_ansi_support())?;
 
-    use tracing_subscriber::fmt::writer::MakeWriterExt;
+    use tracing_subscriber::fmt::writer::{BoxMakeWriter, MakeWriterExt};
 
+    // JSON messages are written to stdout, so everything else is written to stderr.
     let stderr = std::io::stderr.with_max_level(tracing::Level::WARN);
-    let mk_writer = stderr.or_else(std::io::stdout);
+    let mk_writer = match message_format {
+        MessageFormat::Human => BoxMakeWriter::new(stderr.or_else(std::io::stdout)),
+        MessageFormat::Json => BoxMakeWriter::new(std::io::stderr),
+    };
 
     let subscriber = FmtSubscriber::builder()
         // all spans/events with a level higher than TRACE (e.g, debug, info, warn, etc.)
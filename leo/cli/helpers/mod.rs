@@ -16,6 +16,12 @@
 
 pub use super::*;
 
+pub mod client_bindings;
 pub mod context;
+pub mod debug_info;
+pub mod devnet;
 pub mod logger;
+pub mod network;
+pub mod registry;
+pub mod templates;
 pub mod updater;
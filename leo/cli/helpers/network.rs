@@ -0,0 +1,164 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The client that `leo query` reads the state of the network with.
+
+use super::*;
+use leo_compiler::AbiStruct;
+use leo_errors::{CliError, Result};
+
+use snarkvm::prelude::Plaintext;
+
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use reqwest::StatusCode;
+use serde_json::Value as JsonValue;
+use std::{fmt::Write, str::FromStr};
+
+/// The characters that are escaped in a path segment, i.e. all but the unreserved characters of RFC 3986.
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'.').remove(b'_').remove(b'~');
+
+/// A client of the REST API of a node, e.g. `https://api.explorer.aleo.org/v1`.
+pub trait Client {
+    /// Fetches the JSON response at `path`, relative to the network of the endpoint, e.g. `program/token.aleo`.
+    /// Returns `None` if the endpoint does not have it.
+    fn get(&self, path: &str) -> Result<Option<JsonValue>>;
+}
+
+/// A client that sends its requests over HTTP, i.e. the resource `path` is served at `{endpoint}/testnet3/{path}`.
+pub struct HttpClient {
+    endpoint: String,
+}
+
+impl HttpClient {
+    pub fn new(endpoint: &str) -> Self {
+        Self { endpoint: endpoint.trim_end_matches('/').to_string() }
+    }
+}
+
+impl Client for HttpClient {
+    fn get(&self, path: &str) -> Result<Option<JsonValue>> {
+        let url = format!("{}/testnet3/{path}", self.endpoint);
+        let response = reqwest::blocking::get(&url).map_err(|err| CliError::failed_to_query(&url, err))?;
+        match response.status() {
+            StatusCode::NOT_FOUND => Ok(None),
+            status if !status.is_success() => {
+                Err(CliError::failed_to_query(&url, format!("responded with {status}")).into())
+            }
+            _ => Ok(Some(response.json().map_err(|err| CliError::failed_to_query(&url, err))?)),
+        }
+    }
+}
+
+/// Returns the source of the deployed program, or `None` if it is not deployed.
+pub fn program(client: &dyn Client, program_id: &str) -> Result<Option<String>> {
+    let path = format!("program/{program_id}");
+    client.get(&path)?.map(|json| string(&path, json)).transpose()
+}
+
+/// Returns the value of `key` in the mapping, or `None` if the key is not set.
+pub fn mapping_value(
+    client: &dyn Client,
+    program_id: &str,
+    mapping: &str,
+    key: &str,
+) -> Result<Option<Plaintext<CurrentNetwork>>> {
+    // The key is a path segment, and a key like `{ x: 1u8 }` has characters that must be escaped.
+    let path = format!("program/{program_id}/mapping/{mapping}/{}", utf8_percent_encode(key, PATH_SEGMENT));
+    match client.get(&path)? {
+        None => Err(CliError::mapping_not_found(mapping, program_id).into()),
+        Some(JsonValue::Null) => Ok(None),
+        Some(json) => plaintext(&path, json).map(Some),
+    }
+}
+
+/// Returns the transaction, or `None` if it is not on the network.
+pub fn transaction(client: &dyn Client, id: &str) -> Result<Option<JsonValue>> {
+    client.get(&format!("transaction/{id}"))
+}
+
+/// Returns the hash of the block that confirmed the transaction, or `None` if it is not confirmed.
+pub fn confirming_block(client: &dyn Client, id: &str) -> Result<Option<String>> {
+    let path = format!("find/blockHash/{id}");
+    match client.get(&path)? {
+        None | Some(JsonValue::Null) => Ok(None),
+        Some(json) => string(&path, json).map(Some),
+    }
+}
+
+/// Formats the value in Leo syntax, with one member per line.
+/// The structs of type `type_` are named after their definitions in `structs`, and so are their members.
+pub fn format_plaintext(plaintext: &Plaintext<CurrentNetwork>, type_: Option<&str>, structs: &[AbiStruct]) -> String {
+    let mut output = String::new();
+    write_plaintext(&mut output, plaintext, type_, structs, 0);
+    output
+}
+
+fn write_plaintext(
+    output: &mut String,
+    plaintext: &Plaintext<CurrentNetwork>,
+    type_: Option<&str>,
+    structs: &[AbiStruct],
+    indent: usize,
+) {
+    match plaintext {
+        Plaintext::Literal(literal, _) => output.push_str(&literal.to_string()),
+        Plaintext::Struct(members, _) => {
+            let definition = type_.and_then(|type_| structs.iter().find(|struct_| struct_.name == type_));
+            if let Some(definition) = definition {
+                write!(output, "{} ", definition.name).unwrap();
+            }
+            output.push_str("{\n");
+            for (name, member) in members {
+                let name = name.to_string();
+                let member_type = definition
+                    .and_then(|definition| definition.fields.iter().find(|field| field.name == name))
+                    .map(|field| field.type_.as_str());
+                write!(output, "{:indent$}{name}: ", "", indent = (indent + 1) * 4).unwrap();
+                write_plaintext(output, member, member_type, structs, indent + 1);
+                output.push_str(",\n");
+            }
+            write!(output, "{:indent$}}}", "", indent = indent * 4).unwrap();
+        }
+        Plaintext::Array(elements, _) => {
+            // The type of an array is written as `[T; N]`.
+            let element_type = type_
+                .and_then(|type_| type_.strip_prefix('[')?.rsplit_once(';'))
+                .map(|(element_type, _)| element_type.trim());
+            output.push('[');
+            for (i, element) in elements.iter().enumerate() {
+                if i > 0 {
+                    output.push_str(", ");
+                }
+                write_plaintext(output, element, element_type, structs, indent);
+            }
+            output.push(']');
+        }
+    }
+}
+
+/// Decodes a JSON string.
+fn string(path: &str, json: JsonValue) -> Result<String> {
+    match json {
+        JsonValue::String(string) => Ok(string),
+        json => Err(CliError::failed_to_query(path, format!("expected a string, found `{json}`")).into()),
+    }
+}
+
+/// Decodes a JSON string holding a plaintext value, e.g. `"5u64"`.
+fn plaintext(path: &str, json: JsonValue) -> Result<Plaintext<CurrentNetwork>> {
+    let string = string(path, json)?;
+    Ok(Plaintext::from_str(&string).map_err(|err| CliError::failed_to_query(path, err))?)
+}
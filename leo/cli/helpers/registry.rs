@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The registries that the dependencies of a package are fetched from.
+
+use leo_errors::{CliError, PackageError, Result};
+use leo_package::{
+    dependencies::{DirectoryRegistry, FetchedPackage, Registry, MANIFEST_FILENAME},
+    source::MainFile,
+};
+
+use reqwest::StatusCode;
+
+/// The directory of the default registry, relative to the home directory.
+const DEFAULT_REGISTRY: &str = ".aleo/registry";
+
+/// A registry served over HTTP, laid out like a [`DirectoryRegistry`],
+/// i.e. the package `name` at version `version` is served at `{endpoint}/{name}/{version}/`.
+pub struct HttpRegistry {
+    endpoint: String,
+    /// Whether fetching is disabled, in which case only the locked dependencies in the imports directory are used.
+    offline: bool,
+}
+
+impl Registry for HttpRegistry {
+    fn fetch(&self, name: &str, version: &str) -> Result<FetchedPackage> {
+        if self.offline {
+            return Err(CliError::offline_registry(&self.endpoint).into());
+        }
+
+        let fetch_file = |file: &str| -> Result<String> {
+            let url = format!("{}/{name}/{version}/{file}", self.endpoint.trim_end_matches('/'));
+            let response =
+                reqwest::blocking::get(&url).map_err(|err| PackageError::failed_to_fetch_dependency(name, err))?;
+            match response.status() {
+                StatusCode::NOT_FOUND => Err(PackageError::dependency_not_found(name, format!("`{url}`")).into()),
+                status if !status.is_success() => {
+                    Err(PackageError::failed_to_fetch_dependency(name, format!("`{url}` responded with {status}"))
+                        .into())
+                }
+                _ => Ok(response.text().map_err(|err| PackageError::failed_to_fetch_dependency(name, err))?),
+            }
+        };
+
+        Ok(FetchedPackage { manifest: fetch_file(MANIFEST_FILENAME)?, program: fetch_file(&MainFile::filename())? })
+    }
+}
+
+/// Opens the registry at `registry`, which is either a URL or a directory.
+/// Defaults to the directory `~/.aleo/registry`.
+pub fn open_registry(registry: Option<&str>, offline: bool) -> Box<dyn Registry> {
+    match registry {
+        Some(endpoint) if endpoint.starts_with("http://") || endpoint.starts_with("https://") => {
+            Box::new(HttpRegistry { endpoint: endpoint.to_string(), offline })
+        }
+        Some(directory) => Box::new(DirectoryRegistry::new(directory)),
+        None => Box::new(DirectoryRegistry::new(dirs::home_dir().unwrap_or_default().join(DEFAULT_REGISTRY))),
+    }
+}
@@ -0,0 +1,197 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The templates that `leo new --template` and `leo example` create packages from.
+//!
+//! A template is a directory of files, in which `{{program_name}}` is replaced with the name of the new program
+//! and `{{network}}` with its network. A template that is itself a package, like the bundled examples,
+//! also has the name of its own program replaced.
+
+use super::*;
+use leo_errors::{CliError, Result};
+
+use snarkvm::file::Manifest;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// The placeholder for the name of the program, e.g. in `program {{program_name}}.aleo`.
+pub const PROGRAM_NAME_PLACEHOLDER: &str = "{{program_name}}";
+
+/// The placeholder for the network of the program.
+pub const NETWORK_PLACEHOLDER: &str = "{{network}}";
+
+/// The files and directories of a template directory that are not part of the template.
+const IGNORED_FILES: &[&str] = &[".git", ".env", "build", "outputs"];
+
+/// An example package bundled with Leo.
+pub struct BundledExample {
+    /// The name of the example, which is also the name of its program.
+    pub name: &'static str,
+    /// A short description of the example.
+    pub about: &'static str,
+    /// The files of the package, by their path relative to the package.
+    files: &'static [(&'static str, &'static [u8])],
+}
+
+// The examples bundled with Leo, which are available as templates by name, see `build.rs`.
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+
+/// The files of a template.
+pub struct Template {
+    /// The files, by their path relative to the package.
+    files: Vec<(PathBuf, Vec<u8>)>,
+}
+
+impl Template {
+    /// Loads the template `template`, which is the name of a bundled example, a directory or a git repository.
+    pub fn load(template: &str) -> Result<Self> {
+        if let Some(example) = EXAMPLES.iter().find(|example| example.name == template) {
+            let files = example.files.iter().map(|(path, contents)| (PathBuf::from(path), contents.to_vec())).collect();
+            return Ok(Self { files }.with_placeholders(example.name));
+        }
+
+        let directory = Path::new(template);
+        if directory.is_dir() {
+            Self::from_directory(directory)
+        } else if is_git_url(template) {
+            Self::from_git(template)
+        } else {
+            Err(CliError::unknown_template(template).into())
+        }
+    }
+
+    /// Writes the files of the template into the package directory, replacing the placeholders.
+    pub fn instantiate(&self, package_directory: &Path, program_name: &str, network: &str) -> Result<()> {
+        let replace =
+            |text: &str| text.replace(PROGRAM_NAME_PLACEHOLDER, program_name).replace(NETWORK_PLACEHOLDER, network);
+        for (path, contents) in &self.files {
+            let path = package_directory.join(replace(&path.to_string_lossy()));
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).map_err(CliError::failed_to_write_file)?;
+            }
+            // Binary files, e.g. images, are copied as they are.
+            let contents = match std::str::from_utf8(contents) {
+                Ok(text) => replace(text).into_bytes(),
+                Err(_) => contents.clone(),
+            };
+            fs::write(path, contents).map_err(CliError::failed_to_write_file)?;
+        }
+        Ok(())
+    }
+
+    /// Loads the files of a directory, recursively.
+    fn from_directory(directory: &Path) -> Result<Self> {
+        let mut files = Vec::new();
+        collect_files(directory, Path::new(""), &mut files)
+            .map_err(|err| CliError::failed_to_load_template(directory.display(), err))?;
+        let template = Self { files };
+
+        // A package is a template for packages with other names.
+        match Manifest::<CurrentNetwork>::open(directory) {
+            Ok(manifest) => Ok(template.with_placeholders(&manifest.program_id().name().to_string())),
+            Err(_) => Ok(template),
+        }
+    }
+
+    /// Clones a git repository and loads its files.
+    fn from_git(url: &str) -> Result<Self> {
+        let directory = std::env::temp_dir().join(format!("leo-template-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        let status = Command::new("git")
+            .args(["clone", "--quiet", "--depth", "1", url])
+            .arg(&directory)
+            .status()
+            .map_err(|err| CliError::failed_to_load_template(url, err))?;
+        if !status.success() {
+            return Err(CliError::failed_to_load_template(url, format!("`git clone` exited with {status}")).into());
+        }
+
+        let template = Self::from_directory(&directory);
+        let _ = fs::remove_dir_all(&directory);
+        template
+    }
+
+    /// Replaces the name of the program of a package with the placeholder,
+    /// in the program IDs of its files and in the names of its files, e.g. `inputs/name.in`.
+    fn with_placeholders(self, program_name: &str) -> Self {
+        let program_id = format!("{program_name}.aleo");
+        let files = self
+            .files
+            .into_iter()
+            .map(|(path, contents)| {
+                let path = match path.file_stem().and_then(|stem| stem.to_str()) {
+                    Some(stem) if stem == program_name => {
+                        let extension = path.extension().map(|extension| format!(".{}", extension.to_string_lossy()));
+                        path.with_file_name(format!("{PROGRAM_NAME_PLACEHOLDER}{}", extension.unwrap_or_default()))
+                    }
+                    _ => path,
+                };
+                let contents = match String::from_utf8(contents) {
+                    Ok(text) => replace_program_id(&text, &program_id).into_bytes(),
+                    Err(err) => err.into_bytes(),
+                };
+                (path, contents)
+            })
+            .collect();
+        Self { files }
+    }
+}
+
+/// Collects the files of `directory` into `files`, by their path relative to the template.
+fn collect_files(directory: &Path, relative: &Path, files: &mut Vec<(PathBuf, Vec<u8>)>) -> std::io::Result<()> {
+    let mut entries = fs::read_dir(directory)?.collect::<std::io::Result<Vec<_>>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+    for entry in entries {
+        let name = entry.file_name();
+        if IGNORED_FILES.iter().any(|ignored| name == *ignored) {
+            continue;
+        }
+        let relative = relative.join(&name);
+        if entry.file_type()?.is_dir() {
+            collect_files(&entry.path(), &relative, files)?;
+        } else {
+            files.push((relative, fs::read(entry.path())?));
+        }
+    }
+    Ok(())
+}
+
+/// Replaces the program ID in `text` with the placeholder, unless it is the suffix of another program ID.
+fn replace_program_id(text: &str, program_id: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(index) = rest.find(program_id) {
+        let is_suffix = rest[..index].chars().next_back().map_or(false, |c| c.is_alphanumeric() || c == '_');
+        output.push_str(&rest[..index]);
+        match is_suffix {
+            true => output.push_str(program_id),
+            false => output.push_str(&format!("{PROGRAM_NAME_PLACEHOLDER}.aleo")),
+        }
+        rest = &rest[index + program_id.len()..];
+    }
+    output.push_str(rest);
+    output
+}
+
+/// Returns `true` if the template is the URL of a git repository.
+fn is_git_url(template: &str) -> bool {
+    ["https://", "http://", "ssh://", "file://", "git@"].iter().any(|scheme| template.starts_with(scheme))
+        || template.ends_with(".git")
+}
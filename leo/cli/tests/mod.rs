@@ -14,8 +14,46 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::cli::{
+    helpers::context::{Context, MessageFormat},
+    CurrentNetwork,
+};
 use leo_errors::Result;
-// use std::path::PathBuf;
+use leo_package::package::Package as LeoPackage;
+use snarkvm::{package::Package, prelude::ProgramID};
+use std::{path::PathBuf, str::FromStr};
+use test_dir::{DirBuilder, TestDir};
+
+/// A package in a temporary directory, which is removed with the package even if the test fails.
+struct TestPackage {
+    /// The directory of the package.
+    directory: PathBuf,
+    _temp: TestDir,
+}
+
+impl TestPackage {
+    /// Creates an Aleo package of the program `{name}.aleo`.
+    fn aleo(name: &str) -> Self {
+        let temp = TestDir::temp();
+        let directory = temp.root().join(name);
+        let program_id = ProgramID::<CurrentNetwork>::from_str(&format!("{name}.aleo")).unwrap();
+        Package::create(&directory, &program_id).unwrap();
+        Self { directory, _temp: temp }
+    }
+
+    /// Creates a Leo package of the program `{name}.aleo`, whose main file is `main`.
+    fn leo(name: &str, main: &str) -> Result<Self> {
+        let package = Self::aleo(name);
+        LeoPackage::<CurrentNetwork>::initialize(name, &package.directory)?;
+        std::fs::write(package.directory.join("src/main.leo"), main).unwrap();
+        Ok(package)
+    }
+
+    /// Returns the context of the commands that run in the package.
+    fn context(&self) -> Result<Context> {
+        Context::new(Some(self.directory.clone()))
+    }
+}
 
 /* use crate::{
     commands::{
@@ -33,17 +71,767 @@ use leo_errors::Result;
 
 #[test]
 pub fn init_logger() -> Result<()> {
-    crate::cli::helpers::logger::init_logger("test_init_logger", 1)?;
+    crate::cli::helpers::logger::init_logger("test_init_logger", 1, MessageFormat::Human)?;
     Ok(())
 }
 
 #[test]
 pub fn format_event() -> Result<()> {
-    crate::cli::helpers::logger::init_logger("test_format_event", 1)?;
+    crate::cli::helpers::logger::init_logger("test_format_event", 1, MessageFormat::Human)?;
     tracing::info!("test");
     Ok(())
 }
 
+#[test]
+pub fn parse_arguments() {
+    use clap::CommandFactory;
+
+    // Each command is flattened into a variant of the same name, so the commands skip their argument groups.
+    crate::cli::CLI::command().debug_assert();
+}
+
+#[test]
+pub fn deploy_to_devnet() -> Result<()> {
+    use crate::cli::{commands::deploy::broadcast, helpers::devnet::Devnet};
+    use std::{
+        io::{Read, Write},
+        net::{TcpListener, TcpStream},
+    };
+
+    // Create an Aleo package to deploy. Its circuit is small enough for the proving parameters bundled with snarkVM.
+    let package = TestPackage::aleo("devnet_test");
+    std::fs::write(
+        package.directory.join("main.aleo"),
+        "program devnet_test.aleo;\n\nfunction check:\n    input r0 as u8.public;\n    assert.eq r0 r0;\n",
+    )
+    .unwrap();
+    let program_id = ProgramID::<CurrentNetwork>::from_str("devnet_test.aleo").unwrap();
+
+    // Start a local development node on a free port.
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let endpoint = format!("http://{address}");
+    std::thread::spawn(move || Devnet::load()?.serve(listener));
+
+    // The program can be fetched from the node once it is deployed, but not deployed twice.
+    assert_eq!(broadcast(&package.directory, &endpoint)?, program_id);
+    let program = reqwest::blocking::get(format!("{endpoint}/testnet3/program/{program_id}")).unwrap();
+    assert!(program.status().is_success());
+    assert!(broadcast(&package.directory, &endpoint).is_err());
+
+    // The node does not execute transactions, so it has no mapping values or transactions to serve.
+    for path in [format!("program/{program_id}/mapping/counts/0u8"), "transaction/at1cached".to_string()] {
+        let response = reqwest::blocking::get(format!("{endpoint}/testnet3/{path}")).unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND, "{path}");
+    }
+
+    // A request whose body is larger than any deployment is refused before the body is read.
+    let mut stream = TcpStream::connect(address).unwrap();
+    write!(stream, "POST /testnet3/deploy HTTP/1.1\r\nContent-Length: {}\r\n\r\n", u64::MAX).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    assert!(response.starts_with("HTTP/1.1 413"), "{response}");
+
+    Ok(())
+}
+
+#[test]
+pub fn build_package_with_modules() -> Result<()> {
+    use crate::cli::commands::{Build, Command};
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    let package = TestPackage::leo(
+        "modules",
+        "use math;
+
+program modules.aleo {
+    transition main(a: u32) -> u32 {
+        return triple(a);
+    }
+}
+",
+    )?;
+    let directory = &package.directory;
+    std::fs::create_dir_all(directory.join("src/math")).unwrap();
+    std::fs::write(
+        directory.join("src/math.leo"),
+        "use math::constants;
+
+inline triple(a: u32) -> u32 {
+    return a * THREE;
+}
+",
+    )
+    .unwrap();
+    std::fs::write(directory.join("src/math/constants.leo"), "const THREE: u32 = 3u32;\n").unwrap();
+    let build = || {
+        let build = Build { options: Default::default() };
+        create_session_if_not_set_then(|_| build.execute(package.context()?))
+    };
+
+    // The modules that the program uses, directly or through another module, are compiled with it.
+    build()?;
+    assert!(std::fs::read_to_string(directory.join("build/main.aleo")).unwrap().contains("mul r0 3u32"));
+
+    // A source file that the program does not use is reported, rather than silently left out of the build.
+    std::fs::write(directory.join("src/unused.leo"), "const UNUSED: u8 = 0u8;\n").unwrap();
+    let error = build().unwrap_err();
+    assert!(error.to_string().contains("unused.leo"), "{error}");
+
+    Ok(())
+}
+
+#[test]
+pub fn run_package_tests() -> Result<()> {
+    use crate::cli::commands::{Command, Test};
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    // Create a Leo package with a passing and a failing test.
+    let package = TestPackage::leo(
+        "test_runner",
+        "program test_runner.aleo {
+    function twice(a: u8) -> u8 {
+        assert(a < 100u8);
+        return a + a;
+    }
+
+    transition main(a: u8) -> u8 {
+        return twice(a);
+    }
+
+    @test
+    transition test_small() {
+        assert_eq(twice(2u8), 4u8);
+    }
+
+    @test
+    transition test_large() {
+        assert_eq(twice(100u8), 200u8);
+    }
+}
+",
+    )?;
+
+    // Tests can be selected by name, and the package fails if any of its tests fails.
+    let run = |filter: Option<&str>| {
+        let test = Test { filter: filter.map(String::from), compiler_options: Default::default() };
+        create_session_if_not_set_then(|_| test.execute(package.context()?))
+    };
+    run(Some("small")).unwrap();
+    assert!(run(Some("large")).is_err());
+    assert!(run(None).is_err());
+
+    Ok(())
+}
+
+#[test]
+pub fn interpret_package() -> Result<()> {
+    use crate::cli::commands::{Command, Eval};
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    // Create a Leo package whose transition fails on some of its inputs.
+    let package = TestPackage::leo(
+        "eval_runner",
+        "program eval_runner.aleo {
+    transition main(a: u8, b: u8) -> u8 {
+        assert(a < 100u8);
+        return a + b;
+    }
+}
+",
+    )?;
+    let directory = &package.directory;
+
+    // The transition is interpreted without building the package.
+    let eval = |inputs: &[&str]| {
+        let eval = Eval {
+            name: "main".to_string(),
+            inputs: inputs.iter().map(|input| input.to_string()).collect(),
+            compiler_options: Default::default(),
+        };
+        create_session_if_not_set_then(|_| eval.execute(package.context()?))
+    };
+    eval(&["1u8", "2u8"]).unwrap();
+    assert!(eval(&["100u8", "2u8"]).is_err());
+    assert!(eval(&["1u8"]).is_err());
+    assert!(!directory.join("build").join("main.aleo").exists());
+
+    Ok(())
+}
+
+#[test]
+pub fn locate_run_failures() -> Result<()> {
+    use crate::cli::{
+        commands::{Build, BuildOptions, Command},
+        helpers::debug_info::locate_failure,
+    };
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    // Create a Leo package whose transition fails on some of its inputs, in a function that it calls.
+    let package = TestPackage::leo(
+        "debug_info",
+        "program debug_info.aleo {
+    function check(a: u8) -> u8 {
+        assert(a < 100u8);
+        return a;
+    }
+
+    transition main(a: u8, b: u8) -> u8 {
+        let c: u8 = check(a);
+        return c + b;
+    }
+}
+",
+    )?;
+    let directory = &package.directory;
+
+    create_session_if_not_set_then(|_| {
+        // The instructions are only mapped back to the source on request.
+        let build = |enable_debug_info| {
+            let options = BuildOptions { enable_debug_info, ..Default::default() };
+            (Build { options }).execute(package.context()?)
+        };
+        build(false)?;
+        assert!(!directory.join("build/main.aleo.map").exists());
+        build(true)?;
+        assert!(directory.join("build/main.aleo.map").exists());
+
+        // A failure that names its instruction is located directly, and one that does not is reproduced to locate it.
+        let locate = |inputs: &[&str], error: &str| {
+            let inputs: Vec<String> = inputs.iter().map(|input| input.to_string()).collect();
+            locate_failure(directory, "main", &inputs, error).map(|error| error.to_string())
+        };
+        let unsatisfied = "'debug_info.aleo/main' is not satisfied on the given inputs (1000 constraints).";
+        let failure = locate(&["100u8", "1u8"], unsatisfied).unwrap();
+        assert!(
+            failure.contains("The execution of `main` failed") && failure.contains("assert(a < 100u8);"),
+            "{failure}"
+        );
+        let failure = locate(&[], "Failed to evaluate instruction (add r2 r1 into r3;): Integer overflow").unwrap();
+        assert!(failure.contains("Integer overflow") && failure.contains("return c + b;"), "{failure}");
+
+        // A failure that cannot be reproduced, or traced to its instruction, is left as it is.
+        assert!(locate(&["1u8", "1u8"], unsatisfied).is_none());
+        assert!(locate(&["99u8", "200u8"], unsatisfied).is_none());
+
+        // The failure is reproduced with the account of the package, which must have one.
+        let env = std::fs::read_to_string(directory.join(".env")).unwrap();
+        std::fs::remove_file(directory.join(".env")).unwrap();
+        let failure = locate(&["100u8", "1u8"], unsatisfied).unwrap();
+        assert!(failure.contains("env file"), "{failure}");
+        std::fs::write(directory.join(".env"), env).unwrap();
+
+        // The debug information of another build of the program is not used.
+        let program = directory.join("build/main.aleo");
+        std::fs::write(&program, std::fs::read_to_string(&program).unwrap() + "\n").unwrap();
+        assert!(locate(&["100u8", "1u8"], unsatisfied).is_none());
+        Ok::<_, leo_errors::LeoError>(())
+    })?;
+
+    Ok(())
+}
+
+#[test]
+pub fn export_program_abi() -> Result<()> {
+    use crate::cli::commands::{Build, Command};
+    use leo_compiler::{Abi, AbiField, AbiMapping, AbiMode, AbiParameter};
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    let package = TestPackage::leo(
+        "abi",
+        "program abi.aleo {
+    mapping balances: address => u64;
+
+    struct Point {
+        x: i8,
+        y: i8,
+    }
+
+    record token {
+        owner: address,
+        amount: u64,
+    }
+
+    struct Pair<T> {
+        first: T,
+        second: T,
+    }
+
+    enum Bonus {
+        None,
+        Double,
+    }
+
+    function twice(a: u64) -> u64 {
+        return a + a;
+    }
+
+    function apply(bonus: Bonus, pair: Pair<u64>) -> u64 {
+        match bonus {
+            Bonus::None => {
+                return pair.first;
+            }
+            Bonus::Double => {
+                return pair.first + pair.second;
+            }
+        }
+    }
+
+    transition mint(public receiver: address, private amount: u64) -> (token, Point) {
+        let pair: Pair<u64> = Pair { first: amount, second: amount };
+        return (token { owner: receiver, amount: apply(Bonus::Double, pair) }, Point { x: 0i8, y: 0i8 });
+    }
+
+    transition deposit(t: token, public amount: u64) -> public u64 {
+        return t.amount + amount then finalize(t.owner, amount);
+    }
+
+    finalize deposit(public owner: address, public amount: u64) {
+        let balance: u64 = Mapping::get_or_use(balances, owner, 0u64);
+        Mapping::set(balances, owner, balance + amount);
+    }
+}
+",
+    )?;
+    let directory = &package.directory;
+
+    create_session_if_not_set_then(|_| (Build { options: Default::default() }).execute(package.context()?))?;
+    let abi: Abi = serde_json::from_str(&std::fs::read_to_string(directory.join("build/abi.json")).unwrap()).unwrap();
+
+    let field = |name: &str, mode, type_: &str| AbiField { name: name.to_string(), mode, type_: type_.to_string() };
+    let parameter = |name: Option<&str>, mode, type_: &str| AbiParameter {
+        name: name.map(str::to_string),
+        mode,
+        type_: type_.to_string(),
+    };
+    assert_eq!(abi.program, "abi.aleo");
+    // The interface is that of the program as written, without the copies of the generic structs or the lowered enums.
+    assert_eq!(abi.structs.len(), 1);
+    assert_eq!(abi.structs[0].name, "Point");
+    assert_eq!(abi.structs[0].fields, vec![field("x", None, "i8"), field("y", None, "i8")]);
+    assert_eq!(abi.records.len(), 1);
+    assert_eq!(abi.records[0].name, "token");
+    assert_eq!(abi.records[0].fields, vec![
+        field("owner", Some(AbiMode::Private), "address"),
+        field("amount", Some(AbiMode::Private), "u64")
+    ]);
+    assert_eq!(abi.mappings, vec![AbiMapping {
+        name: "balances".to_string(),
+        key: "address".to_string(),
+        value: "u64".to_string()
+    }]);
+
+    // Only the transitions are part of the interface.
+    assert_eq!(abi.transitions.len(), 2);
+    let (mint, deposit) = (&abi.transitions[0], &abi.transitions[1]);
+    assert_eq!(mint.name, "mint");
+    assert_eq!(mint.inputs, vec![
+        parameter(Some("receiver"), AbiMode::Public, "address"),
+        parameter(Some("amount"), AbiMode::Private, "u64")
+    ]);
+    assert_eq!(mint.outputs, vec![
+        parameter(None, AbiMode::Private, "token"),
+        parameter(None, AbiMode::Private, "Point")
+    ]);
+    assert!(!mint.has_finalize);
+    assert_eq!(deposit.name, "deposit");
+    assert_eq!(deposit.inputs, vec![
+        parameter(Some("t"), AbiMode::Private, "token"),
+        parameter(Some("amount"), AbiMode::Public, "u64")
+    ]);
+    assert_eq!(deposit.outputs, vec![parameter(None, AbiMode::Public, "u64")]);
+    assert!(deposit.has_finalize);
+
+    Ok(())
+}
+
+#[test]
+pub fn generate_client_bindings() -> Result<()> {
+    use crate::cli::commands::{bindings::BindingsLanguage, Bindings, Command};
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    let package = TestPackage::leo(
+        "bindings",
+        "program bindings.aleo {
+    struct Point {
+        x: i8,
+        y: i8,
+    }
+
+    record token_box {
+        owner: address,
+        amount: u64,
+        corners: [Point; 2],
+    }
+
+    transition mint(public receiver: address, amount: u64, corner: Point) -> token_box {
+        return token_box { owner: receiver, amount: amount, corners: [corner, corner] };
+    }
+
+    transition split(t: token_box, flags: [bool; 2]) -> (token_box, public u64) {
+        return (t, t.amount);
+    }
+}
+",
+    )?;
+    let directory = &package.directory;
+
+    let generate = |lang| {
+        let bindings = Bindings { lang, output: None, compiler_options: Default::default() };
+        let output = create_session_if_not_set_then(|_| bindings.execute(package.context()?))?;
+        Ok::<_, leo_errors::LeoError>(std::fs::read_to_string(output).unwrap())
+    };
+
+    // The TypeScript bindings write the inputs in the format of the input file.
+    let typescript = generate(BindingsLanguage::Typescript)?;
+    assert!(directory.join("build/bindings/bindings.ts").exists());
+    for expected in [
+        "export interface TokenBox {\n  owner: string;\n  amount: bigint;\n  corners: Point[];\n  _nonce: string;\n}",
+        "export function formatPoint(value: Point, visibility = \"\"): string {\n  return `{x: ${value.x}i8${visibility}, y: \
+         ${value.y}i8${visibility}}`;\n}",
+        "return `{owner: ${value.owner}.private, amount: ${value.amount}u64.private, corners: \
+         [${value.corners.map((element) => formatPoint(element, \".private\")).join(\", \")}], _nonce: ${value._nonce}.public}`;",
+        "export function mintInputs(receiver: string, amount: bigint, corner: Point): string[] {\n  return [receiver, \
+         `${amount}u64`, formatPoint(corner)];\n}",
+        "Its outputs are a private `token_box`, a public `u64`.",
+    ] {
+        assert!(typescript.contains(expected), "{expected}\n{typescript}");
+    }
+
+    // The Rust bindings are compared as text with the expected ones in `bindings.rs.out`.
+    let rust = generate(BindingsLanguage::Rust)?;
+    assert!(directory.join("build/bindings/bindings.rs").exists());
+    let expected = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("leo/cli/tests/bindings.rs.out");
+    if std::env::var("CLEAR_LEO_TEST_EXPECTATIONS").is_ok() {
+        std::fs::write(&expected, &rust).unwrap();
+    }
+    assert_eq!(rust, std::fs::read_to_string(&expected).unwrap(), "the bindings differ from {}", expected.display());
+
+    // The TypeScript bindings write values that snarkVM reads.
+    use snarkvm::prelude::{Plaintext, Record, Value};
+
+    // Fills in the template literal that a formatting function of the TypeScript bindings returns.
+    let template = |function: &str| {
+        let body = &typescript[typescript.find(&format!("export function {function}(")).unwrap()..];
+        let body = &body[body.find("return `").unwrap() + "return `".len()..];
+        body[..body.find("`;\n").unwrap()].to_string()
+    };
+    let format_point = |x: i8, y: i8, visibility: &str| {
+        template("formatPoint")
+            .replace("${value.x}", &x.to_string())
+            .replace("${value.y}", &y.to_string())
+            .replace("${visibility}", visibility)
+    };
+
+    let point = Value::<CurrentNetwork>::from_str(&format_point(1, -2, "")).unwrap();
+    assert_eq!(point.to_string(), Value::<CurrentNetwork>::from_str("{x: 1i8, y: -2i8}").unwrap().to_string());
+
+    let corners = [format_point(1, -2, ".private"), format_point(3, 4, ".private")].join(", ");
+    let record = template("formatTokenBox")
+        .replace("${value.owner}", "aleo1t0uer3jgtsgmx5tq6x6f9ecu8tr57rzzfnc2dgmcqldceal0ls9qf6st7a")
+        .replace("${value.amount}", "5")
+        .replace("${value.corners.map((element) => formatPoint(element, \".private\")).join(\", \")}", &corners)
+        .replace("${value._nonce}", "0group");
+    let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&record).unwrap();
+    assert_eq!(record.owner().to_string(), "aleo1t0uer3jgtsgmx5tq6x6f9ecu8tr57rzzfnc2dgmcqldceal0ls9qf6st7a.private");
+
+    Ok(())
+}
+
+#[test]
+pub fn language_server() -> Result<()> {
+    use crate::cli::commands::lsp::Server;
+    use serde_json::{json, Value};
+    use std::io::BufRead;
+
+    // A package whose program imports another, which is found in the package rather than in the current directory.
+    let package = TestPackage::aleo("lsp_test");
+    let directory = &package.directory;
+    std::fs::create_dir_all(directory.join("imports")).unwrap();
+    std::fs::write(
+        directory.join("imports/other.leo"),
+        "program other.aleo {\n    transition noop(a: u8) -> u8 {\n        return a;\n    }\n}\n",
+    )
+    .unwrap();
+    let uri = format!("file://{}", directory.join("src/main.leo").display());
+    let text = "import other.leo;
+
+program lsp_test.aleo {
+    struct Point {
+        x: u8,
+        y: u8,
+    }
+
+    function sum(p: Point) -> u8 {
+        return p.x + p.y;
+    }
+
+    transition main(a: u8) -> u8 {
+        let p: Point = Point { x: a, y: a };
+        return sum(p);
+    }
+}
+";
+
+    // The messages of a client, framed as they are sent over stdio.
+    let position = |line: u32, character: u32| {
+        let position = json!({ "line": line, "character": character });
+        json!({ "textDocument": { "uri": uri }, "position": position })
+    };
+    let messages = [
+        json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {} }),
+        json!({ "jsonrpc": "2.0", "method": "textDocument/didOpen", "params": {
+            "textDocument": { "uri": uri, "languageId": "leo", "version": 1, "text": text }
+        } }),
+        json!({ "jsonrpc": "2.0", "id": 2, "method": "textDocument/hover", "params": position(13, 16) }),
+        json!({ "jsonrpc": "2.0", "id": 3, "method": "textDocument/definition", "params": position(14, 16) }),
+        json!({ "jsonrpc": "2.0", "method": "textDocument/didChange", "params": {
+            "textDocument": { "uri": uri, "version": 2 },
+            "contentChanges": [{ "text": text.replace("return sum(p);", "return sum(p) + true;") }]
+        } }),
+        json!({ "jsonrpc": "2.0", "id": 4, "method": "shutdown" }),
+        json!({ "jsonrpc": "2.0", "method": "exit" }),
+    ];
+    let mut input = Vec::new();
+    for message in messages {
+        let content = message.to_string();
+        input.extend(format!("Content-Length: {}\r\n\r\n{content}", content.len()).into_bytes());
+    }
+    let mut output = Vec::new();
+    Server::default().run(&mut input.as_slice(), &mut output)?;
+
+    // Read back the replies of the server.
+    let mut reader = output.as_slice();
+    let mut replies = Vec::new();
+    let mut header = String::new();
+    while reader.read_line(&mut header).unwrap() > 0 {
+        let length: usize = header.trim().strip_prefix("Content-Length: ").unwrap().parse().unwrap();
+        reader.read_line(&mut String::new()).unwrap();
+        let (content, rest) = reader.split_at(length);
+        replies.push(serde_json::from_slice::<Value>(content).unwrap());
+        reader = rest;
+        header.clear();
+    }
+    let [initialize, opened, hover, definition, changed, shutdown] = replies.as_slice() else {
+        panic!("unexpected replies: {replies:?}");
+    };
+    assert_eq!(initialize["result"]["capabilities"]["hoverProvider"], true);
+    assert_eq!(shutdown["id"], 4);
+
+    // The document is checked when it is opened, along with its import, and whenever it changes.
+    assert_eq!(opened["method"], "textDocument/publishDiagnostics");
+    assert_eq!(opened["params"]["diagnostics"], json!([]), "{opened}");
+    let diagnostics = changed["params"]["diagnostics"].as_array().unwrap();
+    assert!(!diagnostics.is_empty());
+    assert_eq!(diagnostics[0]["severity"], 1);
+    assert_eq!(diagnostics[0]["range"]["start"]["line"], 14);
+
+    // Hovering over a struct shows its declaration, and the definition of a function is where it is declared.
+    let contents = hover["result"]["contents"]["value"].as_str().unwrap();
+    assert!(contents.contains("struct Point { x: u8, y: u8 }"), "{contents}");
+    assert_eq!(definition["result"]["uri"], uri);
+    assert_eq!(definition["result"]["range"]["start"]["line"], 8);
+    Ok(())
+}
+
+#[test]
+pub fn sign_verify_and_decrypt() -> Result<()> {
+    use crate::cli::commands::{
+        account::{decrypt_record, sign_message, verify_message},
+        Account,
+        Command,
+    };
+    use leo_package::root::Env;
+    use snarkvm::prelude::{Address, Network, Plaintext, PrivateKey, Record, Scalar, TestRng, Uniform};
+
+    let temp = TestDir::temp();
+    let directory = temp.root();
+    let rng = &mut TestRng::default();
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let address = Address::try_from(&private_key).unwrap();
+    Env::<CurrentNetwork>::from(format!("NETWORK=testnet3\nPRIVATE_KEY={private_key}\n")).write_to(directory)?;
+    assert_eq!(Env::<CurrentNetwork>::private_key(directory)?, private_key);
+
+    // Messages are signed as Aleo values, as `signature::verify` checks them, or as raw bytes.
+    let signature = sign_message(&private_key, "{ amount: 5u64, flag: true }", false)?;
+    assert!(verify_message(&address, &signature, "{ amount: 5u64, flag: true }", false)?);
+    assert!(!verify_message(&address, &signature, "{ amount: 6u64, flag: true }", false)?);
+    assert!(sign_message(&private_key, "not a value", false).is_err());
+    let signature = sign_message(&private_key, "not a value", true)?;
+    assert!(verify_message(&address, &signature, "not a value", true)?);
+
+    let verify = |message: &str| {
+        Account::Verify { address, signature, message: message.to_string(), raw: true }
+            .execute(Context::new(Some(directory.to_path_buf()))?)
+    };
+    assert!(verify("not a value").is_ok());
+    assert!(verify("another value").is_err());
+
+    // Records owned by the account are decrypted with its view key.
+    let randomizer = Scalar::<CurrentNetwork>::rand(rng);
+    let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+    let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+        "{{ owner: {address}.private, amount: 5u64.private, _nonce: {nonce}.public }}"
+    ))
+    .unwrap();
+    let ciphertext = record.encrypt(randomizer).unwrap();
+    assert_eq!(decrypt_record(&private_key, &ciphertext)?, record);
+    let stranger = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    assert!(decrypt_record(&stranger, &ciphertext).is_err());
+
+    Ok(())
+}
+
+#[test]
+pub fn query_network_state() -> Result<()> {
+    use crate::cli::commands::{
+        query::{Query, QueryCommands},
+        Command,
+    };
+    use leo_compiler::{Abi, AbiField, AbiMapping, AbiStruct};
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+    };
+
+    // The package only needs its manifest and the interface that `leo build` writes.
+    let package = TestPackage::aleo("query_test");
+    let directory = &package.directory;
+    std::fs::create_dir_all(directory.join("build")).unwrap();
+    let field = |name: &str| AbiField { name: name.to_string(), mode: None, type_: "i8".to_string() };
+    let abi = Abi {
+        program: "query_test.aleo".to_string(),
+        structs: vec![AbiStruct { name: "Point".to_string(), fields: vec![field("x"), field("y")] }],
+        records: Vec::new(),
+        mappings: vec![AbiMapping { name: "points".to_string(), key: "u8".to_string(), value: "Point".to_string() }],
+        transitions: Vec::new(),
+    };
+    std::fs::write(directory.join("build/abi.json"), abi.to_json_string().unwrap()).unwrap();
+
+    // Serve the responses of a node from a local mock server.
+    let routes = [
+        ("/testnet3/program/query_test.aleo", r#""program query_test.aleo;""#),
+        ("/testnet3/program/query_test.aleo/mapping/points/1u8", r#""{ x: 1i8, y: -2i8 }""#),
+        ("/testnet3/program/query_test.aleo/mapping/points/2u8", "null"),
+        ("/testnet3/program/other.aleo/mapping/points/%7B%20x%3A%201i8%20%7D", r#""5u64""#),
+        ("/testnet3/transaction/at1query", r#"{"type":"execute","id":"at1query"}"#),
+        ("/testnet3/find/blockHash/at1query", r#""ab1block""#),
+    ];
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let endpoint = format!("http://{}", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            let mut lines = BufReader::new(stream.try_clone().unwrap()).lines().map_while(|line| line.ok());
+            let request = lines.next().unwrap_or_default();
+            lines.take_while(|line| !line.is_empty()).for_each(drop);
+            let path = request.split_whitespace().nth(1).unwrap_or_default().to_string();
+            let (status, body) = match routes.iter().find(|(route, _)| *route == path) {
+                Some((_, body)) => ("200 OK", *body),
+                None => ("404 Not Found", "\"Not found\""),
+            };
+            let _ = write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+        }
+    });
+
+    let query = |command| Query { endpoint: endpoint.clone(), command }.execute(package.context()?);
+    let mapping = |key: &str, program: Option<&str>| {
+        query(QueryCommands::Mapping {
+            name: "points".to_string(),
+            key: key.to_string(),
+            program: program.map(str::to_string),
+        })
+    };
+
+    // Values are printed with the structs of the package, and keys are escaped in the path.
+    assert_eq!(mapping("1u8", None)?, "Point {\n    x: 1i8,\n    y: -2i8,\n}");
+    assert!(mapping("2u8", None).is_err());
+    assert_eq!(mapping("{ x: 1i8 }", Some("other.aleo"))?, "5u64");
+    assert!(mapping("1u8", Some("other.aleo")).is_err());
+
+    assert_eq!(query(QueryCommands::Program { id: None })?, "program query_test.aleo;");
+    assert!(query(QueryCommands::Program { id: Some("other.aleo".to_string()) }).is_err());
+
+    let transaction = query(QueryCommands::Transaction { id: "at1query".to_string() })?;
+    assert!(transaction.contains(r#""id": "at1query""#));
+    assert!(query(QueryCommands::Transaction { id: "at1missing".to_string() }).is_err());
+
+    Ok(())
+}
+
+#[test]
+pub fn create_package_from_template() -> Result<()> {
+    use crate::cli::helpers::templates::{Template, EXAMPLES};
+    use std::fs;
+
+    let temp = TestDir::temp();
+    let directory = temp.root();
+    let read = |path: &str| fs::read_to_string(directory.join(path)).unwrap();
+
+    // Every bundled example is a template, in which the name of its program is replaced.
+    for example in EXAMPLES {
+        let name = format!("my_{}", example.name);
+        Template::load(example.name)?.instantiate(&directory.join(&name), &name, "testnet3")?;
+        assert!(read(&format!("{name}/src/main.leo")).contains(&format!("program {name}.aleo")), "{}", example.name);
+        assert!(directory.join(&name).join(format!("inputs/{name}.in")).exists(), "{}", example.name);
+    }
+    assert!(directory.join("my_battleship/imports/board.leo").exists());
+
+    // A directory with placeholders.
+    let template = directory.join("template");
+    fs::create_dir_all(template.join("src")).unwrap();
+    fs::create_dir_all(template.join("build")).unwrap();
+    fs::write(template.join("src/main.leo"), "program {{program_name}}.aleo {}\n").unwrap();
+    fs::write(template.join("README.md"), "# {{program_name}}\nDeploy to {{network}}.\n").unwrap();
+    fs::write(template.join("build/main.aleo"), "program template.aleo;\n").unwrap();
+    Template::load(template.to_str().unwrap())?.instantiate(
+        &directory.join("placeholders"),
+        "placeholders",
+        "testnet3",
+    )?;
+    assert_eq!(read("placeholders/src/main.leo"), "program placeholders.aleo {}\n");
+    assert_eq!(read("placeholders/README.md"), "# placeholders\nDeploy to testnet3.\n");
+    assert!(!directory.join("placeholders/build").exists());
+
+    // A package, whose program is renamed, but not the programs that only end with its name.
+    let package = directory.join("package");
+    Package::create(&package, &ProgramID::<CurrentNetwork>::from_str("token.aleo").unwrap()).unwrap();
+    fs::create_dir_all(package.join("src")).unwrap();
+    fs::write(package.join("src/main.leo"), "import simple_token.aleo;\nprogram token.aleo {}\n").unwrap();
+    Template::load(package.to_str().unwrap())?.instantiate(&directory.join("renamed"), "renamed", "testnet3")?;
+    assert_eq!(read("renamed/src/main.leo"), "import simple_token.aleo;\nprogram renamed.aleo {}\n");
+    assert!(read("renamed/program.json").contains("\"renamed.aleo\""));
+
+    // A git repository, which is cloned.
+    let git = |args: &[&str]| {
+        let status = std::process::Command::new("git")
+            .args(["-c", "user.name=leo", "-c", "user.email=leo@aleo.org"])
+            .args(args)
+            .current_dir(&template)
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {args:?}");
+    };
+    git(&["init", "--quiet"]);
+    git(&["add", "src", "README.md"]);
+    git(&["commit", "--quiet", "-m", "template"]);
+    Template::load(&format!("file://{}", template.display()))?.instantiate(
+        &directory.join("cloned"),
+        "cloned",
+        "testnet3",
+    )?;
+    assert_eq!(read("cloned/src/main.leo"), "program cloned.aleo {}\n");
+    assert_eq!(read("cloned/README.md"), "# cloned\nDeploy to testnet3.\n");
+    assert!(!directory.join("cloned/.git").exists());
+
+    assert!(Template::load("not_an_example").is_err());
+
+    Ok(())
+}
+
 // todo (collin): uncomment after refactor
 // #[test]
 // pub fn build_pedersen_hash() -> Result<()> {
@@ -24,6 +24,12 @@ use std::{
 
 pub static BUILD_DIRECTORY_NAME: &str = "build/";
 
+/// The file that maps the Aleo instructions of the program back to its Leo source.
+pub static DEBUG_INFO_FILE_NAME: &str = "main.aleo.map";
+
+/// The file that describes the interface of the program to its clients.
+pub static ABI_FILE_NAME: &str = "abi.json";
+
 pub struct BuildDirectory;
 
 impl BuildDirectory {
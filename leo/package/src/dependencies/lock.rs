@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The lock file `leo.lock`, recording the resolved dependencies of a package.
+
+use crate::dependencies::DependencySource;
+use leo_errors::{PackageError, Result};
+
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fs, path::Path};
+
+pub static LOCK_FILENAME: &str = "leo.lock";
+
+/// A dependency as it was resolved, with the checksum of its program.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedPackage {
+    /// The name of the program.
+    pub name: String,
+    /// Where the program was fetched from.
+    pub source: DependencySource,
+    /// The SHA-256 checksum of the program source.
+    pub checksum: String,
+    /// The names of the direct dependencies of the program.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<String>,
+}
+
+/// The resolved dependencies of a package, ordered such that every package comes after its dependencies.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockFile {
+    #[serde(default, rename = "package")]
+    pub packages: Vec<LockedPackage>,
+}
+
+impl LockFile {
+    pub fn exists_at(path: &Path) -> bool {
+        Self::setup_file_path(path).exists()
+    }
+
+    /// Reads the lock file at the given path, or returns an empty lock file if it does not exist.
+    pub fn read_from(path: &Path) -> Result<Self> {
+        let path = Self::setup_file_path(path);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let string = fs::read_to_string(&path).map_err(PackageError::failed_to_read_lock_file)?;
+        Ok(toml::from_str(&string).map_err(PackageError::failed_to_read_lock_file)?)
+    }
+
+    /// Writes the lock file to the given path.
+    pub fn write_to(&self, path: &Path) -> Result<()> {
+        let path = Self::setup_file_path(path);
+        let string = toml::to_string(self).map_err(PackageError::failed_to_write_lock_file)?;
+
+        fs::write(path, format!("# This file is generated by Leo. Do not edit it manually.\n\n{string}"))
+            .map_err(PackageError::failed_to_write_lock_file)?;
+        Ok(())
+    }
+
+    /// Returns the locked package with the given name, if it exists.
+    pub fn get(&self, name: &str) -> Option<&LockedPackage> {
+        self.packages.iter().find(|package| package.name == name)
+    }
+
+    /// Returns the position of the package with the given name in dependency order, if it exists.
+    pub fn position(&self, name: &str) -> Option<usize> {
+        self.packages.iter().position(|package| package.name == name)
+    }
+
+    fn setup_file_path(path: &Path) -> Cow<'_, Path> {
+        let mut path = Cow::from(path);
+        if path.is_dir() {
+            path.to_mut().push(LOCK_FILENAME);
+        }
+        path
+    }
+}
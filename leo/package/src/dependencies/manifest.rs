@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The `dependencies` section of the manifest file `program.json`.
+
+use leo_errors::{PackageError, Result};
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    fmt,
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub static MANIFEST_FILENAME: &str = "program.json";
+
+/// The key of the dependencies in the manifest file.
+static DEPENDENCIES_KEY: &str = "dependencies";
+
+/// Where the program of a dependency is fetched from.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DependencySource {
+    /// A Leo package in a local directory, relative to the package that depends on it, e.g. `{ "path": "../token" }`.
+    Path { path: PathBuf },
+    /// A version of a Leo package published to a registry, e.g. `{ "version": "0.1.0" }`.
+    Registry { version: String },
+}
+
+impl fmt::Display for DependencySource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Path { path } => write!(f, "path `{}`", path.display()),
+            Self::Registry { version } => write!(f, "version `{version}`"),
+        }
+    }
+}
+
+/// The dependencies of a package, in the order they are declared.
+pub type DependencyMap = IndexMap<String, DependencySource>;
+
+pub struct Dependencies;
+
+impl Dependencies {
+    /// Reads the dependencies declared in the manifest of the package at the given path.
+    pub fn read_from(path: &Path) -> Result<DependencyMap> {
+        let manifest = Self::open(path)?;
+        Self::from_manifest(&manifest)
+            .map_err(|err| PackageError::failed_to_read_dependencies(MANIFEST_FILENAME, err).into())
+    }
+
+    /// Parses the dependencies declared in the given manifest string.
+    pub fn parse(manifest: &str) -> std::result::Result<DependencyMap, serde_json::Error> {
+        Self::from_manifest(&serde_json::from_str(manifest)?)
+    }
+
+    /// Writes the dependencies into the manifest of the package at the given path, keeping its other fields.
+    pub fn write_to(path: &Path, dependencies: &DependencyMap) -> Result<()> {
+        let mut manifest = Self::open(path)?;
+        let fields = manifest.as_object_mut().ok_or_else(|| {
+            PackageError::failed_to_read_dependencies(MANIFEST_FILENAME, "the manifest is not a JSON object")
+        })?;
+
+        match dependencies.is_empty() {
+            true => fields.remove(DEPENDENCIES_KEY),
+            false => fields.insert(
+                DEPENDENCIES_KEY.to_string(),
+                serde_json::to_value(dependencies).map_err(PackageError::failed_to_write_manifest)?,
+            ),
+        };
+
+        let manifest = serde_json::to_string_pretty(&manifest).map_err(PackageError::failed_to_write_manifest)?;
+        fs::write(Self::setup_file_path(path), format!("{manifest}\n"))
+            .map_err(PackageError::failed_to_write_manifest)?;
+        Ok(())
+    }
+
+    fn open(path: &Path) -> Result<Value> {
+        let path = Self::setup_file_path(path);
+        let manifest = fs::read_to_string(&path).map_err(PackageError::failed_to_open_manifest)?;
+        serde_json::from_str(&manifest)
+            .map_err(|err| PackageError::failed_to_read_dependencies(MANIFEST_FILENAME, err).into())
+    }
+
+    fn from_manifest(manifest: &Value) -> std::result::Result<DependencyMap, serde_json::Error> {
+        match manifest.get(DEPENDENCIES_KEY) {
+            Some(dependencies) => serde_json::from_value(dependencies.clone()),
+            None => Ok(DependencyMap::new()),
+        }
+    }
+
+    fn setup_file_path(path: &Path) -> PathBuf {
+        match path.is_dir() {
+            true => path.join(MANIFEST_FILENAME),
+            false => path.to_path_buf(),
+        }
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Registries that Leo packages are published to.
+
+use crate::{dependencies::MANIFEST_FILENAME, source::MainFile};
+use leo_errors::{PackageError, Result};
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The files of a Leo package that are needed to depend on it.
+#[derive(Clone, Debug)]
+pub struct FetchedPackage {
+    /// The manifest `program.json`, declaring the dependencies of the package.
+    pub manifest: String,
+    /// The program source `src/main.leo`.
+    pub program: String,
+}
+
+impl FetchedPackage {
+    /// Reads the package at the given directory, or returns `None` if it is not a package.
+    pub fn read_from(path: &Path) -> Result<Option<Self>> {
+        let manifest_path = path.join(MANIFEST_FILENAME);
+        let program_path = path.join(MainFile::filename());
+        if !manifest_path.is_file() || !program_path.is_file() {
+            return Ok(None);
+        }
+
+        let manifest = fs::read_to_string(&manifest_path)
+            .map_err(|err| PackageError::failed_to_read_file(manifest_path.display(), err))?;
+        let program = fs::read_to_string(&program_path)
+            .map_err(|err| PackageError::failed_to_read_file(program_path.display(), err))?;
+        Ok(Some(Self { manifest, program }))
+    }
+}
+
+/// A source of published Leo packages.
+///
+/// Registries on the network implement this trait, so that a [`DirectoryRegistry`] can stand in for them offline and in tests.
+pub trait Registry {
+    /// Fetches the given version of the package `name`.
+    fn fetch(&self, name: &str, version: &str) -> Result<FetchedPackage>;
+}
+
+/// A registry in a local directory, holding the package `name` at version `version` in `name/version/`.
+pub struct DirectoryRegistry {
+    root: PathBuf,
+}
+
+impl DirectoryRegistry {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl Registry for DirectoryRegistry {
+    fn fetch(&self, name: &str, version: &str) -> Result<FetchedPackage> {
+        let path = self.root.join(name).join(version);
+        FetchedPackage::read_from(&path)?
+            .ok_or_else(|| PackageError::dependency_not_found(name, format!("`{}`", path.display())).into())
+    }
+}
@@ -0,0 +1,306 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Resolution of the dependencies of a package into its `imports/` directory.
+
+use crate::{
+    dependencies::{
+        Dependencies,
+        DependencyMap,
+        DependencySource,
+        FetchedPackage,
+        LockFile,
+        LockedPackage,
+        Registry,
+        MANIFEST_FILENAME,
+    },
+    imports::{ImportsDirectory, IMPORTS_DIRECTORY_NAME},
+    LEO_FILE_EXTENSION,
+};
+use leo_errors::{PackageError, Result};
+
+use indexmap::IndexMap;
+use sha2::{Digest, Sha256};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// A dependency that has been fetched.
+struct ResolvedPackage {
+    /// The entry of the package in the lock file.
+    locked: LockedPackage,
+    /// The program source of the package.
+    program: String,
+    /// The canonical location of the package, which every package depending on it must agree on.
+    location: String,
+}
+
+/// Resolves the dependencies of a package, transitively and in dependency order.
+pub struct Resolver<'a> {
+    /// The path of the package whose dependencies are resolved.
+    path: &'a Path,
+    /// The registry that registry dependencies are fetched from.
+    registry: &'a dyn Registry,
+    /// The previous lock file, whose checksums the packages fetched from the registry must match.
+    lock_file: LockFile,
+    /// The resolved packages, ordered such that every package comes after its dependencies.
+    resolved: IndexMap<String, ResolvedPackage>,
+    /// The names of the packages being resolved, from a dependency of the package to the current one.
+    stack: Vec<String>,
+}
+
+impl<'a> Resolver<'a> {
+    /// Resolves the dependencies declared in the manifest of the package at the given path,
+    /// writes their programs to its `imports/` directory, and records them in its lock file.
+    pub fn resolve(path: &'a Path, registry: &'a dyn Registry) -> Result<LockFile> {
+        let dependencies = Dependencies::read_from(path)?;
+        let mut resolver = Self {
+            path,
+            registry,
+            lock_file: LockFile::read_from(path)?,
+            resolved: IndexMap::new(),
+            stack: Vec::new(),
+        };
+        for (name, source) in dependencies.iter() {
+            resolver.visit(name, source, Some(path), "")?;
+        }
+
+        // Remove the programs of the packages that are no longer depended on.
+        for package in
+            resolver.lock_file.packages.iter().filter(|package| !resolver.resolved.contains_key(&package.name))
+        {
+            let program_path = Self::program_path(path, &package.name);
+            if program_path.exists() {
+                fs::remove_file(&program_path)
+                    .map_err(|err| PackageError::io_error_dependency_file(&package.name, err))?;
+            }
+        }
+
+        if !resolver.resolved.is_empty() {
+            ImportsDirectory::create(path)?;
+        }
+        for (name, package) in resolver.resolved.iter() {
+            let program_path = Self::program_path(path, name);
+            fs::write(program_path, &package.program)
+                .map_err(|err| PackageError::io_error_dependency_file(name, err))?;
+        }
+
+        let lock_file = LockFile { packages: resolver.resolved.into_values().map(|package| package.locked).collect() };
+        if !lock_file.packages.is_empty() || LockFile::exists_at(path) {
+            lock_file.write_to(path)?;
+        }
+        Ok(lock_file)
+    }
+
+    /// Returns the SHA-256 checksum of a program source.
+    pub fn checksum(program: &str) -> String {
+        format!("{:x}", Sha256::digest(program.as_bytes()))
+    }
+
+    /// Resolves the dependency `name` of the package `dependent`, after resolving its own dependencies.
+    /// Path dependencies are relative to `directory`, the directory of the dependent if it is a local package.
+    fn visit(
+        &mut self,
+        name: &str,
+        source: &DependencySource,
+        directory: Option<&Path>,
+        dependent: &str,
+    ) -> Result<()> {
+        if let Some(start) = self.stack.iter().position(|package| package == name) {
+            let mut cycle = self.stack[start..].to_vec();
+            cycle.push(name.to_string());
+            return Err(PackageError::dependency_cycle(cycle.join(" -> ")).into());
+        }
+
+        let package_directory = match source {
+            DependencySource::Path { path } => match directory {
+                Some(directory) => Some(directory.join(path)),
+                None => return Err(PackageError::unresolvable_path_dependency(name, dependent).into()),
+            },
+            DependencySource::Registry { .. } => None,
+        };
+        let location = match (&package_directory, source) {
+            (Some(package_directory), _) => {
+                package_directory.canonicalize().unwrap_or_else(|_| package_directory.clone()).display().to_string()
+            }
+            (None, source) => source.to_string(),
+        };
+
+        // A package that is depended on more than once is only resolved once.
+        if let Some(resolved) = self.resolved.get(name) {
+            return match resolved.location == location {
+                true => Ok(()),
+                false => Err(PackageError::conflicting_dependency(name, &resolved.locked.source, source).into()),
+            };
+        }
+
+        let (program, dependencies) = match (&package_directory, source) {
+            (Some(package_directory), _) => {
+                let package = FetchedPackage::read_from(package_directory)?.ok_or_else(|| {
+                    PackageError::dependency_not_found(name, format!("`{}`", package_directory.display()))
+                })?;
+                (package.program, Self::parse_dependencies(name, &package.manifest)?)
+            }
+            (None, DependencySource::Registry { version }) => match self.locked_program(name, source) {
+                Some(locked) => locked,
+                None => {
+                    let package = self.registry.fetch(name, version)?;
+                    (package.program, Self::parse_dependencies(name, &package.manifest)?)
+                }
+            },
+            (None, DependencySource::Path { .. }) => unreachable!("Path dependencies have a directory."),
+        };
+
+        // A published package must not change once it is locked.
+        let checksum = Self::checksum(&program);
+        if let Some(locked) = self.lock_file.get(name) {
+            if matches!(source, DependencySource::Registry { .. })
+                && locked.source == *source
+                && locked.checksum != checksum
+            {
+                return Err(PackageError::checksum_mismatch(name, &locked.checksum, checksum).into());
+            }
+        }
+
+        self.stack.push(name.to_string());
+        for (dependency, dependency_source) in dependencies.iter() {
+            self.visit(dependency, dependency_source, package_directory.as_deref(), name)?;
+        }
+        self.stack.pop();
+
+        let locked = LockedPackage {
+            name: name.to_string(),
+            source: source.clone(),
+            checksum,
+            dependencies: dependencies.keys().cloned().collect(),
+        };
+        self.resolved.insert(name.to_string(), ResolvedPackage { locked, program, location });
+        Ok(())
+    }
+
+    /// Returns the program and the dependencies of a locked registry package, if its program is in the imports directory.
+    /// Published packages are immutable, so they are only fetched once.
+    fn locked_program(&self, name: &str, source: &DependencySource) -> Option<(String, DependencyMap)> {
+        let locked = self.lock_file.get(name).filter(|locked| locked.source == *source)?;
+        let program = fs::read_to_string(Self::program_path(self.path, name)).ok()?;
+        let dependencies = locked
+            .dependencies
+            .iter()
+            .map(|dependency| Some((dependency.clone(), self.lock_file.get(dependency)?.source.clone())))
+            .collect::<Option<DependencyMap>>()?;
+        Some((program, dependencies))
+    }
+
+    fn parse_dependencies(name: &str, manifest: &str) -> Result<DependencyMap> {
+        Dependencies::parse(manifest)
+            .map_err(|err| PackageError::failed_to_read_dependencies(format!("{name}/{MANIFEST_FILENAME}"), err).into())
+    }
+
+    fn program_path(path: &Path, name: &str) -> PathBuf {
+        path.join(IMPORTS_DIRECTORY_NAME).join(format!("{name}{LEO_FILE_EXTENSION}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dependencies::DirectoryRegistry;
+
+    /// Creates a package at `path` with the given dependencies, as they are written in its manifest.
+    fn create_package(path: &Path, name: &str, dependencies: &str) {
+        fs::create_dir_all(path.join("src")).unwrap();
+        let manifest = format!("{{ \"program\": \"{name}.aleo\", \"dependencies\": {{ {dependencies} }} }}");
+        fs::write(path.join(MANIFEST_FILENAME), manifest).unwrap();
+        fs::write(path.join("src/main.leo"), format!("program {name}.aleo {{}}\n")).unwrap();
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("leo-dependencies-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        directory
+    }
+
+    #[test]
+    fn test_resolve_in_dependency_order() {
+        let directory = temp_dir("order");
+        let registry = DirectoryRegistry::new(directory.join("registry"));
+        create_package(&directory.join("registry/math/0.1.0"), "math", "");
+        create_package(&directory.join("registry/token/0.1.0"), "token", r#""math": { "version": "0.1.0" }"#);
+        create_package(&directory.join("utils"), "utils", r#""math": { "version": "0.1.0" }"#);
+        create_package(
+            &directory.join("app"),
+            "app",
+            r#""token": { "version": "0.1.0" }, "utils": { "path": "../utils" }"#,
+        );
+
+        let lock_file = Resolver::resolve(&directory.join("app"), &registry).unwrap();
+        let names = lock_file.packages.iter().map(|package| package.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["math", "token", "utils"]);
+        assert_eq!(lock_file.get("token").unwrap().dependencies, ["math"]);
+        assert_eq!(lock_file.get("math").unwrap().checksum, Resolver::checksum("program math.aleo {}\n"));
+        assert!(directory.join("app/imports/math.leo").exists());
+
+        // The lock file is stable.
+        assert_eq!(LockFile::read_from(&directory.join("app")).unwrap(), lock_file);
+        assert_eq!(Resolver::resolve(&directory.join("app"), &registry).unwrap(), lock_file);
+
+        // The programs of the dependencies that are removed are removed from the imports directory.
+        Dependencies::write_to(
+            &directory.join("app"),
+            &[("token".to_string(), lock_file.packages[1].source.clone())].into_iter().collect(),
+        )
+        .unwrap();
+        let lock_file = Resolver::resolve(&directory.join("app"), &registry).unwrap();
+        assert_eq!(lock_file.packages.len(), 2);
+        assert!(!directory.join("app/imports/utils.leo").exists());
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_checksum_mismatch() {
+        let directory = temp_dir("checksum");
+        let registry = DirectoryRegistry::new(directory.join("registry"));
+        create_package(&directory.join("registry/token/0.1.0"), "token", "");
+        create_package(&directory.join("app"), "app", r#""token": { "version": "0.1.0" }"#);
+        Resolver::resolve(&directory.join("app"), &registry).unwrap();
+
+        // A locked package is not fetched again, unless its program is missing.
+        fs::write(directory.join("registry/token/0.1.0/src/main.leo"), "program token.aleo { }\n").unwrap();
+        assert!(Resolver::resolve(&directory.join("app"), &registry).is_ok());
+
+        // A published package that changes after it is locked is rejected.
+        fs::remove_file(directory.join("app/imports/token.leo")).unwrap();
+        assert!(Resolver::resolve(&directory.join("app"), &registry).is_err());
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_dependency_cycle() {
+        let directory = temp_dir("cycle");
+        let registry = DirectoryRegistry::new(directory.join("registry"));
+        create_package(&directory.join("a"), "a", r#""b": { "path": "../b" }"#);
+        create_package(&directory.join("b"), "b", r#""a": { "path": "../a" }"#);
+        create_package(&directory.join("app"), "app", r#""a": { "path": "../a" }"#);
+
+        let error = Resolver::resolve(&directory.join("app"), &registry).unwrap_err();
+        assert!(error.to_string().contains("a -> b -> a"));
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
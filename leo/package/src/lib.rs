@@ -18,6 +18,7 @@
 #![doc = include_str!("../README.md")]
 
 pub mod build;
+pub mod dependencies;
 pub mod imports;
 pub mod inputs;
 pub mod outputs;
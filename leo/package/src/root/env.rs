@@ -19,7 +19,7 @@ use leo_errors::{PackageError, Result};
 use snarkvm_console::{account::PrivateKey, prelude::Network};
 
 use serde::Deserialize;
-use std::{borrow::Cow, fs::File, io::Write, marker::PhantomData, path::Path};
+use std::{borrow::Cow, fs::File, io::Write, marker::PhantomData, path::Path, str::FromStr};
 
 pub static ENV_FILENAME: &str = ".env";
 
@@ -57,6 +57,23 @@ impl<N: Network> Env<N> {
         Ok(())
     }
 
+    /// Reads the private key from the `.env` file at the given path, or in the given directory.
+    pub fn private_key(path: &Path) -> Result<PrivateKey<N>> {
+        let mut path = Cow::from(path);
+        if path.is_dir() {
+            path.to_mut().push(ENV_FILENAME);
+        }
+
+        for item in dotenvy::from_path_iter(&path).map_err(PackageError::io_error_env_file)? {
+            let (key, value) = item.map_err(PackageError::io_error_env_file)?;
+            if key == "PRIVATE_KEY" {
+                return PrivateKey::from_str(&value)
+                    .map_err(|error| PackageError::failed_to_parse_private_key(error).into());
+            }
+        }
+        Err(PackageError::missing_private_key(path).into())
+    }
+
     fn template() -> Result<String> {
         // Initialize an RNG.
         let rng = &mut rand::thread_rng();
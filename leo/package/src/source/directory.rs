@@ -57,11 +57,28 @@ impl SourceDirectory {
     }
 
     /// Check that the files in the source directory are valid.
+    /// The program scope is declared in `src/main.leo`, any other file is a module of the program.
     pub fn check_files(paths: &[PathBuf]) -> Result<()> {
-        match paths.len() {
-            0 => Err(PackageError::empty_source_directory().into()),
-            1 if paths[0].as_path().ends_with(MAIN_FILENAME) => Ok(()),
-            _ => Err(PackageError::source_directory_can_contain_only_one_file().into()),
+        if paths.is_empty() {
+            return Err(PackageError::empty_source_directory().into());
+        }
+
+        match paths.iter().any(|path| is_main_file(path)) {
+            true => Ok(()),
+            false => Err(PackageError::missing_main_file().into()),
         }
     }
+
+    /// Check that the files in the source directory other than `src/main.leo` are the `modules` used by the program.
+    pub fn check_modules(paths: &[PathBuf], modules: &[PathBuf]) -> Result<()> {
+        match paths.iter().find(|path| !is_main_file(path) && !modules.contains(path)) {
+            Some(path) => Err(PackageError::unused_source_file(path).into()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Returns `true` if the path is the main file `src/main.leo`.
+fn is_main_file(path: &Path) -> bool {
+    path.ends_with(MAIN_FILENAME) && path.parent().map_or(false, |parent| parent.ends_with(SOURCE_DIRECTORY_NAME))
 }
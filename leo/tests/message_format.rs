@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Checks that the `leo` binary writes its errors and warnings as JSON messages with `--message-format json`.
+
+use assert_cmd::Command;
+use std::path::Path;
+use test_dir::{DirBuilder, TestDir};
+
+/// Parses each line of `output` as a JSON message.
+fn parse_messages(output: &str) -> Vec<serde_json::Value> {
+    output
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap_or_else(|_| panic!("A line is not a JSON message: {line}")))
+        .collect()
+}
+
+/// Runs `leo` in `directory` with `--message-format json`, writing the messages to the `messages.json` file.
+/// Returns whether the command succeeded, and the messages it wrote.
+fn leo_json(directory: &Path, args: &[&str]) -> (bool, Vec<serde_json::Value>) {
+    let message_output = directory.join("messages.json");
+    let output = Command::cargo_bin("leo")
+        .unwrap()
+        .current_dir(directory)
+        .args(args)
+        .args(["--message-format", "json", "--message-output"])
+        .arg(&message_output)
+        .output()
+        .unwrap();
+    (output.status.success(), parse_messages(&std::fs::read_to_string(message_output).unwrap()))
+}
+
+#[test]
+fn json_messages() {
+    let directory = TestDir::temp();
+    let (success, messages) = leo_json(directory.root(), &["new", "json_output"]);
+    assert!(success && messages.is_empty(), "{messages:?}");
+    let package = directory.root().join("json_output");
+
+    // A program that runs writes no messages, even though snarkVM prints its output.
+    let (success, messages) = leo_json(&package, &["run", "main", "1u32", "2u32"]);
+    assert!(success && messages.is_empty(), "{messages:?}");
+
+    // A program that fails to build writes its errors as messages.
+    std::fs::write(
+        package.join("src/main.leo"),
+        "program json_output.aleo {\n    transition main(a: u32) -> u32 {\n        return a + true;\n    }\n}\n",
+    )
+    .unwrap();
+    let (success, build_messages) = leo_json(&package, &["build"]);
+    assert!(!success && !build_messages.is_empty(), "{build_messages:?}");
+
+    // Without a file, the messages are written to stdout.
+    let output = Command::cargo_bin("leo")
+        .unwrap()
+        .current_dir(&package)
+        .args(["build", "--message-format", "json"])
+        .output()
+        .unwrap();
+    assert_eq!(parse_messages(&String::from_utf8(output.stdout).unwrap()), build_messages);
+}
@@ -41,6 +41,8 @@ enum BenchMode {
     Ssa,
     /// Benchmarks flattening.
     Flatten,
+    /// Benchmarks constant folding.
+    Fold,
     /// Benchmarks destructuring.
     Destructure,
     /// Benchmarks function inlining.
@@ -88,7 +90,7 @@ fn new_compiler(handler: &Handler) -> Compiler<'_> {
         PathBuf::from(String::new()),
         PathBuf::from(String::new()),
         Some(CompilerOptions {
-            build: BuildOptions { dce_enabled: true },
+            build: BuildOptions { dce_enabled: true, tests_enabled: false },
             output: OutputOptions {
                 symbol_table_spans_enabled: false,
                 initial_symbol_table: false,
@@ -100,6 +102,7 @@ fn new_compiler(handler: &Handler) -> Compiler<'_> {
                 unrolled_ast: false,
                 ssa_ast: false,
                 flattened_ast: false,
+                const_folded_ast: false,
                 destructured_ast: false,
                 inlined_ast: false,
                 dce_ast: false,
@@ -128,6 +131,7 @@ impl Sample {
             BenchMode::Unroll => self.bench_loop_unroller(c),
             BenchMode::Ssa => self.bench_ssa(c),
             BenchMode::Flatten => self.bench_flattener(c),
+            BenchMode::Fold => self.bench_constant_folder(c),
             BenchMode::Destructure => self.bench_destructurer(c),
             BenchMode::Inline => self.bench_inline(c),
             BenchMode::Dce => self.bench_dce(c),
@@ -233,6 +237,22 @@ impl Sample {
         });
     }
 
+    fn bench_constant_folder(&self, c: &mut Criterion) {
+        self.bencher_after_parse(c, "constant folding pass", |mut compiler| {
+            let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
+            let (symbol_table, _struct_graph, _call_graph) =
+                compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
+            let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
+            compiler.static_single_assignment_pass(&symbol_table).expect("failed to run ssa pass");
+            compiler.flattening_pass(&symbol_table).expect("failed to run flattener pass");
+            let start = Instant::now();
+            let out = compiler.constant_folding_pass();
+            let time = start.elapsed();
+            out.expect("failed to run constant folding pass");
+            time
+        });
+    }
+
     fn bench_destructurer(&self, c: &mut Criterion) {
         self.bencher_after_parse(c, "destructurer pass", |mut compiler| {
             let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
@@ -241,6 +261,7 @@ impl Sample {
             let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
             compiler.static_single_assignment_pass(&symbol_table).expect("failed to run ssa pass");
             compiler.flattening_pass(&symbol_table).expect("failed to run flattener pass");
+            compiler.constant_folding_pass().expect("failed to run constant folding pass");
             let start = Instant::now();
             let out = compiler.destructuring_pass();
             let time = start.elapsed();
@@ -257,6 +278,7 @@ impl Sample {
             let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
             compiler.static_single_assignment_pass(&symbol_table).expect("failed to run ssa pass");
             compiler.flattening_pass(&symbol_table).expect("failed to run flattener pass");
+            compiler.constant_folding_pass().expect("failed to run constant folding pass");
             compiler.destructuring_pass().expect("failed to run destructurer pass");
             let start = Instant::now();
             let out = compiler.function_inlining_pass(&call_graph);
@@ -274,6 +296,7 @@ impl Sample {
             let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
             compiler.static_single_assignment_pass(&symbol_table).expect("failed to run ssa pass");
             compiler.flattening_pass(&symbol_table).expect("failed to run flattener pass");
+            compiler.constant_folding_pass().expect("failed to run constant folding pass");
             compiler.destructuring_pass().expect("failed to run destructurer pass");
             compiler.function_inlining_pass(&call_graph).expect("failed to run inliner pass");
             let start = Instant::now();
@@ -292,6 +315,7 @@ impl Sample {
             let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
             compiler.static_single_assignment_pass(&symbol_table).expect("failed to run ssa pass");
             compiler.flattening_pass(&symbol_table).expect("failed to run flattener pass");
+            compiler.constant_folding_pass().expect("failed to run constant folding pass");
             compiler.destructuring_pass().expect("failed to run destructurer pass");
             compiler.function_inlining_pass(&call_graph).expect("failed to run inliner pass");
             compiler.dead_code_elimination_pass().expect("failed to run dce pass");
@@ -314,6 +338,7 @@ impl Sample {
             let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
             compiler.static_single_assignment_pass(&symbol_table).expect("failed to run ssa pass");
             compiler.flattening_pass(&symbol_table).expect("failed to run flattening pass");
+            compiler.constant_folding_pass().expect("failed to run constant folding pass");
             compiler.destructuring_pass().expect("failed to run destructuring pass");
             compiler.function_inlining_pass(&call_graph).expect("failed to run function inlining pass");
             compiler.dead_code_elimination_pass().expect("failed to run dce pass");
@@ -339,6 +364,7 @@ bench!(bench_type, BenchMode::Type);
 bench!(bench_unroll, BenchMode::Unroll);
 bench!(bench_ssa, BenchMode::Ssa);
 bench!(bench_flatten, BenchMode::Flatten);
+bench!(bench_fold, BenchMode::Fold);
 bench!(bench_destructure, BenchMode::Destructure);
 bench!(bench_inline, BenchMode::Inline);
 bench!(bench_dce, BenchMode::Dce);
@@ -355,6 +381,7 @@ criterion_group!(
         bench_unroll,
         bench_ssa,
         bench_flatten,
+        bench_fold,
         bench_destructure,
         bench_inline,
         bench_dce,